@@ -8,8 +8,9 @@ use thiserror::Error;
 use klick_boundary::{
     self as boundary,
     json_api::{
-        self, ApiToken, ConfirmEmailAddress, Credentials, DownloadId, DownloadRequestResponse,
-        DownloadStatus, RequestPasswordReset, ResetPassword, UserInfo,
+        self, ApiToken, ChangeEmailAddress, ChangePassword, ConfirmEmailAddress, Credentials,
+        DeleteAccount, DownloadId, DownloadRequestResponse, DownloadStatus, RequestPasswordReset,
+        ResetPassword, UserInfo,
     },
     FormData, JsonFormData, SavedProject,
 };
@@ -97,6 +98,15 @@ impl UnauthorizedApi {
             .await?;
         into_json(response).await
     }
+
+    pub async fn confirm_email_address_change(&self, token: String) -> Result<(), Value> {
+        let url = format!("{}/users/confirm-email-address-change", self.url);
+        let response = Request::post(&url)
+            .json(&ConfirmEmailAddress { token })?
+            .send()
+            .await?;
+        into_json(response).await
+    }
 }
 
 impl AuthorizedApi {
@@ -144,6 +154,53 @@ impl AuthorizedApi {
         self.send(Request::get(&url)).await
     }
 
+    pub async fn change_password(
+        &self,
+        old_password: String,
+        new_password: String,
+    ) -> Result<(), Value> {
+        let url = format!("{}/users/change-password", self.url);
+        let data = ChangePassword {
+            old_password,
+            new_password,
+        };
+        self.send_with_json(Request::post(&url), &data).await
+    }
+
+    pub async fn change_email_address(
+        &self,
+        new_email: String,
+        password: String,
+    ) -> Result<(), Value> {
+        let url = format!("{}/users/change-email-address", self.url);
+        let data = ChangeEmailAddress {
+            new_email,
+            password,
+        };
+        self.send_with_json(Request::post(&url), &data).await
+    }
+
+    pub async fn delete_account(&self, password: String) -> Result<(), Value> {
+        let url = format!("{}/users", self.url);
+        self.send_with_json(Request::delete(&url), &DeleteAccount { password })
+            .await
+    }
+
+    /// Download all data of the account as ZIP archive.
+    pub async fn export_account_data(&self) -> Result<Vec<u8>, Value> {
+        let url = format!("{}/users/export", self.url);
+        let response = Request::get(&url)
+            .header("Authorization", &self.auth_header_value())
+            .send()
+            .await?;
+        if response.ok() {
+            Ok(response.binary().await?)
+        } else {
+            log::warn!("Response status: {}", response.status());
+            Err(response.json::<json_api::Error<Value>>().await?.into())
+        }
+    }
+
     #[must_use]
     pub const fn token(&self) -> &ApiToken {
         &self.token
@@ -166,8 +223,12 @@ impl AuthorizedApi {
         self.send(Request::get(&url)).await
     }
 
+    /// Update a project.
+    ///
+    /// Fails with a conflict if the project has been modified
+    /// since `project.modified_at`.
     // TODO: use domain values as argument
-    pub async fn update_project(&self, project: &SavedProject) -> Result<(), Value> {
+    pub async fn update_project(&self, project: &SavedProject) -> Result<SavedProject, Value> {
         let id = ProjectId::from(project.id);
         let url = format!("{}/project/{id}", self.url);
         self.send_with_json(Request::put(&url), project).await
@@ -208,6 +269,14 @@ pub enum Error<T> {
     Api(json_api::Error<T>),
 }
 
+impl<T> Error<T> {
+    /// The resource has been modified in the meantime.
+    #[must_use]
+    pub fn is_conflict(&self) -> bool {
+        matches!(self, Self::Api(err) if err.status.as_u16() == 409)
+    }
+}
+
 impl<T> From<json_api::Error<T>> for Error<T> {
     fn from(e: json_api::Error<T>) -> Self {
         Self::Api(e)
@@ -0,0 +1,229 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+#[allow(clippy::wildcard_imports)]
+use leptos::*;
+
+use klick_presenter::Lng;
+
+use crate::{co2_equivalents_unit, Color};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonutChartSegment {
+    pub label: String,
+    pub value: f64,
+    pub color: Color,
+}
+
+/// A group of the inner ring and its segments of the outer ring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DonutChartArguments {
+    pub group: DonutChartSegment,
+    pub segments: Vec<DonutChartSegment>,
+}
+
+const MARGIN: f64 = 10.0;
+const FONT_SIZE: f64 = 16.0;
+const LEGEND_LINE_HEIGHT: f64 = 1.5;
+
+/// Donut chart with the shares of the groups (inner ring)
+/// and their segments (outer ring) of the total.
+///
+/// Each share is drawn in proportion to the sum of all groups,
+/// negative values are ignored.
+#[allow(
+    clippy::module_name_repetitions,
+    clippy::needless_pass_by_value,
+    clippy::cast_precision_loss
+)]
+#[component]
+pub fn DonutChart(
+    width: f64,
+    height: f64,
+    data: Vec<DonutChartArguments>,
+    aria_label: Option<String>,
+    lang: Lng,
+) -> impl IntoView {
+    let total = data.iter().map(|g| g.group.value.max(0.0)).sum::<f64>();
+
+    let radius = (height / 2.0 - MARGIN).min(width / 4.0).max(0.0);
+    let cx = MARGIN + radius;
+    let cy = height / 2.0;
+
+    let rings = rings(&data, total);
+    let legend_x = cx + radius + MARGIN * 3.0;
+
+    let segments = rings
+        .into_iter()
+        .map(|(segment, start, end, ring)| {
+            let (r_inner, r_outer) = match ring {
+                Ring::Inner => (radius * 0.45, radius * 0.75),
+                Ring::Outer => (radius * 0.77, radius),
+            };
+            let title = share_label(&segment, total, lang);
+            view! {
+              <path
+                class = "donut-segment"
+                d = arc_path(cx, cy, r_inner, r_outer, start, end)
+                fill = segment.color.as_str()
+                stroke = "white"
+                stroke-width = 1
+                tabindex = "0"
+                aria-label = title.clone()
+              >
+                <title>{ title }</title>
+              </path>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let legend = legend(&data, total, lang);
+    let legend_height = legend.len() as f64 * FONT_SIZE * LEGEND_LINE_HEIGHT;
+    let legend_y = ((height - legend_height) / 2.0).max(MARGIN) + FONT_SIZE;
+
+    let legend = legend
+        .into_iter()
+        .enumerate()
+        .map(|(i, (text, color, is_group))| {
+            let y = i as f64 * FONT_SIZE * LEGEND_LINE_HEIGHT;
+            let indent = if is_group { 0.0 } else { FONT_SIZE };
+            let font_weight = if is_group { "bold" } else { "normal" };
+            view! {
+              <g transform = format!("translate({indent},{y})")>
+                <rect
+                  y = -FONT_SIZE * 0.8
+                  width = FONT_SIZE * 0.8
+                  height = FONT_SIZE * 0.8
+                  fill = color.as_str()
+                />
+                <text
+                  x = FONT_SIZE * 1.2
+                  font-size = FONT_SIZE
+                  font-weight = font_weight
+                  font-family = "sans-serif"
+                >
+                  { text }
+                </text>
+              </g>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let total_label = lang.format_number_with_fixed_precision(total, 0);
+    let unit = co2_equivalents_unit(lang);
+
+    view! {
+      <svg
+        width = format!("{width}px")
+        height = format!("{height}px")
+        viewBox = format!("0 0 {width} {height}")
+        xmlns = "http://www.w3.org/2000/svg"
+        role = "img"
+        aria-label = aria_label
+      >
+        <g class="donut-segments">{ segments }</g>
+        <text
+          x = cx
+          y = cy
+          text-anchor = "middle"
+          font-size = FONT_SIZE * 1.5
+          font-weight = "bold"
+          font-family = "sans-serif"
+        >
+          { total_label }
+        </text>
+        <text
+          x = cx
+          y = cy + FONT_SIZE * 1.2
+          text-anchor = "middle"
+          font-size = FONT_SIZE
+          font-family = "sans-serif"
+        >
+          { unit }
+        </text>
+        <g class="donut-legend" transform = format!("translate({legend_x},{legend_y})")>
+          { legend }
+        </g>
+      </svg>
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ring {
+    Inner,
+    Outer,
+}
+
+/// Segments with their start and end angle.
+fn rings(data: &[DonutChartArguments], total: f64) -> Vec<(DonutChartSegment, f64, f64, Ring)> {
+    if total <= 0.0 {
+        return vec![];
+    }
+    let mut segments = vec![];
+    let mut group_start = -FRAC_PI_2;
+    for DonutChartArguments {
+        group,
+        segments: outer,
+    } in data
+    {
+        let group_end = group_start + group.value.max(0.0) / total * TAU;
+        let mut start = group_start;
+        for segment in outer {
+            let end = (start + segment.value.max(0.0) / total * TAU).min(group_end);
+            if end > start {
+                segments.push((segment.clone(), start, end, Ring::Outer));
+            }
+            start = end;
+        }
+        if group_end > group_start {
+            segments.push((group.clone(), group_start, group_end, Ring::Inner));
+        }
+        group_start = group_end;
+    }
+    segments
+}
+
+/// Lines of the legend: text, color and if it's a group.
+fn legend(data: &[DonutChartArguments], total: f64, lang: Lng) -> Vec<(String, Color, bool)> {
+    data.iter()
+        .flat_map(|DonutChartArguments { group, segments }| {
+            std::iter::once((share_label(group, total, lang), group.color, true)).chain(
+                segments
+                    .iter()
+                    .map(|s| (share_label(s, total, lang), s.color, false)),
+            )
+        })
+        .collect()
+}
+
+fn share_label(segment: &DonutChartSegment, total: f64, lang: Lng) -> String {
+    let value = lang.format_number_with_fixed_precision(segment.value, 0);
+    let unit = co2_equivalents_unit(lang);
+    if total > 0.0 {
+        let share = lang.format_number_with_fixed_precision(segment.value / total * 100.0, 1);
+        format!("{}: {value} {unit} ({share} %)", segment.label)
+    } else {
+        format!("{}: {value} {unit}", segment.label)
+    }
+}
+
+/// Path of a ring segment between the angles `start` and `end`.
+///
+/// The arcs are split in the middle to support full circles.
+fn arc_path(cx: f64, cy: f64, r_inner: f64, r_outer: f64, start: f64, end: f64) -> String {
+    let point = |r: f64, angle: f64| (cx + r * angle.cos(), cy + r * angle.sin());
+    let middle = (start + end) / 2.0;
+    let (x0, y0) = point(r_outer, start);
+    let (x1, y1) = point(r_outer, middle);
+    let (x2, y2) = point(r_outer, end);
+    let (x3, y3) = point(r_inner, end);
+    let (x4, y4) = point(r_inner, middle);
+    let (x5, y5) = point(r_inner, start);
+    format!(
+        "M{x0},{y0} \
+         A{r_outer},{r_outer} 0 0 1 {x1},{y1} \
+         A{r_outer},{r_outer} 0 0 1 {x2},{y2} \
+         L{x3},{y3} \
+         A{r_inner},{r_inner} 0 0 0 {x4},{y4} \
+         A{r_inner},{r_inner} 0 0 0 {x5},{y5} Z"
+    )
+}
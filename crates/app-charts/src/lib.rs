@@ -1,13 +1,26 @@
 // TODO: merge both barchart components
 mod barchart;
 mod barchart_radioinputs;
+mod donut_chart;
 mod sankey;
+mod stacked_bar_chart;
 
 #[cfg(feature = "ssr")]
 pub mod ssr;
 
+use klick_presenter::Lng;
+
 pub use self::{
     barchart::{BarChart, BarChartArguments},
     barchart_radioinputs::{BarChartRadioInput, BarChartRadioInputArguments},
-    sankey::{sankey_chart, Color, SankeyData},
+    donut_chart::{DonutChart, DonutChartArguments, DonutChartSegment},
+    sankey::{sankey_chart, Color, Sankey, SankeyData},
+    stacked_bar_chart::{StackedBarChart, StackedBarChartArguments, StackedBarChartSegment},
 };
+
+const fn co2_equivalents_unit(lang: Lng) -> &'static str {
+    match lang {
+        Lng::De => "t CO₂-Äq./a",
+        Lng::En => "t CO₂e/a",
+    }
+}
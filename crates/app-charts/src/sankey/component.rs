@@ -0,0 +1,377 @@
+use std::collections::HashSet;
+
+#[allow(clippy::wildcard_imports)]
+use leptos::*;
+
+use klick_presenter::Lng;
+
+use super::{connected_nodes, layout, leader_line, Color, Layout, NodeId, SankeyData};
+
+const STYLE: &str = "
+  .sankey-node, .sankey-edge, .sankey-toggle {
+    cursor: pointer;
+    outline: none;
+  }
+  .sankey-node:hover text.label, .sankey-node:focus text.label {
+    font-weight: bold;
+  }
+  .sankey-node:focus rect, .sankey-edge:hover, .sankey-edge:focus {
+    stroke: #111;
+    stroke-width: 2;
+  }
+  .sankey-toggle:hover circle, .sankey-toggle:focus circle {
+    stroke-width: 3;
+  }
+  .sankey-dimmed {
+    opacity: 0.2;
+  }
+";
+
+#[derive(Debug, Clone, PartialEq)]
+struct Tooltip {
+    x: f64,
+    y: f64,
+    text: String,
+}
+
+/// Interactive sankey chart.
+///
+/// - Hovering or focusing an edge shows its value and its share of the total.
+/// - Clicking a node (or pressing `Enter`) highlights all flows through it,
+///   `Escape` removes the highlighting.
+/// - Nodes with inputs can be collapsed into a single node.
+///
+/// Use [`crate::sankey_chart`] to render a static version.
+#[component]
+#[allow(clippy::needless_pass_by_value)]
+pub fn Sankey(
+    data: SankeyData,
+    width: f64,
+    height: f64,
+    font_size: f64,
+    aria_label: Option<String>,
+    lang: Lng,
+) -> impl IntoView {
+    let margin_x = width * 0.1;
+    let margin_y = height * 0.05;
+    let inner_width = width - margin_x * 2.0;
+    let inner_height = height - margin_y * 2.0;
+
+    let collapsed = RwSignal::new(HashSet::<NodeId>::new());
+    let selected = RwSignal::new(None::<NodeId>);
+    let tooltip = RwSignal::new(None::<Tooltip>);
+
+    let groups = data.groups();
+    let total = data.total_value();
+
+    let chart = move || {
+        let mut data = data.clone();
+        for id in collapsed.get() {
+            data.collapse(id);
+        }
+        let layout = layout(data, inner_width, inner_height, font_size);
+        let deps = layout.deps.clone();
+        let highlighted =
+            create_memo(move |_| selected.get().and_then(|id| connected_nodes(&deps, id)));
+        let is_dimmed = move |ids: &[NodeId]| {
+            highlighted.with(|h| {
+                h.as_ref()
+                    .is_some_and(|h| ids.iter().any(|id| !h.contains(id)))
+            })
+        };
+        let edges = edges(&layout, total, lang, tooltip, is_dimmed);
+        let nodes = nodes(
+            &layout, &groups, font_size, lang, collapsed, selected, is_dimmed,
+        );
+        view! {
+          <g class="sankey-edges">{ edges }</g>
+          <g class="sankey-nodes">{ nodes }</g>
+        }
+    };
+
+    let tooltip_view = move || {
+        tooltip.get().map(|Tooltip { x, y, text }| {
+            #[allow(clippy::cast_precision_loss)]
+            let tooltip_width = text.chars().count() as f64 * font_size * 0.55 + font_size;
+            let tooltip_height = font_size * 2.0;
+            let x = x.clamp(tooltip_width / 2.0, (width - tooltip_width / 2.0).max(0.0));
+            view! {
+              <g
+                class="sankey-tooltip"
+                role="tooltip"
+                pointer-events="none"
+                transform=format!("translate({x},{y})")
+              >
+                <rect
+                  x = -tooltip_width / 2.0
+                  y = -tooltip_height / 2.0
+                  width = tooltip_width
+                  height = tooltip_height
+                  rx = 3
+                  ry = 3
+                  fill = "white"
+                  fill-opacity = 0.95
+                  stroke = "#555"
+                />
+                <text
+                  text-anchor = "middle"
+                  dominant-baseline = "middle"
+                  font-family = "sans-serif"
+                  font-size = font_size
+                  fill = "#111"
+                >
+                  { text }
+                </text>
+              </g>
+            }
+        })
+    };
+
+    view! {
+      <svg
+        width = format!("{width}px")
+        height = format!("{height}px")
+        viewBox = format!("0 0 {width} {height}")
+        xmlns = "http://www.w3.org/2000/svg"
+        role = "img"
+        aria-label = aria_label
+        on:keydown = move |ev: ev::KeyboardEvent| {
+          if ev.key() == "Escape" {
+            selected.set(None);
+            tooltip.set(None);
+          }
+        }
+      >
+        <style>{ STYLE }</style>
+        <g transform=format!("translate(0,{margin_y})")>
+          { chart }
+          { tooltip_view }
+        </g>
+      </svg>
+    }
+}
+
+fn edges(
+    layout: &Layout,
+    total: f64,
+    lang: Lng,
+    tooltip: RwSignal<Option<Tooltip>>,
+    is_dimmed: impl Fn(&[NodeId]) -> bool + Copy + 'static,
+) -> Vec<impl IntoView> {
+    layout
+        .edge_positions
+        .iter()
+        .map(|edge| {
+            let source = layout.real_source(edge.source);
+            let target = layout.real_target(edge.target);
+            let value = layout.data.nodes[&source].value;
+            let text = edge_text(
+                layout.data.nodes[&source]
+                    .label
+                    .as_deref()
+                    .unwrap_or_default(),
+                layout.data.nodes[&target]
+                    .label
+                    .as_deref()
+                    .unwrap_or_default(),
+                value,
+                total,
+                lang,
+            );
+            let center = edge.center();
+            let edge_tooltip = Tooltip {
+                x: center.x,
+                y: center.y,
+                text: text.clone(),
+            };
+            let show_tooltip = move || tooltip.set(Some(edge_tooltip.clone()));
+            let hide_tooltip = move || tooltip.set(None);
+            let fill = edge.color.unwrap_or(Color::new("purple"));
+            let ids = [edge.source, edge.target];
+            view! {
+              <path
+                class = "sankey-edge"
+                class:sankey-dimmed = move || is_dimmed(&ids)
+                d = edge.path()
+                fill = fill.as_str()
+                stroke = fill.as_str()
+                stroke-width = 1
+                tabindex = "0"
+                aria-label = text
+                on:mouseenter = { let show_tooltip = show_tooltip.clone(); move |_| show_tooltip() }
+                on:mouseleave = move |_| hide_tooltip()
+                on:focus = move |_| show_tooltip()
+                on:blur = move |_| hide_tooltip()
+              />
+            }
+        })
+        .collect()
+}
+
+fn nodes(
+    layout: &Layout,
+    groups: &HashSet<NodeId>,
+    font_size: f64,
+    lang: Lng,
+    collapsed: RwSignal<HashSet<NodeId>>,
+    selected: RwSignal<Option<NodeId>>,
+    is_dimmed: impl Fn(&[NodeId]) -> bool + Copy + 'static,
+) -> Vec<View> {
+    let node_width = layout.node_width;
+    let mut ids = layout.node_positions.keys().copied().collect::<Vec<_>>();
+    ids.sort_by_key(|id| id.0);
+    ids.into_iter()
+        .map(|id| {
+            let position = layout.node_positions[&id];
+            let node = &layout.data.nodes[&id];
+            let fill = node.color.unwrap_or(Color::new("magenta"));
+            let rect = view! {
+              <rect
+                x = position.x
+                y = position.y
+                width = node_width
+                height = position.height
+                fill = fill.as_str()
+                stroke = fill.as_str()
+                stroke-width = 1
+              />
+            };
+            let Some(label) = &node.label else {
+                // virtual nodes are only part of the edges
+                return view! {
+                  <g class:sankey-dimmed = move || is_dimmed(&[id])>{ rect }</g>
+                }
+                .into_view();
+            };
+            let label = format!(
+                "{label} {}",
+                lang.format_number_with_fixed_precision(node.value, 0)
+            );
+            let label_x = position.x + node_width + font_size / 2.0;
+            let leader_line = leader_line(
+                position.x + node_width,
+                position.y + position.height / 2.0,
+                label_x,
+                position.label_y,
+            );
+            let toggle_selection = move || {
+                selected.update(|s| *s = if *s == Some(id) { None } else { Some(id) });
+            };
+            let toggle = groups.contains(&id).then(|| {
+                let radius = font_size / 2.0;
+                let x = position.x - radius - 2.0;
+                let y = position.y + position.height / 2.0;
+                group_toggle(id, &label, x, y, radius, font_size, fill, lang, collapsed)
+            });
+            view! {
+              <g
+                class = "sankey-node"
+                class:sankey-dimmed = move || is_dimmed(&[id])
+                role = "button"
+                tabindex = "0"
+                aria-pressed = move || (selected.get() == Some(id)).to_string()
+                aria-label = label.clone()
+                on:click = move |_| toggle_selection()
+                on:keydown = move |ev: ev::KeyboardEvent| {
+                  if is_activation_key(&ev) {
+                    ev.prevent_default();
+                    toggle_selection();
+                  }
+                }
+              >
+                { rect }
+                { leader_line.map(|d| view! {
+                    <path d = d fill = "none" stroke = "#555" stroke-width = 0.5 />
+                  })
+                }
+                <text
+                  class = "label"
+                  x = label_x
+                  y = position.label_y
+                  fill = "#111"
+                  text-anchor = "start"
+                  font-family = "sans-serif"
+                  font-size = font_size
+                  dominant-baseline = "middle"
+                >
+                  { label }
+                </text>
+              </g>
+              { toggle }
+            }
+            .into_view()
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn group_toggle(
+    id: NodeId,
+    label: &str,
+    x: f64,
+    y: f64,
+    radius: f64,
+    font_size: f64,
+    color: Color,
+    lang: Lng,
+    collapsed: RwSignal<HashSet<NodeId>>,
+) -> impl IntoView {
+    let is_expanded = move || !collapsed.with(|c| c.contains(&id));
+    let toggle = move || {
+        collapsed.update(|c| {
+            if !c.remove(&id) {
+                c.insert(id);
+            }
+        });
+    };
+    let label = label.to_string();
+    let aria_label = move || match (lang, is_expanded()) {
+        (Lng::De, true) => format!("Gruppe {label} einklappen"),
+        (Lng::De, false) => format!("Gruppe {label} ausklappen"),
+        (Lng::En, true) => format!("Collapse group {label}"),
+        (Lng::En, false) => format!("Expand group {label}"),
+    };
+    view! {
+      <g
+        class = "sankey-toggle"
+        role = "button"
+        tabindex = "0"
+        aria-expanded = move || is_expanded().to_string()
+        aria-label = aria_label
+        transform = format!("translate({x},{y})")
+        on:click = move |_| toggle()
+        on:keydown = move |ev: ev::KeyboardEvent| {
+          if is_activation_key(&ev) {
+            ev.prevent_default();
+            toggle();
+          }
+        }
+      >
+        <circle r = radius fill = "white" stroke = color.as_str() stroke-width = 2 />
+        <text
+          text-anchor = "middle"
+          dominant-baseline = "central"
+          font-family = "sans-serif"
+          font-size = font_size
+          fill = "#111"
+        >
+          { move || if is_expanded() { "−" } else { "+" } }
+        </text>
+      </g>
+    }
+}
+
+fn edge_text(source: &str, target: &str, value: f64, total: f64, lang: Lng) -> String {
+    let unit = crate::co2_equivalents_unit(lang);
+    let value_label = lang.format_number_with_fixed_precision(value, 2);
+    if total > 0.0 {
+        let share = lang.format_number_with_fixed_precision(value / total * 100.0, 1);
+        format!("{source} → {target}: {value_label} {unit} ({share} %)")
+    } else {
+        format!("{source} → {target}: {value_label} {unit}")
+    }
+}
+
+fn is_activation_key(ev: &ev::KeyboardEvent) -> bool {
+    matches!(ev.key().as_str(), "Enter" | " ")
+}
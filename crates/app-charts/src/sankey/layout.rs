@@ -0,0 +1,482 @@
+//! Layered layout of the sankey chart.
+//!
+//! 1. Every edge spans exactly one layer
+//!    (see [`SankeyData::insert_virtual_nodes`]).
+//! 2. The nodes of each layer are reordered to minimize
+//!    the number of edge crossings (barycenter heuristic).
+//! 3. Each node is placed at the top of its inputs,
+//!    overlapping nodes are pushed apart.
+//! 4. Labels that would overlap are pushed apart.
+
+use std::{cmp::Ordering, collections::HashMap};
+
+use super::{
+    dependencies, edge_path, layers, Color, Dependencies, Node, NodeId, NodePosition, Point,
+    SankeyData,
+};
+
+/// Minimal height of a node (and thickness of an edge) in pixels,
+/// so that very small flows are still visible.
+pub(super) const MIN_NODE_HEIGHT: f64 = 2.0;
+
+/// Line height of a label relative to the font size.
+const LABEL_LINE_HEIGHT: f64 = 1.2;
+
+/// Maximum number of sweeps to reorder the layers.
+const ORDERING_SWEEPS: usize = 8;
+
+/// Positions of all nodes and edges of a chart.
+pub(super) struct Layout {
+    /// The data including the virtual nodes.
+    pub(super) data: SankeyData,
+    pub(super) deps: HashMap<NodeId, Dependencies>,
+    pub(super) node_width: f64,
+    pub(super) node_positions: HashMap<NodeId, NodePosition>,
+    pub(super) edge_positions: Vec<EdgePosition>,
+}
+
+impl Layout {
+    pub(super) fn is_virtual(&self, id: NodeId) -> bool {
+        self.data.nodes[&id].label.is_none()
+    }
+
+    /// The first node before the given one that is not virtual.
+    pub(super) fn real_source(&self, mut id: NodeId) -> NodeId {
+        while self.is_virtual(id) {
+            let Some(input) = self.deps[&id].inputs.first() else {
+                break;
+            };
+            id = *input;
+        }
+        id
+    }
+
+    /// The first node after the given one that is not virtual.
+    pub(super) fn real_target(&self, mut id: NodeId) -> NodeId {
+        while self.is_virtual(id) {
+            let Some(output) = self.deps[&id].outputs.first() else {
+                break;
+            };
+            id = *output;
+        }
+        id
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct EdgePosition {
+    pub(super) source: NodeId,
+    pub(super) target: NodeId,
+    pub(super) from_top: Point,
+    pub(super) from_bottom: Point,
+    pub(super) to_top: Point,
+    pub(super) to_bottom: Point,
+    pub(super) color: Option<Color>,
+}
+
+impl EdgePosition {
+    pub(super) fn path(&self) -> String {
+        edge_path(
+            &self.from_top,
+            &self.from_bottom,
+            &self.to_top,
+            &self.to_bottom,
+        )
+    }
+
+    pub(super) fn center(&self) -> Point {
+        Point::new(
+            (self.from_top.x + self.to_top.x) / 2.0,
+            (self.from_top.y + self.from_bottom.y + self.to_top.y + self.to_bottom.y) / 4.0,
+        )
+    }
+}
+
+pub(super) fn layout(
+    mut sankey_data: SankeyData,
+    width: f64,
+    height: f64,
+    font_size: f64,
+) -> Layout {
+    let node_separation = height / 50.0;
+    let node_width = width / 70.0; // TODO: make this configurable
+    sankey_data.insert_virtual_nodes();
+    let deps = dependencies(&sankey_data.edges);
+    let mut layers = layers(&deps, &sankey_data.nodes);
+    order_layers(&mut layers, &deps);
+    let layer_x_positions = layer_x_positions(layers.len(), width, node_width);
+    let scale = scale(&layers, &sankey_data.nodes, height, node_separation);
+    let mut node_positions = node_positions(
+        &layers,
+        &layer_x_positions,
+        &sankey_data.nodes,
+        &deps,
+        scale,
+        node_separation,
+        height,
+    );
+    place_labels(
+        &layers,
+        &sankey_data.nodes,
+        &mut node_positions,
+        font_size,
+        height,
+    );
+    let edge_positions = edge_positions(
+        &layers,
+        &sankey_data.nodes,
+        &deps,
+        &node_positions,
+        node_width,
+    );
+    Layout {
+        data: sankey_data,
+        deps,
+        node_width,
+        node_positions,
+        edge_positions,
+    }
+}
+
+fn layer_x_positions(layer_count: usize, width: f64, node_width: f64) -> Vec<f64> {
+    let dx = (width - node_width) / ((layer_count - 1) as f64);
+    (0..layer_count)
+        .map(|i| i as f64 * dx + node_width / 2.0)
+        .collect()
+}
+
+/// Reorder the nodes of each layer to minimize the edge crossings.
+///
+/// The layers are sorted alternately by the mean position
+/// of the outputs and of the inputs of the nodes.
+/// The order with the fewest crossings is kept.
+pub(super) fn order_layers(layers: &mut [Vec<NodeId>], deps: &HashMap<NodeId, Dependencies>) {
+    let mut best = layers.to_vec();
+    let mut best_crossings = crossings(layers, deps);
+    for sweep in 0..ORDERING_SWEEPS {
+        if best_crossings == 0 {
+            break;
+        }
+        if sweep % 2 == 0 {
+            // from the right (roots) to the left (leafs)
+            for i in (1..layers.len()).rev() {
+                let (left, right) = layers.split_at_mut(i);
+                sort_by_barycenter(&mut left[i - 1], &right[0], |id| &deps[id].outputs);
+            }
+        } else {
+            for i in 1..layers.len() {
+                let (left, right) = layers.split_at_mut(i);
+                sort_by_barycenter(&mut right[0], &left[i - 1], |id| &deps[id].inputs);
+            }
+        }
+        let crossings = crossings(layers, deps);
+        if crossings < best_crossings {
+            best = layers.to_vec();
+            best_crossings = crossings;
+        }
+    }
+    layers.clone_from_slice(&best);
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn sort_by_barycenter<'a, F>(layer: &mut [NodeId], neighbour_layer: &[NodeId], neighbours: F)
+where
+    F: Fn(&NodeId) -> &'a Vec<NodeId>,
+{
+    let index = neighbour_layer
+        .iter()
+        .enumerate()
+        .map(|(i, id)| (*id, i as f64))
+        .collect::<HashMap<_, _>>();
+    let barycenters = layer
+        .iter()
+        .enumerate()
+        .map(|(i, id)| {
+            let positions = neighbours(id)
+                .iter()
+                .filter_map(|n| index.get(n))
+                .collect::<Vec<_>>();
+            let barycenter = if positions.is_empty() {
+                i as f64
+            } else {
+                positions.iter().copied().sum::<f64>() / positions.len() as f64
+            };
+            (*id, barycenter)
+        })
+        .collect::<HashMap<_, _>>();
+    // The sort is stable: nodes with the same barycenter keep their order.
+    layer.sort_by(|a, b| {
+        barycenters[a]
+            .partial_cmp(&barycenters[b])
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+/// Number of edge crossings between all adjacent layers.
+pub(super) fn crossings(layers: &[Vec<NodeId>], deps: &HashMap<NodeId, Dependencies>) -> usize {
+    layers
+        .windows(2)
+        .map(|pair| {
+            let index = pair[1]
+                .iter()
+                .enumerate()
+                .map(|(i, id)| (*id, i))
+                .collect::<HashMap<_, _>>();
+            let edges = pair[0]
+                .iter()
+                .enumerate()
+                .flat_map(|(i, id)| {
+                    deps[id]
+                        .outputs
+                        .iter()
+                        .filter_map(|target| index.get(target))
+                        .map(move |j| (i, *j))
+                })
+                .collect::<Vec<_>>();
+            edges
+                .iter()
+                .enumerate()
+                .map(|(k, (a_source, a_target))| {
+                    edges[k + 1..]
+                        .iter()
+                        .filter(|(b_source, b_target)| {
+                            (a_source < b_source && a_target > b_target)
+                                || (a_source > b_source && a_target < b_target)
+                        })
+                        .count()
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn node_height(value: f64, scale: f64) -> f64 {
+    (value * scale).max(MIN_NODE_HEIGHT)
+}
+
+/// Scale (pixels per unit) so that every layer fits into the height.
+///
+/// Nodes below the [`MIN_NODE_HEIGHT`] get enlarged
+/// which reduces the space that is left for the other nodes.
+#[allow(clippy::cast_precision_loss)]
+fn scale(layers: &[Vec<NodeId>], nodes: &HashMap<NodeId, Node>, height: f64, gap: f64) -> f64 {
+    let scale = layers
+        .iter()
+        .filter_map(|layer| {
+            let total = layer.iter().map(|id| nodes[id].value).sum::<f64>();
+            if total <= 0.0 {
+                return None;
+            }
+            let available = height - gap * layer.len().saturating_sub(1) as f64;
+            let mut scale = available / total;
+            // The number of enlarged nodes only grows,
+            // so this terminates after at most one step per node.
+            for _ in 0..layer.len() {
+                let (small_count, large_total) =
+                    layer.iter().fold((0_usize, 0.0), |(count, total), id| {
+                        let value = nodes[id].value;
+                        if value * scale < MIN_NODE_HEIGHT {
+                            (count + 1, total)
+                        } else {
+                            (count, total + value)
+                        }
+                    });
+                if large_total <= 0.0 {
+                    break;
+                }
+                let next_scale =
+                    ((available - small_count as f64 * MIN_NODE_HEIGHT) / large_total).max(0.0);
+                if next_scale >= scale {
+                    break;
+                }
+                scale = next_scale;
+            }
+            Some(scale)
+        })
+        .fold(f64::INFINITY, f64::min);
+    if scale.is_finite() {
+        scale
+    } else {
+        0.0
+    }
+}
+
+// compute node_positions: height, x position and y positions
+fn node_positions(
+    layers: &[Vec<NodeId>],
+    layer_positions: &[f64],
+    nodes: &HashMap<NodeId, Node>,
+    deps: &HashMap<NodeId, Dependencies>,
+    scale: f64,
+    gap: f64,
+    height: f64,
+) -> HashMap<NodeId, NodePosition> {
+    let mut node_positions: HashMap<NodeId, NodePosition> = HashMap::new();
+    for (layer, x) in layers.iter().zip(layer_positions) {
+        let mut spans = layer
+            .iter()
+            .map(|id| {
+                let y = deps[id]
+                    .inputs
+                    .iter()
+                    .filter_map(|input| node_positions.get(input))
+                    .map(|position| position.y)
+                    .reduce(f64::min)
+                    .unwrap_or(0.0);
+                (y, node_height(nodes[id].value, scale))
+            })
+            .collect::<Vec<_>>();
+        spread(&mut spans, gap, 0.0, height);
+        for (id, (y, height)) in layer.iter().zip(spans) {
+            node_positions.insert(*id, NodePosition::new(*x, y, height));
+        }
+    }
+    node_positions
+}
+
+/// Move the labels of each layer apart so that they don't overlap.
+fn place_labels(
+    layers: &[Vec<NodeId>],
+    nodes: &HashMap<NodeId, Node>,
+    node_positions: &mut HashMap<NodeId, NodePosition>,
+    font_size: f64,
+    height: f64,
+) {
+    let line_height = font_size * LABEL_LINE_HEIGHT;
+    for layer in layers {
+        // The nodes of a layer are already sorted by their y position.
+        let labeled = layer
+            .iter()
+            .filter(|id| nodes[id].label.is_some())
+            .collect::<Vec<_>>();
+        let mut centers = labeled
+            .iter()
+            .map(|id| node_positions[id].label_y)
+            .collect::<Vec<_>>();
+        spread_labels(&mut centers, line_height, 0.0, height);
+        for (id, center) in labeled.into_iter().zip(centers) {
+            if let Some(position) = node_positions.get_mut(id) {
+                position.label_y = center;
+            }
+        }
+    }
+}
+
+/// Move the sorted label centers apart by at least the line height
+/// and keep them between the bounds if possible.
+///
+/// Overlapping labels are merged into clusters
+/// that are centered at the mean of the desired positions,
+/// so the labels are moved as little as possible.
+#[allow(clippy::cast_precision_loss)]
+fn spread_labels(centers: &mut [f64], line_height: f64, top: f64, bottom: f64) {
+    struct Cluster {
+        len: usize,
+        // sum of the desired centers relative to the first label of the cluster
+        sum: f64,
+    }
+    impl Cluster {
+        fn first_center(&self, line_height: f64, top: f64, bottom: f64) -> f64 {
+            let len = self.len as f64;
+            let lowest = bottom - (len - 1.0) * line_height;
+            (self.sum / len).min(lowest).max(top)
+        }
+    }
+    let mut clusters: Vec<Cluster> = vec![];
+    for center in centers.iter() {
+        clusters.push(Cluster {
+            len: 1,
+            sum: *center,
+        });
+        while clusters.len() > 1 {
+            let last = &clusters[clusters.len() - 1];
+            let previous = &clusters[clusters.len() - 2];
+            let previous_end =
+                previous.first_center(line_height, top, bottom) + previous.len as f64 * line_height;
+            if previous_end <= last.first_center(line_height, top, bottom) {
+                break;
+            }
+            let last = clusters.pop().expect("last cluster");
+            let previous = clusters.last_mut().expect("previous cluster");
+            previous.sum += last.sum - (last.len * previous.len) as f64 * line_height;
+            previous.len += last.len;
+        }
+    }
+    let mut centers = centers.iter_mut();
+    for cluster in clusters {
+        let first = cluster.first_center(line_height, top, bottom);
+        for (i, center) in centers.by_ref().take(cluster.len).enumerate() {
+            *center = first + i as f64 * line_height;
+        }
+    }
+}
+
+/// Push the sorted spans `(start, length)` apart
+/// so that they are separated by the gap and stay within the bounds if possible.
+fn spread(spans: &mut [(f64, f64)], gap: f64, top: f64, bottom: f64) {
+    let mut end = top - gap;
+    for (start, length) in spans.iter_mut() {
+        *start = start.max(end + gap);
+        end = *start + *length;
+    }
+    let mut limit = bottom;
+    for (start, length) in spans.iter_mut().rev() {
+        if *start + *length > limit {
+            *start = (limit - *length).max(top);
+        }
+        limit = *start - gap;
+    }
+}
+
+// computes 2 edge paths between 2 nodes => one on top, one on bottom
+#[allow(clippy::cast_precision_loss)]
+fn edge_positions(
+    layers: &[Vec<NodeId>],
+    nodes: &HashMap<NodeId, Node>,
+    deps: &HashMap<NodeId, Dependencies>,
+    node_positions: &HashMap<NodeId, NodePosition>,
+    node_width: f64,
+) -> Vec<EdgePosition> {
+    // thickness of an edge at its source
+    let thickness =
+        |source: &NodeId| node_positions[source].height / deps[source].outputs.len() as f64;
+    let mut target_offsets = HashMap::<NodeId, f64>::new();
+    let mut edge_positions = vec![];
+    // The layers are sorted by their position,
+    // so the inputs of each target are visited from top to bottom.
+    for source in layers.iter().flatten() {
+        let from = node_positions[source];
+        let mut targets = deps[source].outputs.clone();
+        targets.sort_by(|a, b| {
+            node_positions[a]
+                .y
+                .partial_cmp(&node_positions[b].y)
+                .unwrap_or(Ordering::Equal)
+        });
+        let mut from_y = from.y;
+        for target in targets {
+            let to = node_positions[&target];
+            let total_input_thickness = deps[&target].inputs.iter().map(thickness).sum::<f64>();
+            let from_thickness = thickness(source);
+            let to_thickness = if total_input_thickness > 0.0 {
+                from_thickness * to.height / total_input_thickness
+            } else {
+                from_thickness
+            };
+            let offset = target_offsets.entry(target).or_default();
+            let to_y = to.y + *offset;
+            *offset += to_thickness;
+            edge_positions.push(EdgePosition {
+                source: *source,
+                target,
+                from_top: Point::new(from.x + node_width, from_y),
+                from_bottom: Point::new(from.x + node_width, from_y + from_thickness),
+                to_top: Point::new(to.x, to_y),
+                to_bottom: Point::new(to.x, to_y + to_thickness),
+                color: nodes[source].edge_color,
+            });
+            from_y += from_thickness;
+        }
+    }
+    edge_positions
+}
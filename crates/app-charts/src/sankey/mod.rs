@@ -4,9 +4,16 @@ use std::{
     hash::Hash,
 };
 
+mod component;
+mod layout;
+
 #[cfg(test)]
 mod tests;
 
+pub use self::component::Sankey;
+
+use self::layout::{layout, Layout};
+
 #[derive(Debug, Default, Clone)]
 pub struct SankeyData {
     nodes: HashMap<NodeId, Node>,
@@ -45,12 +52,20 @@ pub struct NodePosition {
     pub x: f64,
     pub y: f64,
     pub height: f64,
+    /// Vertical center of the label.
+    pub label_y: f64,
 }
 
 impl NodePosition {
     #[must_use]
-    const fn new(x: f64, y: f64, height: f64) -> Self {
-        Self { x, y, height }
+    fn new(x: f64, y: f64, height: f64) -> Self {
+        let label_y = y + height / 2.0;
+        Self {
+            x,
+            y,
+            height,
+            label_y,
+        }
     }
 }
 
@@ -89,19 +104,19 @@ impl SankeyData {
     where
         S: Into<String>,
     {
-        let id = self.nodes.len();
-        let id = NodeId::new(id);
+        let id = self.next_id();
         let label = Some(label.into());
         let node = Node::new(value, label, color, edge_color);
         self.nodes.insert(id, node);
         id
     }
 
-    // virtual nodes are not rendered but help to compute edge paths with no intersections
+    // virtual nodes are not rendered but help to compute edge paths with no intersections:
+    // after the insertion every edge connects two adjacent layers
     pub fn insert_virtual_nodes(&mut self) {
         // STEP 1. let max_count i.e. find longest consecutive node<->edge count
         let deps: HashMap<NodeId, Dependencies> = dependencies(&self.edges);
-        let root_layer = deps
+        let mut root_layer = deps
             .iter()
             .filter_map(|(node, Dependencies { outputs, .. })| {
                 if outputs.is_empty() {
@@ -111,6 +126,7 @@ impl SankeyData {
                 }
             })
             .collect::<Vec<_>>();
+        root_layer.sort_by_key(|id| id.0);
 
         let max_count: u64 = root_layer
             .iter()
@@ -132,6 +148,67 @@ impl SankeyData {
     pub fn node_value(&self, id: &NodeId) -> Option<f64> {
         self.nodes.get(id).map(|n| n.value)
     }
+
+    /// Remove all nodes that flow into the given node
+    /// so that it is shown as a single (collapsed) group.
+    pub fn collapse(&mut self, id: NodeId) {
+        let deps = dependencies(&self.edges);
+        let mut inputs = deps.get(&id).map(|d| d.inputs.clone()).unwrap_or_default();
+        while let Some(input) = inputs.pop() {
+            inputs.extend(deps[&input].inputs.iter().copied());
+            self.nodes.remove(&input);
+        }
+        self.edges.retain(|edge| {
+            self.nodes.contains_key(&edge.source) && self.nodes.contains_key(&edge.target)
+        });
+    }
+
+    /// Nodes that can be collapsed,
+    /// i.e. nodes with inputs that are not a root node.
+    #[must_use]
+    pub fn groups(&self) -> HashSet<NodeId> {
+        dependencies(&self.edges)
+            .into_iter()
+            .filter(|(_, deps)| !deps.inputs.is_empty() && !deps.outputs.is_empty())
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Sum of the values of all root nodes.
+    #[must_use]
+    pub fn total_value(&self) -> f64 {
+        dependencies(&self.edges)
+            .into_iter()
+            .filter(|(_, deps)| deps.outputs.is_empty())
+            .map(|(id, _)| self.nodes[&id].value)
+            .sum()
+    }
+
+    fn next_id(&self) -> NodeId {
+        let id = self.nodes.keys().map(|id| id.0 + 1).max().unwrap_or(0);
+        NodeId::new(id)
+    }
+}
+
+/// All nodes on the paths that lead through the given node.
+///
+/// Returns `None` if the node is not connected.
+fn connected_nodes(deps: &HashMap<NodeId, Dependencies>, id: NodeId) -> Option<HashSet<NodeId>> {
+    let node_deps = deps.get(&id)?;
+    let mut connected = HashSet::from([id]);
+    let mut inputs = node_deps.inputs.clone();
+    while let Some(input) = inputs.pop() {
+        if connected.insert(input) {
+            inputs.extend(deps[&input].inputs.iter().copied());
+        }
+    }
+    let mut outputs = node_deps.outputs.clone();
+    while let Some(output) = outputs.pop() {
+        if connected.insert(output) {
+            outputs.extend(deps[&output].outputs.iter().copied());
+        }
+    }
+    Some(connected)
 }
 
 // From all root nodes, travel left until leaf found, if count < max_count,
@@ -172,19 +249,23 @@ fn travel_and_expand(
             target: *node,
         });
         let value = s.nodes[before_node].value;
-        let id = s.nodes.len();
-        let new_node_id = NodeId::new(id);
         let edge_color = s.nodes[before_node].edge_color;
-        // hiding the node with edge_color painting
-        let new_node = Node::new(value, None, edge_color, edge_color);
-        s.nodes.insert(new_node_id, new_node);
+        // one virtual node for each skipped layer
+        let mut target = *node;
+        for _ in 0..patch {
+            let new_node_id = s.next_id();
+            // hiding the node with edge_color painting
+            let new_node = Node::new(value, None, edge_color, edge_color);
+            s.nodes.insert(new_node_id, new_node);
+            s.edges.insert(Edge {
+                source: new_node_id,
+                target,
+            });
+            target = new_node_id;
+        }
         s.edges.insert(Edge {
             source: *before_node,
-            target: new_node_id,
-        });
-        s.edges.insert(Edge {
-            source: new_node_id,
-            target: *node,
+            target,
         });
     }
 }
@@ -237,7 +318,7 @@ where
 }
 
 fn inner_chart<F>(
-    mut sankey_data: SankeyData,
+    sankey_data: SankeyData,
     width: f64,
     height: f64,
     number_format: F,
@@ -246,33 +327,20 @@ fn inner_chart<F>(
 where
     F: Fn(f64) -> String,
 {
-    let node_separation = height / 50.0;
-    let node_width = width / 70.0; // TODO: make this configurable
-    sankey_data.insert_virtual_nodes();
-    let deps = dependencies(&sankey_data.edges);
-    let layers = layers(&deps, &sankey_data.nodes);
-    let layer_x_positions = layer_x_positions(layers.len(), width, node_width);
-    let scale = scale(&layers, &sankey_data.nodes, height, node_separation);
-    let node_positions = node_positions(
-        &layers,
-        &layer_x_positions,
-        &sankey_data.nodes,
-        &deps,
-        scale,
-        node_separation,
-    );
-    let edge_positions = edge_positions(
-        &sankey_data.edges,
-        &sankey_data.nodes,
-        &deps,
-        &node_positions,
-        &layers,
+    let Layout {
+        data: sankey_data,
         node_width,
-    );
-
-    let (svg_nodes, svg_labels): (Vec<_>, Vec<_>) = node_positions
-        .iter()
-        .map(|(id, node_position)| {
+        node_positions,
+        edge_positions,
+        ..
+    } = layout(sankey_data, width, height, font_size);
+
+    let mut node_ids = node_positions.keys().collect::<Vec<_>>();
+    node_ids.sort_by_key(|id| id.0);
+    let (svg_nodes, svg_labels): (Vec<_>, Vec<_>) = node_ids
+        .into_iter()
+        .map(|id| {
+            let node_position = node_positions[id];
             let x = node_position.x;
             let y = node_position.y;
             let node_height = node_position.height;
@@ -295,6 +363,7 @@ where
                 .set("stroke-width", 1)
                 .set("stroke-dashoffset", 0);
 
+            let label_x = x + node_width + font_size / 2.0;
             let mut label = ::svg::node::element::Text::new("");
             label.get_children_mut().clear();
             if let Some(label_tspan) = label_tspan {
@@ -302,24 +371,41 @@ where
             }
             label = label
                 .set("class", "label")
-                .set("x", x + node_width + font_size / 2.0)
-                .set("y", y + node_height / 2.0)
+                .set("x", label_x)
+                .set("y", node_position.label_y)
                 .set("fill", "#111")
                 .set("text-anchor", "start")
                 .set("font-family", "sans-serif")
                 .set("font-size", font_size)
                 .set("dominant-baseline", "middle");
-            (node, label)
+            let leader_line = leader_line(
+                x + node_width,
+                y + node_height / 2.0,
+                label_x,
+                node_position.label_y,
+            )
+            .map(|d| {
+                ::svg::node::element::Path::new()
+                    .set("d", d)
+                    .set("fill", "none")
+                    .set("stroke", "#555")
+                    .set("stroke-width", 0.5)
+            });
+            let mut label_group = ::svg::node::element::Group::new();
+            if let Some(leader_line) = leader_line {
+                label_group = label_group.add(leader_line);
+            }
+            (node, label_group.add(label))
         })
         .unzip();
 
     let svg_edges = edge_positions
         .iter()
-        .map(|(from_top, from_bottom, to_top, to_bottom, edge_color)| {
-            let d = edge_path(from_top, from_bottom, to_top, to_bottom);
+        .map(|edge| {
+            let d = edge.path();
 
             // TODO: use gradient
-            let fill = edge_color.unwrap_or(Color::new("purple"));
+            let fill = edge.color.unwrap_or(Color::new("purple"));
 
             ::svg::node::element::Path::new()
                 .set("d", d)
@@ -360,6 +446,17 @@ where
         .add(labels)
 }
 
+/// Line from a node to its label if the label was moved.
+fn leader_line(node_x: f64, node_y: f64, label_x: f64, label_y: f64) -> Option<String> {
+    if (node_y - label_y).abs() < 1.0 {
+        return None;
+    }
+    let end_x = label_x - 2.0;
+    Some(format!(
+        "M {node_x:.10} {node_y:.10} L {end_x:.10} {label_y:.10}"
+    ))
+}
+
 fn edge_path(from_top: &Point, from_bottom: &Point, to_top: &Point, to_bottom: &Point) -> String {
     let from_top_x = from_top.x;
     let from_top_y = from_top.y;
@@ -378,7 +475,7 @@ fn edge_path(from_top: &Point, from_bottom: &Point, to_top: &Point, to_bottom: &
     format!("M {from_top_x:.10} {from_top_y:.10} C {mid_x:.10} {from_top_y:.10}, {mid_x:.10} {to_top_y:.10}, {to_top_x:.10} {to_top_y:.10} L {to_bottom_x:.10} {to_bottom_y:.10} C {mid_x:.10} {to_bottom_y:.10}, {mid_x:.10} {from_bottom_y:.10}, {from_bottom_x:.10} {from_bottom_y:.10}  Z")
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct Dependencies {
     pub inputs: Vec<NodeId>,
     pub outputs: Vec<NodeId>,
@@ -390,6 +487,11 @@ fn dependencies(edges: &HashSet<Edge>) -> HashMap<NodeId, Dependencies> {
         deps.entry(*source).or_default().outputs.push(*target);
         deps.entry(*target).or_default().inputs.push(*source);
     }
+    // sort to get the same layout for the same data
+    for Dependencies { inputs, outputs } in deps.values_mut() {
+        inputs.sort_by_key(|id| id.0);
+        outputs.sort_by_key(|id| id.0);
+    }
     deps
 }
 
@@ -411,7 +513,7 @@ pub fn layers(
     deps: &HashMap<NodeId, Dependencies>,
     nodes: &HashMap<NodeId, Node>,
 ) -> Vec<Vec<NodeId>> {
-    let root_layer = deps
+    let mut root_layer = deps
         .iter()
         .filter_map(|(node, Dependencies { outputs, .. })| {
             if outputs.is_empty() {
@@ -421,6 +523,7 @@ pub fn layers(
             }
         })
         .collect::<Vec<_>>();
+    root_layer.sort_by_key(|id| id.0);
     let (mut final_root_layers, final_leafs_layers) = recursive_layers(deps, nodes, root_layer);
     final_root_layers.extend(vec![final_leafs_layers]);
     final_root_layers.reverse();
@@ -464,69 +567,6 @@ pub fn recursive_layers(
     (roots, leafs)
 }
 
-fn layer_x_positions(layer_count: usize, width: f64, node_width: f64) -> Vec<f64> {
-    let dx = (width - node_width) / ((layer_count - 1) as f64);
-    (0..layer_count)
-        .map(|i| i as f64 * dx + node_width / 2.0)
-        .collect()
-}
-
-// compute node_positions: height, x position and y positions
-fn node_positions(
-    layers: &[Vec<NodeId>],
-    layer_positions: &[f64],
-    nodes: &HashMap<NodeId, Node>,
-    deps: &HashMap<NodeId, Dependencies>,
-    scale: f64,
-    gap: f64,
-) -> HashMap<NodeId, NodePosition> {
-    let mut node_positions: HashMap<NodeId, NodePosition> = HashMap::new();
-    for (j, layer) in layers.iter().enumerate() {
-        let x = layer_positions[j];
-        let mut layer_y = 0.0;
-        for id in layer {
-            let y = if j == 0 {
-                layer_y
-            } else {
-                deps[id].inputs.iter().fold(f64::INFINITY, |y, successor| {
-                    y.min(node_positions[successor].y)
-                })
-            };
-            let height = nodes[id].value * scale;
-            let position = NodePosition::new(x, y, height);
-            node_positions.insert(*id, position);
-            layer_y += height + gap;
-        }
-    }
-    node_positions
-}
-
-fn scale(
-    layers: &[Vec<NodeId>],
-    nodes: &HashMap<NodeId, Node>,
-    height: f64,
-    node_separation: f64,
-) -> f64 {
-    let (max_node_count, layer_val) = layers.iter().fold((usize::MIN, 0.0), |(cnt, val), layer| {
-        if layer.len() > cnt {
-            (
-                layer.len(),
-                layer.iter().fold(0.0, |x, id| x + nodes[id].value),
-            )
-        } else {
-            (cnt, val)
-        }
-    });
-
-    let max_gaps = if max_node_count > 0 {
-        max_node_count - 1
-    } else {
-        0
-    } as f64;
-
-    (height - max_gaps * node_separation) / layer_val
-}
-
 #[derive(Debug, Clone, Copy)]
 struct Point {
     x: f64,
@@ -554,52 +594,3 @@ impl Color {
         self.0
     }
 }
-
-// computes 2 edge paths between 2 nodes => one on top, one on bottom
-fn edge_positions(
-    edges: &HashSet<Edge>,
-    nodes: &HashMap<NodeId, Node>,
-    deps: &HashMap<NodeId, Dependencies>,
-    node_positions: &HashMap<NodeId, NodePosition>,
-    layers: &[Vec<NodeId>],
-    node_width: f64,
-) -> Vec<(Point, Point, Point, Point, Option<Color>)> {
-    let mut total_input_values = HashMap::<NodeId, f64>::new();
-    for Edge { source, target } in edges {
-        *total_input_values.entry(*target).or_default() += nodes[source].value;
-    }
-    layers
-        .iter()
-        .flat_map(|layer| {
-            layer
-                .iter()
-                .filter_map(|node| {
-                    let from = node_positions[node];
-                    let edge = edges.iter().find(|edge| edge.source == *node)?;
-                    let to = node_positions[&edge.target];
-                    let scale = to.height / total_input_values[&edge.target];
-                    let mut to_y_start = node_positions.get(&edge.target).unwrap().y;
-                    if (from.y - to.y).abs() > 1.0 {
-                        let prev_nodes = deps[&edge.target]
-                            .inputs
-                            .iter()
-                            .filter(|id| from.y - node_positions[id].y > 1.0)
-                            .collect::<Vec<_>>();
-                        to_y_start += prev_nodes
-                            .iter()
-                            .fold(0.0, |acc, id| acc + node_positions[id].height);
-                    }
-                    let to_y_end = to_y_start + nodes[&edge.source].value * scale;
-                    let points = (
-                        Point::new(from.x + node_width, from.y),
-                        Point::new(from.x + node_width, from.y + from.height),
-                        Point::new(to.x, to_y_start),
-                        Point::new(to.x, to_y_end),
-                        nodes[&edge.source].edge_color,
-                    );
-                    Some(points)
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect()
-}
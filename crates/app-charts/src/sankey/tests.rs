@@ -1,4 +1,7 @@
-use std::{cmp::Ordering, collections::HashMap};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
 
 use crate::sankey::{Dependencies, Node, NodeId, *};
 
@@ -518,3 +521,261 @@ fn test_merge_layers3() {
         ]
     );
 }
+
+// total <- direct <- (n2o, ch4)
+// total <- indirect <- (materials <- (polymers, salt))
+fn emission_tree() -> (SankeyData, [NodeId; 8]) {
+    let mut data = SankeyData::new();
+    let total = data.insert_node(100.0, "total", None, None);
+    let direct = data.insert_node(60.0, "direct", None, None);
+    let indirect = data.insert_node(40.0, "indirect", None, None);
+    let n2o = data.insert_node(50.0, "n2o", None, None);
+    let ch4 = data.insert_node(10.0, "ch4", None, None);
+    let materials = data.insert_node(40.0, "materials", None, None);
+    let polymers = data.insert_node(30.0, "polymers", None, None);
+    let salt = data.insert_node(10.0, "salt", None, None);
+    for (source, target) in [
+        (direct, total),
+        (indirect, total),
+        (n2o, direct),
+        (ch4, direct),
+        (materials, indirect),
+        (polymers, materials),
+        (salt, materials),
+    ] {
+        data.insert_edge(source, target);
+    }
+    let ids = [total, direct, indirect, n2o, ch4, materials, polymers, salt];
+    (data, ids)
+}
+
+#[test]
+fn collapse_group() {
+    let (mut data, [total, direct, indirect, n2o, ch4, materials, polymers, salt]) =
+        emission_tree();
+    data.collapse(indirect);
+    for removed in [materials, polymers, salt] {
+        assert_eq!(data.node_value(&removed), None);
+    }
+    for kept in [total, direct, indirect, n2o, ch4] {
+        assert!(data.node_value(&kept).is_some());
+    }
+    assert_eq!(data.edges.len(), 4);
+    assert_eq!(data.groups(), HashSet::from([direct]));
+    assert_eq!(data.total_value(), 100.0);
+}
+
+#[test]
+fn groups_and_total_value() {
+    let (data, [_, direct, indirect, _, _, materials, ..]) = emission_tree();
+    assert_eq!(data.groups(), HashSet::from([direct, indirect, materials]));
+    assert_eq!(data.total_value(), 100.0);
+}
+
+#[test]
+fn highlight_connected_nodes() {
+    let (data, [total, direct, indirect, n2o, ch4, materials, polymers, salt]) = emission_tree();
+    let deps = dependencies(&data.edges);
+    assert_eq!(
+        connected_nodes(&deps, ch4),
+        Some(HashSet::from([ch4, direct, total]))
+    );
+    assert_eq!(
+        connected_nodes(&deps, materials),
+        Some(HashSet::from([polymers, salt, materials, indirect, total]))
+    );
+    assert_eq!(connected_nodes(&deps, total).map(|n| n.len()), Some(8));
+    assert_eq!(connected_nodes(&deps, NodeId(42)), None);
+    assert!(connected_nodes(&deps, n2o).is_some());
+}
+
+#[test]
+fn virtual_nodes_after_collapse() {
+    let (mut data, [total, direct, ..]) = emission_tree();
+    data.collapse(direct);
+    let node_count = data.nodes.len();
+    let layout = layout(data, 1000.0, 500.0, 16.0);
+    // direct is two layers shorter than indirect
+    assert_eq!(layout.data.nodes.len(), node_count + 2);
+    let virtual_nodes = layout
+        .node_positions
+        .keys()
+        .filter(|id| layout.is_virtual(**id))
+        .collect::<Vec<_>>();
+    assert_eq!(virtual_nodes.len(), 2);
+    for id in virtual_nodes {
+        assert_eq!(layout.real_source(*id), direct);
+        assert_eq!(layout.real_target(*id), total);
+    }
+}
+
+fn deps_of(edges: &[(usize, usize)]) -> HashMap<NodeId, Dependencies> {
+    let edges = edges
+        .iter()
+        .map(|(source, target)| Edge {
+            source: NodeId(*source),
+            target: NodeId(*target),
+        })
+        .collect();
+    dependencies(&edges)
+}
+
+#[test]
+fn count_crossings() {
+    let deps = deps_of(&[(0, 3), (1, 2)]);
+    let layers = vec![vec![NodeId(0), NodeId(1)], vec![NodeId(2), NodeId(3)]];
+    assert_eq!(layout::crossings(&layers, &deps), 1);
+    let layers = vec![vec![NodeId(1), NodeId(0)], vec![NodeId(2), NodeId(3)]];
+    assert_eq!(layout::crossings(&layers, &deps), 0);
+}
+
+#[test]
+fn minimize_crossings() {
+    //  0 ─┐┌─ 4 ─┐
+    //  1 ─┼┼─ 5 ─┼─ 6
+    //  2 ─┘│     │
+    //  3 ──┘     │
+    let deps = deps_of(&[(0, 5), (1, 4), (2, 5), (3, 4), (4, 6), (5, 6)]);
+    let mut layers = vec![
+        vec![NodeId(0), NodeId(1), NodeId(2), NodeId(3)],
+        vec![NodeId(4), NodeId(5)],
+        vec![NodeId(6)],
+    ];
+    assert_eq!(layout::crossings(&layers, &deps), 3);
+    layout::order_layers(&mut layers, &deps);
+    assert_eq!(layout::crossings(&layers, &deps), 0);
+    assert_eq!(
+        layers,
+        vec![
+            vec![NodeId(1), NodeId(3), NodeId(0), NodeId(2)],
+            vec![NodeId(4), NodeId(5)],
+            vec![NodeId(6)],
+        ]
+    );
+}
+
+/// All node positions as text,
+/// sorted from left to right and from top to bottom.
+fn node_positions_snapshot(data: SankeyData) -> String {
+    let layout = layout(data, 1000.0, 500.0, 16.0);
+    let mut positions = layout
+        .node_positions
+        .iter()
+        .map(|(id, position)| {
+            let name = layout.data.nodes[id].label.clone().unwrap_or_else(|| {
+                let source = layout.real_source(*id);
+                format!("~{}", layout.data.nodes[&source].label.as_ref().unwrap())
+            });
+            (name, *position)
+        })
+        .collect::<Vec<_>>();
+    positions.sort_by(|(_, a), (_, b)| {
+        (a.x, a.y)
+            .partial_cmp(&(b.x, b.y))
+            .unwrap_or(Ordering::Equal)
+    });
+    positions
+        .into_iter()
+        .map(|(name, p)| {
+            format!(
+                "{name:<10} x={:6.1} y={:6.1} height={:6.1} label_y={:6.1}",
+                p.x, p.y, p.height, p.label_y
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn snapshot_emission_tree() {
+    let (data, _) = emission_tree();
+    assert_eq!(
+        node_positions_snapshot(data).lines().collect::<Vec<_>>(),
+        [
+            "n2o        x=   7.1 y=   0.0 height= 235.0 label_y= 117.5",
+            "ch4        x=   7.1 y= 245.0 height=  47.0 label_y= 268.5",
+            "polymers   x=   7.1 y= 302.0 height= 141.0 label_y= 372.5",
+            "salt       x=   7.1 y= 453.0 height=  47.0 label_y= 476.5",
+            "~n2o       x= 335.7 y=   0.0 height= 235.0 label_y= 117.5",
+            "~ch4       x= 335.7 y= 245.0 height=  47.0 label_y= 268.5",
+            "materials  x= 335.7 y= 302.0 height= 188.0 label_y= 396.0",
+            "direct     x= 664.3 y=   0.0 height= 282.0 label_y= 141.0",
+            "indirect   x= 664.3 y= 302.0 height= 188.0 label_y= 396.0",
+            "total      x= 992.9 y=   0.0 height= 470.0 label_y= 235.0",
+        ]
+    );
+}
+
+#[test]
+fn snapshot_small_custom_emissions() {
+    let (mut data, [_, _, indirect, ..]) = emission_tree();
+    for i in 0..12 {
+        let custom = data.insert_node(0.01 * f64::from(i), format!("custom {i}"), None, None);
+        data.insert_edge(custom, indirect);
+    }
+    assert_eq!(
+        node_positions_snapshot(data).lines().collect::<Vec<_>>(),
+        [
+            "n2o        x=   7.1 y=   0.0 height= 163.0 label_y=  81.5",
+            "ch4        x=   7.1 y= 173.0 height=  32.6 label_y= 189.3",
+            "polymers   x=   7.1 y= 215.6 height=  97.8 label_y= 250.4",
+            "salt       x=   7.1 y= 323.4 height=  32.6 label_y= 269.6",
+            "custom 11  x=   7.1 y= 366.0 height=   2.0 label_y= 288.8",
+            "custom 10  x=   7.1 y= 378.0 height=   2.0 label_y= 308.0",
+            "custom 9   x=   7.1 y= 390.0 height=   2.0 label_y= 327.2",
+            "custom 8   x=   7.1 y= 402.0 height=   2.0 label_y= 346.4",
+            "custom 7   x=   7.1 y= 414.0 height=   2.0 label_y= 365.6",
+            "custom 6   x=   7.1 y= 426.0 height=   2.0 label_y= 384.8",
+            "custom 5   x=   7.1 y= 438.0 height=   2.0 label_y= 404.0",
+            "custom 4   x=   7.1 y= 450.0 height=   2.0 label_y= 423.2",
+            "custom 3   x=   7.1 y= 462.0 height=   2.0 label_y= 442.4",
+            "custom 2   x=   7.1 y= 474.0 height=   2.0 label_y= 461.6",
+            "custom 1   x=   7.1 y= 486.0 height=   2.0 label_y= 480.8",
+            "custom 0   x=   7.1 y= 498.0 height=   2.0 label_y= 500.0",
+            "~n2o       x= 335.7 y=   0.0 height= 163.0 label_y=  81.5",
+            "~ch4       x= 335.7 y= 173.0 height=  32.6 label_y= 189.3",
+            "materials  x= 335.7 y= 215.6 height= 130.4 label_y= 280.8",
+            "~custom 11 x= 335.7 y= 366.0 height=   2.0 label_y= 367.0",
+            "~custom 10 x= 335.7 y= 378.0 height=   2.0 label_y= 379.0",
+            "~custom 9  x= 335.7 y= 390.0 height=   2.0 label_y= 391.0",
+            "~custom 8  x= 335.7 y= 402.0 height=   2.0 label_y= 403.0",
+            "~custom 7  x= 335.7 y= 414.0 height=   2.0 label_y= 415.0",
+            "~custom 6  x= 335.7 y= 426.0 height=   2.0 label_y= 427.0",
+            "~custom 5  x= 335.7 y= 438.0 height=   2.0 label_y= 439.0",
+            "~custom 4  x= 335.7 y= 450.0 height=   2.0 label_y= 451.0",
+            "~custom 3  x= 335.7 y= 462.0 height=   2.0 label_y= 463.0",
+            "~custom 2  x= 335.7 y= 474.0 height=   2.0 label_y= 475.0",
+            "~custom 1  x= 335.7 y= 486.0 height=   2.0 label_y= 487.0",
+            "~custom 0  x= 335.7 y= 498.0 height=   2.0 label_y= 499.0",
+            "direct     x= 664.3 y=   0.0 height= 195.6 label_y=  97.8",
+            "indirect   x= 664.3 y= 215.6 height= 130.4 label_y= 280.8",
+            "total      x= 992.9 y=   0.0 height= 326.0 label_y= 163.0",
+        ]
+    );
+}
+
+#[test]
+fn labels_do_not_overlap() {
+    let font_size = 16.0;
+    let (mut data, [_, _, indirect, ..]) = emission_tree();
+    for i in 0..20 {
+        let custom = data.insert_node(0.001, format!("custom {i}"), None, None);
+        data.insert_edge(custom, indirect);
+    }
+    let layout = layout(data, 1000.0, 500.0, font_size);
+    let mut layers = HashMap::<u64, Vec<f64>>::new();
+    for (id, position) in &layout.node_positions {
+        assert!(position.height >= layout::MIN_NODE_HEIGHT);
+        if !layout.is_virtual(*id) {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let x = position.x.round() as u64;
+            layers.entry(x).or_default().push(position.label_y);
+        }
+    }
+    for mut label_positions in layers.into_values() {
+        label_positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        for pair in label_positions.windows(2) {
+            assert!(pair[1] - pair[0] >= font_size * 1.2 - 1e-9);
+        }
+    }
+}
@@ -2,7 +2,8 @@
 use leptos::*;
 
 use crate::{
-    BarChart, BarChartArguments, BarChartRadioInput, BarChartRadioInputArguments, SankeyData,
+    BarChart, BarChartArguments, BarChartRadioInput, BarChartRadioInputArguments, DonutChart,
+    DonutChartArguments, SankeyData, StackedBarChart, StackedBarChartArguments,
 };
 
 use klick_presenter::Lng;
@@ -37,8 +38,7 @@ pub fn bar_chart_radio_input(
 }
 
 #[must_use]
-pub fn bar_chart(data: Vec<BarChartArguments>, width: f64, height: f64) -> String {
-    let lang = Lng::De;
+pub fn bar_chart(data: Vec<BarChartArguments>, width: f64, height: f64, lang: Lng) -> String {
     render_view_as_svg(move || {
         let data = data;
         view! {
@@ -53,6 +53,43 @@ pub fn bar_chart(data: Vec<BarChartArguments>, width: f64, height: f64) -> Strin
     })
 }
 
+#[must_use]
+pub fn donut_chart(data: Vec<DonutChartArguments>, width: f64, height: f64, lang: Lng) -> String {
+    render_view_as_svg(move || {
+        let data = data;
+        view! {
+          <DonutChart
+            width
+            height
+            data
+            aria_label = None
+            lang
+          />
+        }
+    })
+}
+
+#[must_use]
+pub fn stacked_bar_chart(
+    data: Vec<StackedBarChartArguments>,
+    width: f64,
+    height: f64,
+    lang: Lng,
+) -> String {
+    render_view_as_svg(move || {
+        let data = data;
+        view! {
+          <StackedBarChart
+            width
+            height
+            data
+            aria_label = None
+            lang
+          />
+        }
+    })
+}
+
 #[must_use]
 pub fn sankey_chart<F>(
     sankey_data: SankeyData,
@@ -87,3 +124,72 @@ where
     rt.dispose();
     svg_string
 }
+
+#[cfg(feature = "png")]
+#[derive(Debug, thiserror::Error)]
+pub enum PngError {
+    #[error(transparent)]
+    Svg(#[from] resvg::usvg::Error),
+    #[error("invalid image size {width}x{height}")]
+    Size { width: u32, height: u32 },
+    #[error("unable to encode PNG: {0}")]
+    Encoding(String),
+}
+
+#[cfg(feature = "png")]
+const FALLBACK_SANS_SERIF_FAMILIES: &[&str] =
+    &["DejaVu Sans", "Liberation Sans", "Noto Sans", "FreeSans"];
+
+/// Rasterize an SVG image into a PNG image of the given size.
+///
+/// Texts are rendered with the fonts of the system.
+#[cfg(feature = "png")]
+pub fn svg_to_png(svg: &str, width: u32, height: u32) -> Result<Vec<u8>, PngError> {
+    use std::sync::{Arc, LazyLock};
+
+    use resvg::{tiny_skia, usvg};
+
+    use usvg::fontdb::{Database, Family, Query};
+
+    static FONTS: LazyLock<Arc<Database>> = LazyLock::new(|| {
+        let mut fonts = Database::new();
+        fonts.load_system_fonts();
+        // The configured generic family may refer to a font that is not installed.
+        let query = Query {
+            families: &[Family::SansSerif],
+            ..Query::default()
+        };
+        if fonts.query(&query).is_none() {
+            let families = fonts
+                .faces()
+                .filter_map(|face| face.families.first().map(|(name, _)| name.clone()))
+                .collect::<Vec<_>>();
+            let fallback = FALLBACK_SANS_SERIF_FAMILIES
+                .iter()
+                .find(|family| families.iter().any(|f| f == *family))
+                .map(ToString::to_string)
+                .or_else(|| families.first().cloned());
+            if let Some(family) = fallback {
+                fonts.set_sans_serif_family(family);
+            }
+        }
+        Arc::new(fonts)
+    });
+
+    let options = usvg::Options {
+        fontdb: Arc::clone(&FONTS),
+        ..Default::default()
+    };
+    let tree = usvg::Tree::from_str(svg, &options)?;
+    let mut pixmap =
+        tiny_skia::Pixmap::new(width, height).ok_or(PngError::Size { width, height })?;
+    #[allow(clippy::cast_precision_loss)]
+    let transform = tiny_skia::Transform::from_scale(
+        width as f32 / tree.size().width(),
+        height as f32 / tree.size().height(),
+    );
+    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    pixmap
+        .encode_png()
+        .map_err(|err| PngError::Encoding(err.to_string()))
+}
@@ -0,0 +1,262 @@
+#[allow(clippy::wildcard_imports)]
+use leptos::*;
+
+use klick_presenter::Lng;
+
+use crate::{co2_equivalents_unit, Color};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackedBarChartSegment {
+    pub label: String,
+    pub value: f64,
+    pub color: Color,
+}
+
+/// A horizontal bar stacked by its segments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StackedBarChartArguments {
+    pub label: String,
+    pub segments: Vec<StackedBarChartSegment>,
+}
+
+const MARGIN: f64 = 10.0;
+const FONT_SIZE: f64 = 16.0;
+const AXIS_TICKS: f64 = 5.0;
+
+/// Horizontal bars stacked by segments,
+/// e.g. the emission groups of several scenarios.
+///
+/// The legend is taken from the segments of the first bar,
+/// negative values are ignored.
+#[allow(clippy::module_name_repetitions, clippy::cast_precision_loss)]
+#[component]
+pub fn StackedBarChart(
+    width: f64,
+    height: f64,
+    data: Vec<StackedBarChartArguments>,
+    aria_label: Option<String>,
+    lang: Lng,
+) -> impl IntoView {
+    let label_width = width * 0.18;
+    let total_width = FONT_SIZE * 5.0;
+    let legend_height = FONT_SIZE * 2.0;
+    let axis_height = FONT_SIZE * 1.5;
+
+    let bars_x = MARGIN + label_width;
+    let bars_width = (width - bars_x - total_width - MARGIN).max(0.0);
+    let bars_y = MARGIN + legend_height;
+    let inner_height = (height - bars_y - axis_height - MARGIN).max(0.0);
+
+    let totals = data
+        .iter()
+        .map(|bar| bar.segments.iter().map(|s| s.value.max(0.0)).sum::<f64>())
+        .collect::<Vec<_>>();
+    let max_total = totals.iter().copied().fold(0.0, f64::max);
+    let step = tick_step(max_total);
+    let axis_max = (max_total / step).ceil() * step;
+    let scale = if axis_max > 0.0 {
+        bars_width / axis_max
+    } else {
+        0.0
+    };
+
+    let row_height = inner_height / data.len().max(1) as f64;
+    let bar_height = row_height * 0.6;
+    let unit = co2_equivalents_unit(lang);
+
+    let legend = data.first().map(|bar| legend(&bar.segments, width));
+
+    let bars = data
+        .into_iter()
+        .zip(totals)
+        .enumerate()
+        .map(|(i, (bar, total))| {
+            let y = bars_y + i as f64 * row_height + (row_height - bar_height) / 2.0;
+            stacked_bar(bar, total, bars_x, y, bar_height, scale, lang)
+        })
+        .collect::<Vec<_>>();
+
+    let axis_y = bars_y + inner_height;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let tick_count = (axis_max / step).round() as usize;
+    let ticks = (0..=tick_count)
+        .map(|i| {
+            let value = step * i as f64;
+            let x = bars_x + value * scale;
+            view! {
+              <line x1 = x y1 = bars_y x2 = x y2 = axis_y stroke = "#ddd" stroke-width = 1 />
+              <text
+                x = x
+                y = axis_y + FONT_SIZE
+                text-anchor = "middle"
+                font-size = FONT_SIZE * 0.8
+                font-family = "sans-serif"
+              >
+                { lang.format_number_with_fixed_precision(value, 0) }
+              </text>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+      <svg
+        width = format!("{width}px")
+        height = format!("{height}px")
+        viewBox = format!("0 0 {width} {height}")
+        xmlns = "http://www.w3.org/2000/svg"
+        role = "img"
+        aria-label = aria_label
+      >
+        <g class="stacked-bar-legend">{ legend }</g>
+        <g class="stacked-bar-axis">
+          { ticks }
+          <line x1 = bars_x y1 = axis_y x2 = bars_x + bars_width y2 = axis_y stroke = "#bbb" stroke-width = 1 />
+          <text
+            x = bars_x - FONT_SIZE / 2.0
+            y = axis_y + FONT_SIZE
+            text-anchor = "end"
+            font-size = FONT_SIZE * 0.8
+            font-family = "sans-serif"
+          >
+            { unit }
+          </text>
+        </g>
+        <g class="stacked-bars">{ bars }</g>
+      </svg>
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn legend(segments: &[StackedBarChartSegment], width: f64) -> Vec<impl IntoView> {
+    let item_width = (width - 2.0 * MARGIN) / segments.len().max(1) as f64;
+    segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let x = MARGIN + i as f64 * item_width;
+            view! {
+              <g transform = format!("translate({x},{})", MARGIN + FONT_SIZE)>
+                <rect
+                  y = -FONT_SIZE * 0.8
+                  width = FONT_SIZE * 0.8
+                  height = FONT_SIZE * 0.8
+                  fill = segment.color.as_str()
+                />
+                <text x = FONT_SIZE * 1.2 font-size = FONT_SIZE font-family = "sans-serif">
+                  { segment.label.clone() }
+                </text>
+              </g>
+            }
+        })
+        .collect()
+}
+
+fn stacked_bar(
+    bar: StackedBarChartArguments,
+    total: f64,
+    x: f64,
+    y: f64,
+    bar_height: f64,
+    scale: f64,
+    lang: Lng,
+) -> impl IntoView {
+    let text_y = y + bar_height / 2.0;
+    let label_x = x - FONT_SIZE / 2.0;
+    let mut x = x;
+    let segments = bar
+        .segments
+        .into_iter()
+        .filter(|s| s.value > 0.0)
+        .map(|segment| {
+            let segment_width = segment.value * scale;
+            let segment_x = x;
+            x += segment_width;
+            let title = segment_label(&bar.label, &segment, total, lang);
+            let share = (total > 0.0 && segment_width > FONT_SIZE * 3.5).then(|| {
+                let share = segment.value / total * 100.0;
+                view! {
+                  <text
+                    x = segment_x + segment_width / 2.0
+                    y = text_y
+                    text-anchor = "middle"
+                    dominant-baseline = "middle"
+                    font-size = FONT_SIZE * 0.8
+                    font-family = "sans-serif"
+                    pointer-events = "none"
+                  >
+                    { format!("{} %", lang.format_number_with_fixed_precision(share, 0)) }
+                  </text>
+                }
+            });
+            view! {
+              <g class = "stacked-bar-segment">
+                <rect
+                  x = segment_x
+                  y = y
+                  width = segment_width
+                  height = bar_height
+                  fill = segment.color.as_str()
+                  stroke = "white"
+                  stroke-width = 1
+                  tabindex = "0"
+                  aria-label = title.clone()
+                >
+                  <title>{ title }</title>
+                </rect>
+                { share }
+              </g>
+            }
+        })
+        .collect::<Vec<_>>();
+    view! {
+      <g class = "stacked-bar">
+        <text
+          x = label_x
+          y = text_y
+          text-anchor = "end"
+          dominant-baseline = "middle"
+          font-size = FONT_SIZE
+          font-weight = "bold"
+          font-family = "sans-serif"
+        >
+          { bar.label }
+        </text>
+        { segments }
+        <text
+          x = x + FONT_SIZE / 2.0
+          y = text_y
+          dominant-baseline = "middle"
+          font-size = FONT_SIZE
+          font-family = "sans-serif"
+        >
+          { lang.format_number_with_fixed_precision(total, 0) }
+        </text>
+      </g>
+    }
+}
+
+fn segment_label(bar: &str, segment: &StackedBarChartSegment, total: f64, lang: Lng) -> String {
+    let value = lang.format_number_with_fixed_precision(segment.value, 0);
+    let unit = co2_equivalents_unit(lang);
+    if total > 0.0 {
+        let share = lang.format_number_with_fixed_precision(segment.value / total * 100.0, 1);
+        format!("{bar} – {}: {value} {unit} ({share} %)", segment.label)
+    } else {
+        format!("{bar} – {}: {value} {unit}", segment.label)
+    }
+}
+
+/// A round step (1, 2 or 5 times a power of ten)
+/// for about [`AXIS_TICKS`] ticks up to `max`.
+fn tick_step(max: f64) -> f64 {
+    if max <= 0.0 {
+        return 1.0;
+    }
+    let raw_step = max / AXIS_TICKS;
+    let magnitude = 10_f64.powf(raw_step.log10().floor());
+    [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|f| f * magnitude)
+        .find(|step| *step >= raw_step)
+        .unwrap_or(10.0 * magnitude)
+}
@@ -2,7 +2,7 @@ use leptos::*;
 
 #[component]
 #[must_use]
-pub fn InfoMessage(message: &'static str) -> impl IntoView {
+pub fn InfoMessage(#[prop(into)] message: MaybeSignal<String>) -> impl IntoView {
     view! {
       <div class="rounded-md bg-blue-50 p-4">
         <div class="flex">
@@ -25,7 +25,7 @@ pub fn InfoMessage(message: &'static str) -> impl IntoView {
 
 #[component]
 #[must_use]
-pub fn SuccessMessage(message: &'static str) -> impl IntoView {
+pub fn SuccessMessage(#[prop(into)] message: MaybeSignal<String>) -> impl IntoView {
     let show: RwSignal<bool> = RwSignal::new(true);
     view! {
       <div class="rounded-md bg-green-50 p-4" style = move || if show.get() { None } else { Some("display:none;") }>
@@ -55,7 +55,7 @@ pub fn SuccessMessage(message: &'static str) -> impl IntoView {
 
 #[component]
 #[must_use]
-pub fn ErrorMessage(message: &'static str) -> impl IntoView {
+pub fn ErrorMessage(#[prop(into)] message: MaybeSignal<String>) -> impl IntoView {
     view! {
       <div class="rounded-md bg-red-50 p-4">
         <div class="flex">
@@ -0,0 +1,40 @@
+use thiserror::Error;
+use time::OffsetDateTime;
+
+use klick_domain::{Account, ApiKey, ApiKeySecret};
+use klick_interfaces::{AccountRepo, ApiKeyRepo};
+
+pub fn authenticate_api_key<R>(
+    repo: &R,
+    secret: &ApiKeySecret,
+    now: OffsetDateTime,
+) -> Result<(Account, ApiKey), Error>
+where
+    R: AccountRepo + ApiKeyRepo,
+{
+    let Some((api_key, owner)) = repo.find_api_key_by_hash(&secret.to_hashed())? else {
+        return Err(Error::InvalidKey);
+    };
+    if api_key.is_expired(now) {
+        return Err(Error::Expired);
+    }
+    let Some(record) = repo.find_account(&owner)? else {
+        return Err(Error::InvalidKey);
+    };
+    if !record.account.email_confirmed {
+        return Err(Error::EmailNotConfirmed);
+    }
+    Ok((record.account, api_key))
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid API key")]
+    InvalidKey,
+    #[error("the API key has expired")]
+    Expired,
+    #[error("your email is not confirmed yet")]
+    EmailNotConfirmed,
+    #[error(transparent)]
+    Repo(#[from] anyhow::Error),
+}
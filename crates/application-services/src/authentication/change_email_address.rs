@@ -0,0 +1,63 @@
+use thiserror::Error;
+use time::{Duration, OffsetDateTime};
+
+use klick_domain::{Account, EmailAddress, EmailChangeToken, EmailNonce, Nonce, Password};
+use klick_interfaces::{AccountRepo, EmailChangeTokenRepo, NotificationEvent, NotificationGateway};
+
+const DEFAULT_EXPIRY_DURATION: Duration = Duration::days(1);
+
+/// Request to change the email address of an account.
+///
+/// The address is not changed until the new address
+/// has been confirmed (see [`confirm_email_address_change`](crate::confirm_email_address_change)).
+pub fn change_email_address<R, N>(
+    repo: &R,
+    notification_gateway: &N,
+    account: &Account,
+    new_email: EmailAddress,
+    password: &Password,
+) -> Result<(), Error>
+where
+    R: AccountRepo + EmailChangeTokenRepo,
+    N: NotificationGateway,
+{
+    let Some(record) = repo.find_account(&account.email_address)? else {
+        return Err(Error::NotFound);
+    };
+    if !record.password.verify(password) {
+        return Err(Error::Credentials);
+    }
+    if new_email == account.email_address {
+        return Err(Error::Unchanged);
+    }
+    if repo.find_account(&new_email)?.is_some() {
+        return Err(Error::AlreadyInUse);
+    }
+    let email_nonce = EmailNonce {
+        email: new_email,
+        nonce: Nonce::new(),
+    };
+    let token = EmailChangeToken {
+        current_email: account.email_address.clone(),
+        email_nonce: email_nonce.clone(),
+        expires_at: OffsetDateTime::now_utc() + DEFAULT_EXPIRY_DURATION,
+    };
+    repo.replace_email_change_token(&token)?;
+    let event = NotificationEvent::AccountEmailChangeRequested { email_nonce };
+    notification_gateway.notify(event);
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid password")]
+    Credentials,
+    #[error("the new email address equals the current one")]
+    Unchanged,
+    #[error("the email address is already in use")]
+    AlreadyInUse,
+    #[error("account does not exist")]
+    NotFound,
+    #[error(transparent)]
+    Repo(#[from] anyhow::Error),
+}
@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+use klick_domain::{Account, HashedPassword, Password};
+use klick_interfaces::AccountRepo;
+
+pub fn change_password<R>(
+    repo: &R,
+    account: &Account,
+    old_password: &Password,
+    new_password: HashedPassword,
+) -> Result<(), Error>
+where
+    R: AccountRepo,
+{
+    let Some(mut record) = repo.find_account(&account.email_address)? else {
+        return Err(Error::NotFound);
+    };
+    if !record.password.verify(old_password) {
+        return Err(Error::Credentials);
+    }
+    log::info!("Changing password for email ({})", account.email_address);
+    record.password = new_password;
+    repo.save_account(&record)?;
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid password")]
+    Credentials,
+    #[error("account does not exist")]
+    NotFound,
+    #[error(transparent)]
+    Repo(#[from] anyhow::Error),
+}
@@ -0,0 +1,53 @@
+use thiserror::Error;
+use time::OffsetDateTime;
+
+use klick_domain::{EmailChangeToken, EmailNonce};
+use klick_interfaces::{AccountRepo, EmailChangeTokenRepo};
+
+/// Swap the email address of an account
+/// after the new address has been confirmed.
+///
+/// Returns the consumed token that contains
+/// the previous and the new address.
+pub fn confirm_email_address_change<R>(
+    repo: &R,
+    email_nonce: &EmailNonce,
+) -> Result<EmailChangeToken, Error>
+where
+    R: AccountRepo + EmailChangeTokenRepo,
+{
+    // The token should be consumed only once, even if the following fails!
+    let token = repo
+        .consume_email_change_token(email_nonce)
+        .map_err(|err| {
+            log::warn!(
+                "missing or invalid token to change email address to '{:?}': {err}",
+                email_nonce.email,
+            );
+            Error::InvalidToken
+        })?;
+    if token.expires_at < OffsetDateTime::now_utc() {
+        return Err(Error::InvalidToken);
+    }
+    // Someone could have registered the address in the meantime.
+    if repo.find_account(&token.email_nonce.email)?.is_some() {
+        return Err(Error::AlreadyInUse);
+    }
+    log::info!(
+        "Changing email address ({}) to ({})",
+        token.current_email,
+        token.email_nonce.email
+    );
+    repo.change_email_address(&token.current_email, &token.email_nonce.email)?;
+    Ok(token)
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("missing or invalid token")]
+    InvalidToken,
+    #[error("the email address is already in use")]
+    AlreadyInUse,
+    #[error(transparent)]
+    Repo(#[from] anyhow::Error),
+}
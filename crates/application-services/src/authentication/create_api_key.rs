@@ -0,0 +1,38 @@
+use time::OffsetDateTime;
+
+use klick_domain::{Account, ApiKey, ApiKeyId, ApiKeyScope, ApiKeySecret};
+use klick_interfaces::{ApiKeyRecord, ApiKeyRepo};
+
+/// Create a new API key.
+///
+/// The returned secret can't be restored later.
+pub fn create_api_key<R>(
+    repo: &R,
+    account: &Account,
+    name: Option<String>,
+    scopes: Option<Vec<ApiKeyScope>>,
+    expires_at: Option<OffsetDateTime>,
+) -> anyhow::Result<(ApiKey, ApiKeySecret)>
+where
+    R: ApiKeyRepo,
+{
+    let created_at = OffsetDateTime::now_utc();
+    if expires_at.is_some_and(|expires_at| expires_at <= created_at) {
+        anyhow::bail!("the expiry date must be in the future");
+    }
+    let api_key = ApiKey {
+        id: ApiKeyId::new(),
+        name,
+        scopes,
+        created_at,
+        expires_at,
+    };
+    let secret = ApiKeySecret::new();
+    let record = ApiKeyRecord {
+        api_key,
+        hashed_secret: secret.to_hashed(),
+    };
+    log::info!("Create API key for account {}", account.email_address);
+    repo.save_api_key(&record, &account.email_address)?;
+    Ok((record.api_key, secret))
+}
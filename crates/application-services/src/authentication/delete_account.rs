@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+use klick_domain::{Account, Password};
+use klick_interfaces::AccountRepo;
+
+/// Delete the account including all of its data.
+///
+/// The password must be confirmed.
+pub fn delete_account<R>(repo: &R, account: &Account, password: &Password) -> Result<(), Error>
+where
+    R: AccountRepo,
+{
+    let Some(record) = repo.find_account(&account.email_address)? else {
+        return Err(Error::NotFound);
+    };
+    if !record.password.verify(password) {
+        return Err(Error::Credentials);
+    }
+    log::info!("Delete account {}", account.email_address);
+    repo.delete_account(&account.email_address)?;
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("invalid password")]
+    Credentials,
+    #[error("account does not exist")]
+    NotFound,
+    #[error(transparent)]
+    Repo(#[from] anyhow::Error),
+}
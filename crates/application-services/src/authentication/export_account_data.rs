@@ -0,0 +1,24 @@
+use klick_domain::{Account, ApiKey, Project};
+use klick_interfaces::{ApiKeyRepo, ProjectRepo};
+
+/// Everything that is stored about an account.
+#[derive(Debug)]
+pub struct AccountData<D> {
+    pub account: Account,
+    pub api_keys: Vec<ApiKey>,
+    pub projects: Vec<Project<D>>,
+}
+
+pub fn export_account_data<R, D>(repo: &R, account: &Account) -> anyhow::Result<AccountData<D>>
+where
+    R: ApiKeyRepo + ProjectRepo<D>,
+{
+    log::info!("Export data of account {}", account.email_address);
+    let api_keys = repo.all_api_keys_by_owner(&account.email_address)?;
+    let projects = repo.all_projects_by_owner(&account.email_address)?;
+    Ok(AccountData {
+        account: account.clone(),
+        api_keys,
+        projects,
+    })
+}
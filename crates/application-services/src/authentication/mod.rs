@@ -1,23 +1,43 @@
+mod authenticate_api_key;
+mod change_email_address;
+mod change_password;
 mod confirm_email_address;
+mod confirm_email_address_change;
 mod consume_account_token;
 mod create_account;
+mod create_api_key;
+mod delete_account;
 mod delete_expired_account_tokens;
+mod export_account_data;
 mod login;
+mod read_all_api_keys;
 mod refresh_account_token;
 mod request_password_reset;
 mod resend_confirmation_email;
 mod reset_password;
+mod revoke_api_key;
 mod send_confirmation_email;
 
 pub use self::{
+    authenticate_api_key::{authenticate_api_key, Error as AuthenticateApiKeyError},
+    change_email_address::{change_email_address, Error as ChangeEmailAddressError},
+    change_password::{change_password, Error as ChangePasswordError},
     confirm_email_address::{confirm_email_address, Error as ConfirmEmailAddressError},
+    confirm_email_address_change::{
+        confirm_email_address_change, Error as ConfirmEmailAddressChangeError,
+    },
     consume_account_token::{consume_account_token, Error as ConsumeAccountTokenError},
     create_account::{create_account, Error as CreateAccountError},
+    create_api_key::create_api_key,
+    delete_account::{delete_account, Error as DeleteAccountError},
     delete_expired_account_tokens::delete_expired_account_tokens,
+    export_account_data::{export_account_data, AccountData},
     login::{login, Error as LoginError},
+    read_all_api_keys::read_all_api_keys,
     refresh_account_token::refresh_account_token,
     request_password_reset::request_password_reset,
     resend_confirmation_email::resend_confirmation_email,
     reset_password::{reset_password, Error as ResetPasswordError},
+    revoke_api_key::{revoke_api_key, Error as RevokeApiKeyError},
     send_confirmation_email::send_confirmation_email,
 };
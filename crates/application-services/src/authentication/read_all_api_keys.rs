@@ -0,0 +1,9 @@
+use klick_domain::{Account, ApiKey};
+use klick_interfaces::ApiKeyRepo;
+
+pub fn read_all_api_keys<R>(repo: &R, account: &Account) -> anyhow::Result<Vec<ApiKey>>
+where
+    R: ApiKeyRepo,
+{
+    repo.all_api_keys_by_owner(&account.email_address)
+}
@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+use klick_domain::{Account, ApiKeyId};
+use klick_interfaces::ApiKeyRepo;
+
+pub fn revoke_api_key<R>(repo: &R, account: &Account, id: &ApiKeyId) -> Result<(), Error>
+where
+    R: ApiKeyRepo,
+{
+    log::info!("Revoke API key {id} of account {}", account.email_address);
+    if !repo.delete_api_key(id, &account.email_address)? {
+        return Err(Error::NotFound);
+    }
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("API key not found")]
+    NotFound,
+    #[error(transparent)]
+    Repo(#[from] anyhow::Error),
+}
@@ -5,6 +5,9 @@ mod read_project;
 mod update_project;
 
 pub use self::{
-    create_new_project::*, delete_project::*, read_all_projects::*, read_project::*,
-    update_project::*,
+    create_new_project::*,
+    delete_project::*,
+    read_all_projects::*,
+    read_project::*,
+    update_project::{update_project, Error as UpdateProjectError},
 };
@@ -1,13 +1,18 @@
 use anyhow::anyhow;
 
-use klick_domain::{Project, ProjectId};
+use klick_domain::{Account, Project, ProjectId};
 use klick_interfaces::ProjectRepo;
 
-pub fn read_project<R, D>(repo: &R, id: ProjectId) -> anyhow::Result<Project<D>>
+/// Read a project of the account.
+pub fn read_project<R, D>(repo: &R, account: &Account, id: ProjectId) -> anyhow::Result<Project<D>>
 where
     R: ProjectRepo<D>,
 {
-    let Some(project) = repo.find_project(&id)? else {
+    let Some(project) = repo
+        .all_projects_by_owner(&account.email_address)?
+        .into_iter()
+        .find(|project| project.id == id)
+    else {
         return Err(anyhow!("project not found"));
     };
     Ok(project)
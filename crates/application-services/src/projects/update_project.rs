@@ -1,23 +1,60 @@
-use anyhow::anyhow;
+use thiserror::Error;
 use time::OffsetDateTime;
 
-use klick_domain::{Account, ProjectId};
+use klick_domain::{Account, Project, ProjectId};
 use klick_interfaces::ProjectRepo;
 
+/// Update the data of a project.
+///
+/// `modified_at` is the modification time of the project
+/// the changes are based on. If the project has been
+/// modified in the meantime the update is rejected.
+///
+/// Returns the updated project.
 pub fn update_project<R, D>(
     repo: &R,
     account: &Account,
     id: &ProjectId,
+    modified_at: Option<OffsetDateTime>,
     data: D,
-) -> anyhow::Result<()>
+) -> Result<Project<D>, Error>
 where
     R: ProjectRepo<D>,
+    D: Clone,
 {
-    let Some(mut project) = repo.find_project(id)? else {
-        return Err(anyhow!("project not found"));
+    let find_own_project = || {
+        repo.all_projects_by_owner(&account.email_address)
+            .map(|projects| projects.into_iter().find(|project| project.id == *id))
     };
+    let Some(mut project) = find_own_project()? else {
+        return Err(Error::NotFound);
+    };
+    if project.modified_at != modified_at {
+        log::info!("Reject outdated update of project {id}");
+        return Err(Error::Conflict {
+            modified_at: project.modified_at,
+        });
+    }
     project.modified_at = Some(OffsetDateTime::now_utc());
     project.data = data;
-    repo.save_project(project, &account.email_address)?;
-    Ok(())
+    if !repo.update_project(project.clone(), &account.email_address, modified_at)? {
+        log::info!("Reject concurrent update of project {id}");
+        let Some(current) = find_own_project()? else {
+            return Err(Error::NotFound);
+        };
+        return Err(Error::Conflict {
+            modified_at: current.modified_at,
+        });
+    }
+    Ok(project)
+}
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("project not found")]
+    NotFound,
+    #[error("the project has been modified in the meantime")]
+    Conflict { modified_at: Option<OffsetDateTime> },
+    #[error(transparent)]
+    Repo(#[from] anyhow::Error),
 }
@@ -1,5 +1,5 @@
 use axum::{
-    http::StatusCode,
+    http::{header, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
 };
 use thiserror::Error;
@@ -7,9 +7,12 @@ use thiserror::Error;
 use klick_application_services as services;
 use klick_boundary::json_api;
 use klick_domain::{
-    EmailAddress, EmailAddressParseError, EmailNonceDecodingError, Password, PasswordParseError,
+    ApiKeyScope, EmailAddress, EmailAddressParseError, EmailNonceDecodingError, Password,
+    PasswordParseError,
 };
 
+use crate::rate_limit;
+
 /// API error
 #[derive(Error, Debug)]
 #[non_exhaustive]
@@ -31,11 +34,29 @@ pub enum ApiError {
     #[error(transparent)]
     Auth(#[from] AuthError),
     #[error(transparent)]
+    ChangePassword(#[from] services::ChangePasswordError),
+    #[error(transparent)]
+    ChangePasswordNewPassword(PasswordParseError),
+    #[error(transparent)]
+    ChangeEmailAddress(#[from] services::ChangeEmailAddressError),
+    #[error(transparent)]
+    ChangeEmailAddressEmail(EmailAddressParseError),
+    #[error(transparent)]
+    ConfirmEmailAddressChange(#[from] services::ConfirmEmailAddressChangeError),
+    #[error(transparent)]
+    DeleteAccount(#[from] services::DeleteAccountError),
+    #[error(transparent)]
     EmailNonce(#[from] EmailNonceDecodingError),
     #[error(transparent)]
     ConfirmEmail(#[from] services::ConfirmEmailAddressError),
     #[error(transparent)]
     ResetPassword(#[from] services::ResetPasswordError),
+    #[error(transparent)]
+    RevokeApiKey(#[from] services::RevokeApiKeyError),
+    #[error(transparent)]
+    UpdateProject(#[from] services::UpdateProjectError),
+    #[error(transparent)]
+    RateLimit(#[from] rate_limit::Error),
     #[error("internal server error")]
     InternalServerError,
     #[error(transparent)]
@@ -59,6 +80,10 @@ pub enum AuthError {
     NotAuthorized,
     #[error("your email is not confirmed yet")]
     EmailNotConfirmed,
+    #[error("the API key lacks the '{0}' scope")]
+    MissingScope(ApiKeyScope),
+    #[error("this action requires a login session")]
+    SessionRequired,
 }
 
 #[derive(Debug, Error)]
@@ -91,6 +116,7 @@ impl From<CredentialParsingError> for ApiError {
 
 // TODO: tidy up and uwe json_api::Error directly
 impl IntoResponse for ApiError {
+    #[allow(clippy::too_many_lines)]
     fn into_response(self) -> Response {
         use json_api::Error as E;
         match self {
@@ -108,8 +134,10 @@ impl IntoResponse for ApiError {
                 }
                 services::CreateAccountError::Repo(_) => internal(),
             },
-            Self::CreateAccountEmail(err) => bad_request(err),
-            Self::CreateAccountPassword(err) => bad_request(err),
+            Self::CreateAccountEmail(err) | Self::ChangeEmailAddressEmail(err) => bad_request(err),
+            Self::CreateAccountPassword(err) | Self::ChangePasswordNewPassword(err) => {
+                bad_request(err)
+            }
             Self::Login(err) => match err {
                 services::LoginError::Credentials => E::bad_request()
                     .details(json_api::login::Error::Credentials)
@@ -125,7 +153,38 @@ impl IntoResponse for ApiError {
                 .message("invalid email or password")
                 .into_response(),
             Self::Logout(err) => bad_request(err),
-            Self::Auth(_) => E::<()>::unauthorized().into_response(),
+            Self::Auth(err) => match err {
+                AuthError::NotAuthorized | AuthError::EmailNotConfirmed => {
+                    E::<()>::unauthorized().into_response()
+                }
+                AuthError::MissingScope(_) | AuthError::SessionRequired => {
+                    E::<()>::forbidden().message(err).into_response()
+                }
+            },
+            Self::ChangePassword(err) => match err {
+                services::ChangePasswordError::Credentials => bad_request(err),
+                services::ChangePasswordError::NotFound => E::<()>::unauthorized().into_response(),
+                services::ChangePasswordError::Repo(_) => internal(),
+            },
+            Self::ChangeEmailAddress(err) => match err {
+                services::ChangeEmailAddressError::Credentials
+                | services::ChangeEmailAddressError::Unchanged
+                | services::ChangeEmailAddressError::AlreadyInUse => bad_request(err),
+                services::ChangeEmailAddressError::NotFound => {
+                    E::<()>::unauthorized().into_response()
+                }
+                services::ChangeEmailAddressError::Repo(_) => internal(),
+            },
+            Self::ConfirmEmailAddressChange(err) => match err {
+                services::ConfirmEmailAddressChangeError::InvalidToken
+                | services::ConfirmEmailAddressChangeError::AlreadyInUse => bad_request(err),
+                services::ConfirmEmailAddressChangeError::Repo(_) => internal(),
+            },
+            Self::DeleteAccount(err) => match err {
+                services::DeleteAccountError::Credentials => bad_request(err),
+                services::DeleteAccountError::NotFound => E::<()>::unauthorized().into_response(),
+                services::DeleteAccountError::Repo(_) => internal(),
+            },
             Self::EmailNonce(err) => bad_request(err).into_response(),
             Self::ConfirmEmail(err) => bad_request(err).into_response(),
             Self::ResetPassword(err) => match err {
@@ -133,6 +192,34 @@ impl IntoResponse for ApiError {
                 | services::ResetPasswordError::NotFound => bad_request(err),
                 services::ResetPasswordError::Repo(_) => internal(),
             },
+            Self::RevokeApiKey(err) => match err {
+                services::RevokeApiKeyError::NotFound => {
+                    E::<()>::not_found().message(err).into_response()
+                }
+                services::RevokeApiKeyError::Repo(_) => internal(),
+            },
+            Self::UpdateProject(err) => match err {
+                services::UpdateProjectError::NotFound => {
+                    E::<()>::not_found().message(err).into_response()
+                }
+                services::UpdateProjectError::Conflict { .. } => {
+                    E::<()>::conflict().message(err).into_response()
+                }
+                services::UpdateProjectError::Repo(_) => internal(),
+            },
+            Self::RateLimit(err) => match err {
+                rate_limit::Error::Exceeded { retry_after } => {
+                    let mut response = E::<()>::too_many_requests().message(&err).into_response();
+                    // Round up to full seconds
+                    let seconds = retry_after.whole_seconds()
+                        + i64::from(retry_after.subsec_nanoseconds() > 0);
+                    response
+                        .headers_mut()
+                        .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+                    response
+                }
+                rate_limit::Error::Repo(_) => internal(),
+            },
             Self::InternalServerError => internal(),
             Self::Other(err) => bad_request(err),
         }
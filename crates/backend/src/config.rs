@@ -16,6 +16,9 @@ pub struct Config {
     pub base_url: Url,
     pub db_connection: String,
     pub smtp: Option<SmtpConfig>,
+    /// Reverse proxies (e.g. nginx) whose `X-Forwarded-For`
+    /// header is used to determine the IP address of a client.
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 impl Default for Config {
@@ -24,11 +27,13 @@ impl Default for Config {
         let base_url = format!("http://{address}").parse().expect("valid base URL");
         let db_connection = DEFAULT_DB_URL.to_string();
         let smtp = None;
+        let trusted_proxies = vec![];
         Self {
             address,
             base_url,
             db_connection,
             smtp,
+            trusted_proxies,
         }
     }
 }
@@ -85,6 +90,7 @@ mod raw {
         base_url: Url,
         db_connection: Option<String>,
         smtp: Option<Smtp>,
+        trusted_proxies: Option<Vec<String>>,
     }
 
     #[derive(Deserialize)]
@@ -114,6 +120,7 @@ mod raw {
                 base_url,
                 db_connection,
                 smtp,
+                trusted_proxies,
             } = from;
             let ip_address = match address {
                 Some(addr) => addr.parse()?,
@@ -123,11 +130,17 @@ mod raw {
             let address = (ip_address, port).into();
             let db_connection = db_connection.unwrap_or_else(|| DEFAULT_DB_URL.to_string());
             let smtp = smtp.map(super::SmtpConfig::try_from).transpose()?;
+            let trusted_proxies = trusted_proxies
+                .unwrap_or_default()
+                .iter()
+                .map(|ip| ip.parse())
+                .collect::<Result<_, _>>()?;
             Ok(Self {
                 address,
                 base_url,
                 db_connection,
                 smtp,
+                trusted_proxies,
             })
         }
     }
@@ -177,6 +190,7 @@ mod raw {
             assert_eq!(raw.port, Some(3000));
             assert_eq!(raw.base_url.as_str(), "https://example.org/");
             assert_eq!(raw.db_connection.as_deref(), Some("db.sqlite"));
+            assert_eq!(raw.trusted_proxies, Some(vec!["127.0.0.1".to_string()]));
             let smtp = raw.smtp.unwrap();
             assert_eq!(smtp.username, "no-reply@example.org");
             assert_eq!(smtp.password, "very-secret");
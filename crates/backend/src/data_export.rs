@@ -0,0 +1,39 @@
+use std::io::{Cursor, Write as _};
+
+use zip::{write::SimpleFileOptions, ZipWriter};
+
+use klick_application_services::AccountData;
+use klick_boundary::{self as boundary, json_api};
+
+/// Bundle all data of an account as ZIP archive.
+///
+/// Each project is stored in the v9
+/// JSON export format.
+pub fn account_data_to_zip(data: AccountData<boundary::JsonFormData>) -> anyhow::Result<Vec<u8>> {
+    let AccountData {
+        account,
+        api_keys,
+        projects,
+    } = data;
+    let account_data = json_api::AccountData {
+        email: account.email_address.into_string(),
+        email_confirmed: account.email_confirmed,
+        created_at: account.created_at,
+        api_keys: api_keys.into_iter().map(Into::into).collect(),
+    };
+
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default();
+
+    zip.start_file("account.json", options)?;
+    zip.write_all(&serde_json::to_vec_pretty(&account_data)?)?;
+
+    for project in projects {
+        zip.start_file(format!("projects/{}.json", project.id.to_uuid()), options)?;
+        let project = boundary::Project::from(project);
+        zip.write_all(&boundary::export_v9_to_vec_pretty(&project)?)?;
+    }
+
+    let bytes = zip.finish()?.into_inner();
+    Ok(bytes)
+}
@@ -1,5 +1,6 @@
 use std::{
     collections::HashMap,
+    net::{IpAddr, SocketAddr},
     path::{self, PathBuf},
     sync::Arc,
     thread,
@@ -7,8 +8,8 @@ use std::{
 
 use anyhow::{anyhow, bail};
 use axum::{
-    extract::{Path, Query, State},
-    http::{header, Method, StatusCode, Uri},
+    extract::{ConnectInfo, Path, Query, State},
+    http::{header, HeaderMap, Method, StatusCode, Uri},
     response::{Html, IntoResponse, Json, Response},
     routing::{delete, get, post, put},
     Router,
@@ -26,14 +27,22 @@ use uuid::Uuid;
 
 use klick_application_services as services;
 use klick_boundary::{self as boundary, json_api};
+use klick_custom_values_parser::{custom_values_from_structure, CustomValues, Diagnostic};
 use klick_db_sqlite::Connection;
-use klick_domain::{Account, EmailAddress, EmailNonce, Password, ProjectId};
-use klick_interfaces::{AccountRepo as _, ProjectRepo};
+use klick_domain::{
+    Account, ApiKeyId, ApiKeyScope, ApiKeySecret, EmailAddress, EmailNonce, InputValueId as In,
+    Password, ProjectId, Value, ValueId as Id,
+};
+use klick_interfaces::{AccountRepo as _, ProjectRepo, RateLimitRepo as _};
 use klick_pdf_export::export_to_pdf;
+use klick_presenter::Lng;
 
 mod adapters;
 mod config;
+mod data_export;
 mod notification_gateway;
+mod openapi;
+mod rate_limit;
 
 use self::adapters::{ApiError, AuthError, LogoutError};
 
@@ -41,6 +50,8 @@ pub use self::config::Config;
 
 static INDEX_HTML: &str = "index.html";
 
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
 #[derive(RustEmbed)]
 #[folder = "../../frontend/dist/"]
 struct Assets;
@@ -51,7 +62,11 @@ pub async fn run(config: &Config) -> anyhow::Result<()> {
     let router = create_router(db, config)?;
     log::info!("Start listening on http://{}", config.address);
     let listener = TcpListener::bind(config.address).await?;
-    axum::serve(listener, router.into_make_service()).await?;
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
     Ok(())
 }
 
@@ -62,13 +77,15 @@ pub fn create_db_connection(config: &Config) -> anyhow::Result<Connection> {
     db.run_embedded_database_migrations()?;
     let created_before = OffsetDateTime::now_utc() - VALIDITY_PERIOD_OF_UNCONFIRMED_ACCOUNTS;
     db.delete_old_unconfirmed_accounts(created_before)?;
+    db.delete_stale_rate_limits(OffsetDateTime::now_utc() - rate_limit::MAX_RECORD_AGE)?;
     Ok(db)
 }
 
 pub fn create_router(db: Connection, config: &Config) -> anyhow::Result<Router> {
     let base_url = config.base_url.clone();
     let notification_gw = notification_gateway::Gateway::new(config);
-    let shared_state = AppState::new(db, base_url, notification_gw);
+    let trusted_proxies = config.trusted_proxies.clone();
+    let shared_state = AppState::new(db, base_url, notification_gw, trusted_proxies);
 
     let cors_layer = CorsLayer::new()
         .allow_methods([Method::GET, Method::DELETE, Method::PUT, Method::POST])
@@ -80,6 +97,14 @@ pub fn create_router(db: Connection, config: &Config) -> anyhow::Result<Router>
         .route("/logout", post(logout))
         .route("/users", post(create_account))
         .route("/users", get(account_info))
+        .route("/users", delete(delete_account))
+        .route("/users/export", get(export_account_data))
+        .route("/users/change-password", post(change_password))
+        .route("/users/change-email-address", post(change_email_address))
+        .route(
+            "/users/confirm-email-address-change",
+            post(confirm_email_address_change),
+        )
         .route(
             "/users/resent-confirmation-email",
             post(resent_confirmation_email),
@@ -90,14 +115,20 @@ pub fn create_router(db: Connection, config: &Config) -> anyhow::Result<Router>
             post(request_password_reset),
         )
         .route("/users/reset-password", post(reset_password))
+        .route("/users/api-keys", get(get_all_api_keys))
+        .route("/users/api-keys", post(create_api_key))
+        .route("/users/api-keys/:id", delete(revoke_api_key))
         .route("/projects", get(get_all_projects))
         .route("/project", post(new_project))
         .route("/project/:id", put(update_project))
         .route("/project/:id", get(get_project))
         .route("/project/:id", delete(delete_project))
         .route("/project/:id/export", get(get_export))
+        .route("/project/:id/chart/:file-name", get(get_chart))
         .route("/download/:download-id", get(get_download))
         .route("/download/:download-id/status", get(get_download_status))
+        .route("/v1/calculate", post(calculate))
+        .route("/openapi.json", get(openapi::openapi_json))
         .route_layer(cors_layer)
         .with_state(shared_state);
 
@@ -142,6 +173,7 @@ pub struct AppState {
     downloads: Arc<RwLock<HashMap<Uuid, Download>>>, // TODO: use stateless JWT
     notification_gw: notification_gateway::Gateway,
     base_url: Url,
+    trusted_proxies: Arc<[IpAddr]>,
 }
 
 #[derive(Debug)]
@@ -172,6 +204,7 @@ impl AppState {
         db: Connection,
         base_url: Url,
         notification_gw: notification_gateway::Gateway,
+        trusted_proxies: Vec<IpAddr>,
     ) -> Self {
         Self {
             db,
@@ -179,14 +212,33 @@ impl AppState {
             tokens: Arc::default(),
             downloads: Arc::default(),
             notification_gw,
+            trusted_proxies: trusted_proxies.into(),
         }
     }
+
+    fn client_ip(
+        &self,
+        connect_info: Option<ConnectInfo<SocketAddr>>,
+        headers: &HeaderMap,
+    ) -> Option<IpAddr> {
+        let ConnectInfo(addr) = connect_info?;
+        let forwarded_for = headers
+            .get(X_FORWARDED_FOR)
+            .and_then(|value| value.to_str().ok());
+        Some(rate_limit::client_ip(
+            addr.ip(),
+            forwarded_for,
+            &self.trusted_proxies,
+        ))
+    }
 }
 
 type Result<T> = std::result::Result<Json<T>, ApiError>;
 
 async fn create_account(
     State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
     Json(credentials): Json<json_api::Credentials>,
 ) -> Result<()> {
     let json_api::Credentials { email, password } = credentials;
@@ -196,17 +248,47 @@ async fn create_account(
     let password = password
         .parse::<Password>()
         .map_err(ApiError::CreateAccountPassword)?;
+    let client_ip = state.client_ip(connect_info, &headers);
+    limit_emails(&state, client_ip, &email)?;
     services::create_account(&state.db, &state.notification_gw, email, &password)?;
     Ok(Json(()))
 }
 
 async fn login(
     State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
     Json(credentials): Json<json_api::Credentials>,
 ) -> Result<json_api::ApiToken> {
     let adapters::Credentials { email, password } = credentials.try_into()?;
     log::debug!("{email} tries to login");
-    let account = services::login(&state.db, &email, &password)?;
+    let now = OffsetDateTime::now_utc();
+    let email_key = rate_limit::login_email_key(&email);
+    let ip_key = state
+        .client_ip(connect_info, &headers)
+        .map(rate_limit::login_ip_key);
+    rate_limit::check(&state.db, &email_key, now)?;
+    if let Some(ip_key) = &ip_key {
+        rate_limit::check(&state.db, ip_key, now)?;
+    }
+    let account = match services::login(&state.db, &email, &password) {
+        Ok(account) => account,
+        Err(err) => {
+            if matches!(err, services::LoginError::Credentials) {
+                log::info!("Failed login attempt for {email}");
+                let policy = &rate_limit::FAILED_LOGINS_PER_EMAIL;
+                rate_limit::register_attempt(&state.db, &email_key, policy, now)?;
+                if let Some(ip_key) = &ip_key {
+                    let policy = &rate_limit::FAILED_LOGINS_PER_IP;
+                    rate_limit::register_attempt(&state.db, ip_key, policy, now)?;
+                }
+            }
+            return Err(err.into());
+        }
+    };
+    // The failures of the client IP are kept,
+    // otherwise an own account could be used to reset them.
+    rate_limit::reset(&state.db, &email_key)?;
     debug_assert_eq!(account.email_address, email);
     let token = Uuid::new_v4();
     state.tokens.write().insert(token, account);
@@ -231,12 +313,138 @@ async fn account_info(
     State(state): State<AppState>,
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
 ) -> Result<json_api::UserInfo> {
-    let account = account_from_token(&state, &auth)?;
+    let account = account_from_token(&state, &auth, None)?;
     let email = account.email_address.into_string();
     let user_info = json_api::UserInfo { email };
     Ok(Json(user_info))
 }
 
+async fn delete_account(
+    State(state): State<AppState>,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(json_api::DeleteAccount { password }): Json<json_api::DeleteAccount>,
+) -> Result<()> {
+    let account = account_from_session_token(&state, &auth)?;
+    confirm_password(
+        &state,
+        &account,
+        || {
+            let password = password
+                .parse::<Password>()
+                .map_err(|_| services::DeleteAccountError::Credentials)?;
+            services::delete_account(&state.db, &account, &password)
+        },
+        |err| matches!(err, services::DeleteAccountError::Credentials),
+    )?;
+    state
+        .tokens
+        .write()
+        .retain(|_, a| a.email_address != account.email_address);
+    Ok(Json(()))
+}
+
+async fn change_password(
+    State(state): State<AppState>,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(data): Json<json_api::ChangePassword>,
+) -> Result<()> {
+    let account = account_from_session_token(&state, &auth)?;
+    let json_api::ChangePassword {
+        old_password,
+        new_password,
+    } = data;
+    let new_password = new_password
+        .parse::<Password>()
+        .map(|pw| pw.to_hashed())
+        .map_err(ApiError::ChangePasswordNewPassword)?;
+    confirm_password(
+        &state,
+        &account,
+        || {
+            let old_password = old_password
+                .parse::<Password>()
+                .map_err(|_| services::ChangePasswordError::Credentials)?;
+            services::change_password(&state.db, &account, &old_password, new_password)
+        },
+        |err| matches!(err, services::ChangePasswordError::Credentials),
+    )?;
+    Ok(Json(()))
+}
+
+async fn change_email_address(
+    State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(data): Json<json_api::ChangeEmailAddress>,
+) -> Result<()> {
+    let account = account_from_session_token(&state, &auth)?;
+    let json_api::ChangeEmailAddress {
+        new_email,
+        password,
+    } = data;
+    let new_email = new_email
+        .parse::<EmailAddress>()
+        .map_err(ApiError::ChangeEmailAddressEmail)?;
+    let client_ip = state.client_ip(connect_info, &headers);
+    limit_emails(&state, client_ip, &new_email)?;
+    confirm_password(
+        &state,
+        &account,
+        || {
+            let password = password
+                .parse::<Password>()
+                .map_err(|_| services::ChangeEmailAddressError::Credentials)?;
+            services::change_email_address(
+                &state.db,
+                &state.notification_gw,
+                &account,
+                new_email,
+                &password,
+            )
+        },
+        |err| matches!(err, services::ChangeEmailAddressError::Credentials),
+    )?;
+    Ok(Json(()))
+}
+
+async fn confirm_email_address_change(
+    State(state): State<AppState>,
+    Json(data): Json<json_api::ConfirmEmailAddress>,
+) -> Result<()> {
+    let json_api::ConfirmEmailAddress { token } = data;
+    let email_nonce = EmailNonce::decode_from_str(&token)?;
+    let token = services::confirm_email_address_change(&state.db, &email_nonce)?;
+    // Keep the active sessions of the account
+    for account in state.tokens.write().values_mut() {
+        if account.email_address == token.current_email {
+            account.email_address = token.email_nonce.email.clone();
+            account.email_confirmed = true;
+        }
+    }
+    Ok(Json(()))
+}
+
+async fn export_account_data(
+    State(state): State<AppState>,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+) -> std::result::Result<Response, ApiError> {
+    let account = account_from_session_token(&state, &auth)?;
+    let data = services::export_account_data(&state.db, &account)?;
+    let bytes = data_export::account_data_to_zip(data).map_err(|err| {
+        log::warn!("Unable to export account data: {err}");
+        ApiError::InternalServerError
+    })?;
+    let headers = [
+        (header::CONTENT_TYPE, "application/zip"),
+        (
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=klick-data-export.zip",
+        ),
+    ];
+    Ok((headers, bytes).into_response())
+}
+
 async fn confirm_email_address(
     State(state): State<AppState>,
     Json(data): Json<json_api::ConfirmEmailAddress>,
@@ -249,10 +457,14 @@ async fn confirm_email_address(
 
 async fn resent_confirmation_email(
     State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
     Json(credentials): Json<json_api::Credentials>,
 ) -> Result<()> {
     let adapters::Credentials { email, password } = credentials.try_into()?;
     log::debug!("{email} requests a new email to confirm");
+    let client_ip = state.client_ip(connect_info, &headers);
+    limit_emails(&state, client_ip, &email)?;
     services::resend_confirmation_email(&state.db, &state.notification_gw, email, &password)
         .map_err(|err| {
             log::warn!("Unable to resent confirmation email: {err}");
@@ -263,12 +475,16 @@ async fn resent_confirmation_email(
 
 async fn request_password_reset(
     State(state): State<AppState>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    headers: HeaderMap,
     Json(data): Json<json_api::RequestPasswordReset>,
 ) -> Result<()> {
     let json_api::RequestPasswordReset { email } = data;
     let email = email
         .parse::<EmailAddress>()
         .map_err(ApiError::LoginEmail)?;
+    let client_ip = state.client_ip(connect_info, &headers);
+    limit_emails(&state, client_ip, &email)?;
     if let Err(err) = services::request_password_reset(&state.db, &state.notification_gw, email) {
         // We do not report any error to the user here,
         // but we create a log statement.
@@ -277,6 +493,49 @@ async fn request_password_reset(
     Ok(Json(()))
 }
 
+// Prevent spamming mailboxes through our SMTP account.
+fn limit_emails(
+    state: &AppState,
+    client_ip: Option<IpAddr>,
+    email: &EmailAddress,
+) -> std::result::Result<(), rate_limit::Error> {
+    let now = OffsetDateTime::now_utc();
+    if let Some(ip) = client_ip {
+        let key = rate_limit::email_ip_key(ip);
+        rate_limit::acquire(&state.db, &key, &rate_limit::EMAILS_PER_IP, now)?;
+    }
+    let key = rate_limit::email_address_key(email);
+    rate_limit::acquire(&state.db, &key, &rate_limit::EMAILS_PER_ADDRESS, now)
+}
+
+// Prevent guessing the password with a hijacked session.
+fn confirm_password<T, E>(
+    state: &AppState,
+    account: &Account,
+    confirm: impl FnOnce() -> std::result::Result<T, E>,
+    is_wrong_password: impl FnOnce(&E) -> bool,
+) -> std::result::Result<T, ApiError>
+where
+    ApiError: From<E>,
+{
+    let now = OffsetDateTime::now_utc();
+    let key = rate_limit::password_confirmation_key(&account.email_address);
+    rate_limit::check(&state.db, &key, now)?;
+    match confirm() {
+        Ok(value) => {
+            rate_limit::reset(&state.db, &key)?;
+            Ok(value)
+        }
+        Err(err) => {
+            if is_wrong_password(&err) {
+                let policy = &rate_limit::FAILED_PASSWORD_CONFIRMATIONS;
+                rate_limit::register_attempt(&state.db, &key, policy, now)?;
+            }
+            Err(err.into())
+        }
+    }
+}
+
 async fn reset_password(
     State(state): State<AppState>,
     Json(data): Json<json_api::ResetPassword>,
@@ -294,12 +553,58 @@ async fn reset_password(
     Ok(Json(()))
 }
 
+async fn get_all_api_keys(
+    State(state): State<AppState>,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+) -> Result<Vec<json_api::ApiKey>> {
+    let account = account_from_session_token(&state, &auth)?;
+    let api_keys = services::read_all_api_keys(&state.db, &account)?
+        .into_iter()
+        .map(Into::into)
+        .collect();
+    Ok(Json(api_keys))
+}
+
+async fn create_api_key(
+    State(state): State<AppState>,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+    Json(new_api_key): Json<json_api::NewApiKey>,
+) -> Result<json_api::CreatedApiKey> {
+    let account = account_from_session_token(&state, &auth)?;
+    let json_api::NewApiKey {
+        name,
+        scopes,
+        expires_at,
+    } = new_api_key;
+    let scopes = scopes.map(|scopes| scopes.into_iter().map(Into::into).collect());
+    let (api_key, secret) =
+        services::create_api_key(&state.db, &account, name, scopes, expires_at)?;
+    Ok(Json(json_api::CreatedApiKey {
+        api_key: api_key.into(),
+        secret: secret.as_str().to_string(),
+    }))
+}
+
+async fn revoke_api_key(
+    State(state): State<AppState>,
+    Path(uuid): Path<Uuid>,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+) -> Result<()> {
+    let account = account_from_session_token(&state, &auth)?;
+    let id = ApiKeyId::from_uuid(uuid);
+    services::revoke_api_key(&state.db, &account, &id)?;
+    Ok(Json(()))
+}
+
 async fn new_project(
     State(state): State<AppState>,
+    Query(params): Query<LanguageParams>,
+    headers: HeaderMap,
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
     Json(data): Json<boundary::JsonFormData>,
 ) -> Result<boundary::ProjectId> {
-    let account = account_from_token(&state, &auth)?;
+    let account = account_from_token(&state, &auth, Some(ApiKeyScope::WriteProjects))?;
+    check_custom_emissions(&data, request_language(&params, &headers))?;
     let id = services::create_new_project(&state.db, &account, data)?;
     let id = boundary::ProjectId::from(id);
     Ok(Json(id))
@@ -307,13 +612,22 @@ async fn new_project(
 
 async fn update_project(
     State(state): State<AppState>,
+    Query(params): Query<LanguageParams>,
+    headers: HeaderMap,
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
     Json(updated): Json<boundary::SavedProject>,
-) -> Result<()> {
-    let account = account_from_token(&state, &auth)?;
+) -> Result<boundary::SavedProject> {
+    let account = account_from_token(&state, &auth, Some(ApiKeyScope::WriteProjects))?;
     let id = ProjectId::from(updated.id);
-    services::update_project(&state.db, &account, &id, updated.form_data)?;
-    Ok(Json(()))
+    check_custom_emissions(&updated.form_data, request_language(&params, &headers))?;
+    let project = services::update_project(
+        &state.db,
+        &account,
+        &id,
+        updated.modified_at,
+        updated.form_data,
+    )?;
+    Ok(Json(project.into()))
 }
 
 async fn get_project(
@@ -321,9 +635,9 @@ async fn get_project(
     Path(uuid): Path<Uuid>,
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
 ) -> Result<boundary::SavedProject> {
-    account_from_token(&state, &auth)?;
+    let account = account_from_token(&state, &auth, Some(ApiKeyScope::ReadProjects))?;
     let id = ProjectId::from_uuid(uuid);
-    let project = services::read_project(&state.db, id)?;
+    let project = services::read_project(&state.db, &account, id)?;
     Ok(Json(project.into()))
 }
 
@@ -347,16 +661,17 @@ async fn get_export(
     Query(params): Query<Export>,
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
 ) -> Result<json_api::DownloadRequestResponse> {
-    let account = account_from_token(&state, &auth)?;
+    let account = account_from_token(&state, &auth, Some(ApiKeyScope::ReadProjects))?;
     let project_id = ProjectId::from_uuid(uuid);
     log::debug!(
         "{} requested an {:?} export of project {project_id}",
         account.email_address,
         params.format
     );
-    if state.db.find_project(&project_id)?.is_none() {
-        let err = anyhow!("project {project_id} not found");
-        log::warn!("{err}");
+    if let Err(err) =
+        services::read_project::<_, boundary::JsonFormData>(&state.db, &account, project_id)
+    {
+        log::warn!("Unable to export project {project_id}: {err}");
         return Err(ApiError::from(err));
     };
     let extension = match params.format {
@@ -440,6 +755,109 @@ async fn get_download(
     }
 }
 
+#[derive(Deserialize)]
+struct ChartParams {
+    width: Option<f64>,
+    height: Option<f64>,
+    #[serde(default)]
+    lang: Language,
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+enum Language {
+    #[default]
+    De,
+    En,
+}
+
+impl From<Language> for Lng {
+    fn from(from: Language) -> Self {
+        match from {
+            Language::De => Self::De,
+            Language::En => Self::En,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct LanguageParams {
+    lang: Option<Language>,
+}
+
+/// The `lang` query parameter takes precedence over
+/// the `Accept-Language` header. Defaults to German.
+fn request_language(params: &LanguageParams, headers: &HeaderMap) -> Lng {
+    if let Some(lang) = params.lang {
+        return lang.into();
+    }
+    let accepted = headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    accepted
+        .split(',')
+        .filter_map(|range| range.split(';').next())
+        .find_map(|tag| match tag.trim().split('-').next() {
+            Some(lang) if lang.eq_ignore_ascii_case("de") => Some(Lng::De),
+            Some(lang) if lang.eq_ignore_ascii_case("en") => Some(Lng::En),
+            _ => None,
+        })
+        .unwrap_or(Lng::De)
+}
+
+const MAX_CHART_SIZE: f64 = 4096.0;
+
+async fn get_chart(
+    State(state): State<AppState>,
+    Path((uuid, file_name)): Path<(Uuid, String)>,
+    Query(params): Query<ChartParams>,
+    TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
+) -> std::result::Result<Response, ApiError> {
+    let account = account_from_token(&state, &auth, Some(ApiKeyScope::ReadProjects))?;
+    let Some((chart, extension)) = file_name.rsplit_once('.') else {
+        return Err(ApiError::from(anyhow!("File name has no extension")));
+    };
+    let chart = chart
+        .parse::<klick_pdf_export::Chart>()
+        .map_err(anyhow::Error::from)?;
+    if !matches!(extension, "svg" | "png") {
+        let err = anyhow!("Invalid file extension ({extension}) expected: svg or png");
+        return Err(ApiError::from(err));
+    }
+    let (default_width, default_height) = chart.default_size();
+    let width = params.width.unwrap_or(default_width);
+    let height = params.height.unwrap_or(default_height);
+    if !(1.0..=MAX_CHART_SIZE).contains(&width) || !(1.0..=MAX_CHART_SIZE).contains(&height) {
+        let err = anyhow!("Invalid chart size {width}x{height} (max. {MAX_CHART_SIZE})");
+        return Err(ApiError::from(err));
+    }
+
+    let project = services::read_project(&state.db, &account, ProjectId::from_uuid(uuid))?;
+    let project = boundary::Project::from(project);
+    let form_data: HashMap<In, Value> = project.into_form_data().try_into()?;
+    let form_data = form_data
+        .into_iter()
+        .map(|(id, value)| (id.into(), value))
+        .collect();
+    let Some(svg) =
+        klick_pdf_export::render_chart(&form_data, chart, width, height, params.lang.into())
+    else {
+        let err = anyhow!("The project data is not sufficient for the {chart} chart");
+        return Err(ApiError::from(err));
+    };
+
+    let response = if extension == "png" {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let png = klick_app_charts::ssr::svg_to_png(&svg, width as u32, height as u32)
+            .map_err(anyhow::Error::from)?;
+        ([(header::CONTENT_TYPE, "image/png")], png).into_response()
+    } else {
+        ([(header::CONTENT_TYPE, "image/svg+xml")], svg).into_response()
+    };
+    Ok(response)
+}
+
 async fn get_download_status(
     State(state): State<AppState>,
     Path(download_id): Path<Uuid>,
@@ -468,7 +886,7 @@ async fn get_all_projects(
     State(state): State<AppState>,
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
 ) -> Result<Vec<boundary::SavedProject>> {
-    let account = account_from_token(&state, &auth)?;
+    let account = account_from_token(&state, &auth, Some(ApiKeyScope::ReadProjects))?;
     let projects = services::read_all_projects(&state.db, &account)?
         .into_iter()
         .map(Into::into)
@@ -481,16 +899,125 @@ async fn delete_project(
     Path(uuid): Path<Uuid>,
     TypedHeader(auth): TypedHeader<Authorization<Bearer>>,
 ) -> Result<()> {
-    account_from_token(&state, &auth)?;
+    account_from_token(&state, &auth, Some(ApiKeyScope::WriteProjects))?;
     let id = ProjectId::from_uuid(uuid);
     services::delete_project(&state.db, id)?;
     Ok(Json(()))
 }
 
+/// Stateless calculation of all emissions.
+#[utoipa::path(
+    post,
+    path = "/api/v1/calculate",
+    request_body = boundary::JsonFormData,
+    params(
+        ("lang" = Option<String>, Query, description = "Language of the diagnostics (`de` or `en`), defaults to the `Accept-Language` header")
+    ),
+    responses(
+        (status = 200, description = "Calculation outcome", body = json_api::calculate::CalculationOutcome),
+        (status = 400, description = "Invalid input values or custom emissions"),
+        (status = 401, description = "Invalid bearer token or API key"),
+        (status = 403, description = "The API key lacks the `calculate` scope")
+    )
+)]
+async fn calculate(
+    State(state): State<AppState>,
+    Query(params): Query<LanguageParams>,
+    headers: HeaderMap,
+    auth: Option<TypedHeader<Authorization<Bearer>>>,
+    Json(form_data): Json<boundary::JsonFormData>,
+) -> Result<json_api::calculate::CalculationOutcome> {
+    // Anonymous requests are allowed,
+    // but given credentials must be valid.
+    if let Some(TypedHeader(auth)) = auth {
+        account_from_token(&state, &auth, Some(ApiKeyScope::Calculate))?;
+    }
+    let input: HashMap<In, Value> = form_data.try_into()?;
+    let CustomValues {
+        values: custom_values,
+        edges: custom_edges,
+        leafs: custom_leafs,
+        ..
+    } = match input.get(&In::SensitivityAdditionalCustomEmissions) {
+        Some(value) => {
+            let emissions = boundary::CustomEmissions::from_value(value)?;
+            let lang = request_language(&params, &headers);
+            custom_values_from_structure(&emissions, lang).map_err(|d| diagnostics_error(&d))?
+        }
+        None => CustomValues::default(),
+    };
+    let values: HashMap<_, _> = input
+        .into_iter()
+        .map(|(id, value)| (Id::from(id), value))
+        .chain(custom_values)
+        .collect();
+    let custom_edges = if custom_edges.is_empty() {
+        None
+    } else {
+        Some(&*custom_edges)
+    };
+    let outcome = klick_usecases::calculate_emissions(&values, custom_edges, custom_leafs);
+    Ok(Json(outcome.into()))
+}
+
+/// Custom emissions of saved projects must form a valid graph.
+fn check_custom_emissions(form_data: &boundary::JsonFormData, lang: Lng) -> anyhow::Result<()> {
+    let input: HashMap<In, Value> = form_data.clone().try_into()?;
+    let Some(value) = input.get(&In::SensitivityAdditionalCustomEmissions) else {
+        return Ok(());
+    };
+    let emissions = boundary::CustomEmissions::from_value(value)?;
+    custom_values_from_structure(&emissions, lang).map_err(|d| diagnostics_error(&d))?;
+    Ok(())
+}
+
+fn diagnostics_error(diagnostics: &[Diagnostic]) -> anyhow::Error {
+    let messages: Vec<_> = diagnostics.iter().map(ToString::to_string).collect();
+    anyhow!(messages.join("\n"))
+}
+
+/// Accepts session tokens and API keys.
+///
+/// API keys must grant the given scope, if any.
 fn account_from_token(
     state: &AppState,
     auth: &Authorization<Bearer>,
+    scope: Option<ApiKeyScope>,
 ) -> std::result::Result<Account, ApiError> {
+    let Ok(secret) = auth.token().parse::<ApiKeySecret>() else {
+        return account_from_session_token(state, auth);
+    };
+    let now = OffsetDateTime::now_utc();
+    let (account, api_key) =
+        services::authenticate_api_key(&state.db, &secret, now).map_err(|err| match err {
+            services::AuthenticateApiKeyError::InvalidKey
+            | services::AuthenticateApiKeyError::Expired => {
+                ApiError::from(AuthError::NotAuthorized)
+            }
+            services::AuthenticateApiKeyError::EmailNotConfirmed => {
+                ApiError::from(AuthError::EmailNotConfirmed)
+            }
+            services::AuthenticateApiKeyError::Repo(err) => {
+                log::warn!("Unable to authenticate API key: {err}");
+                ApiError::InternalServerError
+            }
+        })?;
+    if let Some(scope) = scope {
+        if !api_key.has_scope(scope) {
+            return Err(AuthError::MissingScope(scope).into());
+        }
+    }
+    Ok(account)
+}
+
+/// Accepts session tokens only.
+fn account_from_session_token(
+    state: &AppState,
+    auth: &Authorization<Bearer>,
+) -> std::result::Result<Account, ApiError> {
+    if auth.token().parse::<ApiKeySecret>().is_ok() {
+        return Err(AuthError::SessionRequired.into());
+    }
     let token = auth
         .token()
         .parse::<Uuid>()
@@ -118,6 +118,17 @@ impl NotificationGateway for Gateway {
                         log::warn!("Unable to send confirmation e-mail: {err:#}");
                     }
                 }
+                NotificationEvent::AccountEmailChangeRequested { email_nonce } => {
+                    log::info!(
+                        "A change of the email address to ({:?}) was requested.",
+                        email_nonce.email
+                    );
+                    if let Err(err) =
+                        send_email_address_change_mail(&email_nonce, &gateway.base_url, &mailer)
+                    {
+                        log::warn!("Unable to send confirmation e-mail: {err:#}");
+                    }
+                }
             }
         });
     }
@@ -182,6 +193,34 @@ fn send_reset_password_request_mail(
     Ok(())
 }
 
+fn send_email_address_change_mail(
+    nonce: &EmailNonce,
+    base_url: &Url,
+    mailer: &Mailer,
+) -> anyhow::Result<()> {
+    // TODO: use templates
+
+    let subject = "Neue Emailadresse bestätigen".to_string();
+
+    let link = email_address_change_url(nonce, base_url)?;
+    let body = [
+        "Sie möchten die Emailadresse Ihres KlicK-Kontos ändern.\n\n",
+        "Um die neue Adresse zu bestätigen, klicken Sie bitte auf folgenden Link:\n",
+        &link,
+        "\n\nFalls Sie keine Änderung angefordert haben, können Sie diese Nachricht ignorieren.",
+    ]
+    .join("");
+
+    let email = Message::builder()
+        .from(mailer.from.clone())
+        .to(nonce.email.as_str().parse()?)
+        .subject(subject)
+        .header(ContentType::TEXT_PLAIN)
+        .body(body)?;
+    mailer.transport.send(&email)?;
+    Ok(())
+}
+
 const CONFIRM_EMAIL_PAGE_PATH: &str = "confirm-email-address";
 
 fn email_confirmation_url(nonce: &EmailNonce, base_url: &Url) -> anyhow::Result<String> {
@@ -201,6 +240,14 @@ fn email_confirmation_and_password_reset_url(
     Ok(format!("{link}?token={token}"))
 }
 
+const CONFIRM_EMAIL_CHANGE_PAGE_PATH: &str = "confirm-email-address-change";
+
+fn email_address_change_url(nonce: &EmailNonce, base_url: &Url) -> anyhow::Result<String> {
+    let token = nonce.encode_to_string();
+    let link = base_url.join(CONFIRM_EMAIL_CHANGE_PAGE_PATH)?;
+    Ok(format!("{link}?token={token}"))
+}
+
 #[test]
 fn create_email_confirmation_url() {
     let nonce = klick_domain::Nonce::new();
@@ -0,0 +1,38 @@
+// The code generated by `utoipa` triggers this lint.
+#![allow(clippy::needless_for_each)]
+
+use axum::response::Json;
+use utoipa::OpenApi;
+
+use klick_boundary::{self as boundary, json_api::calculate};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "KlicK API"),
+    paths(crate::calculate),
+    components(schemas(
+        boundary::JsonFormData,
+        boundary::InputValueId,
+        boundary::CustomEmissions,
+        boundary::CustomNode,
+        boundary::CustomEdge,
+        boundary::CustomQuantity,
+        boundary::CustomFactor,
+        boundary::CustomGas,
+        boundary::CustomRecommendation,
+        calculate::CalculationOutcome,
+        calculate::OutputValue,
+        calculate::Edge,
+        calculate::N2oScenario,
+        calculate::Ch4ChpScenario,
+        calculate::ValueId,
+        calculate::OutputValueId,
+        calculate::N2oEmissionFactorCalcMethod,
+        calculate::CH4ChpEmissionFactorCalcMethod,
+    ))
+)]
+struct ApiDoc;
+
+pub async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
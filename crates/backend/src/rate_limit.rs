@@ -0,0 +1,273 @@
+use std::net::IpAddr;
+
+use thiserror::Error;
+use time::{Duration, OffsetDateTime};
+
+use klick_domain::EmailAddress;
+use klick_interfaces::{RateLimitRecord, RateLimitRepo};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Policy {
+    /// Number of attempts allowed within the window.
+    max_attempts: u32,
+    window: Duration,
+    lockout: Lockout,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Lockout {
+    /// Block further attempts until the window ends.
+    EndOfWindow,
+    /// Block further attempts for a period
+    /// that doubles with every exceeding attempt.
+    Exponential { base: Duration, max: Duration },
+}
+
+const LOGIN_LOCKOUT: Lockout = Lockout::Exponential {
+    base: Duration::seconds(30),
+    max: Duration::hours(1),
+};
+
+// Anyone who knows an email address can trigger this lockout,
+// so it must be short to not lock the owner out of the account.
+const ACCOUNT_LOCKOUT: Lockout = Lockout::Exponential {
+    base: Duration::seconds(30),
+    max: Duration::minutes(15),
+};
+
+pub const FAILED_LOGINS_PER_EMAIL: Policy = Policy {
+    max_attempts: 5,
+    window: Duration::hours(1),
+    lockout: ACCOUNT_LOCKOUT,
+};
+
+pub const FAILED_LOGINS_PER_IP: Policy = Policy {
+    max_attempts: 20,
+    window: Duration::days(1),
+    lockout: LOGIN_LOCKOUT,
+};
+
+/// Wrong passwords entered to confirm an action of a logged in account.
+pub const FAILED_PASSWORD_CONFIRMATIONS: Policy = Policy {
+    max_attempts: 5,
+    window: Duration::hours(1),
+    lockout: ACCOUNT_LOCKOUT,
+};
+
+pub const EMAILS_PER_ADDRESS: Policy = Policy {
+    max_attempts: 3,
+    window: Duration::hours(1),
+    lockout: Lockout::EndOfWindow,
+};
+
+pub const EMAILS_PER_IP: Policy = Policy {
+    max_attempts: 10,
+    window: Duration::hours(1),
+    lockout: Lockout::EndOfWindow,
+};
+
+/// Records older than this are no longer relevant.
+pub const MAX_RECORD_AGE: Duration = Duration::days(1);
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("too many requests, please try again later")]
+    Exceeded { retry_after: Duration },
+    #[error(transparent)]
+    Repo(#[from] anyhow::Error),
+}
+
+pub fn login_email_key(email: &EmailAddress) -> String {
+    format!("login:email:{}", email.as_str().to_lowercase())
+}
+
+pub fn login_ip_key(ip: IpAddr) -> String {
+    format!("login:ip:{ip}")
+}
+
+pub fn password_confirmation_key(email: &EmailAddress) -> String {
+    format!("password:email:{}", email.as_str().to_lowercase())
+}
+
+pub fn email_address_key(email: &EmailAddress) -> String {
+    format!("email:address:{}", email.as_str().to_lowercase())
+}
+
+pub fn email_ip_key(ip: IpAddr) -> String {
+    format!("email:ip:{ip}")
+}
+
+/// Determine the IP address of the client.
+///
+/// Trusted reverse proxies append the address of their peer
+/// to the `X-Forwarded-For` header, so the rightmost address
+/// that does not belong to a trusted proxy is the client.
+pub fn client_ip(peer: IpAddr, forwarded_for: Option<&str>, trusted_proxies: &[IpAddr]) -> IpAddr {
+    let mut client = peer;
+    if !trusted_proxies.contains(&client) {
+        return client;
+    }
+    for ip in forwarded_for.unwrap_or_default().rsplit(',') {
+        let Ok(ip) = ip.trim().parse::<IpAddr>() else {
+            break;
+        };
+        client = ip;
+        if !trusted_proxies.contains(&client) {
+            break;
+        }
+    }
+    client
+}
+
+/// Fail if the key is currently blocked.
+pub fn check<R>(repo: &R, key: &str, now: OffsetDateTime) -> Result<(), Error>
+where
+    R: RateLimitRepo,
+{
+    let record = repo.find_rate_limit(key)?;
+    if let Some(retry_after) = record.and_then(|r| retry_after(&r, now)) {
+        return Err(Error::Exceeded { retry_after });
+    }
+    Ok(())
+}
+
+/// Count an attempt without failing if the limit is exceeded.
+///
+/// Use [`check`] before the next attempt.
+pub fn register_attempt<R>(
+    repo: &R,
+    key: &str,
+    policy: &Policy,
+    now: OffsetDateTime,
+) -> anyhow::Result<()>
+where
+    R: RateLimitRepo,
+{
+    let record = repo.find_rate_limit(key)?;
+    let record = next_record(record, policy, now);
+    repo.save_rate_limit(key, &record)
+}
+
+/// Count an attempt and fail if it is not allowed.
+pub fn acquire<R>(repo: &R, key: &str, policy: &Policy, now: OffsetDateTime) -> Result<(), Error>
+where
+    R: RateLimitRepo,
+{
+    check(repo, key, now)?;
+    register_attempt(repo, key, policy, now)?;
+    check(repo, key, now)
+}
+
+pub fn reset<R>(repo: &R, key: &str) -> anyhow::Result<()>
+where
+    R: RateLimitRepo,
+{
+    repo.delete_rate_limit(key)
+}
+
+fn retry_after(record: &RateLimitRecord, now: OffsetDateTime) -> Option<Duration> {
+    record
+        .blocked_until
+        .filter(|blocked_until| *blocked_until > now)
+        .map(|blocked_until| blocked_until - now)
+}
+
+fn next_record(
+    record: Option<RateLimitRecord>,
+    policy: &Policy,
+    now: OffsetDateTime,
+) -> RateLimitRecord {
+    let mut record = record
+        .filter(|r| now - r.window_start < policy.window)
+        .unwrap_or(RateLimitRecord {
+            attempts: 0,
+            window_start: now,
+            blocked_until: None,
+        });
+    record.attempts += 1;
+    if record.attempts > policy.max_attempts {
+        let blocked_until = match policy.lockout {
+            Lockout::EndOfWindow => record.window_start + policy.window,
+            Lockout::Exponential { base, max } => {
+                // Limit the exponent to avoid an overflow
+                let exceeded = (record.attempts - policy.max_attempts - 1).min(16);
+                now + (base * (1_i32 << exceeded)).min(max)
+            }
+        };
+        record.blocked_until = Some(blocked_until);
+    }
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempts(policy: &Policy, count: u32, now: OffsetDateTime) -> RateLimitRecord {
+        (0..count)
+            .fold(None, |record, _| Some(next_record(record, policy, now)))
+            .unwrap()
+    }
+
+    #[test]
+    fn allow_attempts_within_limit() {
+        let now = OffsetDateTime::now_utc();
+        let record = attempts(&EMAILS_PER_ADDRESS, 3, now);
+        assert_eq!(record.attempts, 3);
+        assert_eq!(retry_after(&record, now), None);
+    }
+
+    #[test]
+    fn block_until_end_of_window() {
+        let now = OffsetDateTime::now_utc();
+        let record = attempts(&EMAILS_PER_ADDRESS, 4, now);
+        assert_eq!(retry_after(&record, now), Some(Duration::hours(1)));
+    }
+
+    #[test]
+    fn exponential_lockout() {
+        let now = OffsetDateTime::now_utc();
+        let record = attempts(&FAILED_LOGINS_PER_EMAIL, 6, now);
+        assert_eq!(retry_after(&record, now), Some(Duration::seconds(30)));
+        let record = next_record(Some(record), &FAILED_LOGINS_PER_EMAIL, now);
+        assert_eq!(retry_after(&record, now), Some(Duration::seconds(60)));
+        let record = attempts(&FAILED_LOGINS_PER_EMAIL, 100, now);
+        assert_eq!(retry_after(&record, now), Some(Duration::minutes(15)));
+        let record = attempts(&FAILED_LOGINS_PER_IP, 100, now);
+        assert_eq!(retry_after(&record, now), Some(Duration::hours(1)));
+    }
+
+    #[test]
+    fn reset_after_window() {
+        let now = OffsetDateTime::now_utc();
+        let record = attempts(&EMAILS_PER_ADDRESS, 4, now);
+        let later = now + Duration::hours(1);
+        assert_eq!(retry_after(&record, later), None);
+        let record = next_record(Some(record), &EMAILS_PER_ADDRESS, later);
+        assert_eq!(record.attempts, 1);
+        assert_eq!(record.blocked_until, None);
+    }
+
+    #[test]
+    fn client_ip_without_proxy() {
+        let peer = "203.0.113.7".parse().unwrap();
+        let ip = client_ip(peer, Some("198.51.100.1"), &[]);
+        assert_eq!(ip, peer);
+    }
+
+    #[test]
+    fn client_ip_behind_trusted_proxy() {
+        let proxy = "127.0.0.1".parse().unwrap();
+        let ip = client_ip(proxy, Some("198.51.100.1, 203.0.113.7"), &[proxy]);
+        assert_eq!(ip, "203.0.113.7".parse::<IpAddr>().unwrap());
+        let ip = client_ip(proxy, None, &[proxy]);
+        assert_eq!(ip, proxy);
+    }
+
+    #[test]
+    fn client_ip_behind_multiple_proxies() {
+        let proxies = ["127.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap()];
+        let ip = client_ip(proxies[0], Some("203.0.113.7, 10.0.0.2"), &proxies);
+        assert_eq!(ip, "203.0.113.7".parse::<IpAddr>().unwrap());
+    }
+}
@@ -5,10 +5,16 @@ use serde_json::{json, Value};
 use tokio::net::TcpListener;
 
 use klick_backend::Config;
-use klick_boundary as boundary;
+use klick_boundary::{
+    self as boundary,
+    json_api::calculate::{OutputValueId, ValueId::Out},
+};
 use klick_db_sqlite::Connection;
-use klick_domain::{EmailAddress, EmailNonce, Nonce};
-use klick_interfaces::{AccountRepo as _, AccountTokenRepo};
+use klick_domain::{EmailAddress, EmailNonce, Nonce, ProjectId};
+use klick_interfaces::{
+    AccountRepo as _, AccountTokenRepo, EmailChangeTokenRepo as _, ProjectRepo as _,
+    RateLimitRepo as _,
+};
 
 async fn run_server() -> (SocketAddr, Connection) {
     run_server_with_default_test_config().await
@@ -202,6 +208,643 @@ mod auth {
     }
 }
 
+mod account {
+    use std::io::{Cursor, Read as _};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn delete_account_with_invalid_password() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let client = reqwest::Client::new();
+        let res = client
+            .delete(endpoint(addr, "/users"))
+            .bearer_auth(&token)
+            .json(&json!({ "password": "wrong" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 400);
+        assert!(db
+            .find_account(&TEST_ACCOUNT_EMAIL.parse().unwrap())
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn delete_account() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let client = reqwest::Client::new();
+        let project_id = client
+            .post(endpoint(addr, "/project"))
+            .bearer_auth(&token)
+            .json(&boundary::JsonFormData::default())
+            .send()
+            .await
+            .unwrap()
+            .json::<uuid::Uuid>()
+            .await
+            .unwrap();
+
+        let res = client
+            .delete(endpoint(addr, "/users"))
+            .bearer_auth(&token)
+            .json(&json!({ "password": TEST_ACCOUNT_PASSWORD }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+
+        let email = TEST_ACCOUNT_EMAIL.parse().unwrap();
+        assert!(db.find_account(&email).unwrap().is_none());
+        let project_id = ProjectId::from_uuid(project_id);
+        assert!(db.find_project(&project_id).unwrap().is_none());
+
+        // The session is no longer valid
+        let res = client
+            .get(endpoint(addr, "/users"))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn change_password() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let client = reqwest::Client::new();
+
+        let res = client
+            .post(endpoint(addr, "/users/change-password"))
+            .bearer_auth(&token)
+            .json(&json!({ "old_password": "wrong", "new_password": "new-secret" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 400);
+
+        let res = client
+            .post(endpoint(addr, "/users/change-password"))
+            .bearer_auth(&token)
+            .json(&json!({
+                "old_password": TEST_ACCOUNT_PASSWORD,
+                "new_password": "new-secret"
+            }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+
+        let login = |password: &'static str| {
+            client
+                .post(endpoint(addr, "/login"))
+                .json(&json!({ "email": TEST_ACCOUNT_EMAIL, "password": password }))
+                .send()
+        };
+        assert_eq!(login(TEST_ACCOUNT_PASSWORD).await.unwrap().status(), 400);
+        assert_eq!(login("new-secret").await.unwrap().status(), 200);
+    }
+
+    #[tokio::test]
+    async fn change_email_address() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let client = reqwest::Client::new();
+        let new_email = "new@user.com";
+
+        let res = client
+            .post(endpoint(addr, "/users/change-email-address"))
+            .bearer_auth(&token)
+            .json(&json!({ "new_email": new_email, "password": "wrong" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 400);
+
+        let res = client
+            .post(endpoint(addr, "/users/change-email-address"))
+            .bearer_auth(&token)
+            .json(&json!({ "new_email": new_email, "password": TEST_ACCOUNT_PASSWORD }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+
+        // The address is not changed before confirmation
+        let old_email = TEST_ACCOUNT_EMAIL.parse::<EmailAddress>().unwrap();
+        assert!(db.find_account(&old_email).unwrap().is_some());
+
+        let change_token = db.get_email_change_token_by_account(&old_email).unwrap();
+        assert_eq!(change_token.email_nonce.email.as_str(), new_email);
+        let confirm = || {
+            client
+                .post(endpoint(addr, "/users/confirm-email-address-change"))
+                .json(&json!({ "token": change_token.email_nonce.encode_to_string() }))
+                .send()
+        };
+        assert_eq!(confirm().await.unwrap().status(), 200);
+
+        assert!(db.find_account(&old_email).unwrap().is_none());
+        let new_email_address = new_email.parse::<EmailAddress>().unwrap();
+        let record = db.find_account(&new_email_address).unwrap().unwrap();
+        assert!(record.account.email_confirmed);
+
+        // The token can only be used once
+        assert_eq!(confirm().await.unwrap().status(), 400);
+
+        // The session remains valid
+        let info = client
+            .get(endpoint(addr, "/users"))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap()
+            .json::<Value>()
+            .await
+            .unwrap();
+        assert_eq!(info["email"], new_email);
+
+        let res = client
+            .post(endpoint(addr, "/login"))
+            .json(&json!({ "email": new_email, "password": TEST_ACCOUNT_PASSWORD }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn change_email_address_to_existing_account() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        register_account(addr, "other@user.com", "secret").await;
+        let res = reqwest::Client::new()
+            .post(endpoint(addr, "/users/change-email-address"))
+            .bearer_auth(&token)
+            .json(&json!({ "new_email": "other@user.com", "password": TEST_ACCOUNT_PASSWORD }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn export_account_data() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let client = reqwest::Client::new();
+        let json: Value = serde_json::from_str(EXAMPLE_PROJECT).unwrap();
+        let project_id = client
+            .post(endpoint(addr, "/project"))
+            .bearer_auth(&token)
+            .json(&json["form_data"])
+            .send()
+            .await
+            .unwrap()
+            .json::<uuid::Uuid>()
+            .await
+            .unwrap();
+
+        let res = client
+            .get(endpoint(addr, "/users/export"))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/zip");
+        let bytes = res.bytes().await.unwrap();
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).unwrap();
+        assert_eq!(archive.len(), 2);
+
+        let mut account = String::new();
+        archive
+            .by_name("account.json")
+            .unwrap()
+            .read_to_string(&mut account)
+            .unwrap();
+        let account: Value = serde_json::from_str(&account).unwrap();
+        assert_eq!(account["email"], TEST_ACCOUNT_EMAIL);
+
+        let mut project = String::new();
+        archive
+            .by_name(&format!("projects/{project_id}.json"))
+            .unwrap()
+            .read_to_string(&mut project)
+            .unwrap();
+        let version = serde_json::from_str::<Value>(&project).unwrap()["version"].clone();
+        assert_eq!(version, 9);
+        let project = boundary::import_from_str(&project).unwrap();
+        assert_eq!(
+            project.form_data(),
+            &serde_json::from_value(json["form_data"].clone()).unwrap()
+        );
+    }
+}
+
+mod rate_limit {
+    use super::*;
+
+    async fn login(addr: SocketAddr, password: &str) -> reqwest::Response {
+        reqwest::Client::new()
+            .post(endpoint(addr, "/login"))
+            .json(&json!({ "email": TEST_ACCOUNT_EMAIL, "password": password }))
+            .send()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn lock_out_after_failed_logins() {
+        let (addr, db) = run_server().await;
+        register_test_account(addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+
+        for _ in 0..6 {
+            assert_eq!(login(addr, "wrong").await.status(), 400);
+        }
+        let record = db.find_rate_limit("login:email:test@user.com").unwrap();
+        assert_eq!(record.unwrap().attempts, 6);
+
+        // Even the correct password is rejected during the lockout
+        let res = login(addr, TEST_ACCOUNT_PASSWORD).await;
+        assert_eq!(res.status(), 429);
+        let retry_after = res.headers()[header::RETRY_AFTER]
+            .to_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap();
+        assert!(retry_after > 0 && retry_after <= 30);
+    }
+
+    #[tokio::test]
+    async fn reset_failed_logins_after_success() {
+        let (addr, db) = run_server().await;
+        register_test_account(addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+
+        for _ in 0..5 {
+            assert_eq!(login(addr, "wrong").await.status(), 400);
+        }
+        assert_eq!(login(addr, TEST_ACCOUNT_PASSWORD).await.status(), 200);
+        assert!(db
+            .find_rate_limit("login:email:test@user.com")
+            .unwrap()
+            .is_none());
+        // The failures of the client IP are kept
+        let record = db.find_rate_limit("login:ip:127.0.0.1").unwrap();
+        assert_eq!(record.unwrap().attempts, 5);
+        assert_eq!(login(addr, "wrong").await.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn limit_emails_on_account_creation() {
+        let (addr, _) = run_server().await;
+        let register = || {
+            reqwest::Client::new()
+                .post(endpoint(addr, "/users"))
+                .json(&json!({ "email": TEST_ACCOUNT_EMAIL, "password": TEST_ACCOUNT_PASSWORD }))
+                .send()
+        };
+        for _ in 0..3 {
+            assert_ne!(register().await.unwrap().status(), 429);
+        }
+        assert_eq!(register().await.unwrap().status(), 429);
+    }
+
+    async fn login_forwarded_for(addr: SocketAddr, client_ip: &str) -> reqwest::Response {
+        reqwest::Client::new()
+            .post(endpoint(addr, "/login"))
+            .header("X-Forwarded-For", client_ip)
+            .json(&json!({ "email": TEST_ACCOUNT_EMAIL, "password": "wrong" }))
+            .send()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn use_forwarded_client_ip_behind_trusted_proxy() {
+        let config = Config {
+            trusted_proxies: vec!["127.0.0.1".parse().unwrap()],
+            ..Default::default()
+        };
+        let (addr, db) = run_server_with_config(config).await;
+        register_test_account(addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+
+        assert_eq!(login_forwarded_for(addr, "203.0.113.7").await.status(), 400);
+        let record = db.find_rate_limit("login:ip:203.0.113.7").unwrap();
+        assert_eq!(record.unwrap().attempts, 1);
+        assert!(db.find_rate_limit("login:ip:127.0.0.1").unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn ignore_forwarded_client_ip_without_trusted_proxy() {
+        let (addr, db) = run_server().await;
+        register_test_account(addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+
+        assert_eq!(login_forwarded_for(addr, "203.0.113.7").await.status(), 400);
+        assert!(db
+            .find_rate_limit("login:ip:203.0.113.7")
+            .unwrap()
+            .is_none());
+        let record = db.find_rate_limit("login:ip:127.0.0.1").unwrap();
+        assert_eq!(record.unwrap().attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn lock_out_after_failed_password_confirmations() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let client = reqwest::Client::new();
+        let change_password = |old_password: &'static str| {
+            client
+                .post(endpoint(addr, "/users/change-password"))
+                .bearer_auth(&token)
+                .json(&json!({ "old_password": old_password, "new_password": "new-secret" }))
+                .send()
+        };
+        for _ in 0..6 {
+            assert_eq!(change_password("wrong").await.unwrap().status(), 400);
+        }
+        let res = change_password(TEST_ACCOUNT_PASSWORD).await.unwrap();
+        assert_eq!(res.status(), 429);
+
+        // The lockout also applies to other actions that need the password
+        let res = client
+            .delete(endpoint(addr, "/users"))
+            .bearer_auth(&token)
+            .json(&json!({ "password": TEST_ACCOUNT_PASSWORD }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 429);
+    }
+
+    #[tokio::test]
+    async fn limit_email_address_change_requests() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let client = reqwest::Client::new();
+        let request = || {
+            client
+                .post(endpoint(addr, "/users/change-email-address"))
+                .bearer_auth(&token)
+                .json(&json!({ "new_email": "new@user.com", "password": TEST_ACCOUNT_PASSWORD }))
+                .send()
+        };
+        for _ in 0..3 {
+            assert_eq!(request().await.unwrap().status(), 200);
+        }
+        assert_eq!(request().await.unwrap().status(), 429);
+    }
+
+    #[tokio::test]
+    async fn limit_password_reset_requests() {
+        let (addr, db) = run_server().await;
+        register_test_account(addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let client = reqwest::Client::new();
+        let request = || {
+            client
+                .post(endpoint(addr, "/users/reset-password-request"))
+                .json(&json!({ "email": TEST_ACCOUNT_EMAIL }))
+                .send()
+        };
+        // The registration already sent the first email
+        for _ in 0..2 {
+            assert_eq!(request().await.unwrap().status(), 200);
+        }
+        let res = request().await.unwrap();
+        assert_eq!(res.status(), 429);
+        assert!(res.headers().contains_key(header::RETRY_AFTER));
+    }
+
+    #[tokio::test]
+    async fn limit_confirmation_emails() {
+        let (addr, _) = run_server().await;
+        register_test_account(addr).await;
+        let client = reqwest::Client::new();
+        let request = || {
+            client
+                .post(endpoint(addr, "/users/resent-confirmation-email"))
+                .json(&json!({ "email": TEST_ACCOUNT_EMAIL, "password": TEST_ACCOUNT_PASSWORD }))
+                .send()
+        };
+        // The registration already sent the first email
+        for _ in 0..2 {
+            assert_eq!(request().await.unwrap().status(), 200);
+        }
+        assert_eq!(request().await.unwrap().status(), 429);
+    }
+}
+
+mod api_keys {
+    use super::*;
+
+    async fn create_api_key(addr: SocketAddr, token: &str, new_api_key: &Value) -> Value {
+        let client = reqwest::Client::new();
+        let res = client
+            .post(endpoint(addr, "/users/api-keys"))
+            .bearer_auth(token)
+            .json(new_api_key)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        res.json::<Value>().await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn create_and_list() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let created = create_api_key(
+            addr,
+            &token,
+            &json!({ "name": "plant information system", "scopes": ["read-projects"] }),
+        )
+        .await;
+        let secret = created["secret"].as_str().unwrap();
+        assert!(secret.starts_with("klick_"));
+
+        let client = reqwest::Client::new();
+        let res = client
+            .get(endpoint(addr, "/users/api-keys"))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let api_keys = res.json::<Value>().await.unwrap();
+        let api_keys = api_keys.as_array().unwrap();
+        assert_eq!(api_keys.len(), 1);
+        assert_eq!(api_keys[0]["id"], created["api_key"]["id"]);
+        assert_eq!(api_keys[0]["name"], "plant information system");
+        assert_eq!(api_keys[0]["scopes"], json!(["read-projects"]));
+        // The secret is never returned again
+        assert!(api_keys[0].get("secret").is_none());
+    }
+
+    #[tokio::test]
+    async fn access_projects_with_api_key() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let created = create_api_key(addr, &token, &json!({})).await;
+        let secret = created["secret"].as_str().unwrap();
+
+        let client = reqwest::Client::new();
+        let res = client
+            .post(endpoint(addr, "/project"))
+            .bearer_auth(secret)
+            .json(&boundary::JsonFormData::default())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+
+        let res = client
+            .get(endpoint(addr, "/projects"))
+            .bearer_auth(secret)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        let projects = res.json::<Value>().await.unwrap();
+        assert_eq!(projects.as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn reject_missing_scope() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let created = create_api_key(addr, &token, &json!({ "scopes": ["calculate"] })).await;
+        let secret = created["secret"].as_str().unwrap();
+
+        let client = reqwest::Client::new();
+        let res = client
+            .get(endpoint(addr, "/projects"))
+            .bearer_auth(secret)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 403);
+
+        let res = client
+            .post(endpoint(addr, "/v1/calculate"))
+            .bearer_auth(secret)
+            .json(&boundary::JsonFormData::default())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn reject_calculation_without_scope() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let created = create_api_key(addr, &token, &json!({ "scopes": ["read-projects"] })).await;
+        let secret = created["secret"].as_str().unwrap();
+
+        let client = reqwest::Client::new();
+        let res = client
+            .post(endpoint(addr, "/v1/calculate"))
+            .bearer_auth(secret)
+            .json(&boundary::JsonFormData::default())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 403);
+    }
+
+    #[tokio::test]
+    async fn revoke() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let created = create_api_key(addr, &token, &json!({})).await;
+        let secret = created["secret"].as_str().unwrap();
+        let id = created["api_key"]["id"].as_str().unwrap();
+
+        let client = reqwest::Client::new();
+        let res = client
+            .delete(endpoint(addr, &format!("/users/api-keys/{id}")))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+
+        let res = client
+            .get(endpoint(addr, "/projects"))
+            .bearer_auth(secret)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 401);
+
+        let res = client
+            .delete(endpoint(addr, &format!("/users/api-keys/{id}")))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 404);
+    }
+
+    #[tokio::test]
+    async fn api_keys_cannot_manage_api_keys() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let created = create_api_key(addr, &token, &json!({})).await;
+        let secret = created["secret"].as_str().unwrap();
+
+        let client = reqwest::Client::new();
+        let res = client
+            .post(endpoint(addr, "/users/api-keys"))
+            .bearer_auth(secret)
+            .json(&json!({}))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 403);
+    }
+
+    #[tokio::test]
+    async fn reject_expiry_in_the_past() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let client = reqwest::Client::new();
+        let res = client
+            .post(endpoint(addr, "/users/api-keys"))
+            .bearer_auth(&token)
+            .json(&json!({ "expires_at": "2020-01-01T00:00:00Z" }))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn reject_unknown_api_key() {
+        let (addr, _) = run_server().await;
+        let client = reqwest::Client::new();
+        let res = client
+            .get(endpoint(addr, "/projects"))
+            .bearer_auth("klick_0123456789")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 401);
+    }
+}
+
 const EXAMPLE_PROJECT: &str = include_str!("unsaved_example_project.json");
 
 #[test]
@@ -244,6 +887,286 @@ mod projects {
         assert_eq!(res.status(), 200);
         res.json::<uuid::Uuid>().await.unwrap();
     }
+
+    #[tokio::test]
+    async fn create_with_invalid_custom_emissions() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let client = reqwest::Client::new();
+        let endpoint = endpoint(addr, "/project");
+        let project = json!({
+            "sensitivity-additional-custom-emissions": {
+                "edges": [
+                    { "source": "Kantine", "target": "Verbrauch", "value": { "value": 10.0 } }
+                ]
+            }
+        });
+        let req = client.post(endpoint).bearer_auth(token).json(&project);
+        let res = req.send().await.unwrap();
+        assert_eq!(res.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn update_with_invalid_custom_emissions() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let client = reqwest::Client::new();
+        let id = client
+            .post(endpoint(addr, "/project"))
+            .bearer_auth(&token)
+            .json(&boundary::JsonFormData::default())
+            .send()
+            .await
+            .unwrap()
+            .json::<uuid::Uuid>()
+            .await
+            .unwrap();
+        let project = json!({
+            "id": id,
+            "created_at": "2024-01-01T00:00:00Z",
+            "form_data": {
+                "sensitivity-additional-custom-emissions": {
+                    "edges": [
+                        { "source": "TotalEmissions", "target": "IndirectEmissions" }
+                    ]
+                }
+            }
+        });
+        let res = client
+            .put(endpoint(addr, &format!("/project/{id}")))
+            .bearer_auth(&token)
+            .json(&project)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn update_with_modification_time() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let client = reqwest::Client::new();
+        let id = client
+            .post(endpoint(addr, "/project"))
+            .bearer_auth(&token)
+            .json(&boundary::JsonFormData::default())
+            .send()
+            .await
+            .unwrap()
+            .json::<uuid::Uuid>()
+            .await
+            .unwrap();
+        let mut project = client
+            .get(endpoint(addr, &format!("/project/{id}")))
+            .bearer_auth(&token)
+            .send()
+            .await
+            .unwrap()
+            .json::<boundary::SavedProject>()
+            .await
+            .unwrap();
+        assert!(project.modified_at.is_none());
+
+        let update = |project: boundary::SavedProject| {
+            client
+                .put(endpoint(addr, &format!("/project/{id}")))
+                .bearer_auth(&token)
+                .json(&project)
+                .send()
+        };
+
+        let res = update(project.clone()).await.unwrap();
+        assert_eq!(res.status(), 200);
+        let updated = res.json::<boundary::SavedProject>().await.unwrap();
+        assert_eq!(updated.created_at, project.created_at);
+        assert!(updated.modified_at.is_some());
+
+        // The project was modified in the meantime
+        let res = update(project.clone()).await.unwrap();
+        assert_eq!(res.status(), 409);
+
+        project.modified_at = updated.modified_at;
+        let res = update(project).await.unwrap();
+        assert_eq!(res.status(), 200);
+    }
+
+    #[tokio::test]
+    async fn update_unknown_project() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let id = uuid::Uuid::new_v4();
+        let project = json!({
+            "id": id,
+            "created_at": "2024-01-01T00:00:00Z",
+            "form_data": {}
+        });
+        let res = reqwest::Client::new()
+            .put(endpoint(addr, &format!("/project/{id}")))
+            .bearer_auth(&token)
+            .json(&project)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 404);
+    }
+}
+
+mod calculate {
+    use super::*;
+
+    #[tokio::test]
+    async fn calculate_example_project() {
+        let (addr, _) = run_server().await;
+        let client = reqwest::Client::new();
+        let json: Value = serde_json::from_str(EXAMPLE_PROJECT).unwrap();
+        let form_data = &json["form_data"];
+        let req = client
+            .post(endpoint(addr, "/v1/calculate"))
+            .json(&form_data);
+        let res = req.send().await.unwrap();
+        assert_eq!(res.status(), 200);
+
+        let outcome = res
+            .json::<boundary::json_api::calculate::CalculationOutcome>()
+            .await
+            .unwrap();
+        let output = outcome.output.unwrap();
+        let total = output
+            .iter()
+            .find(|v| v.id == Out(OutputValueId::TotalEmissions))
+            .unwrap();
+        assert!(total.value > 0.0);
+        assert!(outcome.graph.is_some_and(|graph| !graph.is_empty()));
+        assert_eq!(outcome.n2o_scenarios.unwrap().len(), 5);
+        assert_eq!(outcome.ch4_chp_scenarios.unwrap().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn calculate_incomplete_input() {
+        let (addr, _) = run_server().await;
+        let client = reqwest::Client::new();
+        let form_data = boundary::JsonFormData::default();
+        let req = client
+            .post(endpoint(addr, "/v1/calculate"))
+            .json(&form_data);
+        let res = req.send().await.unwrap();
+        assert_eq!(res.status(), 200);
+
+        let data = res.json::<Value>().await.unwrap();
+        assert!(data.get("output").is_none());
+    }
+
+    #[tokio::test]
+    async fn calculate_with_custom_emissions() {
+        let (addr, _) = run_server().await;
+        let client = reqwest::Client::new();
+        let mut json: Value = serde_json::from_str(EXAMPLE_PROJECT).unwrap();
+        let form_data = &mut json["form_data"];
+        form_data["sensitivity-additional-custom-emissions"] = json!({
+            "nodes": [{ "id": "Kantine" }],
+            "edges": [{
+                "source": "Kantine",
+                "target": "IndirectEmissions",
+                "value": { "value": 10.0 }
+            }]
+        });
+        let req = client
+            .post(endpoint(addr, "/v1/calculate"))
+            .json(&form_data);
+        let res = req.send().await.unwrap();
+        assert_eq!(res.status(), 200);
+
+        let data = res.json::<Value>().await.unwrap();
+        let output = data["output"].as_array().unwrap();
+        assert!(output.contains(&json!({ "id": { "custom": "Kantine" }, "value": 10.0 })));
+        assert!(output
+            .contains(&json!({ "id": { "out": "additional-custom-emissions" }, "value": 10.0 })));
+        let graph = data["graph"].as_array().unwrap();
+        assert!(graph.contains(&json!({
+            "source": { "custom": "Kantine" },
+            "target": { "out": "indirect-emissions" }
+        })));
+    }
+
+    #[tokio::test]
+    async fn calculate_with_invalid_custom_emissions() {
+        let (addr, _) = run_server().await;
+        let client = reqwest::Client::new();
+        let form_data = json!({
+            "sensitivity-additional-custom-emissions": {
+                "edges": [{ "source": "Kantine", "target": "Kantine", "value": { "value": 10.0 } }]
+            }
+        });
+        let req = client
+            .post(endpoint(addr, "/v1/calculate"))
+            .json(&form_data);
+        let res = req.send().await.unwrap();
+        assert_eq!(res.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn localize_custom_emission_diagnostics() {
+        let (addr, _) = run_server().await;
+        let diagnostics = |path: &'static str, accept_language: &'static str| async move {
+            let form_data = json!({
+                "sensitivity-additional-custom-emissions": {
+                    "edges": [{ "source": "Kantine", "target": "Kantine", "value": { "value": 10.0 } }]
+                }
+            });
+            let res = reqwest::Client::new()
+                .post(endpoint(addr, path))
+                .header(header::ACCEPT_LANGUAGE, accept_language)
+                .json(&form_data)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), 400);
+            res.json::<Value>().await.unwrap()["message"].clone()
+        };
+        let de = diagnostics("/v1/calculate", "de-DE,de;q=0.9").await;
+        let en = diagnostics("/v1/calculate", "en-US,en;q=0.9").await;
+        assert_eq!(de, "Kanten-Zyklus erkannt auf Zeile: 1");
+        assert_eq!(en, "Edge-Cycle detected on line: 1");
+        assert_eq!(diagnostics("/v1/calculate", "").await, de);
+        assert_eq!(diagnostics("/v1/calculate?lang=en", "de").await, en);
+    }
+
+    #[tokio::test]
+    async fn calculate_with_invalid_value() {
+        let (addr, _) = run_server().await;
+        let client = reqwest::Client::new();
+        let form_data = json!({ "profile-wastewater": "a lot" });
+        let req = client
+            .post(endpoint(addr, "/v1/calculate"))
+            .json(&form_data);
+        let res = req.send().await.unwrap();
+        assert_eq!(res.status(), 400);
+    }
+
+    #[tokio::test]
+    async fn serve_openapi_document() {
+        let (addr, _) = run_server().await;
+        let client = reqwest::Client::new();
+        let res = client
+            .get(endpoint(addr, "/openapi.json"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+
+        let data = res.json::<Value>().await.unwrap();
+        assert!(data["openapi"].as_str().unwrap().starts_with("3."));
+        assert!(data["paths"]["/api/v1/calculate"]["post"].is_object());
+        let schemas = &data["components"]["schemas"];
+        assert!(schemas["JsonFormData"].is_object());
+        assert!(schemas["CalculationOutcome"].is_object());
+    }
 }
 
 mod export {
@@ -302,3 +1225,157 @@ mod export {
         let (_addr, _db) = run_server().await;
     }
 }
+
+mod charts {
+    use super::*;
+
+    async fn create_example_project(addr: SocketAddr, token: &str) -> uuid::Uuid {
+        let json: Value = serde_json::from_str(EXAMPLE_PROJECT).unwrap();
+        reqwest::Client::new()
+            .post(endpoint(addr, "/project"))
+            .bearer_auth(token)
+            .json(&json["form_data"])
+            .send()
+            .await
+            .unwrap()
+            .json::<uuid::Uuid>()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn render_sankey_svg() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let project_id = create_example_project(addr, &token).await;
+
+        let endpoint = endpoint(addr, &format!("/project/{project_id}/chart/sankey.svg"));
+        let res = reqwest::Client::new()
+            .get(endpoint)
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "image/svg+xml");
+        let svg = res.text().await.unwrap();
+        assert!(svg.contains("<svg"));
+        assert!(svg.contains("Lachgas"));
+    }
+
+    #[tokio::test]
+    async fn render_recommendation_sankey_svg() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let project_id = create_example_project(addr, &token).await;
+
+        let client = reqwest::Client::new();
+        let render = |chart: &'static str| {
+            client
+                .get(endpoint(
+                    addr,
+                    &format!("/project/{project_id}/chart/{chart}.svg"),
+                ))
+                .bearer_auth(&token)
+                .send()
+        };
+        let baseline = render("sankey").await.unwrap().text().await.unwrap();
+        let res = render("recommendation-sankey").await.unwrap();
+        assert_eq!(res.status(), 200);
+        let recommendation = res.text().await.unwrap();
+        assert!(recommendation.contains("<svg"));
+        assert_ne!(recommendation, baseline);
+    }
+
+    #[tokio::test]
+    async fn render_sankey_png() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let project_id = create_example_project(addr, &token).await;
+
+        let endpoint = endpoint(
+            addr,
+            &format!("/project/{project_id}/chart/sankey.png?width=600&height=400&lang=en"),
+        );
+        let res = reqwest::Client::new()
+            .get(endpoint)
+            .bearer_auth(token)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(res.status(), 200);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "image/png");
+        let png = res.bytes().await.unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+        // The width and height are stored in the IHDR chunk
+        assert_eq!(png[16..20], 600_u32.to_be_bytes());
+        assert_eq!(png[20..24], 400_u32.to_be_bytes());
+    }
+
+    #[tokio::test]
+    async fn render_emission_charts_svg() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let project_id = create_example_project(addr, &token).await;
+
+        for (chart, expected) in [
+            ("emission-shares", "Direkte Emissionen"),
+            ("emission-groups", "Empfehlung"),
+        ] {
+            let endpoint = endpoint(addr, &format!("/project/{project_id}/chart/{chart}.svg"));
+            let res = reqwest::Client::new()
+                .get(endpoint)
+                .bearer_auth(&token)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), 200);
+            let svg = res.text().await.unwrap();
+            assert!(svg.contains("<svg"));
+            assert!(svg.contains(expected), "{chart}");
+        }
+    }
+
+    #[tokio::test]
+    async fn reject_chart_of_other_account() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        let project_id = create_example_project(addr, &token).await;
+        let other_token =
+            register_and_login_account(&db, addr, "other@user.com", "other-secret").await;
+
+        for (token, status) in [(&token, 200), (&other_token, 400)] {
+            let endpoint = endpoint(addr, &format!("/project/{project_id}/chart/sankey.svg"));
+            let res = reqwest::Client::new()
+                .get(endpoint)
+                .bearer_auth(token)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), status);
+        }
+    }
+
+    #[tokio::test]
+    async fn reject_unknown_chart() {
+        let (addr, db) = run_server().await;
+        let token = register_and_login_test_account(&db, addr).await;
+        set_email_address_as_confirmed(&db, TEST_ACCOUNT_EMAIL);
+        let project_id = create_example_project(addr, &token).await;
+
+        for file_name in ["foo.svg", "sankey.gif", "sankey"] {
+            let endpoint = endpoint(addr, &format!("/project/{project_id}/chart/{file_name}"));
+            let res = reqwest::Client::new()
+                .get(endpoint)
+                .bearer_auth(&token)
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(res.status(), 400);
+        }
+    }
+}
@@ -65,6 +65,53 @@ impl From<domain::Project<JsonFormData>> for Project {
     }
 }
 
+// -----   ----- //
+//   API keys    //
+// -----   ----- //
+
+impl From<json_api::ApiKeyScope> for domain::ApiKeyScope {
+    fn from(from: json_api::ApiKeyScope) -> Self {
+        use json_api::ApiKeyScope as FROM;
+        match from {
+            FROM::ReadProjects => Self::ReadProjects,
+            FROM::WriteProjects => Self::WriteProjects,
+            FROM::Calculate => Self::Calculate,
+        }
+    }
+}
+
+impl From<domain::ApiKeyScope> for json_api::ApiKeyScope {
+    fn from(from: domain::ApiKeyScope) -> Self {
+        use domain::ApiKeyScope as FROM;
+        match from {
+            FROM::ReadProjects => Self::ReadProjects,
+            FROM::WriteProjects => Self::WriteProjects,
+            FROM::Calculate => Self::Calculate,
+        }
+    }
+}
+
+impl From<domain::ApiKey> for json_api::ApiKey {
+    fn from(from: domain::ApiKey) -> Self {
+        let domain::ApiKey {
+            id,
+            name,
+            scopes,
+            created_at,
+            expires_at,
+        } = from;
+        let id = id.to_uuid();
+        let scopes = scopes.map(|scopes| scopes.into_iter().map(Into::into).collect());
+        Self {
+            id,
+            name,
+            scopes,
+            created_at,
+            expires_at,
+        }
+    }
+}
+
 // -----   ----- //
 //    Values     //
 // -----   ----- //
@@ -78,6 +125,7 @@ impl From<domain::units::N2oEmissionFactorCalcMethod> for crate::N2oEmissionFact
             FROM::Pesimistic => Self::Pesimistic,
             FROM::Ipcc2019 => Self::Ipcc2019,
             FROM::Custom => Self::CustomFactor,
+            FROM::Measured => Self::Measured,
         }
     }
 }
@@ -90,6 +138,7 @@ impl From<domain::units::Ch4ChpEmissionFactorCalcMethod> for crate::CH4ChpEmissi
             FROM::GasolineEngine => Self::GasolineEngine,
             FROM::JetEngine => Self::JetEngine,
             FROM::Custom => Self::CustomFactor,
+            FROM::Mix => Self::Mix,
         }
     }
 }
@@ -103,6 +152,7 @@ impl From<crate::N2oEmissionFactorCalcMethod> for domain::units::N2oEmissionFact
             FROM::Pesimistic => Self::Pesimistic,
             FROM::Ipcc2019 => Self::Ipcc2019,
             FROM::CustomFactor => Self::Custom,
+            FROM::Measured => Self::Measured,
         }
     }
 }
@@ -115,6 +165,149 @@ impl From<crate::CH4ChpEmissionFactorCalcMethod> for domain::units::Ch4ChpEmissi
             FROM::GasolineEngine => Self::GasolineEngine,
             FROM::JetEngine => Self::JetEngine,
             FROM::CustomFactor => Self::Custom,
+            FROM::Mix => Self::Mix,
+        }
+    }
+}
+
+impl From<domain::units::N2oEmissionFactorCalcMethod> for v4::N2oEmissionFactorCalcMethod {
+    fn from(from: domain::units::N2oEmissionFactorCalcMethod) -> Self {
+        use domain::units::N2oEmissionFactorCalcMethod as FROM;
+        match from {
+            FROM::TuWien2016 => Self::TuWien2016,
+            FROM::Optimistic => Self::Optimistic,
+            FROM::Pesimistic => Self::Pesimistic,
+            // Measurement campaigns are not known before v10,
+            // so the default method is used instead.
+            FROM::Ipcc2019 | FROM::Measured => Self::Ipcc2019,
+            FROM::Custom => Self::CustomFactor,
+        }
+    }
+}
+
+impl From<v4::N2oEmissionFactorCalcMethod> for domain::units::N2oEmissionFactorCalcMethod {
+    fn from(from: v4::N2oEmissionFactorCalcMethod) -> Self {
+        use v4::N2oEmissionFactorCalcMethod as FROM;
+        match from {
+            FROM::TuWien2016 => Self::TuWien2016,
+            FROM::Optimistic => Self::Optimistic,
+            FROM::Pesimistic => Self::Pesimistic,
+            FROM::Ipcc2019 => Self::Ipcc2019,
+            FROM::CustomFactor => Self::Custom,
+        }
+    }
+}
+
+impl From<domain::units::Ch4ChpEmissionFactorCalcMethod> for v3::CH4ChpEmissionFactorCalcMethod {
+    fn from(from: domain::units::Ch4ChpEmissionFactorCalcMethod) -> Self {
+        use domain::units::Ch4ChpEmissionFactorCalcMethod as FROM;
+        match from {
+            FROM::MicroGasTurbines => Self::MicroGasTurbines,
+            // The mix of CHP units is not known before v10,
+            // so the default engine is used instead.
+            FROM::GasolineEngine | FROM::Mix => Self::GasolineEngine,
+            FROM::JetEngine => Self::JetEngine,
+            FROM::Custom => Self::CustomFactor,
+        }
+    }
+}
+
+impl From<v3::CH4ChpEmissionFactorCalcMethod> for domain::units::Ch4ChpEmissionFactorCalcMethod {
+    fn from(from: v3::CH4ChpEmissionFactorCalcMethod) -> Self {
+        use v3::CH4ChpEmissionFactorCalcMethod as FROM;
+        match from {
+            FROM::MicroGasTurbines => Self::MicroGasTurbines,
+            FROM::GasolineEngine => Self::GasolineEngine,
+            FROM::JetEngine => Self::JetEngine,
+            FROM::CustomFactor => Self::Custom,
+        }
+    }
+}
+
+impl From<domain::units::SludgeDisposalRoute> for crate::SludgeDisposalRoute {
+    fn from(from: domain::units::SludgeDisposalRoute) -> Self {
+        use domain::units::SludgeDisposalRoute as FROM;
+        match from {
+            FROM::MonoIncineration => Self::MonoIncineration,
+            FROM::CoIncineration => Self::CoIncineration,
+            FROM::Agriculture => Self::Agriculture,
+            FROM::Landfill => Self::Landfill,
+        }
+    }
+}
+
+impl From<crate::SludgeDisposalRoute> for domain::units::SludgeDisposalRoute {
+    fn from(from: crate::SludgeDisposalRoute) -> Self {
+        use crate::SludgeDisposalRoute as FROM;
+        match from {
+            FROM::MonoIncineration => Self::MonoIncineration,
+            FROM::CoIncineration => Self::CoIncineration,
+            FROM::Agriculture => Self::Agriculture,
+            FROM::Landfill => Self::Landfill,
+        }
+    }
+}
+
+impl From<ChpUnit> for domain::chp_units::ChpUnit {
+    fn from(from: ChpUnit) -> Self {
+        let ChpUnit {
+            calculation_method,
+            custom_factor,
+            gas_share,
+            gas_volume,
+        } = from;
+        Self {
+            calculation_method: calculation_method.into(),
+            custom_factor: custom_factor.map(domain::units::Percent::new),
+            gas_share: gas_share.map(domain::units::Percent::new),
+            gas_volume: gas_volume.map(domain::units::Qubicmeters::new),
+        }
+    }
+}
+
+impl From<domain::chp_units::ChpUnit> for ChpUnit {
+    fn from(from: domain::chp_units::ChpUnit) -> Self {
+        let domain::chp_units::ChpUnit {
+            calculation_method,
+            custom_factor,
+            gas_share,
+            gas_volume,
+        } = from;
+        Self {
+            calculation_method: calculation_method.into(),
+            custom_factor: custom_factor.map(Into::into),
+            gas_share: gas_share.map(Into::into),
+            gas_volume: gas_volume.map(Into::into),
+        }
+    }
+}
+
+impl From<ElectricityContract> for domain::electricity_contracts::ElectricityContract {
+    fn from(from: ElectricityContract) -> Self {
+        let ElectricityContract {
+            energy,
+            emission_factor,
+            guarantee_of_origin,
+        } = from;
+        Self {
+            energy: domain::units::Kilowatthours::new(energy),
+            emission_factor: emission_factor.map(domain::units::GramsPerKilowatthour::new),
+            guarantee_of_origin,
+        }
+    }
+}
+
+impl From<domain::electricity_contracts::ElectricityContract> for ElectricityContract {
+    fn from(from: domain::electricity_contracts::ElectricityContract) -> Self {
+        let domain::electricity_contracts::ElectricityContract {
+            energy,
+            emission_factor,
+            guarantee_of_origin,
+        } = from;
+        Self {
+            energy: energy.into(),
+            emission_factor: emission_factor.map(Into::into),
+            guarantee_of_origin,
         }
     }
 }
@@ -141,7 +334,7 @@ impl TryFrom<v8::FormData> for JsonFormData {
         Ok(from
             .into_iter()
             .map(|(id, value)| {
-                let id = v9::InputValueId::from(id);
+                let id = InputValueId::from(id);
                 id.value_to_json(value).map(|json_value| (id, json_value))
             })
             .collect::<Result<HashMap<_, _>, _>>()?
@@ -149,9 +342,10 @@ impl TryFrom<v8::FormData> for JsonFormData {
     }
 }
 
-impl From<v9::InputValueId> for domain::InputValueId {
-    fn from(from: v9::InputValueId) -> Self {
-        use v9::InputValueId as In;
+impl From<InputValueId> for domain::InputValueId {
+    #[allow(clippy::too_many_lines)]
+    fn from(from: InputValueId) -> Self {
+        use InputValueId as In;
         match from {
             In::ProjectName => Self::ProjectName,
             In::ProfilePlantName => Self::ProfilePlantName,
@@ -166,11 +360,22 @@ impl From<v9::InputValueId> for domain::InputValueId {
             In::ProfileEffluentChemicalOxygenDemand => Self::ProfileEffluentChemicalOxygenDemand,
             In::ProfileSewageGasProduced => Self::ProfileSewageGasProduced,
             In::ProfileMethaneFraction => Self::ProfileMethaneFraction,
+            In::ProfileSewageGasFlared => Self::ProfileSewageGasFlared,
+            In::ProfileFlareEfficiency => Self::ProfileFlareEfficiency,
+            In::ProfileSewageGasVented => Self::ProfileSewageGasVented,
+            In::ProfileSewageGasUpgraded => Self::ProfileSewageGasUpgraded,
+            In::ProfileUpgradingMethaneSlip => Self::ProfileUpgradingMethaneSlip,
+            In::ProfileUpgradingPowerConsumption => Self::ProfileUpgradingPowerConsumption,
+            In::ProfileHeatExported => Self::ProfileHeatExported,
             In::ProfileGasSupply => Self::ProfileGasSupply,
             In::ProfilePurchaseOfBiogas => Self::ProfilePurchaseOfBiogas,
             In::ProfileTotalPowerConsumption => Self::ProfileTotalPowerConsumption,
             In::ProfileOnSitePowerGeneration => Self::ProfileOnSitePowerGeneration,
             In::ProfileEmissionFactorElectricityMix => Self::ProfileEmissionFactorElectricityMix,
+            In::ProfileElectricityContracts => Self::ProfileElectricityContracts,
+            In::ProfileElectricityResidualMixEmissionFactor => {
+                Self::ProfileElectricityResidualMixEmissionFactor
+            }
             In::ProfileHeatingOil => Self::ProfileHeatingOil,
             In::ProfileSideStreamTotalNitrogen => Self::ProfileSideStreamTotalNitrogen,
             In::ProfileOperatingMaterialFeCl3 => Self::ProfileOperatingMaterialFeCl3,
@@ -179,11 +384,36 @@ impl From<v9::InputValueId> for domain::InputValueId {
             In::ProfileOperatingMaterialSyntheticPolymers => {
                 Self::ProfileOperatingMaterialSyntheticPolymers
             }
+            In::ProfileOperatingMaterialMethanol => Self::ProfileOperatingMaterialMethanol,
+            In::ProfileOperatingMaterialPolyaluminiumChloride => {
+                Self::ProfileOperatingMaterialPolyaluminiumChloride
+            }
+            In::ProfileOperatingMaterialSodiumHydroxide => {
+                Self::ProfileOperatingMaterialSodiumHydroxide
+            }
+            In::ProfileOperatingMaterialSodiumHydroxideConcentration => {
+                Self::ProfileOperatingMaterialSodiumHydroxideConcentration
+            }
+            In::ProfileOperatingMaterialAceticAcid => Self::ProfileOperatingMaterialAceticAcid,
+            In::ProfileOperatingMaterialAceticAcidConcentration => {
+                Self::ProfileOperatingMaterialAceticAcidConcentration
+            }
+            In::ProfileOperatingMaterialActivatedCarbon => {
+                Self::ProfileOperatingMaterialActivatedCarbon
+            }
             In::SensitivityN2OCalculationMethod => Self::SensitivityN2OCalculationMethod,
             In::SensitivityN2OCustomFactor => Self::SensitivityN2OCustomFactor,
+            In::SensitivityN2OMeasuredFactor => Self::SensitivityN2OMeasuredFactor,
+            In::SensitivityN2OMeasurementPeriod => Self::SensitivityN2OMeasurementPeriod,
+            In::SensitivityN2OMeasurementSamples => Self::SensitivityN2OMeasurementSamples,
+            In::SensitivityN2OMeasurementCoverage => Self::SensitivityN2OMeasurementCoverage,
+            In::SensitivityN2OMeasurementAnnualCoverage => {
+                Self::SensitivityN2OMeasurementAnnualCoverage
+            }
             In::SensitivityN2OSideStreamFactor => Self::SensitivityN2OSideStreamFactor,
             In::SensitivityCH4ChpCalculationMethod => Self::SensitivityCH4ChpCalculationMethod,
             In::SensitivityCH4ChpCustomFactor => Self::SensitivityCH4ChpCustomFactor,
+            In::SensitivityCH4ChpUnits => Self::SensitivityCH4ChpUnits,
             In::SensitivityCO2FossilCustomFactor => Self::SensitivityCO2FossilCustomFactor,
             In::SensitivitySludgeBagsCustomFactor => Self::SensitivitySludgeBagsCustomFactor,
             In::SensitivitySludgeStorageCustomFactor => Self::SensitivitySludgeStorageCustomFactor,
@@ -193,7 +423,24 @@ impl From<v9::InputValueId> for domain::InputValueId {
             }
             In::ProfileSludgeDisposal => Self::ProfileSludgeDisposal,
             In::ProfileSludgeTransportDistance => Self::ProfileSludgeTransportDistance,
+            In::ProfileSludgeDisposalRoute => Self::ProfileSludgeDisposalRoute,
+            In::ProfileSludgeDisposalShareMonoIncineration => {
+                Self::ProfileSludgeDisposalShareMonoIncineration
+            }
+            In::ProfileSludgeDisposalShareCoIncineration => {
+                Self::ProfileSludgeDisposalShareCoIncineration
+            }
+            In::ProfileSludgeDisposalShareAgriculture => {
+                Self::ProfileSludgeDisposalShareAgriculture
+            }
+            In::ProfileSludgeDisposalShareLandfill => Self::ProfileSludgeDisposalShareLandfill,
             In::ProfileSludgeDigesterCount => Self::ProfileSludgeDigesterCount,
+            In::ProfileSewerPressureMainLength => Self::ProfileSewerPressureMainLength,
+            In::ProfileSewerPressureMainRetentionTime => {
+                Self::ProfileSewerPressureMainRetentionTime
+            }
+            In::ProfileSewerPumpingStationEnergy => Self::ProfileSewerPumpingStationEnergy,
+            In::ProfileSewerOverflowVolume => Self::ProfileSewerOverflowVolume,
             In::RecommendationSludgeBagsAreOpen => Self::RecommendationSludgeBagsAreOpen,
             In::RecommendationSludgeStorageContainersAreOpen => {
                 Self::RecommendationSludgeStorageContainersAreOpen
@@ -201,9 +448,14 @@ impl From<v9::InputValueId> for domain::InputValueId {
             In::RecommendationN2OSideStreamCoverIsOpen => {
                 Self::RecommendationN2OSideStreamCoverIsOpen
             }
+            In::RecommendationFlareIsOpen => Self::RecommendationFlareIsOpen,
             In::RecommendationProcessEnergySaving => Self::RecommendationProcessEnergySaving,
             In::RecommendationFossilEnergySaving => Self::RecommendationFossilEnergySaving,
             In::RecommendationDistrictHeating => Self::RecommendationDistrictHeating,
+            In::RecommendationHeatPumpThermalOutput => Self::RecommendationHeatPumpThermalOutput,
+            In::RecommendationHeatPumpCoefficientOfPerformance => {
+                Self::RecommendationHeatPumpCoefficientOfPerformance
+            }
             In::RecommendationPhotovoltaicEnergyExpansion => {
                 Self::RecommendationPhotovoltaicEnergyExpansion
             }
@@ -218,12 +470,28 @@ impl From<v9::InputValueId> for domain::InputValueId {
             In::RecommendationEstimatedSelfWaterEnergyUsage => {
                 Self::RecommendationEstimatedSelfWaterEnergyUsage
             }
+            In::RecommendationPhotovoltaicPeakPower => Self::RecommendationPhotovoltaicPeakPower,
+            In::RecommendationPhotovoltaicEmbodiedEmissions => {
+                Self::RecommendationPhotovoltaicEmbodiedEmissions
+            }
+            In::RecommendationWindEmbodiedEmissions => Self::RecommendationWindEmbodiedEmissions,
+            In::RecommendationWaterEmbodiedEmissions => Self::RecommendationWaterEmbodiedEmissions,
+            In::RecommendationHeatPumpEmbodiedEmissions => {
+                Self::RecommendationHeatPumpEmbodiedEmissions
+            }
+            In::RecommendationFlareEmbodiedEmissions => Self::RecommendationFlareEmbodiedEmissions,
+            In::RecommendationDigesterCoverArea => Self::RecommendationDigesterCoverArea,
+            In::RecommendationDigesterCoverConcrete => Self::RecommendationDigesterCoverConcrete,
+            In::RecommendationDigesterCoverEmbodiedEmissions => {
+                Self::RecommendationDigesterCoverEmbodiedEmissions
+            }
             In::SensitivityAdditionalCustomEmissions => Self::SensitivityAdditionalCustomEmissions,
         }
     }
 }
 
-impl From<domain::InputValueId> for v9::InputValueId {
+impl From<domain::InputValueId> for InputValueId {
+    #[allow(clippy::too_many_lines)]
     fn from(from: domain::InputValueId) -> Self {
         use domain::InputValueId as In;
         match from {
@@ -240,11 +508,22 @@ impl From<domain::InputValueId> for v9::InputValueId {
             In::ProfileEffluentChemicalOxygenDemand => Self::ProfileEffluentChemicalOxygenDemand,
             In::ProfileSewageGasProduced => Self::ProfileSewageGasProduced,
             In::ProfileMethaneFraction => Self::ProfileMethaneFraction,
+            In::ProfileSewageGasFlared => Self::ProfileSewageGasFlared,
+            In::ProfileFlareEfficiency => Self::ProfileFlareEfficiency,
+            In::ProfileSewageGasVented => Self::ProfileSewageGasVented,
+            In::ProfileSewageGasUpgraded => Self::ProfileSewageGasUpgraded,
+            In::ProfileUpgradingMethaneSlip => Self::ProfileUpgradingMethaneSlip,
+            In::ProfileUpgradingPowerConsumption => Self::ProfileUpgradingPowerConsumption,
+            In::ProfileHeatExported => Self::ProfileHeatExported,
             In::ProfileGasSupply => Self::ProfileGasSupply,
             In::ProfilePurchaseOfBiogas => Self::ProfilePurchaseOfBiogas,
             In::ProfileTotalPowerConsumption => Self::ProfileTotalPowerConsumption,
             In::ProfileOnSitePowerGeneration => Self::ProfileOnSitePowerGeneration,
             In::ProfileEmissionFactorElectricityMix => Self::ProfileEmissionFactorElectricityMix,
+            In::ProfileElectricityContracts => Self::ProfileElectricityContracts,
+            In::ProfileElectricityResidualMixEmissionFactor => {
+                Self::ProfileElectricityResidualMixEmissionFactor
+            }
             In::ProfileHeatingOil => Self::ProfileHeatingOil,
             In::ProfileSideStreamTotalNitrogen => Self::ProfileSideStreamTotalNitrogen,
             In::ProfileOperatingMaterialFeCl3 => Self::ProfileOperatingMaterialFeCl3,
@@ -253,11 +532,36 @@ impl From<domain::InputValueId> for v9::InputValueId {
             In::ProfileOperatingMaterialSyntheticPolymers => {
                 Self::ProfileOperatingMaterialSyntheticPolymers
             }
+            In::ProfileOperatingMaterialMethanol => Self::ProfileOperatingMaterialMethanol,
+            In::ProfileOperatingMaterialPolyaluminiumChloride => {
+                Self::ProfileOperatingMaterialPolyaluminiumChloride
+            }
+            In::ProfileOperatingMaterialSodiumHydroxide => {
+                Self::ProfileOperatingMaterialSodiumHydroxide
+            }
+            In::ProfileOperatingMaterialSodiumHydroxideConcentration => {
+                Self::ProfileOperatingMaterialSodiumHydroxideConcentration
+            }
+            In::ProfileOperatingMaterialAceticAcid => Self::ProfileOperatingMaterialAceticAcid,
+            In::ProfileOperatingMaterialAceticAcidConcentration => {
+                Self::ProfileOperatingMaterialAceticAcidConcentration
+            }
+            In::ProfileOperatingMaterialActivatedCarbon => {
+                Self::ProfileOperatingMaterialActivatedCarbon
+            }
             In::SensitivityN2OCalculationMethod => Self::SensitivityN2OCalculationMethod,
             In::SensitivityN2OCustomFactor => Self::SensitivityN2OCustomFactor,
+            In::SensitivityN2OMeasuredFactor => Self::SensitivityN2OMeasuredFactor,
+            In::SensitivityN2OMeasurementPeriod => Self::SensitivityN2OMeasurementPeriod,
+            In::SensitivityN2OMeasurementSamples => Self::SensitivityN2OMeasurementSamples,
+            In::SensitivityN2OMeasurementCoverage => Self::SensitivityN2OMeasurementCoverage,
+            In::SensitivityN2OMeasurementAnnualCoverage => {
+                Self::SensitivityN2OMeasurementAnnualCoverage
+            }
             In::SensitivityN2OSideStreamFactor => Self::SensitivityN2OSideStreamFactor,
             In::SensitivityCH4ChpCalculationMethod => Self::SensitivityCH4ChpCalculationMethod,
             In::SensitivityCH4ChpCustomFactor => Self::SensitivityCH4ChpCustomFactor,
+            In::SensitivityCH4ChpUnits => Self::SensitivityCH4ChpUnits,
             In::SensitivityCO2FossilCustomFactor => Self::SensitivityCO2FossilCustomFactor,
             In::SensitivitySludgeBagsCustomFactor => Self::SensitivitySludgeBagsCustomFactor,
             In::SensitivitySludgeStorageCustomFactor => Self::SensitivitySludgeStorageCustomFactor,
@@ -267,7 +571,119 @@ impl From<domain::InputValueId> for v9::InputValueId {
             }
             In::ProfileSludgeDisposal => Self::ProfileSludgeDisposal,
             In::ProfileSludgeTransportDistance => Self::ProfileSludgeTransportDistance,
+            In::ProfileSludgeDisposalRoute => Self::ProfileSludgeDisposalRoute,
+            In::ProfileSludgeDisposalShareMonoIncineration => {
+                Self::ProfileSludgeDisposalShareMonoIncineration
+            }
+            In::ProfileSludgeDisposalShareCoIncineration => {
+                Self::ProfileSludgeDisposalShareCoIncineration
+            }
+            In::ProfileSludgeDisposalShareAgriculture => {
+                Self::ProfileSludgeDisposalShareAgriculture
+            }
+            In::ProfileSludgeDisposalShareLandfill => Self::ProfileSludgeDisposalShareLandfill,
+            In::ProfileSludgeDigesterCount => Self::ProfileSludgeDigesterCount,
+            In::ProfileSewerPressureMainLength => Self::ProfileSewerPressureMainLength,
+            In::ProfileSewerPressureMainRetentionTime => {
+                Self::ProfileSewerPressureMainRetentionTime
+            }
+            In::ProfileSewerPumpingStationEnergy => Self::ProfileSewerPumpingStationEnergy,
+            In::ProfileSewerOverflowVolume => Self::ProfileSewerOverflowVolume,
+            In::RecommendationSludgeBagsAreOpen => Self::RecommendationSludgeBagsAreOpen,
+            In::RecommendationSludgeStorageContainersAreOpen => {
+                Self::RecommendationSludgeStorageContainersAreOpen
+            }
+            In::RecommendationN2OSideStreamCoverIsOpen => {
+                Self::RecommendationN2OSideStreamCoverIsOpen
+            }
+            In::RecommendationFlareIsOpen => Self::RecommendationFlareIsOpen,
+            In::RecommendationProcessEnergySaving => Self::RecommendationProcessEnergySaving,
+            In::RecommendationFossilEnergySaving => Self::RecommendationFossilEnergySaving,
+            In::RecommendationDistrictHeating => Self::RecommendationDistrictHeating,
+            In::RecommendationHeatPumpThermalOutput => Self::RecommendationHeatPumpThermalOutput,
+            In::RecommendationHeatPumpCoefficientOfPerformance => {
+                Self::RecommendationHeatPumpCoefficientOfPerformance
+            }
+            In::RecommendationPhotovoltaicEnergyExpansion => {
+                Self::RecommendationPhotovoltaicEnergyExpansion
+            }
+            In::RecommendationEstimatedSelfPhotovolaticUsage => {
+                Self::RecommendationEstimatedSelfPhotovolaticUsage
+            }
+            In::RecommendationWindEnergyExpansion => Self::RecommendationWindEnergyExpansion,
+            In::RecommendationEstimatedSelfWindEnergyUsage => {
+                Self::RecommendationEstimatedSelfWindEnergyUsage
+            }
+            In::RecommendationWaterEnergyExpansion => Self::RecommendationWaterEnergyExpansion,
+            In::RecommendationEstimatedSelfWaterEnergyUsage => {
+                Self::RecommendationEstimatedSelfWaterEnergyUsage
+            }
+            In::RecommendationPhotovoltaicPeakPower => Self::RecommendationPhotovoltaicPeakPower,
+            In::RecommendationPhotovoltaicEmbodiedEmissions => {
+                Self::RecommendationPhotovoltaicEmbodiedEmissions
+            }
+            In::RecommendationWindEmbodiedEmissions => Self::RecommendationWindEmbodiedEmissions,
+            In::RecommendationWaterEmbodiedEmissions => Self::RecommendationWaterEmbodiedEmissions,
+            In::RecommendationHeatPumpEmbodiedEmissions => {
+                Self::RecommendationHeatPumpEmbodiedEmissions
+            }
+            In::RecommendationFlareEmbodiedEmissions => Self::RecommendationFlareEmbodiedEmissions,
+            In::RecommendationDigesterCoverArea => Self::RecommendationDigesterCoverArea,
+            In::RecommendationDigesterCoverConcrete => Self::RecommendationDigesterCoverConcrete,
+            In::RecommendationDigesterCoverEmbodiedEmissions => {
+                Self::RecommendationDigesterCoverEmbodiedEmissions
+            }
+            In::SensitivityAdditionalCustomEmissions => Self::SensitivityAdditionalCustomEmissions,
+        }
+    }
+}
+
+impl From<v9::InputValueId> for InputValueId {
+    fn from(from: v9::InputValueId) -> Self {
+        use v9::InputValueId as In;
+        match from {
+            In::ProjectName => Self::ProjectName,
+            In::ProfilePlantName => Self::ProfilePlantName,
+            In::ProfilePopulationEquivalent => Self::ProfilePopulationEquivalent,
+            In::ProfileWastewater => Self::ProfileWastewater,
+            In::ProfileInfluentNitrogen => Self::ProfileInfluentNitrogen,
+            In::ProfileInfluentChemicalOxygenDemand => Self::ProfileInfluentChemicalOxygenDemand,
+            In::ProfileInfluentTotalOrganicCarbohydrates => {
+                Self::ProfileInfluentTotalOrganicCarbohydrates
+            }
+            In::ProfileEffluentNitrogen => Self::ProfileEffluentNitrogen,
+            In::ProfileEffluentChemicalOxygenDemand => Self::ProfileEffluentChemicalOxygenDemand,
+            In::ProfileSewageGasProduced => Self::ProfileSewageGasProduced,
+            In::ProfileMethaneFraction => Self::ProfileMethaneFraction,
+            In::ProfileGasSupply => Self::ProfileGasSupply,
+            In::ProfilePurchaseOfBiogas => Self::ProfilePurchaseOfBiogas,
+            In::ProfileTotalPowerConsumption => Self::ProfileTotalPowerConsumption,
+            In::ProfileOnSitePowerGeneration => Self::ProfileOnSitePowerGeneration,
+            In::ProfileEmissionFactorElectricityMix => Self::ProfileEmissionFactorElectricityMix,
+            In::ProfileHeatingOil => Self::ProfileHeatingOil,
+            In::ProfileSideStreamTotalNitrogen => Self::ProfileSideStreamTotalNitrogen,
+            In::ProfileOperatingMaterialFeCl3 => Self::ProfileOperatingMaterialFeCl3,
+            In::ProfileOperatingMaterialFeClSO4 => Self::ProfileOperatingMaterialFeClSO4,
+            In::ProfileOperatingMaterialCaOH2 => Self::ProfileOperatingMaterialCaOH2,
+            In::ProfileOperatingMaterialSyntheticPolymers => {
+                Self::ProfileOperatingMaterialSyntheticPolymers
+            }
+            In::ProfileSludgeBagsAreOpen => Self::ProfileSludgeBagsAreOpen,
+            In::ProfileSludgeStorageContainersAreOpen => {
+                Self::ProfileSludgeStorageContainersAreOpen
+            }
+            In::ProfileSludgeDisposal => Self::ProfileSludgeDisposal,
+            In::ProfileSludgeTransportDistance => Self::ProfileSludgeTransportDistance,
             In::ProfileSludgeDigesterCount => Self::ProfileSludgeDigesterCount,
+            In::SensitivityN2OCalculationMethod => Self::SensitivityN2OCalculationMethod,
+            In::SensitivityN2OCustomFactor => Self::SensitivityN2OCustomFactor,
+            In::SensitivityN2OSideStreamFactor => Self::SensitivityN2OSideStreamFactor,
+            In::SensitivityCH4ChpCalculationMethod => Self::SensitivityCH4ChpCalculationMethod,
+            In::SensitivityCH4ChpCustomFactor => Self::SensitivityCH4ChpCustomFactor,
+            In::SensitivityCO2FossilCustomFactor => Self::SensitivityCO2FossilCustomFactor,
+            In::SensitivitySludgeBagsCustomFactor => Self::SensitivitySludgeBagsCustomFactor,
+            In::SensitivitySludgeStorageCustomFactor => Self::SensitivitySludgeStorageCustomFactor,
+            In::SensitivityAdditionalCustomEmissions => Self::SensitivityAdditionalCustomEmissions,
             In::RecommendationSludgeBagsAreOpen => Self::RecommendationSludgeBagsAreOpen,
             In::RecommendationSludgeStorageContainersAreOpen => {
                 Self::RecommendationSludgeStorageContainersAreOpen
@@ -292,7 +708,242 @@ impl From<domain::InputValueId> for v9::InputValueId {
             In::RecommendationEstimatedSelfWaterEnergyUsage => {
                 Self::RecommendationEstimatedSelfWaterEnergyUsage
             }
+        }
+    }
+}
+
+impl TryFrom<InputValueId> for v9::InputValueId {
+    type Error = anyhow::Error;
+
+    fn try_from(from: InputValueId) -> Result<Self, Self::Error> {
+        use InputValueId as In;
+        let id = match from {
+            In::ProjectName => Self::ProjectName,
+            In::ProfilePlantName => Self::ProfilePlantName,
+            In::ProfilePopulationEquivalent => Self::ProfilePopulationEquivalent,
+            In::ProfileWastewater => Self::ProfileWastewater,
+            In::ProfileInfluentNitrogen => Self::ProfileInfluentNitrogen,
+            In::ProfileInfluentChemicalOxygenDemand => Self::ProfileInfluentChemicalOxygenDemand,
+            In::ProfileInfluentTotalOrganicCarbohydrates => {
+                Self::ProfileInfluentTotalOrganicCarbohydrates
+            }
+            In::ProfileEffluentNitrogen => Self::ProfileEffluentNitrogen,
+            In::ProfileEffluentChemicalOxygenDemand => Self::ProfileEffluentChemicalOxygenDemand,
+            In::ProfileSewageGasProduced => Self::ProfileSewageGasProduced,
+            In::ProfileMethaneFraction => Self::ProfileMethaneFraction,
+            In::ProfileGasSupply => Self::ProfileGasSupply,
+            In::ProfilePurchaseOfBiogas => Self::ProfilePurchaseOfBiogas,
+            In::ProfileTotalPowerConsumption => Self::ProfileTotalPowerConsumption,
+            In::ProfileOnSitePowerGeneration => Self::ProfileOnSitePowerGeneration,
+            In::ProfileEmissionFactorElectricityMix => Self::ProfileEmissionFactorElectricityMix,
+            In::ProfileHeatingOil => Self::ProfileHeatingOil,
+            In::ProfileSideStreamTotalNitrogen => Self::ProfileSideStreamTotalNitrogen,
+            In::ProfileOperatingMaterialFeCl3 => Self::ProfileOperatingMaterialFeCl3,
+            In::ProfileOperatingMaterialFeClSO4 => Self::ProfileOperatingMaterialFeClSO4,
+            In::ProfileOperatingMaterialCaOH2 => Self::ProfileOperatingMaterialCaOH2,
+            In::ProfileOperatingMaterialSyntheticPolymers => {
+                Self::ProfileOperatingMaterialSyntheticPolymers
+            }
+            In::ProfileSludgeBagsAreOpen => Self::ProfileSludgeBagsAreOpen,
+            In::ProfileSludgeStorageContainersAreOpen => {
+                Self::ProfileSludgeStorageContainersAreOpen
+            }
+            In::ProfileSludgeDisposal => Self::ProfileSludgeDisposal,
+            In::ProfileSludgeTransportDistance => Self::ProfileSludgeTransportDistance,
+            In::ProfileSludgeDigesterCount => Self::ProfileSludgeDigesterCount,
+            In::SensitivityN2OCalculationMethod => Self::SensitivityN2OCalculationMethod,
+            In::SensitivityN2OCustomFactor => Self::SensitivityN2OCustomFactor,
+            In::SensitivityN2OSideStreamFactor => Self::SensitivityN2OSideStreamFactor,
+            In::SensitivityCH4ChpCalculationMethod => Self::SensitivityCH4ChpCalculationMethod,
+            In::SensitivityCH4ChpCustomFactor => Self::SensitivityCH4ChpCustomFactor,
+            In::SensitivityCO2FossilCustomFactor => Self::SensitivityCO2FossilCustomFactor,
+            In::SensitivitySludgeBagsCustomFactor => Self::SensitivitySludgeBagsCustomFactor,
+            In::SensitivitySludgeStorageCustomFactor => Self::SensitivitySludgeStorageCustomFactor,
             In::SensitivityAdditionalCustomEmissions => Self::SensitivityAdditionalCustomEmissions,
+            In::RecommendationSludgeBagsAreOpen => Self::RecommendationSludgeBagsAreOpen,
+            In::RecommendationSludgeStorageContainersAreOpen => {
+                Self::RecommendationSludgeStorageContainersAreOpen
+            }
+            In::RecommendationN2OSideStreamCoverIsOpen => {
+                Self::RecommendationN2OSideStreamCoverIsOpen
+            }
+            In::RecommendationProcessEnergySaving => Self::RecommendationProcessEnergySaving,
+            In::RecommendationFossilEnergySaving => Self::RecommendationFossilEnergySaving,
+            In::RecommendationDistrictHeating => Self::RecommendationDistrictHeating,
+            In::RecommendationPhotovoltaicEnergyExpansion => {
+                Self::RecommendationPhotovoltaicEnergyExpansion
+            }
+            In::RecommendationEstimatedSelfPhotovolaticUsage => {
+                Self::RecommendationEstimatedSelfPhotovolaticUsage
+            }
+            In::RecommendationWindEnergyExpansion => Self::RecommendationWindEnergyExpansion,
+            In::RecommendationEstimatedSelfWindEnergyUsage => {
+                Self::RecommendationEstimatedSelfWindEnergyUsage
+            }
+            In::RecommendationWaterEnergyExpansion => Self::RecommendationWaterEnergyExpansion,
+            In::RecommendationEstimatedSelfWaterEnergyUsage => {
+                Self::RecommendationEstimatedSelfWaterEnergyUsage
+            }
+            _ => anyhow::bail!("{from:?} is not supported by v9"),
+        };
+        Ok(id)
+    }
+}
+
+impl TryFrom<domain::InputValueId> for v9::InputValueId {
+    type Error = anyhow::Error;
+
+    fn try_from(from: domain::InputValueId) -> Result<Self, Self::Error> {
+        InputValueId::from(from).try_into()
+    }
+}
+
+// -----   ----- //
+//  Calculation  //
+// -----   ----- //
+
+impl From<domain::CalculationOutcome> for json_api::calculate::CalculationOutcome {
+    fn from(from: domain::CalculationOutcome) -> Self {
+        use json_api::calculate::{Ch4ChpScenario, N2oScenario};
+
+        let domain::CalculationOutcome {
+            input: _,
+            output,
+            graph,
+            sensitivity_n2o_calculations,
+            sensitivity_ch4_chp_calculations,
+        } = from;
+
+        let output = output.map(output_values);
+        let graph = graph.map(|edges| {
+            edges
+                .into_iter()
+                .filter_map(|(source, target)| {
+                    let source = value_id(source)?;
+                    let target = value_id(target)?;
+                    Some(json_api::calculate::Edge { source, target })
+                })
+                .collect()
+        });
+        let n2o_scenarios = sensitivity_n2o_calculations.map(|scenarios| {
+            scenarios
+                .into_iter()
+                .map(|(method, values)| N2oScenario {
+                    method: method.into(),
+                    values: output_values(values),
+                })
+                .collect()
+        });
+        let ch4_chp_scenarios = sensitivity_ch4_chp_calculations.map(|scenarios| {
+            scenarios
+                .into_iter()
+                .map(|(method, emissions, emission_factor)| Ch4ChpScenario {
+                    method: method.into(),
+                    emissions: emissions.into(),
+                    emission_factor: emission_factor.into(),
+                })
+                .collect()
+        });
+
+        Self {
+            output,
+            graph,
+            n2o_scenarios,
+            ch4_chp_scenarios,
+        }
+    }
+}
+
+fn output_values(values: domain::Values) -> Vec<json_api::calculate::OutputValue> {
+    let mut values: Vec<_> = values
+        .into_iter()
+        .filter_map(|(id, value)| {
+            let id = value_id(id)?;
+            let value = f64::from(value.as_float()?);
+            Some(json_api::calculate::OutputValue { id, value })
+        })
+        .collect();
+    // The domain values are unordered
+    values.sort_by(|a, b| a.id.cmp(&b.id));
+    values
+}
+
+fn value_id(from: domain::ValueId) -> Option<json_api::calculate::ValueId> {
+    use json_api::calculate::ValueId;
+    match from {
+        domain::ValueId::Custom(id) => Some(ValueId::Custom(id)),
+        domain::ValueId::Out(id) => Some(ValueId::Out(id.into())),
+        domain::ValueId::In(_) => None,
+    }
+}
+
+impl From<domain::OutputValueId> for json_api::calculate::OutputValueId {
+    fn from(from: domain::OutputValueId) -> Self {
+        use domain::OutputValueId as Out;
+        match from {
+            Out::N2oPlant => Self::N2oPlant,
+            Out::N2oWater => Self::N2oWater,
+            Out::N2oSideStream => Self::N2oSideStream,
+            Out::N2oEmissions => Self::N2oEmissions,
+            Out::Ch4Plant => Self::Ch4Plant,
+            Out::Ch4SludgeStorageContainers => Self::Ch4SludgeStorageContainers,
+            Out::Ch4SludgeBags => Self::Ch4SludgeBags,
+            Out::Ch4Water => Self::Ch4Water,
+            Out::Ch4CombinedHeatAndPowerPlant => Self::Ch4CombinedHeatAndPowerPlant,
+            Out::Ch4Flare => Self::Ch4Flare,
+            Out::Ch4Vented => Self::Ch4Vented,
+            Out::Ch4Upgrading => Self::Ch4Upgrading,
+            Out::Ch4Emissions => Self::Ch4Emissions,
+            Out::FossilEmissions => Self::FossilEmissions,
+            Out::Fecl3 => Self::Fecl3,
+            Out::Feclso4 => Self::Feclso4,
+            Out::Caoh2 => Self::Caoh2,
+            Out::SyntheticPolymers => Self::SyntheticPolymers,
+            Out::Methanol => Self::Methanol,
+            Out::PolyaluminiumChloride => Self::PolyaluminiumChloride,
+            Out::SodiumHydroxide => Self::SodiumHydroxide,
+            Out::AceticAcid => Self::AceticAcid,
+            Out::ActivatedCarbon => Self::ActivatedCarbon,
+            Out::ElectricityMix => Self::ElectricityMix,
+            Out::ElectricityMixMarketBased => Self::ElectricityMixMarketBased,
+            Out::OilEmissions => Self::OilEmissions,
+            Out::GasEmissions => Self::GasEmissions,
+            Out::UpgradingElectricity => Self::UpgradingElectricity,
+            Out::OperatingMaterials => Self::OperatingMaterials,
+            Out::SewageSludgeTransport => Self::SewageSludgeTransport,
+            Out::SludgeDisposalMonoIncineration => Self::SludgeDisposalMonoIncineration,
+            Out::SludgeDisposalCoIncineration => Self::SludgeDisposalCoIncineration,
+            Out::SludgeDisposalAgriculture => Self::SludgeDisposalAgriculture,
+            Out::SludgeDisposalLandfill => Self::SludgeDisposalLandfill,
+            Out::Ch4PressureMains => Self::Ch4PressureMains,
+            Out::Ch4SewerOverflows => Self::Ch4SewerOverflows,
+            Out::SewerPumpingStationElectricity => Self::SewerPumpingStationElectricity,
+            Out::SewerNetworkEmissions => Self::SewerNetworkEmissions,
+            Out::TotalEmissions => Self::TotalEmissions,
+            Out::DirectEmissions => Self::DirectEmissions,
+            Out::ProcessEnergySavings => Self::ProcessEnergySavings,
+            Out::PhotovoltaicExpansionSavings => Self::PhotovoltaicExpansionSavings,
+            Out::WindExpansionSavings => Self::WindExpansionSavings,
+            Out::WaterExpansionSavings => Self::WaterExpansionSavings,
+            Out::DistrictHeatingSavings => Self::DistrictHeatingSavings,
+            Out::FossilEnergySavings => Self::FossilEnergySavings,
+            Out::EnclosedFlareSavings => Self::EnclosedFlareSavings,
+            Out::DigesterCoverSavings => Self::DigesterCoverSavings,
+            Out::HeatPumpSavings => Self::HeatPumpSavings,
+            Out::IndirectEmissions => Self::IndirectEmissions,
+            Out::OtherIndirectEmissions => Self::OtherIndirectEmissions,
+            Out::ExcessEnergyCo2Equivalent => Self::ExcessEnergyCo2Equivalent,
+            Out::BiomethaneInjectionCredit => Self::BiomethaneInjectionCredit,
+            Out::HeatExportCredit => Self::HeatExportCredit,
+            Out::AvoidedEmissions => Self::AvoidedEmissions,
+            Out::N2oCalculatedEmissionFactor => Self::N2oCalculatedEmissionFactor,
+            Out::Ch4ChpCalculatedEmissionFactor => Self::Ch4ChpCalculatedEmissionFactor,
+            Out::N2oEmissionFactorCalcMethod => Self::N2oEmissionFactorCalcMethod,
+            Out::Ch4ChpEmissionFactorCalcMethod => Self::Ch4ChpEmissionFactorCalcMethod,
+            Out::N2oEmissionCustomFactor => Self::N2oEmissionCustomFactor,
+            Out::Ch4ChpEmissionCustomFactor => Self::Ch4ChpEmissionCustomFactor,
+            Out::AdditionalCustomEmissions => Self::AdditionalCustomEmissions,
+            Out::AdditionalCustomEmissionsSavings => Self::AdditionalCustomEmissionsSavings,
         }
     }
 }
@@ -1,6 +1,18 @@
+use std::collections::HashMap;
+
 use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    v10::{
+        CH4ChpEmissionFactorCalcMethod, CustomEmissions, DecimalSeparator, InputValueId,
+        JsonFormData, N2oEmissionFactorCalcMethod, Project, SavedProject, UnsavedProject,
+    },
+    v9, CURRENT_VERSION,
+};
 
-use crate::{v9::Project, CURRENT_VERSION};
+/// The last version that stored the custom emissions as text.
+const V9_VERSION: u32 = 9;
 
 #[derive(Serialize)]
 struct Export<'a> {
@@ -9,6 +21,13 @@ struct Export<'a> {
     pub data: &'a Project,
 }
 
+#[derive(Serialize)]
+struct ExportV9<'a> {
+    pub version: u32,
+    #[serde(flatten)]
+    pub data: &'a v9::Project,
+}
+
 #[must_use]
 #[allow(clippy::missing_panics_doc)]
 pub fn export_to_string_pretty(data: &Project) -> String {
@@ -30,9 +49,78 @@ pub fn export_to_vec_pretty(data: &Project) -> Vec<u8> {
     serde_json::to_vec_pretty(&export).expect("Valid input data")
 }
 
+/// Export a project in the v9 format.
+///
+/// The custom emissions are written as text
+/// with German number format like v9 did.
+/// Values that are not known in v9 are omitted.
+pub fn export_v9_to_vec_pretty(data: &Project) -> anyhow::Result<Vec<u8>> {
+    let project = to_v9(data.clone())?;
+    let export = ExportV9 {
+        version: V9_VERSION,
+        data: &project,
+    };
+    Ok(serde_json::to_vec_pretty(&export)?)
+}
+
 fn pack(data: &Project) -> Export {
     Export {
         version: CURRENT_VERSION,
         data,
     }
 }
+
+fn to_v9(project: Project) -> anyhow::Result<v9::Project> {
+    let project = match project {
+        Project::Unsaved(UnsavedProject { form_data }) => {
+            let form_data = to_v9_form_data(form_data)?;
+            v9::UnsavedProject { form_data }.into()
+        }
+        Project::Saved(SavedProject {
+            id,
+            created_at,
+            modified_at,
+            form_data,
+        }) => {
+            let form_data = to_v9_form_data(form_data)?;
+            v9::SavedProject {
+                id,
+                created_at,
+                modified_at,
+                form_data,
+            }
+            .into()
+        }
+    };
+    Ok(project)
+}
+
+fn to_v9_form_data(data: JsonFormData) -> anyhow::Result<v9::JsonFormData> {
+    let mut form_data = HashMap::new();
+    for (id, value) in data.0 {
+        let value = match id {
+            InputValueId::SensitivityAdditionalCustomEmissions => {
+                let emissions: CustomEmissions = serde_json::from_value(value)?;
+                JsonValue::String(emissions.to_text(DecimalSeparator::Comma))
+            }
+            InputValueId::SensitivityCH4ChpCalculationMethod
+                if serde_json::from_value::<CH4ChpEmissionFactorCalcMethod>(value.clone())?
+                    == CH4ChpEmissionFactorCalcMethod::Mix =>
+            {
+                continue;
+            }
+            InputValueId::SensitivityN2OCalculationMethod
+                if serde_json::from_value::<N2oEmissionFactorCalcMethod>(value.clone())?
+                    == N2oEmissionFactorCalcMethod::Measured =>
+            {
+                continue;
+            }
+            _ => value,
+        };
+        let Ok(id) = v9::InputValueId::try_from(id) else {
+            continue;
+        };
+        form_data.insert(id, value);
+    }
+    Ok(v9::JsonFormData::from(form_data))
+}
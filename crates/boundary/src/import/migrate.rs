@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
-use crate::{v1, v2, v3, v4, v5, v6, v7, v8, v9};
+use serde_json::Value as JsonValue;
+
+use crate::{v1, v10, v2, v3, v4, v5, v6, v7, v8, v9};
 
 const V1_OPERATING_MATERIALS_DIVISOR: f64 = 1_000.0;
 
@@ -596,11 +598,61 @@ pub fn from_v8(data: v8::Data) -> v9::Project {
 fn from_v8_form_data(data: v8::JsonFormData) -> v9::JsonFormData {
     let form_data = v8::FormData::from(data)
         .into_iter()
-        .map(|(id, value)| {
-            let id = v9::InputValueId::from(id);
+        .filter_map(|(id, value)| {
+            // Values that were added after v9 are not supported by v8.
+            let id = v9::InputValueId::try_from(id).ok()?;
             let value = id.value_to_json(value).unwrap();
-            (id, value)
+            Some((id, value))
         })
         .collect::<HashMap<_, _>>();
     v9::JsonFormData::from(form_data)
 }
+
+pub fn from_v9(project: v9::Project) -> v10::Project {
+    match project {
+        v9::Project::Unsaved(v9::UnsavedProject { form_data }) => {
+            let form_data = from_v9_form_data(form_data);
+            v10::UnsavedProject { form_data }.into()
+        }
+        v9::Project::Saved(saved_project) => {
+            let v9::SavedProject {
+                id,
+                created_at,
+                modified_at,
+                form_data,
+            } = saved_project;
+            let form_data = from_v9_form_data(form_data);
+            v10::SavedProject {
+                id,
+                created_at,
+                modified_at,
+                form_data,
+            }
+            .into()
+        }
+    }
+}
+
+fn from_v9_form_data(data: v9::JsonFormData) -> v10::JsonFormData {
+    let mut data: HashMap<_, _> = data
+        .0
+        .into_iter()
+        .map(|(id, value)| (v10::InputValueId::from(id), value))
+        .collect();
+    let id = v10::InputValueId::SensitivityAdditionalCustomEmissions;
+    if let Some(JsonValue::String(text)) = data.remove(&id) {
+        // The text was written in the number format of the user interface.
+        // If it can not be detected, the German number format is used
+        // like in the calculation of v9.
+        let separator =
+            v10::DecimalSeparator::detect(&text).unwrap_or(v10::DecimalSeparator::Comma);
+        let (emissions, unreadable_lines) = v10::CustomEmissions::from_text_lossy(&text, separator);
+        for v10::UnreadableLine { number, content } in unreadable_lines {
+            log::warn!("Custom emissions line {number} is kept as comment: {content}");
+        }
+        if !emissions.is_empty() {
+            data.insert(id, serde_json::to_value(emissions).unwrap());
+        }
+    }
+    v10::JsonFormData::from(data)
+}
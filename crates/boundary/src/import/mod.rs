@@ -1,17 +1,17 @@
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::{v1, v2, v3, v4, v5, v6, v7, v8, v9, CURRENT_VERSION};
+use crate::{v1, v10, v2, v3, v4, v5, v6, v7, v8, v9, CURRENT_VERSION};
 
 mod migrate;
 
-pub fn import_from_str(json: &str) -> Result<v9::Project> {
+pub fn import_from_str(json: &str) -> Result<v10::Project> {
     import_from_slice(json.as_bytes())
 }
 
-pub fn import_from_slice(slice: &[u8]) -> Result<v9::Project> {
+pub fn import_from_slice(slice: &[u8]) -> Result<v10::Project> {
     let VersionInfo { version } = serde_json::from_slice(slice)?;
-    let project: v9::Project = match version {
+    let project: v10::Project = match version {
         1 => {
             let v1 = import::<v1::Import>(slice)?;
             let v2 = migrate::from_v1(v1);
@@ -21,7 +21,8 @@ pub fn import_from_slice(slice: &[u8]) -> Result<v9::Project> {
             let v6 = migrate::from_v5(v5);
             let v7 = migrate::from_v6(v6);
             let v8 = migrate::from_v7(v7);
-            migrate::from_v8(v8)
+            let v9 = migrate::from_v8(v8);
+            migrate::from_v9(v9)
         }
         2 => {
             let v2 = import::<v2::Import>(slice)?;
@@ -31,7 +32,8 @@ pub fn import_from_slice(slice: &[u8]) -> Result<v9::Project> {
             let v6 = migrate::from_v5(v5);
             let v7 = migrate::from_v6(v6);
             let v8 = migrate::from_v7(v7);
-            migrate::from_v8(v8)
+            let v9 = migrate::from_v8(v8);
+            migrate::from_v9(v9)
         }
         3 => {
             let v3 = import::<v3::Import>(slice)?;
@@ -40,7 +42,8 @@ pub fn import_from_slice(slice: &[u8]) -> Result<v9::Project> {
             let v6 = migrate::from_v5(v5);
             let v7 = migrate::from_v6(v6);
             let v8 = migrate::from_v7(v7);
-            migrate::from_v8(v8)
+            let v9 = migrate::from_v8(v8);
+            migrate::from_v9(v9)
         }
         4 => {
             let v4 = import::<v4::Import>(slice)?;
@@ -48,31 +51,40 @@ pub fn import_from_slice(slice: &[u8]) -> Result<v9::Project> {
             let v6 = migrate::from_v5(v5);
             let v7 = migrate::from_v6(v6);
             let v8 = migrate::from_v7(v7);
-            migrate::from_v8(v8)
+            let v9 = migrate::from_v8(v8);
+            migrate::from_v9(v9)
         }
         5 => {
             let v5 = import::<v5::Data>(slice)?;
             let v6 = migrate::from_v5(v5);
             let v7 = migrate::from_v6(v6);
             let v8 = migrate::from_v7(v7);
-            migrate::from_v8(v8)
+            let v9 = migrate::from_v8(v8);
+            migrate::from_v9(v9)
         }
         6 => {
             let v6 = import::<v6::Data>(slice)?;
             let v7 = migrate::from_v6(v6);
             let v8 = migrate::from_v7(v7);
-            migrate::from_v8(v8)
+            let v9 = migrate::from_v8(v8);
+            migrate::from_v9(v9)
         }
         7 => {
             let v7 = import::<v7::Data>(slice)?;
             let v8 = migrate::from_v7(v7);
-            migrate::from_v8(v8)
+            let v9 = migrate::from_v8(v8);
+            migrate::from_v9(v9)
         }
         8 => {
             let v8 = import::<v8::Data>(slice)?;
-            migrate::from_v8(v8)
+            let v9 = migrate::from_v8(v8);
+            migrate::from_v9(v9)
         }
-        9 => import(slice)?,
+        9 => {
+            let v9 = import::<v9::Project>(slice)?;
+            migrate::from_v9(v9)
+        }
+        10 => import(slice)?,
         _ => {
             return Err(Error::Version {
                 actual: version,
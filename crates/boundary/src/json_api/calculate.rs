@@ -0,0 +1,143 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::{CH4ChpEmissionFactorCalcMethod, JsonFormData, N2oEmissionFactorCalcMethod};
+
+/// Result of `POST /api/v1/calculate`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CalculationOutcome {
+    /// Calculated values
+    ///
+    /// Missing if the input is incomplete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Vec<OutputValue>>,
+
+    /// Edges of the emission graph
+    ///
+    /// Missing if the input is incomplete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub graph: Option<Vec<Edge>>,
+
+    /// Results of all N₂O emission factor scenarios
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n2o_scenarios: Option<Vec<N2oScenario>>,
+
+    /// Results of all CH₄ CHP emission factor scenarios
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ch4_chp_scenarios: Option<Vec<Ch4ChpScenario>>,
+}
+
+/// A calculated value
+///
+/// Emissions are given in tons CO₂ equivalents,
+/// emission factors as fraction.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct OutputValue {
+    pub id: ValueId,
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Edge {
+    pub source: ValueId,
+    pub target: ValueId,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct N2oScenario {
+    pub method: N2oEmissionFactorCalcMethod,
+    pub values: Vec<OutputValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Ch4ChpScenario {
+    pub method: CH4ChpEmissionFactorCalcMethod,
+    pub emissions: f64,
+    pub emission_factor: f64,
+}
+
+/// Node of the emission graph
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum ValueId {
+    /// User defined emission
+    Custom(String),
+    /// Calculated value
+    Out(OutputValueId),
+}
+
+// Since the IDs in the domain layer may change in the future,
+// we need a stable ID for serialization at this point.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputValueId {
+    N2oPlant,
+    N2oWater,
+    N2oSideStream,
+    N2oEmissions,
+    Ch4Plant,
+    Ch4SludgeStorageContainers,
+    Ch4SludgeBags,
+    Ch4Water,
+    Ch4CombinedHeatAndPowerPlant,
+    Ch4Flare,
+    Ch4Vented,
+    Ch4Upgrading,
+    Ch4Emissions,
+    FossilEmissions,
+    Fecl3,
+    Feclso4,
+    Caoh2,
+    SyntheticPolymers,
+    Methanol,
+    PolyaluminiumChloride,
+    SodiumHydroxide,
+    AceticAcid,
+    ActivatedCarbon,
+    ElectricityMix,
+    ElectricityMixMarketBased,
+    OilEmissions,
+    GasEmissions,
+    UpgradingElectricity,
+    OperatingMaterials,
+    SewageSludgeTransport,
+    SludgeDisposalMonoIncineration,
+    SludgeDisposalCoIncineration,
+    SludgeDisposalAgriculture,
+    SludgeDisposalLandfill,
+    Ch4PressureMains,
+    Ch4SewerOverflows,
+    SewerPumpingStationElectricity,
+    SewerNetworkEmissions,
+    TotalEmissions,
+    DirectEmissions,
+    ProcessEnergySavings,
+    PhotovoltaicExpansionSavings,
+    WindExpansionSavings,
+    WaterExpansionSavings,
+    DistrictHeatingSavings,
+    FossilEnergySavings,
+    EnclosedFlareSavings,
+    DigesterCoverSavings,
+    HeatPumpSavings,
+    IndirectEmissions,
+    OtherIndirectEmissions,
+    ExcessEnergyCo2Equivalent,
+    BiomethaneInjectionCredit,
+    HeatExportCredit,
+    AvoidedEmissions,
+    N2oCalculatedEmissionFactor,
+    Ch4ChpCalculatedEmissionFactor,
+    N2oEmissionFactorCalcMethod,
+    Ch4ChpEmissionFactorCalcMethod,
+    N2oEmissionCustomFactor,
+    Ch4ChpEmissionCustomFactor,
+    AdditionalCustomEmissions,
+    AdditionalCustomEmissionsSavings,
+}
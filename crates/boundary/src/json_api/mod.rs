@@ -1,10 +1,12 @@
 use http::StatusCode;
 use serde::{Deserialize, Serialize};
+use time::{serde::iso8601, OffsetDateTime};
 use uuid::Uuid;
 
 #[cfg(feature = "axum")]
 mod axum;
 
+pub mod calculate;
 pub mod login;
 pub mod register;
 
@@ -42,6 +44,42 @@ impl<T> Error<T> {
         }
     }
 
+    #[must_use]
+    pub const fn forbidden() -> Self {
+        Self {
+            message: None,
+            status: StatusCode::FORBIDDEN,
+            details: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn not_found() -> Self {
+        Self {
+            message: None,
+            status: StatusCode::NOT_FOUND,
+            details: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn conflict() -> Self {
+        Self {
+            message: None,
+            status: StatusCode::CONFLICT,
+            details: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn too_many_requests() -> Self {
+        Self {
+            message: None,
+            status: StatusCode::TOO_MANY_REQUESTS,
+            details: None,
+        }
+    }
+
     #[must_use]
     pub const fn bad_request() -> Self {
         Self {
@@ -83,6 +121,82 @@ pub struct ApiToken {
     pub token: String,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ChangePassword {
+    pub old_password: String,
+    pub new_password: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ChangeEmailAddress {
+    pub new_email: String,
+    pub password: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct DeleteAccount {
+    pub password: String,
+}
+
+/// Account metadata as part of the data export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountData {
+    pub email: String,
+    pub email_confirmed: bool,
+    #[serde(with = "iso8601")]
+    pub created_at: OffsetDateTime,
+    pub api_keys: Vec<ApiKey>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ApiKeyScope {
+    ReadProjects,
+    WriteProjects,
+    Calculate,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewApiKey {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Omit to grant all scopes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "iso8601::option"
+    )]
+    pub expires_at: Option<OffsetDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+    #[serde(with = "iso8601")]
+    pub created_at: OffsetDateTime,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "iso8601::option"
+    )]
+    pub expires_at: Option<OffsetDateTime>,
+}
+
+/// A newly created API key
+///
+/// The secret is only returned once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedApiKey {
+    pub api_key: ApiKey,
+    pub secret: String,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct RequestPasswordReset {
     pub email: String,
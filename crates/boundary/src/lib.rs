@@ -2,6 +2,7 @@ mod export;
 mod import;
 
 mod v1;
+mod v10;
 mod v2;
 mod v3;
 mod v4;
@@ -9,17 +10,20 @@ mod v5;
 mod v6;
 mod v7;
 mod v8;
+#[allow(dead_code)] // frozen format
 mod v9;
 
 pub mod json_api;
 
 pub use self::{
-    export::{export_to_string, export_to_string_pretty, export_to_vec_pretty},
+    export::{
+        export_to_string, export_to_string_pretty, export_to_vec_pretty, export_v9_to_vec_pretty,
+    },
     import::{import_from_slice, import_from_str, Error as ImportError},
-    v9::*,
+    v10::*,
 };
 
 #[cfg(feature = "conversion")]
 mod conversion;
 
-pub const CURRENT_VERSION: u32 = 9;
+pub const CURRENT_VERSION: u32 = 10;
@@ -0,0 +1,896 @@
+use std::collections::HashMap;
+
+use anyhow::anyhow;
+use derive_more::From;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use time::{serde::iso8601, OffsetDateTime};
+
+use klick_domain::{chp_units, electricity_contracts, Value};
+
+pub use crate::v9::{FormData, ProjectId};
+
+mod text;
+
+pub use self::text::{DecimalSeparator, UnreadableLine};
+
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq, From)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct JsonFormData(pub(crate) HashMap<InputValueId, JsonValue>);
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, From)]
+#[serde(untagged)]
+pub enum Project {
+    Saved(SavedProject),
+    Unsaved(UnsavedProject),
+}
+
+impl Project {
+    #[must_use]
+    pub const fn form_data(&self) -> &JsonFormData {
+        match self {
+            Self::Saved(SavedProject { form_data, .. })
+            | Self::Unsaved(UnsavedProject { form_data }) => form_data,
+        }
+    }
+
+    #[must_use]
+    pub fn into_form_data(self) -> JsonFormData {
+        match self {
+            Self::Saved(SavedProject { form_data, .. })
+            | Self::Unsaved(UnsavedProject { form_data }) => form_data,
+        }
+    }
+}
+
+impl Default for Project {
+    fn default() -> Self {
+        UnsavedProject::default().into()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct UnsavedProject {
+    pub form_data: JsonFormData,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SavedProject {
+    pub id: ProjectId,
+    #[serde(with = "iso8601")]
+    pub created_at: OffsetDateTime,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        with = "iso8601::option"
+    )]
+    pub modified_at: Option<OffsetDateTime>,
+    pub form_data: JsonFormData,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum N2oEmissionFactorCalcMethod {
+    TuWien2016,
+    Optimistic,
+    Pesimistic,
+    Ipcc2019,
+    CustomFactor,
+    /// Measured by a campaign
+    Measured,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum CH4ChpEmissionFactorCalcMethod {
+    MicroGasTurbines,
+    GasolineEngine,
+    JetEngine,
+    CustomFactor,
+    /// Mix of all CHP units
+    Mix,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum SludgeDisposalRoute {
+    MonoIncineration,
+    CoIncineration,
+    Agriculture,
+    Landfill,
+}
+
+/// A CHP unit of the mix of all units.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChpUnit {
+    pub calculation_method: CH4ChpEmissionFactorCalcMethod,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_factor: Option<f64>,
+    /// Share of the sewage gas produced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_share: Option<f64>,
+    /// Volume of the sewage gas (takes precedence over the share)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_volume: Option<f64>,
+}
+
+/// A contract for purchased electricity.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ElectricityContract {
+    pub energy: f64,
+    /// Emission factor of the supplier
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emission_factor: Option<f64>,
+    #[serde(default)]
+    pub guarantee_of_origin: bool,
+}
+
+// Since the IDs in the domain layer may change in the future,
+// we need a stable ID for serialization and deserialization at this point,
+// which can always be resolved for v10.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum InputValueId {
+    // --- Project ---//
+    ProjectName,
+
+    // --- Profile ---//
+    ProfilePlantName,
+    ProfilePopulationEquivalent,
+    ProfileWastewater,
+    ProfileInfluentNitrogen,
+    ProfileInfluentChemicalOxygenDemand,
+    ProfileInfluentTotalOrganicCarbohydrates,
+    ProfileEffluentNitrogen,
+    ProfileEffluentChemicalOxygenDemand,
+    ProfileSewageGasProduced,
+    ProfileMethaneFraction,
+    ProfileSewageGasFlared,
+    ProfileFlareEfficiency,
+    ProfileSewageGasVented,
+    ProfileSewageGasUpgraded,
+    ProfileUpgradingMethaneSlip,
+    ProfileUpgradingPowerConsumption,
+    ProfileHeatExported,
+    ProfileGasSupply,
+    ProfilePurchaseOfBiogas,
+    ProfileTotalPowerConsumption,
+    ProfileOnSitePowerGeneration,
+    ProfileEmissionFactorElectricityMix,
+    ProfileElectricityContracts,
+    ProfileElectricityResidualMixEmissionFactor,
+    ProfileHeatingOil,
+    ProfileSideStreamTotalNitrogen,
+    #[serde(rename = "profile-operating-material-fe-cl3")]
+    ProfileOperatingMaterialFeCl3,
+    #[serde(rename = "profile-operating-material-fe-cl-so4")]
+    ProfileOperatingMaterialFeClSO4,
+    #[serde(rename = "profile-operating-material-ca-oh2")]
+    ProfileOperatingMaterialCaOH2,
+    ProfileOperatingMaterialSyntheticPolymers,
+    ProfileOperatingMaterialMethanol,
+    ProfileOperatingMaterialPolyaluminiumChloride,
+    ProfileOperatingMaterialSodiumHydroxide,
+    ProfileOperatingMaterialSodiumHydroxideConcentration,
+    ProfileOperatingMaterialAceticAcid,
+    ProfileOperatingMaterialAceticAcidConcentration,
+    ProfileOperatingMaterialActivatedCarbon,
+    ProfileSludgeBagsAreOpen,
+    ProfileSludgeStorageContainersAreOpen,
+    ProfileSludgeDisposal,
+    ProfileSludgeTransportDistance,
+    ProfileSludgeDisposalRoute,
+    ProfileSludgeDisposalShareMonoIncineration,
+    ProfileSludgeDisposalShareCoIncineration,
+    ProfileSludgeDisposalShareAgriculture,
+    ProfileSludgeDisposalShareLandfill,
+    ProfileSludgeDigesterCount,
+    ProfileSewerPressureMainLength,
+    ProfileSewerPressureMainRetentionTime,
+    ProfileSewerPumpingStationEnergy,
+    ProfileSewerOverflowVolume,
+
+    // --- Sensitivity ---//
+    #[serde(rename = "sensitivity-n2o-calculation-method")]
+    SensitivityN2OCalculationMethod,
+    #[serde(rename = "sensitivity-n2o-custom-factor")]
+    SensitivityN2OCustomFactor,
+    #[serde(rename = "sensitivity-n2o-measured-factor")]
+    SensitivityN2OMeasuredFactor,
+    #[serde(rename = "sensitivity-n2o-measurement-period")]
+    SensitivityN2OMeasurementPeriod,
+    #[serde(rename = "sensitivity-n2o-measurement-samples")]
+    SensitivityN2OMeasurementSamples,
+    #[serde(rename = "sensitivity-n2o-measurement-coverage")]
+    SensitivityN2OMeasurementCoverage,
+    #[serde(rename = "sensitivity-n2o-measurement-annual-coverage")]
+    SensitivityN2OMeasurementAnnualCoverage,
+    #[serde(rename = "sensitivity-n2o-side-stream-factor")]
+    SensitivityN2OSideStreamFactor,
+    #[serde(rename = "sensitivity-ch4-chp-calculation-method")]
+    SensitivityCH4ChpCalculationMethod,
+    #[serde(rename = "sensitivity-ch4-chp-custom-factor")]
+    SensitivityCH4ChpCustomFactor,
+    #[serde(rename = "sensitivity-ch4-chp-units")]
+    SensitivityCH4ChpUnits,
+    #[serde(rename = "sensitivity-co2-fossil-custom-factor")]
+    SensitivityCO2FossilCustomFactor,
+    SensitivitySludgeBagsCustomFactor,
+    SensitivitySludgeStorageCustomFactor,
+    SensitivityAdditionalCustomEmissions,
+
+    // --- Recommendation ---//
+    RecommendationSludgeBagsAreOpen,
+    RecommendationSludgeStorageContainersAreOpen,
+    #[serde(rename = "recommendation-n2o-side-stream-cover-is-open")]
+    RecommendationN2OSideStreamCoverIsOpen,
+    RecommendationFlareIsOpen,
+    RecommendationProcessEnergySaving,
+    RecommendationFossilEnergySaving,
+    RecommendationDistrictHeating,
+    RecommendationHeatPumpThermalOutput,
+    RecommendationHeatPumpCoefficientOfPerformance,
+    RecommendationPhotovoltaicEnergyExpansion,
+    RecommendationEstimatedSelfPhotovolaticUsage,
+    RecommendationWindEnergyExpansion,
+    RecommendationEstimatedSelfWindEnergyUsage,
+    RecommendationWaterEnergyExpansion,
+    RecommendationEstimatedSelfWaterEnergyUsage,
+    RecommendationPhotovoltaicPeakPower,
+    RecommendationPhotovoltaicEmbodiedEmissions,
+    RecommendationWindEmbodiedEmissions,
+    RecommendationWaterEmbodiedEmissions,
+    RecommendationHeatPumpEmbodiedEmissions,
+    RecommendationFlareEmbodiedEmissions,
+    RecommendationDigesterCoverArea,
+    RecommendationDigesterCoverConcrete,
+    RecommendationDigesterCoverEmbodiedEmissions,
+}
+
+impl InputValueId {
+    /// Convert JSON values to domain values
+    // Since the data types in the domain layer may change in the future,
+    // we need a clear assignment for v10 at this point.
+    pub fn value_from_json(&self, v: JsonValue) -> anyhow::Result<Value> {
+        let v = match self {
+            Self::ProjectName | Self::ProfilePlantName => {
+                let v = v
+                    .as_str()
+                    .ok_or_else(|| anyhow!("Expected text value for {self:?}, got {v:?}"))?;
+                Value::text(v)
+            }
+
+            // Boolean values
+            Self::RecommendationSludgeStorageContainersAreOpen
+            | Self::ProfilePurchaseOfBiogas
+            | Self::ProfileSludgeBagsAreOpen
+            | Self::RecommendationSludgeBagsAreOpen
+            | Self::RecommendationN2OSideStreamCoverIsOpen
+            | Self::RecommendationFlareIsOpen
+            | Self::ProfileSludgeStorageContainersAreOpen => {
+                let v = v
+                    .as_bool()
+                    .ok_or_else(|| anyhow!("Expected bool value for {self:?}, got {v:?}"))?;
+                Value::bool(v)
+            }
+
+            // Count values
+            Self::ProfilePopulationEquivalent
+            | Self::ProfileSludgeDigesterCount
+            | Self::SensitivityN2OMeasurementSamples => {
+                let v = v
+                    .as_u64()
+                    .ok_or_else(|| anyhow!("Expected count value for {self:?}, got {v:?}"))?;
+                Value::count(v)
+            }
+
+            // Qubicmeters values
+            Self::ProfileWastewater
+            | Self::ProfileSewageGasProduced
+            | Self::ProfileSewageGasFlared
+            | Self::ProfileSewageGasVented
+            | Self::ProfileSewageGasUpgraded
+            | Self::ProfileGasSupply
+            | Self::ProfileSewerOverflowVolume
+            | Self::RecommendationDigesterCoverConcrete => {
+                let v = v
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Expected qubicmeters value for {self:?}, got {v:?}"))?;
+                Value::qubicmeters(v)
+            }
+
+            // MilligramsPerLiter values
+            Self::ProfileInfluentNitrogen
+            | Self::ProfileInfluentChemicalOxygenDemand
+            | Self::ProfileInfluentTotalOrganicCarbohydrates
+            | Self::ProfileEffluentNitrogen
+            | Self::ProfileEffluentChemicalOxygenDemand => {
+                let v = v.as_f64().ok_or_else(|| {
+                    anyhow!("Expected milligrams_per_liter value for {self:?}, got {v:?}")
+                })?;
+                Value::milligrams_per_liter(v)
+            }
+
+            // Kilowatthours values
+            Self::ProfileTotalPowerConsumption
+            | Self::ProfileUpgradingPowerConsumption
+            | Self::ProfileHeatExported
+            | Self::ProfileOnSitePowerGeneration
+            | Self::ProfileSewerPumpingStationEnergy
+            | Self::RecommendationDistrictHeating
+            | Self::RecommendationHeatPumpThermalOutput
+            | Self::RecommendationPhotovoltaicEnergyExpansion
+            | Self::RecommendationWindEnergyExpansion
+            | Self::RecommendationWaterEnergyExpansion => {
+                let v = v.as_f64().ok_or_else(|| {
+                    anyhow!("Expected kilowatthours value for {self:?}, got {v:?}")
+                })?;
+                Value::kilowatthours(v)
+            }
+
+            // GramsPerKilowatthour values
+            Self::ProfileEmissionFactorElectricityMix
+            | Self::ProfileElectricityResidualMixEmissionFactor => {
+                let v = v.as_f64().ok_or_else(|| {
+                    anyhow!("Expected grams_per_kilowatthour value for {self:?}, got {v:?}")
+                })?;
+                Value::grams_per_kilowatthour(v)
+            }
+
+            // Liters values
+            Self::ProfileHeatingOil => {
+                let v = v
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Expected liters value for {self:?}, got {v:?}"))?;
+                Value::liters(v)
+            }
+
+            // Tons values
+            Self::ProfileSideStreamTotalNitrogen
+            | Self::ProfileOperatingMaterialFeCl3
+            | Self::ProfileOperatingMaterialFeClSO4
+            | Self::ProfileOperatingMaterialCaOH2
+            | Self::ProfileOperatingMaterialSyntheticPolymers
+            | Self::ProfileOperatingMaterialMethanol
+            | Self::ProfileOperatingMaterialPolyaluminiumChloride
+            | Self::ProfileOperatingMaterialSodiumHydroxide
+            | Self::ProfileOperatingMaterialAceticAcid
+            | Self::ProfileOperatingMaterialActivatedCarbon
+            | Self::ProfileSludgeDisposal
+            | Self::RecommendationPhotovoltaicEmbodiedEmissions
+            | Self::RecommendationWindEmbodiedEmissions
+            | Self::RecommendationWaterEmbodiedEmissions
+            | Self::RecommendationHeatPumpEmbodiedEmissions
+            | Self::RecommendationFlareEmbodiedEmissions
+            | Self::RecommendationDigesterCoverEmbodiedEmissions => {
+                let v = v
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Expected tons value for {self:?}, got {v:?}"))?;
+                Value::tons(v)
+            }
+
+            // Kilometers values
+            Self::ProfileSludgeTransportDistance | Self::ProfileSewerPressureMainLength => {
+                let v = v
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Expected kilometers value for {self:?}, got {v:?}"))?;
+                Value::kilometers(v)
+            }
+
+            // Hours values
+            Self::ProfileSewerPressureMainRetentionTime => {
+                let v = v
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Expected hours value for {self:?}, got {v:?}"))?;
+                Value::hours(v)
+            }
+
+            // SquareMeters values
+            Self::RecommendationDigesterCoverArea => {
+                let v = v.as_f64().ok_or_else(|| {
+                    anyhow!("Expected square_meters value for {self:?}, got {v:?}")
+                })?;
+                Value::square_meters(v)
+            }
+
+            // Kilowatts values
+            Self::RecommendationPhotovoltaicPeakPower => {
+                let v = v
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Expected kilowatts value for {self:?}, got {v:?}"))?;
+                Value::kilowatts(v)
+            }
+
+            // Percent values
+            Self::ProfileMethaneFraction
+            | Self::ProfileOperatingMaterialSodiumHydroxideConcentration
+            | Self::ProfileOperatingMaterialAceticAcidConcentration
+            | Self::ProfileFlareEfficiency
+            | Self::ProfileUpgradingMethaneSlip
+            | Self::ProfileSludgeDisposalShareMonoIncineration
+            | Self::ProfileSludgeDisposalShareCoIncineration
+            | Self::ProfileSludgeDisposalShareAgriculture
+            | Self::ProfileSludgeDisposalShareLandfill
+            | Self::SensitivityN2OCustomFactor
+            | Self::SensitivityN2OMeasuredFactor
+            | Self::SensitivityN2OMeasurementCoverage
+            | Self::SensitivityN2OMeasurementAnnualCoverage
+            | Self::SensitivityN2OSideStreamFactor
+            | Self::SensitivityCH4ChpCustomFactor
+            | Self::SensitivityCO2FossilCustomFactor
+            | Self::SensitivitySludgeStorageCustomFactor
+            | Self::RecommendationProcessEnergySaving
+            | Self::RecommendationFossilEnergySaving
+            | Self::RecommendationEstimatedSelfPhotovolaticUsage
+            | Self::RecommendationEstimatedSelfWindEnergyUsage
+            | Self::RecommendationEstimatedSelfWaterEnergyUsage => {
+                let v = v
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Expected percent value for {self:?}, got {v:?}"))?;
+                Value::percent(v)
+            }
+
+            // QubicmetersPerHour values
+            Self::SensitivitySludgeBagsCustomFactor => {
+                let v = v.as_f64().ok_or_else(|| {
+                    anyhow!("Expected qubicmeters_per_hour value for {self:?}, got {v:?}")
+                })?;
+                Value::qubicmeters_per_hour(v)
+            }
+
+            // Factor values
+            Self::RecommendationHeatPumpCoefficientOfPerformance => {
+                let v = v
+                    .as_f64()
+                    .ok_or_else(|| anyhow!("Expected factor value for {self:?}, got {v:?}"))?;
+                Value::factor(v)
+            }
+
+            // N2oEmissionFactorCalcMethod values
+            Self::SensitivityN2OCalculationMethod => {
+                let method: N2oEmissionFactorCalcMethod = serde_json::from_value(v)?;
+                Value::n2o_emission_factor_calc_method(method.into())
+            }
+
+            // CH4ChpEmissionFactorCalcMethod values
+            Self::SensitivityCH4ChpCalculationMethod => {
+                let method: CH4ChpEmissionFactorCalcMethod = serde_json::from_value(v)?;
+                Value::ch4_chp_emission_factor_calc_method(method.into())
+            }
+
+            // ChpUnit values
+            Self::SensitivityCH4ChpUnits => {
+                let units: Vec<ChpUnit> = serde_json::from_value(v)?;
+                let units: Vec<_> = units.into_iter().map(Into::into).collect();
+                chp_units::to_value(&units)
+            }
+
+            // ElectricityContract values
+            Self::ProfileElectricityContracts => {
+                let contracts: Vec<ElectricityContract> = serde_json::from_value(v)?;
+                let contracts: Vec<_> = contracts.into_iter().map(Into::into).collect();
+                electricity_contracts::to_value(&contracts)
+            }
+
+            // SludgeDisposalRoute values
+            Self::ProfileSludgeDisposalRoute => {
+                let route: SludgeDisposalRoute = serde_json::from_value(v)?;
+                Value::sludge_disposal_route(route.into())
+            }
+
+            // CustomEmissions values
+            Self::SensitivityAdditionalCustomEmissions => {
+                let emissions: CustomEmissions = serde_json::from_value(v)?;
+                emissions.to_value()
+            }
+
+            // Text values
+            Self::SensitivityN2OMeasurementPeriod => {
+                let v = v
+                    .as_str()
+                    .ok_or_else(|| anyhow!("Expected text value for {self:?}, got {v:?}"))?;
+                Value::text(v)
+            }
+        };
+        Ok(v)
+    }
+
+    /// Convert domain values to JSON
+    // Since the data types in the domain layer may change in the future,
+    // we need a clear assignment for v10 at this point.
+    pub fn value_to_json(&self, value: Value) -> anyhow::Result<JsonValue> {
+        let value_clone = value.clone(); // used in error messages
+
+        match self {
+            // Text values
+            Self::ProjectName | Self::ProfilePlantName | Self::SensitivityN2OMeasurementPeriod => {
+                let text_value = value
+                    .as_text()
+                    .ok_or_else(|| {
+                        anyhow!("Expected text value for {self:?}, got {value_clone:?}")
+                    })?
+                    .to_string();
+                Ok(JsonValue::String(text_value))
+            }
+
+            // CustomEmissions values
+            Self::SensitivityAdditionalCustomEmissions => {
+                let emissions = CustomEmissions::from_value(&value)?;
+                Ok(serde_json::to_value(emissions)?)
+            }
+
+            // Boolean values
+            Self::RecommendationSludgeStorageContainersAreOpen
+            | Self::ProfilePurchaseOfBiogas
+            | Self::ProfileSludgeBagsAreOpen
+            | Self::RecommendationSludgeBagsAreOpen
+            | Self::RecommendationN2OSideStreamCoverIsOpen
+            | Self::RecommendationFlareIsOpen
+            | Self::ProfileSludgeStorageContainersAreOpen => {
+                let bool_value = value.as_bool().ok_or_else(|| {
+                    anyhow!("Expected bool value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::Bool(bool_value))
+            }
+
+            // Count values
+            Self::ProfilePopulationEquivalent
+            | Self::ProfileSludgeDigesterCount
+            | Self::SensitivityN2OMeasurementSamples => {
+                let count_value = value.as_count().ok_or_else(|| {
+                    anyhow!("Expected count value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(u64::from(count_value)))
+            }
+
+            // Qubicmeters values
+            Self::ProfileWastewater
+            | Self::ProfileSewageGasProduced
+            | Self::ProfileSewageGasFlared
+            | Self::ProfileSewageGasVented
+            | Self::ProfileSewageGasUpgraded
+            | Self::ProfileGasSupply
+            | Self::ProfileSewerOverflowVolume
+            | Self::RecommendationDigesterCoverConcrete => {
+                let qubicmeters_value = value.as_qubicmeters().ok_or_else(|| {
+                    anyhow!("Expected qubicmeters value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(qubicmeters_value)))
+            }
+
+            // MilligramsPerLiter values
+            Self::ProfileInfluentNitrogen
+            | Self::ProfileInfluentChemicalOxygenDemand
+            | Self::ProfileInfluentTotalOrganicCarbohydrates
+            | Self::ProfileEffluentNitrogen
+            | Self::ProfileEffluentChemicalOxygenDemand => {
+                let milligrams_value = value.as_milligrams_per_liter().ok_or_else(|| {
+                    anyhow!("Expected milligrams_per_liter value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(milligrams_value)))
+            }
+
+            // Kilowatthours values
+            Self::ProfileTotalPowerConsumption
+            | Self::ProfileUpgradingPowerConsumption
+            | Self::ProfileHeatExported
+            | Self::ProfileOnSitePowerGeneration
+            | Self::ProfileSewerPumpingStationEnergy
+            | Self::RecommendationDistrictHeating
+            | Self::RecommendationHeatPumpThermalOutput
+            | Self::RecommendationPhotovoltaicEnergyExpansion
+            | Self::RecommendationWindEnergyExpansion
+            | Self::RecommendationWaterEnergyExpansion => {
+                let kilowatt_value = value.as_kilowatthours().ok_or_else(|| {
+                    anyhow!("Expected kilowatthours value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(kilowatt_value)))
+            }
+
+            // GramsPerKilowatthour values
+            Self::ProfileEmissionFactorElectricityMix
+            | Self::ProfileElectricityResidualMixEmissionFactor => {
+                let grams_value = value.as_grams_per_kilowatthour().ok_or_else(|| {
+                    anyhow!(
+                        "Expected grams_per_kilowatthour value for {self:?}, got {value_clone:?}"
+                    )
+                })?;
+                Ok(JsonValue::from(f64::from(grams_value)))
+            }
+
+            // Liters values
+            Self::ProfileHeatingOil => {
+                let liters_value = value.as_liters().ok_or_else(|| {
+                    anyhow!("Expected liters value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(liters_value)))
+            }
+
+            // Tons values
+            Self::ProfileSideStreamTotalNitrogen
+            | Self::ProfileOperatingMaterialFeCl3
+            | Self::ProfileOperatingMaterialFeClSO4
+            | Self::ProfileOperatingMaterialCaOH2
+            | Self::ProfileOperatingMaterialSyntheticPolymers
+            | Self::ProfileOperatingMaterialMethanol
+            | Self::ProfileOperatingMaterialPolyaluminiumChloride
+            | Self::ProfileOperatingMaterialSodiumHydroxide
+            | Self::ProfileOperatingMaterialAceticAcid
+            | Self::ProfileOperatingMaterialActivatedCarbon
+            | Self::ProfileSludgeDisposal
+            | Self::RecommendationPhotovoltaicEmbodiedEmissions
+            | Self::RecommendationWindEmbodiedEmissions
+            | Self::RecommendationWaterEmbodiedEmissions
+            | Self::RecommendationHeatPumpEmbodiedEmissions
+            | Self::RecommendationFlareEmbodiedEmissions
+            | Self::RecommendationDigesterCoverEmbodiedEmissions => {
+                let tons_value = value.as_tons().ok_or_else(|| {
+                    anyhow!("Expected tons value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(tons_value)))
+            }
+
+            // Kilometers values
+            Self::ProfileSludgeTransportDistance | Self::ProfileSewerPressureMainLength => {
+                let kilometers_value = value.as_kilometers().ok_or_else(|| {
+                    anyhow!("Expected kilometers value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(kilometers_value)))
+            }
+
+            // Hours values
+            Self::ProfileSewerPressureMainRetentionTime => {
+                let hours_value = value.as_hours().ok_or_else(|| {
+                    anyhow!("Expected hours value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(hours_value)))
+            }
+
+            // SquareMeters values
+            Self::RecommendationDigesterCoverArea => {
+                let square_meters_value = value.as_square_meters().ok_or_else(|| {
+                    anyhow!("Expected square_meters value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(square_meters_value)))
+            }
+
+            // Kilowatts values
+            Self::RecommendationPhotovoltaicPeakPower => {
+                let kilowatts_value = value.as_kilowatts().ok_or_else(|| {
+                    anyhow!("Expected kilowatts value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(kilowatts_value)))
+            }
+
+            // Percent values
+            Self::ProfileMethaneFraction
+            | Self::ProfileOperatingMaterialSodiumHydroxideConcentration
+            | Self::ProfileOperatingMaterialAceticAcidConcentration
+            | Self::ProfileFlareEfficiency
+            | Self::ProfileUpgradingMethaneSlip
+            | Self::ProfileSludgeDisposalShareMonoIncineration
+            | Self::ProfileSludgeDisposalShareCoIncineration
+            | Self::ProfileSludgeDisposalShareAgriculture
+            | Self::ProfileSludgeDisposalShareLandfill
+            | Self::SensitivityN2OCustomFactor
+            | Self::SensitivityN2OMeasuredFactor
+            | Self::SensitivityN2OMeasurementCoverage
+            | Self::SensitivityN2OMeasurementAnnualCoverage
+            | Self::SensitivityN2OSideStreamFactor
+            | Self::SensitivityCH4ChpCustomFactor
+            | Self::SensitivityCO2FossilCustomFactor
+            | Self::SensitivitySludgeStorageCustomFactor
+            | Self::RecommendationProcessEnergySaving
+            | Self::RecommendationFossilEnergySaving
+            | Self::RecommendationEstimatedSelfPhotovolaticUsage
+            | Self::RecommendationEstimatedSelfWindEnergyUsage
+            | Self::RecommendationEstimatedSelfWaterEnergyUsage => {
+                let percent_value = value.as_percent().ok_or_else(|| {
+                    anyhow!("Expected percent value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(percent_value)))
+            }
+
+            // QubicmetersPerHour values
+            Self::SensitivitySludgeBagsCustomFactor => {
+                let qubicmeters_per_hour_value =
+                    value.as_qubicmeters_per_hour().ok_or_else(|| {
+                        anyhow!(
+                            "Expected qubicmeters_per_hour value for {self:?}, got {value_clone:?}"
+                        )
+                    })?;
+                Ok(JsonValue::from(f64::from(qubicmeters_per_hour_value)))
+            }
+
+            // Factor values
+            Self::RecommendationHeatPumpCoefficientOfPerformance => {
+                let factor_value = value.as_factor().ok_or_else(|| {
+                    anyhow!("Expected factor value for {self:?}, got {value_clone:?}")
+                })?;
+                Ok(JsonValue::from(f64::from(factor_value)))
+            }
+
+            // N2oEmissionFactorCalcMethod values
+            Self::SensitivityN2OCalculationMethod => {
+                let method : N2oEmissionFactorCalcMethod= value
+                    .as_n2o_emission_factor_calc_method()
+                    .ok_or_else(|| {
+                        anyhow!("Expected N2oEmissionFactorCalcMethod value for {self:?}, got {value_clone:?}")
+                    })?
+                    .into();
+                Ok(serde_json::to_value(method)?)
+            }
+
+            // CH4ChpEmissionFactorCalcMethod values
+            Self::SensitivityCH4ChpCalculationMethod => {
+                let method : CH4ChpEmissionFactorCalcMethod= value
+                    .as_ch4_chp_emission_factor_calc_method()
+                    .ok_or_else(|| {
+                        anyhow!("Expected Ch4ChpEmissionFactorCalcMethod value for {self:?}, got {value_clone:?}")
+                    })?
+                    .into();
+                Ok(serde_json::to_value(method)?)
+            }
+
+            // ChpUnit values
+            Self::SensitivityCH4ChpUnits => {
+                let units: Vec<_> = chp_units::from_value(&value)?
+                    .into_iter()
+                    .map(ChpUnit::from)
+                    .collect();
+                Ok(serde_json::to_value(units)?)
+            }
+
+            // ElectricityContract values
+            Self::ProfileElectricityContracts => {
+                let contracts: Vec<_> = electricity_contracts::from_value(&value)?
+                    .into_iter()
+                    .map(ElectricityContract::from)
+                    .collect();
+                Ok(serde_json::to_value(contracts)?)
+            }
+
+            // SludgeDisposalRoute values
+            Self::ProfileSludgeDisposalRoute => {
+                let route: SludgeDisposalRoute = value
+                    .as_sludge_disposal_route()
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "Expected SludgeDisposalRoute value for {self:?}, got {value_clone:?}"
+                        )
+                    })?
+                    .into();
+                Ok(serde_json::to_value(route)?)
+            }
+        }
+    }
+}
+
+/// Additional custom emissions as a graph of nodes and edges.
+///
+/// In the domain layer the custom emissions are a text value
+/// that contains this structure as JSON
+/// (see [`CustomEmissions::from_value`] and [`CustomEmissions::to_value`]).
+#[derive(Serialize, Deserialize, Default, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CustomEmissions {
+    /// All source nodes in order of their first appearance.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub nodes: Vec<CustomNode>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edges: Vec<CustomEdge>,
+    /// Comment lines after the last edge.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CustomNode {
+    pub id: String,
+    /// Display label
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CustomEdge {
+    pub source: String,
+    pub target: String,
+    /// Emitted mass or activity data
+    /// (`None` for edges that only connect nodes).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<CustomQuantity>,
+    /// Emissions in the recommendation scenario.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recommendation: Option<CustomRecommendation>,
+    /// Where the value or factor comes from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub citation: Option<String>,
+    /// Comment lines before the edge.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub comments: Vec<String>,
+    /// Comment at the end of the line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CustomQuantity {
+    pub value: f64,
+    /// Unit abbreviation (e.g. `l` or `kWh`),
+    /// the value is given in tons if missing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Emission factor that converts the activity data into an emitted mass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factor: Option<CustomFactor>,
+    /// The gas is CO₂ equivalents if missing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas: Option<CustomGas>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CustomFactor {
+    pub value: f64,
+    /// Unit abbreviation (e.g. `kg/l`)
+    pub unit: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum CustomGas {
+    Co2e,
+    Ch4,
+    N2o,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(rename_all = "kebab-case")]
+pub enum CustomRecommendation {
+    /// Emissions of the recommendation scenario
+    Absolute(CustomQuantity),
+    /// Reduction in percent
+    Reduction(f64),
+}
+
+impl CustomEmissions {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty() && self.comments.is_empty()
+    }
+
+    /// Label of a node.
+    #[must_use]
+    pub fn label(&self, node: &str) -> Option<&str> {
+        self.nodes
+            .iter()
+            .find(|n| n.id == node)
+            .and_then(|n| n.label.as_deref())
+    }
+
+    pub fn from_value(value: &Value) -> anyhow::Result<Self> {
+        let json = value
+            .clone()
+            .as_text()
+            .ok_or_else(|| anyhow!("Expected text value for custom emissions, got {value:?}"))?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn to_value(&self) -> Value {
+        Value::text(serde_json::to_string(self).expect("Valid custom emissions"))
+    }
+}
@@ -0,0 +1,418 @@
+//! Text view of the custom emissions.
+//!
+//! Each edge is written on its own line:
+//!
+//! `"ID" [as "NAME"] [NUM [UNIT] [* NUM UNIT] [GAS]] "ID" [recommend VALUE] [cite "SOURCE"]`
+//!
+//! Everything after a `#` outside of quotes is a comment.
+//! Only the syntax is handled here, units and the graph
+//! are checked by the custom values parser.
+
+use std::collections::HashSet;
+
+use super::{
+    CustomEdge, CustomEmissions, CustomFactor, CustomGas, CustomNode, CustomQuantity,
+    CustomRecommendation,
+};
+
+const LABEL_KEYWORD: &str = "as";
+const CITATION_KEYWORD: &str = "cite";
+const RECOMMENDATION_KEYWORD: &str = "recommend";
+
+/// Decimal separator of the numbers in the text view.
+///
+/// Separators of the other kind are interpreted as digit grouping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalSeparator {
+    /// e.g. `1.200,5`
+    Comma,
+    /// e.g. `1,200.5`
+    Point,
+}
+
+/// A line of the text view that can not be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreadableLine {
+    /// Line number (starting at 1).
+    pub number: usize,
+    pub content: String,
+}
+
+impl DecimalSeparator {
+    /// Detect the decimal separator of the numbers in a text view.
+    ///
+    /// Returns `None` if the text contains no number
+    /// that tells a decimal separator from a digit grouping
+    /// (e.g. `1.200`).
+    #[must_use]
+    pub fn detect(text: &str) -> Option<Self> {
+        text.lines()
+            .filter_map(tokenize)
+            .flat_map(|(tokens, _)| tokens)
+            .filter_map(|token| match token {
+                Token::Word(word) => Some(word.trim_start_matches('-')),
+                Token::Quoted(_) => None,
+            })
+            .filter(|word| word.starts_with(|c: char| c.is_ascii_digit()))
+            .find_map(number_separator)
+    }
+}
+
+/// Decimal separator of a single number.
+fn number_separator(number: &str) -> Option<DecimalSeparator> {
+    let last = number.rfind([',', '.'])?;
+    let (last_char, other_char, separator, other_separator) = if number[last..].starts_with(',') {
+        (',', '.', DecimalSeparator::Comma, DecimalSeparator::Point)
+    } else {
+        ('.', ',', DecimalSeparator::Point, DecimalSeparator::Comma)
+    };
+    if number[..last].contains(other_char) {
+        // e.g. `1.200,5` or `1,200.5`
+        return Some(separator);
+    }
+    if number.matches(last_char).count() > 1 {
+        // e.g. `1.200.000`
+        return Some(other_separator);
+    }
+    let decimals = number[last + 1..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .count();
+    // e.g. `2,65`, while `1.200` is ambiguous
+    (decimals != 3).then_some(separator)
+}
+
+impl CustomEmissions {
+    /// Read the text view.
+    ///
+    /// Fails with all lines that can not be read.
+    pub fn from_text(text: &str, separator: DecimalSeparator) -> Result<Self, Vec<UnreadableLine>> {
+        let (emissions, unreadable_lines) = Self::from_text_lossy(text, separator);
+        if !unreadable_lines.is_empty() {
+            return Err(unreadable_lines);
+        }
+        Ok(emissions)
+    }
+
+    /// Read the text view.
+    ///
+    /// Lines that can not be read are kept as comments
+    /// and returned as well.
+    pub(crate) fn from_text_lossy(
+        text: &str,
+        separator: DecimalSeparator,
+    ) -> (Self, Vec<UnreadableLine>) {
+        let mut emissions = Self::default();
+        let mut comments = vec![];
+        let mut unreadable_lines = vec![];
+        for (index, line) in text.lines().enumerate() {
+            match read_line(line, separator) {
+                Some(Line::Edge { mut edge, label }) => {
+                    emissions.add_node(&edge.source, label);
+                    edge.comments = std::mem::take(&mut comments);
+                    emissions.edges.push(*edge);
+                }
+                Some(Line::Comment(comment)) => comments.push(comment),
+                Some(Line::Empty) => {}
+                None => {
+                    comments.push(line.trim().to_string());
+                    unreadable_lines.push(UnreadableLine {
+                        number: index + 1,
+                        content: line.to_string(),
+                    });
+                }
+            }
+        }
+        emissions.comments = comments;
+        (emissions, unreadable_lines)
+    }
+
+    /// Write the text view.
+    ///
+    /// The label of a node is written to the first edge
+    /// that starts at the node.
+    #[must_use]
+    pub fn to_text(&self, separator: DecimalSeparator) -> String {
+        let mut labeled_nodes = HashSet::new();
+        let mut lines = vec![];
+        for edge in &self.edges {
+            lines.extend(edge.comments.iter().map(|c| comment_line(c)));
+            let mut line = quoted(&edge.source);
+            if labeled_nodes.insert(&edge.source) {
+                if let Some(label) = self.label(&edge.source) {
+                    line = format!("{line} {LABEL_KEYWORD} {}", quoted(label));
+                }
+            }
+            if let Some(value) = &edge.value {
+                line = format!("{line} {}", write_quantity(value, separator));
+            }
+            line = format!("{line} {}", quoted(&edge.target));
+            if let Some(recommendation) = &edge.recommendation {
+                let recommendation = match recommendation {
+                    CustomRecommendation::Absolute(quantity) => write_quantity(quantity, separator),
+                    CustomRecommendation::Reduction(percent) => {
+                        format!("-{} %", write_number(*percent, separator))
+                    }
+                };
+                line = format!("{line} {RECOMMENDATION_KEYWORD} {recommendation}");
+            }
+            if let Some(citation) = &edge.citation {
+                line = format!("{line} {CITATION_KEYWORD} {}", quoted(citation));
+            }
+            if let Some(comment) = &edge.comment {
+                line = format!("{line} {}", comment_line(comment));
+            }
+            lines.push(line);
+        }
+        lines.extend(self.comments.iter().map(|c| comment_line(c)));
+        lines.join("\n")
+    }
+
+    fn add_node(&mut self, id: &str, label: Option<String>) {
+        if !self.nodes.iter().any(|n| n.id == id) {
+            self.nodes.push(CustomNode {
+                id: id.to_string(),
+                label: None,
+            });
+        }
+        if let Some(label) = label {
+            if let Some(node) = self.nodes.iter_mut().find(|n| n.id == id) {
+                node.label = Some(label);
+            }
+        }
+    }
+}
+
+enum Line {
+    Empty,
+    Comment(String),
+    Edge {
+        edge: Box<CustomEdge>,
+        label: Option<String>,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Quoted(&'a str),
+    Word(&'a str),
+}
+
+/// Split a line into tokens and the comment.
+///
+/// Returns `None` if a quote is not closed.
+fn tokenize(line: &str) -> Option<(Vec<Token<'_>>, Option<&str>)> {
+    let mut tokens = vec![];
+    let mut rest = line.trim_start();
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '#' => return Some((tokens, Some(rest[1..].trim()))),
+            '"' => {
+                let len = rest[1..].find('"')?;
+                tokens.push(Token::Quoted(rest[1..=len].trim()));
+                rest = &rest[len + 2..];
+            }
+            _ => {
+                let len = rest
+                    .find(|c: char| c.is_whitespace() || c == '"' || c == '#')
+                    .unwrap_or(rest.len());
+                tokens.push(Token::Word(&rest[..len]));
+                rest = &rest[len..];
+            }
+        }
+        rest = rest.trim_start();
+    }
+    Some((tokens, None))
+}
+
+fn read_line(line: &str, separator: DecimalSeparator) -> Option<Line> {
+    let (tokens, comment) = tokenize(line)?;
+    if tokens.is_empty() {
+        return Some(comment.map_or(Line::Empty, |c| Line::Comment(c.to_string())));
+    }
+    let mut tokens = tokens.into_iter().peekable();
+    let source = next_quoted(&mut tokens)?;
+
+    let label = if tokens.next_if_eq(&Token::Word(LABEL_KEYWORD)).is_some() {
+        Some(next_quoted(&mut tokens)?)
+    } else {
+        None
+    };
+    let mut quantity = vec![];
+    while let Some(Token::Word(word)) = tokens.next_if(|t| matches!(t, Token::Word(_))) {
+        quantity.push(word);
+    }
+    let target = next_quoted(&mut tokens)?;
+    let recommendation = if tokens
+        .next_if_eq(&Token::Word(RECOMMENDATION_KEYWORD))
+        .is_some()
+    {
+        let mut words = vec![];
+        while let Some(Token::Word(word)) =
+            tokens.next_if(|t| matches!(t, Token::Word(w) if *w != CITATION_KEYWORD))
+        {
+            words.push(word);
+        }
+        Some(read_recommendation(&words.join(" "), separator)?)
+    } else {
+        None
+    };
+    let citation = if tokens.next_if_eq(&Token::Word(CITATION_KEYWORD)).is_some() {
+        Some(next_quoted(&mut tokens)?)
+    } else {
+        None
+    };
+    if tokens.next().is_some() {
+        return None;
+    }
+    let value = if quantity.is_empty() {
+        if recommendation.is_some() || citation.is_some() {
+            return None;
+        }
+        None
+    } else {
+        Some(read_quantity(&quantity.join(" "), separator)?)
+    };
+    let edge = CustomEdge {
+        source,
+        target,
+        value,
+        recommendation,
+        citation,
+        comments: vec![],
+        comment: comment.map(ToString::to_string),
+    };
+    Some(Line::Edge {
+        edge: Box::new(edge),
+        label,
+    })
+}
+
+fn next_quoted<'a>(tokens: &mut impl Iterator<Item = Token<'a>>) -> Option<String> {
+    match tokens.next() {
+        Some(Token::Quoted(s)) if !s.is_empty() => Some(s.to_string()),
+        _ => None,
+    }
+}
+
+/// Read `-NUM %` or a quantity.
+fn read_recommendation(input: &str, separator: DecimalSeparator) -> Option<CustomRecommendation> {
+    let Some(reduction) = input.strip_suffix('%') else {
+        return read_quantity(input, separator).map(CustomRecommendation::Absolute);
+    };
+    let reduction = reduction.trim().strip_prefix('-')?;
+    read_number(reduction.trim(), separator).map(CustomRecommendation::Reduction)
+}
+
+/// Read `NUM [UNIT] [* NUM UNIT] [GAS]`.
+fn read_quantity(input: &str, separator: DecimalSeparator) -> Option<CustomQuantity> {
+    let (activity, factor) = match input.split_once('*') {
+        Some((activity, factor)) => (activity, Some(factor)),
+        None => (input, None),
+    };
+    let mut activity = activity.split_whitespace();
+    let mut factor = factor.map(str::split_whitespace);
+
+    let value = read_number(activity.next()?, separator)?;
+    let unit = activity.next().map(read_unit);
+    let factor_value = if let Some(factor) = factor.as_mut() {
+        Some(CustomFactor {
+            value: read_number(factor.next()?, separator)?,
+            unit: read_unit(factor.next()?),
+        })
+    } else {
+        None
+    };
+
+    // The gas is the last token of the expression.
+    let mut rest = activity
+        .chain(factor.into_iter().flatten())
+        .collect::<Vec<_>>();
+    let gas = match (rest.pop(), rest.is_empty()) {
+        (None, _) => None,
+        (Some(gas), true) => Some(read_gas(gas)?),
+        (Some(_), false) => return None,
+    };
+
+    Some(CustomQuantity {
+        value,
+        unit,
+        factor: factor_value,
+        gas,
+    })
+}
+
+fn read_number(input: &str, separator: DecimalSeparator) -> Option<f64> {
+    let normalized = match separator {
+        DecimalSeparator::Comma => input.replace('.', "").replace(',', "."),
+        DecimalSeparator::Point => input.replace(',', ""),
+    };
+    normalized.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+fn read_unit(input: &str) -> String {
+    input.replace("m3", "m³")
+}
+
+fn read_gas(input: &str) -> Option<CustomGas> {
+    let gas = match input
+        .replace('₂', "2")
+        .replace('₄', "4")
+        .to_uppercase()
+        .as_str()
+    {
+        "CO2" | "CO2E" | "CO2-EQ" | "CO2-ÄQ" | "CO2-ÄQ." => CustomGas::Co2e,
+        "CH4" => CustomGas::Ch4,
+        "N2O" => CustomGas::N2o,
+        _ => return None,
+    };
+    Some(gas)
+}
+
+fn write_quantity(quantity: &CustomQuantity, separator: DecimalSeparator) -> String {
+    let CustomQuantity {
+        value,
+        unit,
+        factor,
+        gas,
+    } = quantity;
+    let mut text = write_number(*value, separator);
+    match (unit, gas) {
+        (Some(unit), _) => text = format!("{text} {unit}"),
+        // A gas requires a unit
+        (None, Some(_)) => text = format!("{text} t"),
+        (None, None) => {}
+    }
+    if let Some(CustomFactor { value, unit }) = factor {
+        text = format!("{text} * {} {unit}", write_number(*value, separator));
+    }
+    if let Some(gas) = gas {
+        let gas = match gas {
+            CustomGas::Co2e => "CO₂e",
+            CustomGas::Ch4 => "CH₄",
+            CustomGas::N2o => "N₂O",
+        };
+        text = format!("{text} {gas}");
+    }
+    text
+}
+
+fn write_number(value: f64, separator: DecimalSeparator) -> String {
+    let text = value.to_string();
+    match separator {
+        DecimalSeparator::Comma => text.replace('.', ","),
+        DecimalSeparator::Point => text,
+    }
+}
+
+fn quoted(text: &str) -> String {
+    format!("\"{text}\"")
+}
+
+fn comment_line(comment: &str) -> String {
+    if comment.is_empty() {
+        "#".to_string()
+    } else {
+        format!("# {comment}")
+    }
+}
@@ -394,12 +394,18 @@ pub struct JsonFormData {
 
 impl JsonFormData {
     fn set(&mut self, id: domain::InputValueId, value: Option<Value>) {
-        use domain::{InputValueId as Id, Value as V};
+        use crate::v9::InputValueId as Id;
+        use domain::Value as V;
 
         debug_assert!(value
             .as_ref()
             .map_or(true, |v| v.value_type() == id.value_type()));
 
+        // Values that were added after v9 are not supported by v8.
+        let Ok(id) = Id::try_from(id) else {
+            return;
+        };
+
         match id {
             Id::ProjectName => {
                 self.project_title = value.map(Value::as_text_unchecked);
@@ -1,4 +1,13 @@
-use klick_boundary::{export_to_string_pretty, import_from_str};
+use klick_boundary::{export_to_string_pretty, import_from_str, FormData, JsonFormData};
+use klick_domain::{
+    chp_units::{self, ChpUnit},
+    electricity_contracts::{self, ElectricityContract},
+    units::{
+        Ch4ChpEmissionFactorCalcMethod, GramsPerKilowatthour, Kilowatthours,
+        N2oEmissionFactorCalcMethod, Percent, Qubicmeters, SludgeDisposalRoute,
+    },
+    InputValueId as In, Value as V,
+};
 
 #[test]
 fn roundtrip() {
@@ -16,3 +25,181 @@ fn roundtrip() {
 
     assert_eq!(project, re_imported_project);
 }
+
+#[test]
+fn sludge_disposal_roundtrip() {
+    let mut form_data = FormData::new();
+    form_data.insert(
+        In::ProfileSludgeDisposalRoute,
+        V::sludge_disposal_route(SludgeDisposalRoute::CoIncineration),
+    );
+    form_data.insert(In::ProfileSludgeDisposalShareAgriculture, V::percent(40.0));
+
+    let json_form_data = JsonFormData::try_from(form_data.clone()).unwrap();
+    let json = serde_json::to_value(&json_form_data).unwrap();
+    assert_eq!(json["profile-sludge-disposal-route"], "co-incineration");
+    assert_eq!(json["profile-sludge-disposal-share-agriculture"], 40.0);
+
+    let converted = FormData::try_from(json_form_data).unwrap();
+    assert_eq!(converted, form_data);
+}
+
+#[test]
+fn heat_pump_roundtrip() {
+    let mut form_data = FormData::new();
+    form_data.insert(
+        In::RecommendationHeatPumpThermalOutput,
+        V::kilowatthours(35_000.0),
+    );
+    form_data.insert(
+        In::RecommendationHeatPumpCoefficientOfPerformance,
+        V::factor(4.2),
+    );
+
+    let json_form_data = JsonFormData::try_from(form_data.clone()).unwrap();
+    let json = serde_json::to_value(&json_form_data).unwrap();
+    assert_eq!(json["recommendation-heat-pump-thermal-output"], 35_000.0);
+    assert_eq!(
+        json["recommendation-heat-pump-coefficient-of-performance"],
+        4.2
+    );
+
+    let converted = FormData::try_from(json_form_data).unwrap();
+    assert_eq!(converted, form_data);
+}
+
+#[test]
+fn electricity_contracts_roundtrip() {
+    let contracts = [
+        ElectricityContract {
+            energy: Kilowatthours::new(120_000.0),
+            emission_factor: None,
+            guarantee_of_origin: true,
+        },
+        ElectricityContract {
+            energy: Kilowatthours::new(40_000.0),
+            emission_factor: Some(GramsPerKilowatthour::new(35.0)),
+            guarantee_of_origin: false,
+        },
+    ];
+    let mut form_data = FormData::new();
+    form_data.insert(
+        In::ProfileElectricityContracts,
+        electricity_contracts::to_value(&contracts),
+    );
+    form_data.insert(
+        In::ProfileElectricityResidualMixEmissionFactor,
+        V::grams_per_kilowatthour(520.0),
+    );
+
+    let json_form_data = JsonFormData::try_from(form_data.clone()).unwrap();
+    let json = serde_json::to_value(&json_form_data).unwrap();
+    assert_eq!(
+        json["profile-electricity-contracts"],
+        serde_json::json!([
+            {
+                "energy": 120_000.0,
+                "guarantee_of_origin": true
+            },
+            {
+                "energy": 40_000.0,
+                "emission_factor": 35.0,
+                "guarantee_of_origin": false
+            }
+        ])
+    );
+    assert_eq!(
+        json["profile-electricity-residual-mix-emission-factor"],
+        520.0
+    );
+
+    let converted = FormData::try_from(json_form_data).unwrap();
+    assert_eq!(converted, form_data);
+}
+
+#[test]
+fn chp_units_roundtrip() {
+    let units = [
+        ChpUnit {
+            calculation_method: Ch4ChpEmissionFactorCalcMethod::Custom,
+            custom_factor: Some(Percent::new(1.5)),
+            gas_share: Some(Percent::new(60.0)),
+            gas_volume: None,
+        },
+        ChpUnit {
+            calculation_method: Ch4ChpEmissionFactorCalcMethod::MicroGasTurbines,
+            custom_factor: None,
+            gas_share: None,
+            gas_volume: Some(Qubicmeters::new(80_000.0)),
+        },
+    ];
+    let mut form_data = FormData::new();
+    form_data.insert(In::SensitivityCH4ChpUnits, chp_units::to_value(&units));
+
+    let json_form_data = JsonFormData::try_from(form_data.clone()).unwrap();
+    let json = serde_json::to_value(&json_form_data).unwrap();
+    assert_eq!(
+        json["sensitivity-ch4-chp-units"],
+        serde_json::json!([
+            {
+                "calculation_method": "custom-factor",
+                "custom_factor": 1.5,
+                "gas_share": 60.0
+            },
+            {
+                "calculation_method": "micro-gas-turbines",
+                "gas_volume": 80_000.0
+            }
+        ])
+    );
+
+    let converted = FormData::try_from(json_form_data).unwrap();
+    assert_eq!(converted, form_data);
+}
+
+#[test]
+fn n2o_measurement_campaign_roundtrip() {
+    let mut form_data = FormData::new();
+    form_data.insert(
+        In::SensitivityN2OCalculationMethod,
+        V::n2o_emission_factor_calc_method(N2oEmissionFactorCalcMethod::Measured),
+    );
+    form_data.insert(In::SensitivityN2OMeasuredFactor, V::percent(1.2));
+    form_data.insert(
+        In::SensitivityN2OMeasurementPeriod,
+        V::text("2024-03-01 – 2024-03-31"),
+    );
+    form_data.insert(In::SensitivityN2OMeasurementSamples, V::count(2_977));
+    form_data.insert(In::SensitivityN2OMeasurementCoverage, V::percent(99.5));
+
+    let json_form_data = JsonFormData::try_from(form_data.clone()).unwrap();
+    let json = serde_json::to_value(&json_form_data).unwrap();
+    assert_eq!(json["sensitivity-n2o-calculation-method"], "measured");
+    assert_eq!(json["sensitivity-n2o-measured-factor"], 1.2);
+    assert_eq!(json["sensitivity-n2o-measurement-samples"], 2_977);
+
+    let converted = FormData::try_from(json_form_data).unwrap();
+    assert_eq!(converted, form_data);
+}
+
+#[test]
+fn embodied_emissions_roundtrip() {
+    let mut form_data = FormData::new();
+    form_data.insert(In::RecommendationPhotovoltaicPeakPower, V::kilowatts(250.0));
+    form_data.insert(In::RecommendationDigesterCoverArea, V::square_meters(400.0));
+    form_data.insert(
+        In::RecommendationDigesterCoverConcrete,
+        V::qubicmeters(80.0),
+    );
+    form_data.insert(In::RecommendationHeatPumpEmbodiedEmissions, V::tons(35.0));
+
+    let json_form_data = JsonFormData::try_from(form_data.clone()).unwrap();
+    let json = serde_json::to_value(&json_form_data).unwrap();
+    assert_eq!(json["recommendation-photovoltaic-peak-power"], 250.0);
+    assert_eq!(json["recommendation-digester-cover-area"], 400.0);
+    assert_eq!(json["recommendation-digester-cover-concrete"], 80.0);
+    assert_eq!(json["recommendation-heat-pump-embodied-emissions"], 35.0);
+
+    let converted = FormData::try_from(json_form_data).unwrap();
+    assert_eq!(converted, form_data);
+}
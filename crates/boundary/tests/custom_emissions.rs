@@ -0,0 +1,87 @@
+use klick_boundary::{CustomEmissions, DecimalSeparator, UnreadableLine};
+
+const TEXT_DE: &str = "\"H₂ Generator\" 1223,2 \"TotalEmissions\"\n\
+# Fällmittel\n\
+\"Eisen(II)-sulfat\" as \"Eisensulfat\" 200 \"Fällmittel\" cite \"Lieferschein\"\n\
+\"Fällmittel\" \"OperatingMaterials\" # ohne Wert\n\
+\"Diesel\" 1200 l * 2,65 kg/l CO₂e \"IndirectEmissions\" recommend 800 l * 2,65 kg/l\n\
+\"Lachgasmessung\" 12,5 kg N₂O \"N2oEmissions\" recommend -12,5 %\n\
+#\n\
+# Ende";
+
+#[test]
+fn text_roundtrip() {
+    let emissions = CustomEmissions::from_text(TEXT_DE, DecimalSeparator::Comma).unwrap();
+    assert_eq!(emissions.edges.len(), 5);
+    assert_eq!(emissions.comments, vec![String::new(), "Ende".to_string()]);
+    assert_eq!(emissions.to_text(DecimalSeparator::Comma), TEXT_DE);
+}
+
+#[test]
+fn text_roundtrip_with_other_decimal_separator() {
+    let emissions = CustomEmissions::from_text(TEXT_DE, DecimalSeparator::Comma).unwrap();
+    let text_en = emissions.to_text(DecimalSeparator::Point);
+    assert!(text_en.contains("\"Diesel\" 1200 l * 2.65 kg/l CO₂e"));
+    assert_eq!(
+        CustomEmissions::from_text(&text_en, DecimalSeparator::Point).unwrap(),
+        emissions
+    );
+}
+
+#[test]
+fn json_roundtrip() {
+    let emissions = CustomEmissions::from_text(TEXT_DE, DecimalSeparator::Comma).unwrap();
+    let value = emissions.to_value();
+    assert_eq!(CustomEmissions::from_value(&value).unwrap(), emissions);
+}
+
+#[test]
+fn normalize_text() {
+    let text = "  \"a\"  1.000,5   m3 *  2 kg/m3 co2   \"b\"   #comment  ";
+    let emissions = CustomEmissions::from_text(text, DecimalSeparator::Comma).unwrap();
+    assert_eq!(
+        emissions.to_text(DecimalSeparator::Comma),
+        "\"a\" 1000,5 m³ * 2 kg/m³ CO₂e \"b\" # comment"
+    );
+}
+
+#[test]
+fn reject_unreadable_lines() {
+    let text = "\"a\" x y \"b\"\n\"a\" 1 \"b\"\n\"c";
+    let lines = CustomEmissions::from_text(text, DecimalSeparator::Comma).unwrap_err();
+    assert_eq!(
+        lines,
+        vec![
+            UnreadableLine {
+                number: 1,
+                content: "\"a\" x y \"b\"".to_string()
+            },
+            UnreadableLine {
+                number: 3,
+                content: "\"c".to_string()
+            }
+        ]
+    );
+}
+
+#[test]
+fn detect_decimal_separator() {
+    let detect = DecimalSeparator::detect;
+    assert_eq!(detect("\"a\" 2,65 \"b\""), Some(DecimalSeparator::Comma));
+    assert_eq!(detect("\"a\" 2.65 \"b\""), Some(DecimalSeparator::Point));
+    assert_eq!(detect("\"a\" 1.200,5 \"b\""), Some(DecimalSeparator::Comma));
+    assert_eq!(detect("\"a\" 1,200.5 \"b\""), Some(DecimalSeparator::Point));
+    assert_eq!(
+        detect("\"a\" 1.200.000 \"b\""),
+        Some(DecimalSeparator::Comma)
+    );
+    assert_eq!(
+        detect("\"a\" 1 l * 0.8 kg/l \"b\" recommend -12,5 %"),
+        Some(DecimalSeparator::Point)
+    );
+    assert_eq!(
+        detect("\"a\" 1 \"b\" recommend -12,5 %"),
+        Some(DecimalSeparator::Comma)
+    );
+    assert_eq!(detect("\"2,5\" 1.200 \"b\" # 2,5"), None);
+}
@@ -101,3 +101,62 @@ fn roundtrip() {
 
     assert_eq!(imported, project);
 }
+
+#[test]
+fn export_v9() {
+    let emissions = CustomEmissions::from_text(
+        "\"Diesel\" 1200 l * 2,65 kg/l \"IndirectEmissions\" # Notstrom",
+        DecimalSeparator::Comma,
+    )
+    .unwrap();
+    let form_data: HashMap<In, serde_json::Value> = [
+        (In::ProjectName, json!("Project")),
+        (
+            In::SensitivityAdditionalCustomEmissions,
+            serde_json::to_value(&emissions).unwrap(),
+        ),
+    ]
+    .into_iter()
+    .collect();
+    let project = UnsavedProject {
+        form_data: form_data.into(),
+    }
+    .into();
+
+    let bytes = export_v9_to_vec_pretty(&project).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(json["version"], 9);
+    assert_eq!(
+        json["form_data"]["sensitivity-additional-custom-emissions"],
+        "\"Diesel\" 1200 l * 2,65 kg/l \"IndirectEmissions\" # Notstrom"
+    );
+
+    let imported = import_from_slice(&bytes).unwrap();
+    assert_eq!(imported, project);
+}
+
+#[test]
+fn export_v9_without_newer_values() {
+    let form_data: HashMap<In, serde_json::Value> = [
+        (In::ProjectName, json!("Project")),
+        (In::ProfileSewageGasFlared, json!(1_000.0)),
+        (
+            In::SensitivityN2OCalculationMethod,
+            serde_json::to_value(N2oEmissionFactorCalcMethod::Measured).unwrap(),
+        ),
+        (
+            In::SensitivityCH4ChpCalculationMethod,
+            serde_json::to_value(CH4ChpEmissionFactorCalcMethod::Mix).unwrap(),
+        ),
+    ]
+    .into_iter()
+    .collect();
+    let project = UnsavedProject {
+        form_data: form_data.into(),
+    }
+    .into();
+
+    let bytes = export_v9_to_vec_pretty(&project).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(json["form_data"], json!({ "project-name": "Project" }));
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use klick_boundary::{import_from_str, ImportError, CURRENT_VERSION};
+use klick_boundary::{
+    import_from_str, CustomEmissions, CustomFactor, CustomGas, CustomNode, CustomQuantity,
+    CustomRecommendation, DecimalSeparator, ImportError, CURRENT_VERSION,
+};
 use klick_domain::{units::*, InputValueId as In, Value as V};
 
 #[test]
@@ -583,3 +586,110 @@ fn import_v9() {
         Some(Value::bool(true))
     );
 }
+
+#[test]
+fn import_v9_custom_emissions() {
+    let json = r##"{
+      "version": 9,
+      "form_data": {
+        "sensitivity-additional-custom-emissions": "# Betrieb\n\"Diesel\" as \"Notstromdiesel\" 1.200,5 l * 2,65 kg/l \"IndirectEmissions\" recommend -30 % # geschätzt\n\"Fällmittel\" 3 m3 \"OperatingMaterials\n\n\"Gas\" 12,5 kg N₂O \"N2oEmissions\""
+      }
+    }"##;
+    let project = import_from_str(json).unwrap();
+    let form_data = project.form_data().clone();
+    let form_data = HashMap::<In, V>::try_from(form_data).unwrap();
+    let value = form_data
+        .get(&In::SensitivityAdditionalCustomEmissions)
+        .unwrap();
+    let emissions = CustomEmissions::from_value(value).unwrap();
+
+    assert_eq!(
+        emissions.nodes,
+        vec![
+            CustomNode {
+                id: "Diesel".to_string(),
+                label: Some("Notstromdiesel".to_string())
+            },
+            CustomNode {
+                id: "Gas".to_string(),
+                label: None
+            }
+        ]
+    );
+    assert_eq!(emissions.edges.len(), 2);
+
+    let diesel = &emissions.edges[0];
+    assert_eq!(diesel.target, "IndirectEmissions");
+    assert_eq!(diesel.comments, vec!["Betrieb".to_string()]);
+    assert_eq!(diesel.comment.as_deref(), Some("geschätzt"));
+    assert_eq!(
+        diesel.value,
+        Some(CustomQuantity {
+            value: 1200.5,
+            unit: Some("l".to_string()),
+            factor: Some(CustomFactor {
+                value: 2.65,
+                unit: "kg/l".to_string()
+            }),
+            gas: None
+        })
+    );
+    assert_eq!(
+        diesel.recommendation,
+        Some(CustomRecommendation::Reduction(30.0))
+    );
+
+    // Invalid lines are kept as comments
+    let gas = &emissions.edges[1];
+    assert_eq!(
+        gas.comments,
+        vec!["\"Fällmittel\" 3 m3 \"OperatingMaterials".to_string()]
+    );
+    assert_eq!(gas.value.as_ref().unwrap().gas, Some(CustomGas::N2o));
+}
+
+#[test]
+fn import_v9_custom_emissions_with_decimal_point() {
+    let json = r#"{
+      "version": 9,
+      "form_data": {
+        "sensitivity-additional-custom-emissions": "\"Diesel\" 1,200 l * 2.65 kg/l \"IndirectEmissions\""
+      }
+    }"#;
+    let project = import_from_str(json).unwrap();
+    let form_data = HashMap::<In, V>::try_from(project.form_data().clone()).unwrap();
+    let value = form_data
+        .get(&In::SensitivityAdditionalCustomEmissions)
+        .unwrap();
+    let emissions = CustomEmissions::from_value(value).unwrap();
+
+    let diesel = emissions.edges[0].value.as_ref().unwrap();
+    assert_eq!(diesel.value, 1200.0);
+    assert_eq!(diesel.factor.as_ref().unwrap().value, 2.65);
+}
+
+#[test]
+fn import_v10() {
+    let json = include_str!("example_data_v10.json");
+    let project = import_from_str(json).unwrap();
+    let form_data = HashMap::<In, V>::try_from(project.form_data().clone()).unwrap();
+
+    assert_eq!(
+        form_data.get(&In::ProfilePlantName).cloned(),
+        Some(V::text("Muster Klärwerk".to_string()))
+    );
+
+    let value = form_data
+        .get(&In::SensitivityAdditionalCustomEmissions)
+        .unwrap();
+    let emissions = CustomEmissions::from_value(value).unwrap();
+    assert_eq!(emissions.nodes.len(), 3);
+    assert_eq!(emissions.edges.len(), 3);
+    assert_eq!(
+        emissions.to_text(DecimalSeparator::Comma),
+        "\"Fällmittel\" \"OperatingMaterials\"\n\
+         \"Eisen(II)-sulfat\" 200 \"Fällmittel\" # geschätzt\n\
+         # Betrieb\n\
+         \"Diesel\" as \"Notstromdiesel\" 1200 l * 2,65 kg/l \"IndirectEmissions\" recommend -30 % cite \"UBA 2023\""
+    );
+}
@@ -11,6 +11,7 @@ fn MyCodemirror() -> impl IntoView {
     });
 
     let errors = RwSignal::new(Vec::new());
+    let completions = RwSignal::new(vec!["foo".to_string(), "bar".to_string()]);
 
     view! {
       <h1>"Codemirror Example"</h1>
@@ -18,10 +19,15 @@ fn MyCodemirror() -> impl IntoView {
         input = input.into()
         on_change
         errors = errors.into()
+        completions = completions
       />
       <button
         on:click = move |_| {
-          errors.update(|errors|errors.push(ErrorMarker { line: 0.into() }));
+          errors.update(|errors|errors.push(ErrorMarker {
+            line: 0,
+            columns: 0..1,
+            message: "Something is wrong".to_string(),
+          }));
         }
       >
         "set error marker"
@@ -1,17 +1,19 @@
-use std::rc::Rc;
+use std::{ops::Range, rc::Rc};
 
-use codemirror::{DocApi, Editor, EditorOptions, GutterId, Line};
-use leptos::{
-    html::Textarea,
-    web_sys::{Document, Element},
-    *,
-};
+use codemirror_sys as sys;
+use js_sys::{Array, Function, Object, Reflect};
+use leptos::{html::Textarea, *};
+use wasm_bindgen::prelude::*;
 
-const GUTTER_ERROR: GutterId = GutterId::new("gutter-error");
+const LINT_GUTTER: &str = "CodeMirror-lint-markers";
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ErrorMarker {
-    pub line: Line, // TODO: add error message
+    /// Line (starting at 0).
+    pub line: usize,
+    /// Character columns within the line (starting at 0).
+    pub columns: Range<usize>,
+    pub message: String,
 }
 
 #[component]
@@ -19,6 +21,7 @@ pub struct ErrorMarker {
 pub fn CodeMirror(
     input: Signal<Option<String>>,
     errors: Signal<Vec<ErrorMarker>>,
+    #[prop(optional, into)] completions: Signal<Vec<String>>,
     #[prop(into)] on_change: Callback<Option<String>, ()>,
 ) -> impl IntoView {
     let textarea_ref = NodeRef::<Textarea>::new();
@@ -26,41 +29,39 @@ pub fn CodeMirror(
         let _ = el.on_mount(move |el| {
             log::debug!("Initialize codemirror editor");
 
-            let options = EditorOptions::default()
-                .line_numbers(true)
-                .gutters(&[GUTTER_ERROR]);
-            let editor = Editor::from_text_area(&el, &options);
-            editor.on_change(move |editor, _| {
-                let value = editor.value();
-                on_change.call(value);
-            });
+            let options = editor_options(errors, completions);
+            let editor = Rc::new(sys::from_text_area(&el, &options));
+
+            let on_change = Closure::wrap(Box::new(move |editor: sys::Editor, _: JsValue| {
+                on_change.call(editor.get_value().as_string());
+            }) as Box<dyn FnMut(sys::Editor, JsValue)>);
+            editor.on("change", on_change.as_ref().unchecked_ref());
+            on_change.forget();
 
-            let editor = Rc::new(editor);
+            let on_input_read = Closure::wrap(Box::new(move |editor: sys::Editor, _: JsValue| {
+                if quoted_prefix(&editor).is_some() {
+                    call(&editor, "showHint", &Array::new());
+                }
+            })
+                as Box<dyn FnMut(sys::Editor, JsValue)>);
+            editor.on("inputRead", on_input_read.as_ref().unchecked_ref());
+            on_input_read.forget();
 
             Effect::new({
                 let editor = Rc::clone(&editor);
                 move |_| {
                     input.try_with(|x| {
-                        let txt = match x {
-                            Some(v) => v,
-                            None => "",
-                        };
-                        if editor.value() != *x {
-                            editor.set_value(txt);
+                        let txt = x.as_deref().unwrap_or_default();
+                        if editor.get_value().as_string().as_deref() != Some(txt) {
+                            editor.set_value(&txt.into());
                         }
                     });
                 }
             });
 
             Effect::new(move |_| {
-                errors.with(|errors| {
-                    editor.clear_gutter(GUTTER_ERROR);
-                    let doc = document();
-                    for ErrorMarker { line } in errors {
-                        let marker = create_error_marker(&doc);
-                        editor.set_gutter_marker(*line, GUTTER_ERROR, &marker);
-                    }
-                });
+                errors.track();
+                call(&editor, "performLint", &Array::new());
             });
         });
     });
@@ -68,11 +69,151 @@ pub fn CodeMirror(
     view! { <textarea _ref=textarea_ref /> }
 }
 
-const ERROR_MARKER_CLASS: &str = "CodeMirror-lint-marker-error CodeMirror-lint-marker";
+fn editor_options(errors: Signal<Vec<ErrorMarker>>, completions: Signal<Vec<String>>) -> Object {
+    let get_annotations = Closure::wrap(Box::new(move |text: JsValue| {
+        let text = text.as_string().unwrap_or_default();
+        errors
+            .try_with_untracked(|errors| annotations(&text, errors))
+            .unwrap_or_default()
+    }) as Box<dyn FnMut(JsValue) -> Array>);
 
-#[must_use]
-fn create_error_marker(document: &Document) -> Element {
-    let marker = document.create_element("div").unwrap();
-    marker.set_attribute("class", ERROR_MARKER_CLASS).unwrap();
-    marker
+    let lint = Object::new();
+    set(&lint, "getAnnotations", get_annotations.as_ref());
+    set(&lint, "lintOnChange", &false.into());
+    get_annotations.forget();
+
+    let hint = Closure::wrap(Box::new(move |editor: sys::Editor| {
+        let (line_number, from, prefix) = quoted_prefix(&editor)?;
+        let prefix = prefix.to_lowercase();
+        let matches = completions
+            .try_with_untracked(|completions| {
+                completions
+                    .iter()
+                    .filter(|c| c.to_lowercase().starts_with(&prefix))
+                    .map(|c| JsValue::from_str(&format!("{c}\"")))
+                    .collect::<Array>()
+            })
+            .unwrap_or_default();
+        let result = Object::new();
+        set(&result, "list", &matches);
+        set(&result, "from", &position(line_number, from));
+        set(&result, "to", &call(&editor, "getCursor", &Array::new())?);
+        Some(result)
+    }) as Box<dyn FnMut(sys::Editor) -> Option<Object>>);
+
+    let hint_options = Object::new();
+    set(&hint_options, "hint", hint.as_ref());
+    set(&hint_options, "completeSingle", &false.into());
+    hint.forget();
+
+    let extra_keys = Object::new();
+    set(&extra_keys, "Ctrl-Space", &"autocomplete".into());
+
+    let gutters = Array::of1(&LINT_GUTTER.into());
+
+    let options = Object::new();
+    set(&options, "lineNumbers", &true.into());
+    set(&options, "gutters", &gutters);
+    set(&options, "lint", &lint);
+    set(&options, "hintOptions", &hint_options);
+    set(&options, "extraKeys", &extra_keys);
+    options
+}
+
+/// Convert the markers into `CodeMirror` lint annotations.
+fn annotations(text: &str, errors: &[ErrorMarker]) -> Array {
+    let lines: Vec<_> = text.lines().collect();
+    errors
+        .iter()
+        .map(
+            |ErrorMarker {
+                 line,
+                 columns,
+                 message,
+             }| {
+                let text = lines.get(*line).copied().unwrap_or_default();
+                let line = u32::try_from(*line).unwrap_or(u32::MAX);
+                let annotation = Object::new();
+                set(
+                    &annotation,
+                    "from",
+                    &position(line, utf16_column(text, columns.start)),
+                );
+                set(
+                    &annotation,
+                    "to",
+                    &position(line, utf16_column(text, columns.end)),
+                );
+                set(&annotation, "message", &message.into());
+                set(&annotation, "severity", &"error".into());
+                annotation
+            },
+        )
+        .collect()
+}
+
+/// Find an unclosed quote before the cursor.
+///
+/// Returns the line, the (UTF-16) column after the quote
+/// and the text between the quote and the cursor.
+fn quoted_prefix(editor: &sys::Editor) -> Option<(u32, u32, String)> {
+    let cursor = call(editor, "getCursor", &Array::new())?;
+    let line = get_u32(&cursor, "line")?;
+    let ch = get_u32(&cursor, "ch")?;
+    let text = call(editor, "getLine", &Array::of1(&line.into()))?.as_string()?;
+    let text: Vec<_> = text.encode_utf16().collect();
+    let before_cursor = String::from_utf16_lossy(text.get(..ch as usize)?);
+    if before_cursor.matches('"').count() % 2 == 0 {
+        return None;
+    }
+    let (before_quote, prefix) = before_cursor.rsplit_once('"')?;
+    let from = utf16_column(before_quote, usize::MAX) + 1;
+    Some((line, from, prefix.to_string()))
+}
+
+/// `CodeMirror` counts columns in UTF-16 code units.
+fn utf16_column(text: &str, column: usize) -> u32 {
+    text.chars()
+        .take(column)
+        .map(char::len_utf16)
+        .sum::<usize>()
+        .try_into()
+        .unwrap_or(u32::MAX)
+}
+
+fn position(line: u32, ch: u32) -> Object {
+    let position = Object::new();
+    set(&position, "line", &line.into());
+    set(&position, "ch", &ch.into());
+    position
+}
+
+fn set(target: &Object, key: &str, value: &JsValue) {
+    if let Err(err) = Reflect::set(target, &key.into(), value) {
+        log::warn!("Unable to set property '{key}': {err:?}");
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn get_u32(target: &JsValue, key: &str) -> Option<u32> {
+    let value = Reflect::get(target, &key.into()).ok()?.as_f64()?;
+    (value >= 0.0).then_some(value as u32)
+}
+
+/// Call methods of `CodeMirror` addons that are not covered by [`sys::Editor`].
+///
+/// Returns `None` if the method is missing (e.g. the addon is not loaded)
+/// or throws an exception.
+fn call(editor: &sys::Editor, method: &str, args: &Array) -> Option<JsValue> {
+    let Some(function) = Reflect::get(editor, &method.into())
+        .ok()
+        .and_then(|f| f.dyn_into::<Function>().ok())
+    else {
+        log::warn!("CodeMirror method '{method}' is not available");
+        return None;
+    };
+    function
+        .apply(editor, args)
+        .map_err(|err| log::warn!("Unable to call CodeMirror method '{method}': {err:?}"))
+        .ok()
 }
@@ -1,5 +1,22 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::Range,
+};
+
+use klick_boundary::{
+    CustomEdge, CustomEmissions, CustomFactor, CustomGas, CustomQuantity, DecimalSeparator,
+};
+use klick_domain::{
+    constants::{GWP_CH4, GWP_N2O},
+    units::{
+        Factor, Float, FloatType, GramsPerKilowatthour, KilogramsPerLiter, Kilowatthours, Liters,
+        MassExt as _, Percent, Tons, UnitAbbreviation as _,
+    },
+    Value, ValueId as Id,
+};
 use klick_presenter::Lng;
-use std::collections::HashSet;
+use klick_usecases::{get_all_internal_nodes, CustomRecommendation};
 
 #[cfg(test)]
 mod tests;
@@ -9,7 +26,111 @@ pub struct EdgeDefined {
     pub line: usize,
     pub source: String,
     pub target: String,
+    /// Display label of the source node.
+    pub label: Option<String>,
+    /// Emitted mass or activity data (e.g. liters of diesel).
     pub value: f64,
+    pub unit: FloatType,
+    /// Emission factor that converts the activity data into an emitted mass.
+    pub factor: Option<Float>,
+    pub gas: Gas,
+    /// Where the value or factor comes from.
+    pub citation: Option<String>,
+    /// Emissions in the recommendation scenario.
+    pub recommendation: Option<CustomRecommendation>,
+}
+
+impl EdgeDefined {
+    /// Emitted CO₂ equivalents.
+    ///
+    /// Returns `None` if the unit does not fit to the factor.
+    #[must_use]
+    pub fn co2_equivalents(&self) -> Option<Tons> {
+        co2_equivalents(self.value, self.unit, self.factor, self.gas)
+    }
+}
+
+/// Greenhouse gas of a custom emission.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum Gas {
+    #[default]
+    Co2Equivalents,
+    Ch4,
+    N2o,
+}
+
+impl Gas {
+    #[must_use]
+    pub const fn global_warming_potential(self) -> Factor {
+        match self {
+            Self::Co2Equivalents => Factor::new(1.0),
+            Self::Ch4 => GWP_CH4,
+            Self::N2o => GWP_N2O,
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        let gas = match s
+            .replace('₂', "2")
+            .replace('₄', "4")
+            .to_uppercase()
+            .as_str()
+        {
+            "CO2" | "CO2E" | "CO2-EQ" | "CO2-ÄQ" | "CO2-ÄQ." => Self::Co2Equivalents,
+            "CH4" => Self::Ch4,
+            "N2O" => Self::N2o,
+            _ => return None,
+        };
+        Some(gas)
+    }
+}
+
+/// Units that can be used for activity data, emitted masses and emission factors.
+const UNITS: [FloatType; 10] = [
+    FloatType::grams(),
+    FloatType::kilograms(),
+    FloatType::tons(),
+    FloatType::liters(),
+    FloatType::qubicmeters(),
+    FloatType::kilowatthours(),
+    FloatType::kilograms_per_liter(),
+    FloatType::kilograms_per_qubicmeter(),
+    FloatType::grams_per_kilowatthour(),
+    FloatType::kilograms_per_kilowatthour(),
+];
+
+fn parse_unit(s: &str) -> Option<FloatType> {
+    let s = s.replace("m3", "m³");
+    UNITS.into_iter().find(|unit| unit.abbreviation() == s)
+}
+
+fn co2_equivalents(value: f64, unit: FloatType, factor: Option<Float>, gas: Gas) -> Option<Tons> {
+    let quantity = Float::from_f64_with_type(value, unit);
+    emitted_mass(quantity, factor).map(|mass| mass * gas.global_warming_potential())
+}
+
+fn emitted_mass(quantity: Float, factor: Option<Float>) -> Option<Tons> {
+    let mass = match (quantity, factor) {
+        (Float::Mass(mass), None) => mass.convert_to::<Tons>().unchecked_tons(),
+        (Float::Volume(volume), Some(Float::Density(factor))) => {
+            let volume = volume.convert_to::<Liters>().unchecked_liters();
+            let factor = factor
+                .convert_to::<KilogramsPerLiter>()
+                .unchecked_kilograms_per_liter();
+            (volume * factor).convert_to()
+        }
+        (Float::Energy(energy), Some(Float::SpecificEnergyDensity(factor))) => {
+            let energy = energy
+                .convert_to::<Kilowatthours>()
+                .unchecked_kilowatthours();
+            let factor = factor
+                .convert_to::<GramsPerKilowatthour>()
+                .unchecked_grams_per_kilowatthour();
+            (energy * factor).convert_to()
+        }
+        _ => return None,
+    };
+    Some(mass)
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -17,6 +138,8 @@ pub struct EdgeUndefined {
     pub line: usize,
     pub source: String,
     pub target: String,
+    /// Display label of the source node.
+    pub label: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -49,62 +172,308 @@ impl CustomEmission {
             Self::EdgeDefined(e) => &e.source,
         }
     }
+
+    #[must_use]
+    pub const fn label(&self) -> Option<&String> {
+        match self {
+            Self::EdgeUndefined(e) => e.label.as_ref(),
+            Self::EdgeDefined(e) => e.label.as_ref(),
+        }
+    }
 }
 
-fn parse_line(line_number: usize, line: &str, lang: Lng) -> Result<Option<CustomEmission>, String> {
-    let trimmed = line.trim();
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Quoted(&'a str),
+    Word(&'a str),
+}
 
-    if trimmed.is_empty() {
-        return Ok(None);
-    }
+const LABEL_KEYWORD: &str = "as";
+const CITATION_KEYWORD: &str = "cite";
+const RECOMMENDATION_KEYWORD: &str = "recommend";
 
-    if !trimmed.starts_with('"') || !trimmed.ends_with('"') || trimmed.len() < 2 {
-        return Err(
-            match lang {
-                Lng::De => format!("Zeile \"{line_number}\" ist nicht im erwarteten Format, erwartet war: [\"ID\" \"ID\"] oder [\"ID\" NUM \"ID\"]"),
-                Lng::En => format!("Line \"{line_number}\" is not formatted correctly, expected was: [\"ID\" \"ID\"] oder [\"ID\" NUM \"ID\"]")
+type Columns = Range<usize>;
+
+/// Split a line into quoted strings and words
+/// together with their character columns.
+///
+/// Everything after a `#` outside of quotes is a comment.
+/// Fails with the columns of a quote that is not closed.
+fn tokenize(line: &str) -> Result<Vec<(Token<'_>, Columns)>, Columns> {
+    let column = |byte_index: usize| line[..byte_index].chars().count();
+    let mut tokens = vec![];
+    let mut start = 0;
+    loop {
+        let rest = line[start..].trim_start();
+        start = line.len() - rest.len();
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+        let end = match c {
+            '#' => break,
+            '"' => {
+                let Some(len) = rest[1..].find('"') else {
+                    return Err(column(start)..column(line.len()));
+                };
+                let end = start + len + 2;
+                tokens.push((Token::Quoted(line[start + 1..end - 1].trim()), start..end));
+                end
             }
-        );
+            _ => {
+                let len = rest
+                    .find(|c: char| c.is_whitespace() || c == '"' || c == '#')
+                    .unwrap_or(rest.len());
+                let end = start + len;
+                tokens.push((Token::Word(&line[start..end]), start..end));
+                end
+            }
+        };
+        start = end;
     }
+    let tokens = tokens
+        .into_iter()
+        .map(|(token, bytes)| (token, column(bytes.start)..column(bytes.end)))
+        .collect();
+    Ok(tokens)
+}
 
-    let trimmed = &trimmed[1..trimmed.len() - 1];
+/// Character columns of the line without leading whitespace and comments.
+fn content_columns(line: &str) -> Columns {
+    match tokenize(line) {
+        Ok(tokens) => match (tokens.first(), tokens.last()) {
+            (Some((_, first)), Some((_, last))) => first.start..last.end,
+            _ => 0..0,
+        },
+        Err(columns) => columns,
+    }
+}
 
-    let mut parts = trimmed
-        .split('"')
-        .filter_map(|part| {
-            let part = part.trim();
-            if part.is_empty() {
-                None
-            } else {
-                Some(part)
-            }
-        })
-        .collect::<Vec<&str>>();
+fn parse_line(
+    line_number: usize,
+    line: &str,
+    lang: Lng,
+) -> Result<Option<CustomEmission>, Diagnostic> {
+    let diagnostic = |columns: Columns, message: String| Diagnostic {
+        line: line_number,
+        columns,
+        message,
+    };
+    let format_error =
+        |columns: Columns| diagnostic(columns, unexpected_format_message(line_number, lang));
 
-    if parts.len() < 2 || parts.len() > 3 {
-        return Err(
-            match lang {
-                Lng::De => format!("Zeile \"{line_number}\" ist nicht im erwarteten Format, erwartet war: [\"ID\" \"ID\"] oder [\"ID\" NUM \"ID\"]"),
-                Lng::En => format!("Line \"{line_number}\" is not formatted correctly, expected was: [\"ID\" \"ID\"] oder [\"ID\" NUM \"ID\"]")
-            }
-        );
+    let tokens = tokenize(line).map_err(format_error)?;
+    let line_columns = content_columns(line);
+    if tokens.is_empty() {
+        return Ok(None);
     }
+    let mut tokens = tokens.into_iter().peekable();
 
-    let source = parts.remove(0).to_string();
-    let target = if parts.len() == 2 {
-        parts.remove(1).to_string()
-    } else {
-        parts.remove(0).to_string()
+    let quoted = |token: Option<(Token<'_>, Columns)>| match token {
+        Some((Token::Quoted(name), _)) if !name.is_empty() => Ok(name.to_string()),
+        Some((_, columns)) => Err(format_error(columns)),
+        None => Err(format_error(line_columns.clone())),
     };
+    let keyword = |keyword| move |(token, _): &(Token<'_>, Columns)| *token == Token::Word(keyword);
 
-    let Some(value_str) = parts.first() else {
+    let source = quoted(tokens.next())?;
+    let label = tokens
+        .next_if(keyword(LABEL_KEYWORD))
+        .map(|_| quoted(tokens.next()))
+        .transpose()?;
+    let mut quantity = vec![];
+    let mut quantity_columns = line_columns.end..line_columns.end;
+    while let Some((Token::Word(word), columns)) =
+        tokens.next_if(|(t, _)| matches!(t, Token::Word(_)))
+    {
+        if quantity.is_empty() {
+            quantity_columns.start = columns.start;
+        }
+        quantity_columns.end = columns.end;
+        quantity.push(word);
+    }
+    let target = quoted(tokens.next())?;
+    let recommendation = tokens
+        .next_if(keyword(RECOMMENDATION_KEYWORD))
+        .map(|(_, keyword_columns)| {
+            let mut words = vec![];
+            let mut columns = keyword_columns.clone();
+            while let Some((Token::Word(word), word_columns)) =
+                tokens.next_if(|(t, _)| matches!(t, Token::Word(word) if *word != CITATION_KEYWORD))
+            {
+                columns.end = word_columns.end;
+                words.push(word);
+            }
+            if words.is_empty() {
+                return Err(format_error(keyword_columns));
+            }
+            Ok((words.join(" "), columns))
+        })
+        .transpose()?;
+    let citation = tokens
+        .next_if(keyword(CITATION_KEYWORD))
+        .map(|(_, columns)| quoted(tokens.next()).map(|citation| (citation, columns)))
+        .transpose()?;
+    if let Some((_, columns)) = tokens.next() {
+        return Err(format_error(columns));
+    }
+
+    if quantity.is_empty() {
+        if let Some((_, columns)) = recommendation.or(citation) {
+            return Err(format_error(columns));
+        }
         return Ok(Some(CustomEmission::EdgeUndefined(EdgeUndefined {
             line: line_number,
             source,
             target,
+            label,
         })));
+    }
+
+    let (value, unit, factor, gas) = parse_quantity(line_number, &quantity.join(" "), lang)
+        .map_err(|message| diagnostic(quantity_columns, message))?;
+    let recommendation = recommendation
+        .map(|(input, columns)| {
+            parse_recommendation(line_number, &input, lang)
+                .map_err(|message| diagnostic(columns, message))
+        })
+        .transpose()?;
+
+    Ok(Some(CustomEmission::EdgeDefined(EdgeDefined {
+        line: line_number,
+        source,
+        target,
+        label,
+        value,
+        unit,
+        factor,
+        gas,
+        citation: citation.map(|(citation, _)| citation),
+        recommendation,
+    })))
+}
+
+/// Parse `-NUM %` or `NUM [UNIT] [* NUM UNIT] [GAS]`.
+fn parse_recommendation(
+    line_number: usize,
+    input: &str,
+    lang: Lng,
+) -> Result<CustomRecommendation, String> {
+    let Some(reduction) = input.strip_suffix('%') else {
+        let (value, unit, factor, gas) = parse_quantity(line_number, input, lang)?;
+        let co2_equivalents = co2_equivalents(value, unit, factor, gas).unwrap_or_else(Tons::zero);
+        return Ok(CustomRecommendation::Absolute(co2_equivalents));
+    };
+    let Some(reduction) = reduction.trim().strip_prefix('-') else {
+        return Err(unexpected_format_message(line_number, lang));
     };
+    let reduction = parse_number(line_number, reduction.trim(), lang)?;
+    check_reduction(line_number, reduction, lang)
+}
 
+fn check_reduction(
+    line_number: usize,
+    reduction: f64,
+    lang: Lng,
+) -> Result<CustomRecommendation, String> {
+    if reduction > 100.0 {
+        return Err(match lang {
+            Lng::De => format!(
+                "Die Reduktion um \"{reduction}\" % auf Zeile \"{line_number}\" ist größer als 100 %"
+            ),
+            Lng::En => format!(
+                "The reduction by \"{reduction}\" % on line \"{line_number}\" is greater than 100 %"
+            ),
+        });
+    }
+    Ok(CustomRecommendation::Reduction(Percent::new(reduction)))
+}
+
+/// Parse `NUM [UNIT] [* NUM UNIT] [GAS]`.
+///
+/// A number without a unit is interpreted as tons of CO₂ equivalents.
+fn parse_quantity(
+    line_number: usize,
+    input: &str,
+    lang: Lng,
+) -> Result<(f64, FloatType, Option<Float>, Gas), String> {
+    let (activity, factor) = match input.split_once('*') {
+        Some((activity, factor)) => (activity, Some(factor)),
+        None => (input, None),
+    };
+    let mut activity = activity.split_whitespace();
+    let mut factor = factor.map(str::split_whitespace);
+
+    let Some(value) = activity.next() else {
+        return Err(unexpected_format_message(line_number, lang));
+    };
+    let value = parse_number(line_number, value, lang)?;
+    let unit = activity
+        .next()
+        .map(|unit| parse_unit(unit).ok_or_else(|| unknown_unit_message(line_number, unit, lang)))
+        .transpose()?
+        .unwrap_or(FloatType::tons());
+
+    let factor_value = factor
+        .as_mut()
+        .map(|factor| {
+            let (Some(value), Some(unit)) = (factor.next(), factor.next()) else {
+                return Err(unexpected_format_message(line_number, lang));
+            };
+            let value = parse_number(line_number, value, lang)?;
+            let unit =
+                parse_unit(unit).ok_or_else(|| unknown_unit_message(line_number, unit, lang))?;
+            Ok(Float::from_f64_with_type(value, unit))
+        })
+        .transpose()?;
+
+    // The gas is the last token of the expression.
+    let mut rest = activity
+        .chain(factor.into_iter().flatten())
+        .collect::<Vec<_>>();
+    let gas = match (rest.pop(), rest.is_empty()) {
+        (None, _) => Gas::default(),
+        (Some(gas), true) => {
+            Gas::parse(gas).ok_or_else(|| unknown_unit_message(line_number, gas, lang))?
+        }
+        (Some(_), false) => return Err(unexpected_format_message(line_number, lang)),
+    };
+
+    check_emitted_mass(line_number, value, unit, factor_value, lang)?;
+    Ok((value, unit, factor_value, gas))
+}
+
+/// Check that the activity data can be converted into an emitted mass.
+fn check_emitted_mass(
+    line_number: usize,
+    value: f64,
+    unit: FloatType,
+    factor_value: Option<Float>,
+    lang: Lng,
+) -> Result<(), String> {
+    let quantity = Float::from_f64_with_type(value, unit);
+    if emitted_mass(quantity, factor_value).is_none() {
+        let unit = unit.abbreviation();
+        return Err(match (factor_value, lang) {
+            (Some(factor), Lng::De) => format!(
+                "Die Einheit \"{unit}\" passt nicht zum Emissionsfaktor in \"{factor_unit}\" auf Zeile \"{line_number}\"",
+                factor_unit = factor.abbreviation()
+            ),
+            (Some(factor), Lng::En) => format!(
+                "The unit \"{unit}\" does not match the emission factor in \"{factor_unit}\" on line \"{line_number}\"",
+                factor_unit = factor.abbreviation()
+            ),
+            (None, Lng::De) => format!(
+                "Für die Einheit \"{unit}\" auf Zeile \"{line_number}\" wird ein Emissionsfaktor benötigt"
+            ),
+            (None, Lng::En) => format!(
+                "The unit \"{unit}\" on line \"{line_number}\" requires an emission factor"
+            ),
+        });
+    }
+    Ok(())
+}
+
+fn parse_number(line_number: usize, value_str: &str, lang: Lng) -> Result<f64, String> {
     let normalized_value = match lang {
         Lng::De => value_str.replace('.', "").replace(',', "."),
         Lng::En => value_str.replace(',', ""),
@@ -116,7 +485,10 @@ fn parse_line(line_number: usize, line: &str, lang: Lng) -> Result<Option<Custom
             Lng::En => format!("The number \"{value_str}\" on line \"{line_number}\" was not expected: {err}"),
         }
     })?;
+    check_number(line_number, value, value_str, lang)
+}
 
+fn check_number(line_number: usize, value: f64, value_str: &str, lang: Lng) -> Result<f64, String> {
     if value < 0.0 {
         return Err(match lang {
             Lng::De => format!(
@@ -128,24 +500,187 @@ fn parse_line(line_number: usize, line: &str, lang: Lng) -> Result<Option<Custom
         });
     }
 
-    Ok(Some(CustomEmission::EdgeDefined(EdgeDefined {
-        line: line_number,
+    Ok(value)
+}
+
+fn unexpected_format_message(line_number: usize, lang: Lng) -> String {
+    match lang {
+        Lng::De => format!("Zeile \"{line_number}\" ist nicht im erwarteten Format, erwartet war: [\"ID\" [as \"NAME\"] \"ID\"] oder [\"ID\" [as \"NAME\"] NUM [EINHEIT] [* NUM EINHEIT] [GAS] \"ID\" [recommend WERT] [cite \"QUELLE\"]]"),
+        Lng::En => format!("Line \"{line_number}\" is not formatted correctly, expected was: [\"ID\" [as \"NAME\"] \"ID\"] or [\"ID\" [as \"NAME\"] NUM [UNIT] [* NUM UNIT] [GAS] \"ID\" [recommend VALUE] [cite \"SOURCE\"]]")
+    }
+}
+
+fn unknown_unit_message(line_number: usize, unit: &str, lang: Lng) -> String {
+    match lang {
+        Lng::De => format!("Unbekannte Einheit \"{unit}\" auf Zeile \"{line_number}\""),
+        Lng::En => format!("Unknown unit \"{unit}\" on line \"{line_number}\""),
+    }
+}
+
+/// A problem at a position of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Line number (starting at 1).
+    pub line: usize,
+    /// Character columns within the line (starting at 0).
+    pub columns: Range<usize>,
+    /// Localized message.
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// Parse all lines.
+///
+/// Fails with the diagnostics of all invalid lines.
+pub fn parse_emission(input: &str, lang: Lng) -> Result<Vec<CustomEmission>, Vec<Diagnostic>> {
+    let mut emissions = vec![];
+    let mut diagnostics = vec![];
+    for (index, line) in input.lines().enumerate() {
+        match parse_line(index + 1, line, lang) {
+            Ok(Some(emission)) => emissions.push(emission),
+            Ok(None) => {}
+            Err(diagnostic) => diagnostics.push(diagnostic),
+        }
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+    Ok(emissions)
+}
+
+/// Check all edges of the structured custom emissions.
+///
+/// The line numbers of the diagnostics refer to the text view
+/// (see [`format_custom_emissions`]), the columns are empty.
+pub fn emissions_from_structure(
+    emissions: &CustomEmissions,
+    lang: Lng,
+) -> Result<Vec<CustomEmission>, Vec<Diagnostic>> {
+    let mut labeled_nodes = HashSet::new();
+    let mut line_number = 0;
+    let mut edges = vec![];
+    let mut diagnostics = vec![];
+    for edge in &emissions.edges {
+        line_number += edge.comments.len() + 1;
+        // Like in the text view the label belongs to the first edge of a node.
+        let label = labeled_nodes
+            .insert(&edge.source)
+            .then(|| emissions.label(&edge.source))
+            .flatten()
+            .map(ToString::to_string);
+        match edge_from_structure(line_number, edge, label, lang) {
+            Ok(edge) => edges.push(edge),
+            Err(message) => diagnostics.push(Diagnostic {
+                line: line_number,
+                columns: 0..0,
+                message,
+            }),
+        }
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+    Ok(edges)
+}
+
+fn edge_from_structure(
+    line_number: usize,
+    edge: &CustomEdge,
+    label: Option<String>,
+    lang: Lng,
+) -> Result<CustomEmission, String> {
+    let CustomEdge {
         source,
         target,
         value,
-    })))
+        recommendation,
+        citation,
+        ..
+    } = edge;
+    if source.is_empty() || target.is_empty() {
+        return Err(unexpected_format_message(line_number, lang));
+    }
+    let Some(quantity) = value else {
+        if recommendation.is_some() || citation.is_some() {
+            return Err(unexpected_format_message(line_number, lang));
+        }
+        return Ok(CustomEmission::EdgeUndefined(EdgeUndefined {
+            line: line_number,
+            source: source.clone(),
+            target: target.clone(),
+            label,
+        }));
+    };
+    let (value, unit, factor, gas) = quantity_from_structure(line_number, quantity, lang)?;
+    let recommendation = recommendation
+        .as_ref()
+        .map(|recommendation| match recommendation {
+            klick_boundary::CustomRecommendation::Absolute(quantity) => {
+                let (value, unit, factor, gas) =
+                    quantity_from_structure(line_number, quantity, lang)?;
+                let co2_equivalents =
+                    co2_equivalents(value, unit, factor, gas).unwrap_or_else(Tons::zero);
+                Ok(CustomRecommendation::Absolute(co2_equivalents))
+            }
+            klick_boundary::CustomRecommendation::Reduction(reduction) => {
+                let reduction =
+                    check_number(line_number, *reduction, &reduction.to_string(), lang)?;
+                check_reduction(line_number, reduction, lang)
+            }
+        })
+        .transpose()?;
+    Ok(CustomEmission::EdgeDefined(EdgeDefined {
+        line: line_number,
+        source: source.clone(),
+        target: target.clone(),
+        label,
+        value,
+        unit,
+        factor,
+        gas,
+        citation: citation.clone(),
+        recommendation,
+    }))
 }
 
-pub fn parse_emission(input: &str, lang: Lng) -> Result<Vec<CustomEmission>, String> {
-    let emissions = input
-        .lines()
-        .enumerate()
-        .map(|(line_number, line)| parse_line(line_number + 1, line, lang))
-        .collect::<Result<Vec<_>, _>>()?
-        .into_iter()
-        .flatten()
-        .collect();
-    Ok(emissions)
+fn quantity_from_structure(
+    line_number: usize,
+    quantity: &CustomQuantity,
+    lang: Lng,
+) -> Result<(f64, FloatType, Option<Float>, Gas), String> {
+    let CustomQuantity {
+        value,
+        unit,
+        factor,
+        gas,
+    } = quantity;
+    let value = check_number(line_number, *value, &value.to_string(), lang)?;
+    let unit = unit
+        .as_deref()
+        .map(|unit| parse_unit(unit).ok_or_else(|| unknown_unit_message(line_number, unit, lang)))
+        .transpose()?
+        .unwrap_or(FloatType::tons());
+    let factor = factor
+        .as_ref()
+        .map(|CustomFactor { value, unit }| {
+            let value = check_number(line_number, *value, &value.to_string(), lang)?;
+            let unit =
+                parse_unit(unit).ok_or_else(|| unknown_unit_message(line_number, unit, lang))?;
+            Ok::<_, String>(Float::from_f64_with_type(value, unit))
+        })
+        .transpose()?;
+    let gas = match gas {
+        None | Some(CustomGas::Co2e) => Gas::Co2Equivalents,
+        Some(CustomGas::Ch4) => Gas::Ch4,
+        Some(CustomGas::N2o) => Gas::N2o,
+    };
+    check_emitted_mass(line_number, value, unit, factor, lang)?;
+    Ok((value, unit, factor, gas))
 }
 
 impl CustomEmissionParserError {
@@ -194,6 +729,7 @@ impl CustomEmissionParserError {
                 }
             }
             Self::DetachedNodesVoilation { lines } => {
+                let lines = lines.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
                 match lang {
                     Lng::De => format!("Nicht korrekt verbundene Knoten gefunden (auf Zeile(n) {})", lines),
                     Lng::En => format!("Found nodes which are not connected properly (one line(s) {})", lines)
@@ -212,14 +748,51 @@ pub enum CustomEmissionParserError {
     EdgeToLeafVoilation { e1_line: usize, e2_line: usize },
     NodeToNodeLinkVoilation { e1_line: usize, e2_line: usize },
     EdgeCycleVoilation { name: String, line: usize },
-    DetachedNodesVoilation { lines: String },
+    DetachedNodesVoilation { lines: Vec<usize> },
 }
 
-// these checks are written for like 8 nodes max, so performance isn't the main objective
+impl CustomEmissionParserError {
+    /// Lines that are involved in the violation.
+    #[must_use]
+    pub fn lines(&self) -> Vec<usize> {
+        match self {
+            Self::ReservedNameVoilation { line, .. }
+            | Self::InsideEdgeCycleVoilation { line }
+            | Self::EdgeCycleVoilation { line, .. } => vec![*line],
+            Self::EdgeNotUniqueVoilation { e1_line, e2_line }
+            | Self::DuplicatedNodeNameVoilation { e1_line, e2_line }
+            | Self::EdgeToLeafVoilation { e1_line, e2_line }
+            | Self::NodeToNodeLinkVoilation { e1_line, e2_line } => vec![*e1_line, *e2_line],
+            Self::DetachedNodesVoilation { lines } => lines.clone(),
+        }
+    }
+}
+
+/// Check the graph and return the first violation.
 pub fn check_graph(
     custom_edges: &[CustomEmission],
     all_internal_nodes_names: Vec<String>,
 ) -> Result<(), CustomEmissionParserError> {
+    match graph_errors(custom_edges, all_internal_nodes_names)
+        .into_iter()
+        .next()
+    {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Check the graph and return all violations.
+///
+/// The cycle and connection checks only run
+/// if all other checks passed.
+// these checks are written for like 8 nodes max, so performance isn't the main objective
+#[must_use]
+pub fn graph_errors(
+    custom_edges: &[CustomEmission],
+    all_internal_nodes_names: Vec<String>,
+) -> Vec<CustomEmissionParserError> {
+    let mut errors = vec![];
     let mut edges_defined: Vec<EdgeDefined> = vec![];
     let mut edges_undefined: Vec<EdgeUndefined> = vec![];
     for edge in custom_edges {
@@ -231,26 +804,21 @@ pub fn check_graph(
 
     // 1. no use of reserved names in source
     let reserved_names: Vec<String> = all_internal_nodes_names.clone();
-    if let Some(edge) = custom_edges
+    for edge in custom_edges
         .iter()
-        .find(|edge| reserved_names.iter().any(|f| f == edge.source()))
+        .filter(|edge| reserved_names.iter().any(|f| f == edge.source()))
     {
-        return Err(CustomEmissionParserError::ReservedNameVoilation {
+        errors.push(CustomEmissionParserError::ReservedNameVoilation {
             name: edge.source().to_string(),
             line: edge.line(),
         });
     }
 
     // 2. all edges must be unique
-    for i in 0..edges_defined.len() {
-        for j in 0..edges_defined.len() {
-            if i == j {
-                continue;
-            }
-            let e1 = &edges_defined[i];
-            let e2 = &edges_defined[j];
+    for (i, e1) in edges_defined.iter().enumerate() {
+        for e2 in &edges_defined[i + 1..] {
             if e1.source == e2.source && e1.target == e2.target {
-                return Err(CustomEmissionParserError::EdgeNotUniqueVoilation {
+                errors.push(CustomEmissionParserError::EdgeNotUniqueVoilation {
                     e1_line: e1.line,
                     e2_line: e2.line,
                 });
@@ -258,20 +826,16 @@ pub fn check_graph(
         }
     }
     // 3. no cycle inside edge(s)
-    if let Some(edge) = custom_edges.iter().find(|e| e.source() == e.target()) {
-        return Err(CustomEmissionParserError::InsideEdgeCycleVoilation { line: edge.line() });
+    for edge in custom_edges.iter().filter(|e| e.source() == e.target()) {
+        errors.push(CustomEmissionParserError::InsideEdgeCycleVoilation { line: edge.line() });
     }
 
     // 4. ensure that leaf source names are unique
-    for i in 0..edges_defined.len() {
-        for j in 0..edges_defined.len() {
-            if i == j {
-                continue;
-            }
-            let e1 = &edges_defined[i];
-            let e2 = &edges_defined[j];
-            if e1.source == e2.source {
-                return Err(CustomEmissionParserError::DuplicatedNodeNameVoilation {
+    //    (identical edges are already reported)
+    for (i, e1) in edges_defined.iter().enumerate() {
+        for e2 in &edges_defined[i + 1..] {
+            if e1.source == e2.source && e1.target != e2.target {
+                errors.push(CustomEmissionParserError::DuplicatedNodeNameVoilation {
                     e1_line: e1.line,
                     e2_line: e2.line,
                 });
@@ -283,7 +847,7 @@ pub fn check_graph(
     for eu in &edges_undefined {
         for ed in &edges_defined {
             if eu.target == ed.source {
-                return Err(CustomEmissionParserError::EdgeToLeafVoilation {
+                errors.push(CustomEmissionParserError::EdgeToLeafVoilation {
                     e1_line: eu.line,
                     e2_line: ed.line,
                 });
@@ -292,15 +856,10 @@ pub fn check_graph(
     }
 
     // 6. leafs (EdgeDefined) must not link to leafs either (EdgeDefined)
-    for i in 0..edges_defined.len() {
-        for j in 0..edges_defined.len() {
-            if i == j {
-                continue;
-            }
-            let e1 = &edges_defined[i];
-            let e2 = &edges_defined[j];
-            if e1.target == e2.source {
-                return Err(CustomEmissionParserError::NodeToNodeLinkVoilation {
+    for (i, e1) in edges_defined.iter().enumerate() {
+        for (j, e2) in edges_defined.iter().enumerate() {
+            if i != j && e1.target == e2.source {
+                errors.push(CustomEmissionParserError::NodeToNodeLinkVoilation {
                     e1_line: e1.line,
                     e2_line: e2.line,
                 });
@@ -308,6 +867,10 @@ pub fn check_graph(
         }
     }
 
+    if !errors.is_empty() {
+        return errors;
+    }
+
     // 7. no cycle between edge(s)
     let mut edges_unvisited: Vec<CustomEmission> = custom_edges.to_owned();
     let mut edges_visited: Vec<CustomEmission> = vec![];
@@ -334,10 +897,11 @@ pub fn check_graph(
             if let Some(n) = visited_targets.get(e.source()) {
                 //   - if they are already, find collision and report Err
                 // FIXME find the other nodes in conflict
-                return Err(CustomEmissionParserError::EdgeCycleVoilation {
+                errors.push(CustomEmissionParserError::EdgeCycleVoilation {
                     name: n.to_string(),
                     line: e.line(),
                 });
+                return errors;
             }
             //   - if not, add them & and add edges to edges_unvisited (set found_one_more to true)
             visited_targets_new.insert(e.source().clone());
@@ -357,12 +921,180 @@ pub fn check_graph(
     // 8. enforce connections: no loose edges, each must connect to something
     //    if all_edges_list is not empty, report the remainder elements as loose edges
     if !edges_unvisited.is_empty() {
-        let lines: String = edges_unvisited.into_iter().fold(String::new(), |sum, e| {
-            let comma = if sum.is_empty() { "" } else { ", " };
-            sum + format!("{}{}", comma, e.line()).as_str()
-        });
-        return Err(CustomEmissionParserError::DetachedNodesVoilation { lines });
+        let lines = edges_unvisited.iter().map(CustomEmission::line).collect();
+        errors.push(CustomEmissionParserError::DetachedNodesVoilation { lines });
     }
 
-    Ok(())
+    errors
+}
+
+/// Values, edges and leafs of a custom emission graph
+/// as they are expected by the calculation use cases.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CustomValues {
+    pub values: Vec<(Id, Value)>,
+    pub edges: Vec<(Id, Id)>,
+    pub leafs: Vec<Id>,
+    /// Display labels of the custom nodes.
+    pub labels: HashMap<Id, String>,
+    /// Citations of the custom emission values.
+    pub citations: Vec<(Id, String)>,
+    /// Emissions of custom leafs in the recommendation scenario.
+    pub recommendations: Vec<(Id, CustomRecommendation)>,
+}
+
+/// Parse and check custom emissions.
+///
+/// Fails with the diagnostics of all invalid lines
+/// or, if all lines are valid, of all graph violations.
+pub fn parse_custom_values(input: &str, lang: Lng) -> Result<CustomValues, Vec<Diagnostic>> {
+    let emissions = parse_emission(input, lang)?;
+    let lines = &input.lines().collect::<Vec<_>>();
+    check_custom_values(&emissions, lang, |line| {
+        lines
+            .get(line - 1)
+            .map_or(0..0, |content| content_columns(content))
+    })
+}
+
+fn check_custom_values(
+    emissions: &[CustomEmission],
+    lang: Lng,
+    columns: impl Fn(usize) -> Columns,
+) -> Result<CustomValues, Vec<Diagnostic>> {
+    let errors = graph_errors(emissions, internal_node_names());
+    if !errors.is_empty() {
+        let diagnostics = errors
+            .into_iter()
+            .flat_map(|err| {
+                let message = err.format_error(lang);
+                err.lines()
+                    .into_iter()
+                    .map(|line| Diagnostic {
+                        line,
+                        columns: columns(line),
+                        message: message.clone(),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        return Err(diagnostics);
+    }
+    Ok(custom_values(emissions))
+}
+
+/// Parse and check the text view of custom emissions.
+pub fn parse_custom_emissions(input: &str, lang: Lng) -> Result<CustomEmissions, Vec<Diagnostic>> {
+    parse_custom_values(input, lang)?;
+    CustomEmissions::from_text(input, decimal_separator(lang)).map_err(|lines| {
+        lines
+            .into_iter()
+            .map(|line| Diagnostic {
+                line: line.number,
+                columns: content_columns(&line.content),
+                message: unexpected_format_message(line.number, lang),
+            })
+            .collect()
+    })
+}
+
+/// Text view of custom emissions.
+#[must_use]
+pub fn format_custom_emissions(emissions: &CustomEmissions, lang: Lng) -> String {
+    emissions.to_text(decimal_separator(lang))
+}
+
+/// Check custom emissions and convert them into custom values.
+///
+/// The line numbers of the diagnostics refer to the text view,
+/// the columns are empty.
+pub fn custom_values_from_structure(
+    emissions: &CustomEmissions,
+    lang: Lng,
+) -> Result<CustomValues, Vec<Diagnostic>> {
+    let emissions = emissions_from_structure(emissions, lang)?;
+    check_custom_values(&emissions, lang, |_| 0..0)
+}
+
+const fn decimal_separator(lang: Lng) -> DecimalSeparator {
+    match lang {
+        Lng::De => DecimalSeparator::Comma,
+        Lng::En => DecimalSeparator::Point,
+    }
+}
+
+/// Names of all custom nodes that are used in the input.
+///
+/// Invalid lines are ignored.
+#[must_use]
+pub fn custom_node_names(input: &str) -> Vec<String> {
+    let internal_node_names = internal_node_names();
+    let mut names = input
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| parse_line(index + 1, line, Lng::En).ok().flatten())
+        .flat_map(|emission| [emission.source().clone(), emission.target().clone()])
+        .filter(|name| !internal_node_names.contains(name))
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    names
+}
+
+#[must_use]
+pub fn internal_node_names() -> Vec<String> {
+    get_all_internal_nodes()
+        .iter()
+        .map(|id| format!("{id:?}"))
+        .collect()
+}
+
+#[must_use]
+pub fn custom_values(emissions: &[CustomEmission]) -> CustomValues {
+    let internal_nodes: HashMap<String, Id> = get_all_internal_nodes()
+        .into_iter()
+        .map(|id| (format!("{id:?}"), id.into()))
+        .collect();
+    let target_id = |target: &str| {
+        internal_nodes
+            .get(target)
+            .cloned()
+            .unwrap_or_else(|| Id::Custom(target.to_string()))
+    };
+
+    let mut custom_values = CustomValues::default();
+
+    for emission in emissions {
+        match emission {
+            CustomEmission::EdgeDefined(edge) => {
+                let source = Id::Custom(edge.source.clone());
+                custom_values
+                    .edges
+                    .push((source.clone(), target_id(&edge.target)));
+                custom_values.leafs.push(source.clone());
+                if let Some(recommendation) = edge.recommendation {
+                    custom_values
+                        .recommendations
+                        .push((source.clone(), recommendation));
+                }
+                if let Some(citation) = &edge.citation {
+                    custom_values
+                        .citations
+                        .push((source.clone(), citation.clone()));
+                }
+                let value = edge.co2_equivalents().unwrap_or_else(Tons::zero);
+                custom_values.values.push((source, Value::from(value)));
+            }
+            CustomEmission::EdgeUndefined(edge) => {
+                let source = Id::Custom(edge.source.clone());
+                custom_values.edges.push((source, target_id(&edge.target)));
+            }
+        }
+        if let Some(label) = emission.label() {
+            custom_values
+                .labels
+                .insert(Id::Custom(emission.source().clone()), label.clone());
+        }
+    }
+    custom_values
 }
@@ -1,5 +1,21 @@
 use super::*;
 
+/// An edge with a value in tons of CO₂ equivalents.
+fn edge_in_tons(line: usize, source: &str, target: &str, value: f64) -> EdgeDefined {
+    EdgeDefined {
+        line,
+        source: source.to_string(),
+        target: target.to_string(),
+        label: None,
+        value,
+        unit: FloatType::tons(),
+        factor: None,
+        gas: Gas::Co2Equivalents,
+        citation: None,
+        recommendation: None,
+    }
+}
+
 fn internal_node_names() -> Vec<String> {
     [
         "N2oEmissions",
@@ -52,6 +68,7 @@ fn edge_undefined() {
         line: 1,
         source: "asdf1".to_string(),
         target: "asdf2".to_string(),
+        label: None,
     })];
     assert_eq!(output.as_slice(), q.as_slice());
 }
@@ -64,6 +81,7 @@ fn edge_undefined_umlauts() {
         line: 1,
         source: "H₂ Generator".to_string(),
         target: "fällmittel".to_string(),
+        label: None,
     })];
     assert_eq!(output.as_slice(), q.as_slice());
 }
@@ -76,6 +94,7 @@ fn edge_undefined_other() {
         line: 1,
         source: "H₂ Generator".to_string(),
         target: "Midtbø".to_string(),
+        label: None,
     })];
     assert_eq!(output.as_slice(), q.as_slice());
 }
@@ -84,12 +103,9 @@ fn edge_undefined_other() {
 fn edge_defined() {
     let input = "\"asdf1\" 1,1 \"asdf2\"";
     let output = parse_emission(input, Lng::De).unwrap();
-    let q: Vec<CustomEmission> = vec![CustomEmission::EdgeDefined(EdgeDefined {
-        line: 1,
-        source: "asdf1".to_string(),
-        target: "asdf2".to_string(),
-        value: 1.1,
-    })];
+    let q: Vec<CustomEmission> = vec![CustomEmission::EdgeDefined(edge_in_tons(
+        1, "asdf1", "asdf2", 1.1,
+    ))];
     assert_eq!(output.as_slice(), q.as_slice());
 }
 
@@ -97,12 +113,12 @@ fn edge_defined() {
 fn edge_defined_umlauts() {
     let input = "\"H₂ Generator\" 1,1 \"fällmittel\"";
     let output = parse_emission(input, Lng::De).unwrap();
-    let q: Vec<CustomEmission> = vec![CustomEmission::EdgeDefined(EdgeDefined {
-        line: 1,
-        source: "H₂ Generator".to_string(),
-        target: "fällmittel".to_string(),
-        value: 1.1,
-    })];
+    let q: Vec<CustomEmission> = vec![CustomEmission::EdgeDefined(edge_in_tons(
+        1,
+        "H₂ Generator",
+        "fällmittel",
+        1.1,
+    ))];
     assert_eq!(output.as_slice(), q.as_slice());
 }
 
@@ -179,12 +195,7 @@ fn edge_defined_de_floats_de() {
             Lng::En => f.replace(',', ""),
         };
         let v = normalized_value.parse::<f64>().unwrap();
-        let edges = vec![CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "a".to_string(),
-            target: "b".to_string(),
-            value: v,
-        })];
+        let edges = vec![CustomEmission::EdgeDefined(edge_in_tons(1, "a", "b", v))];
         let q: Vec<CustomEmission> = edges;
         assert_eq!(output.as_slice(), q.as_slice());
     }
@@ -194,12 +205,9 @@ fn edge_defined_de_floats_de() {
 fn edge_defined_us() {
     let input = "\"asdf1\" 1.1 \"asdf2\"";
     let output = parse_emission(input, Lng::En).unwrap();
-    let edges = vec![CustomEmission::EdgeDefined(EdgeDefined {
-        line: 1,
-        source: "asdf1".to_string(),
-        target: "asdf2".to_string(),
-        value: 1.1,
-    })];
+    let edges = vec![CustomEmission::EdgeDefined(edge_in_tons(
+        1, "asdf1", "asdf2", 1.1,
+    ))];
     let q: Vec<CustomEmission> = edges;
     assert_eq!(output.as_slice(), q.as_slice());
 }
@@ -238,12 +246,7 @@ fn edge_defined_de_floats_us() {
         let output = parse_emission(&input, Lng::En).unwrap();
         let v = f.parse::<f64>().unwrap();
 
-        let edges = vec![CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "a".to_string(),
-            target: "b".to_string(),
-            value: v,
-        })];
+        let edges = vec![CustomEmission::EdgeDefined(edge_in_tons(1, "a", "b", v))];
         let q: Vec<CustomEmission> = edges;
         assert_eq!(output.as_slice(), q.as_slice());
     }
@@ -259,12 +262,7 @@ fn check_graph_empty() {
 
 #[test]
 fn check_graph_cycle_edge_defined() {
-    let edges = &[CustomEmission::EdgeDefined(EdgeDefined {
-        line: 1,
-        source: "a".to_string(),
-        target: "a".to_string(),
-        value: 1.1,
-    })];
+    let edges = &[CustomEmission::EdgeDefined(edge_in_tons(1, "a", "a", 1.1))];
     let q = edges;
     let r = check_graph(q, internal_node_names());
     match r {
@@ -281,6 +279,7 @@ fn check_graph_cycle_edge_undefined() {
         line: 1,
         source: "a".to_string(),
         target: "a".to_string(),
+        label: None,
     })];
     let q = edges;
     let r = check_graph(q, internal_node_names());
@@ -299,16 +298,19 @@ fn check_graph_cycle_two_nodes() {
             line: 1,
             source: "a".to_string(),
             target: "b".to_string(),
+            label: None,
         }),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 2,
             source: "b".to_string(),
             target: "a".to_string(),
+            label: None,
         }),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 3,
             source: "b".to_string(),
             target: "TotalEmissions".to_string(),
+            label: None,
         }),
     ];
     let q = edges;
@@ -330,21 +332,25 @@ fn check_graph_cycle_many_nodes() {
             line: 1,
             source: "a".to_string(),
             target: "b".to_string(),
+            label: None,
         }),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 2,
             source: "b".to_string(),
             target: "c".to_string(),
+            label: None,
         }),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 3,
             source: "b".to_string(),
             target: "a".to_string(),
+            label: None,
         }),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 4,
             source: "c".to_string(),
             target: "TotalEmissions".to_string(),
+            label: None,
         }),
     ];
     let q = edges;
@@ -362,26 +368,24 @@ fn check_graph_cycle_many_nodes() {
 #[test]
 fn check_graph_cycle_many_nodes_two() {
     let edges = &[
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "a".to_string(),
-            target: "b".to_string(),
-            value: 1.1,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(1, "a", "b", 1.1)),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 2,
             source: "c".to_string(),
             target: "b".to_string(),
+            label: None,
         }),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 3,
             source: "b".to_string(),
             target: "c".to_string(),
+            label: None,
         }),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 4,
             source: "c".to_string(),
             target: "TotalEmissions".to_string(),
+            label: None,
         }),
     ];
     let q = edges;
@@ -399,18 +403,8 @@ fn check_graph_cycle_many_nodes_two() {
 #[test]
 fn check_graph_conflict_source_name_not_unique() {
     let edges = &[
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "a".to_string(),
-            target: "b".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 2,
-            source: "a".to_string(),
-            target: "b".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(1, "a", "b", 1.2)),
+        CustomEmission::EdgeDefined(edge_in_tons(2, "a", "b", 1.2)),
     ];
     let q = edges;
     let r = check_graph(q, internal_node_names());
@@ -426,18 +420,8 @@ fn check_graph_conflict_source_name_not_unique() {
 #[test]
 fn check_graph_too_many_values() {
     let edges = &[
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "a".to_string(),
-            target: "b".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 2,
-            source: "b".to_string(),
-            target: "c".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(1, "a", "b", 1.2)),
+        CustomEmission::EdgeDefined(edge_in_tons(2, "b", "c", 1.2)),
     ];
     let q = edges;
     let r = check_graph(q, internal_node_names());
@@ -456,10 +440,11 @@ fn check_graph_unconnected_edge() {
         line: 1,
         source: "a".to_string(),
         target: "b".to_string(),
+        label: None,
     })];
     let q = edges;
     let r = check_graph(q, internal_node_names());
-    let found_lines = "1".to_string();
+    let found_lines = vec![1];
     match r {
         Err(CustomEmissionParserError::DetachedNodesVoilation { lines }) => {
             assert_eq!(lines, found_lines);
@@ -471,28 +456,13 @@ fn check_graph_unconnected_edge() {
 #[test]
 fn check_graph_unconnected_edges() {
     let edges = &[
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "foo".to_string(),
-            target: "TotalEmissions".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 2,
-            source: "bar".to_string(),
-            target: "unconnected".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 3,
-            source: "batz".to_string(),
-            target: "TotalEmissions".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(1, "foo", "TotalEmissions", 1.2)),
+        CustomEmission::EdgeDefined(edge_in_tons(2, "bar", "unconnected", 1.2)),
+        CustomEmission::EdgeDefined(edge_in_tons(3, "batz", "TotalEmissions", 1.2)),
     ];
     let q = edges;
     let r = check_graph(q, internal_node_names());
-    let found_lines = "2".to_string();
+    let found_lines = vec![2];
     match r {
         Err(CustomEmissionParserError::DetachedNodesVoilation { lines }) => {
             assert_eq!(lines, found_lines);
@@ -504,18 +474,8 @@ fn check_graph_unconnected_edges() {
 #[test]
 fn check_graph_emission_node_links_emission_node() {
     let edges = &[
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "foo".to_string(),
-            target: "TotalEmissions".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 2,
-            source: "MyCrazyEmission".to_string(),
-            target: "foo".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(1, "foo", "TotalEmissions", 1.2)),
+        CustomEmission::EdgeDefined(edge_in_tons(2, "MyCrazyEmission", "foo", 1.2)),
     ];
     let q = edges;
     let r = check_graph(q, internal_node_names());
@@ -530,12 +490,12 @@ fn check_graph_emission_node_links_emission_node() {
 
 #[test]
 fn check_graph_proper_edge() {
-    let q = &[CustomEmission::EdgeDefined(EdgeDefined {
-        line: 1,
-        source: "a".to_string(),
-        target: "TotalEmissions".to_string(),
-        value: 1.2,
-    })];
+    let q = &[CustomEmission::EdgeDefined(edge_in_tons(
+        1,
+        "a",
+        "TotalEmissions",
+        1.2,
+    ))];
     let r = check_graph(q, internal_node_names());
     assert!(r.is_ok());
 }
@@ -543,18 +503,8 @@ fn check_graph_proper_edge() {
 #[test]
 fn check_graph_using_reserved_names() {
     let edges = &[
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "TotalEmissions".to_string(),
-            target: "a".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "DirectEmissions".to_string(),
-            target: "a".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(1, "TotalEmissions", "a", 1.2)),
+        CustomEmission::EdgeDefined(edge_in_tons(1, "DirectEmissions", "a", 1.2)),
     ];
     let q = edges;
     let r = check_graph(q, internal_node_names());
@@ -571,18 +521,8 @@ fn check_graph_using_reserved_names() {
 #[test]
 fn check_graph_duplicate_name() {
     let q = &[
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "a".to_string(),
-            target: "TotalEmissions".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 2,
-            source: "a".to_string(),
-            target: "DirectEmissions".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(1, "a", "TotalEmissions", 1.2)),
+        CustomEmission::EdgeDefined(edge_in_tons(2, "a", "DirectEmissions", 1.2)),
     ];
     let r = check_graph(q, internal_node_names());
     match r {
@@ -601,13 +541,9 @@ fn check_graph_edge_to_leaf() {
             line: 1,
             source: "a".to_string(),
             target: "b".to_string(),
+            label: None,
         }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 2,
-            source: "b".to_string(),
-            target: "DirectEmissions".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(2, "b", "DirectEmissions", 1.2)),
     ];
     let r = check_graph(q, internal_node_names());
     match r {
@@ -622,37 +558,332 @@ fn check_graph_edge_to_leaf() {
 #[test]
 fn check_graph_example() {
     let q = &[
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 1,
-            source: "H₂ Generator".to_string(),
-            target: "OtherIndirectEmissions".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 2,
-            source: "Fällmittel (AI)".to_string(),
-            target: "fällmittel".to_string(),
-            value: 1.2,
-        }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 3,
-            source: "Fällmittel (Eisen(II)-sulfat)".to_string(),
-            target: "fällmittel".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(
+            1,
+            "H₂ Generator",
+            "OtherIndirectEmissions",
+            1.2,
+        )),
+        CustomEmission::EdgeDefined(edge_in_tons(2, "Fällmittel (AI)", "fällmittel", 1.2)),
+        CustomEmission::EdgeDefined(edge_in_tons(
+            3,
+            "Fällmittel (Eisen(II)-sulfat)",
+            "fällmittel",
+            1.2,
+        )),
         CustomEmission::EdgeUndefined(EdgeUndefined {
             line: 4,
             source: "fällmittel".to_string(),
             target: "TotalEmissions".to_string(),
+            label: None,
         }),
-        CustomEmission::EdgeDefined(EdgeDefined {
-            line: 5,
-            source: "Flüssiggas".to_string(),
-            target: "DirectEmissions".to_string(),
-            value: 1.2,
-        }),
+        CustomEmission::EdgeDefined(edge_in_tons(5, "Flüssiggas", "DirectEmissions", 1.2)),
     ];
     let r = check_graph(q, internal_node_names());
     println!("{r:?}");
     assert!(r.is_ok());
 }
+
+#[test]
+fn custom_values_from_emissions() {
+    let input = "\"Kantine\" 1,5 \"verbrauch\"\n\"verbrauch\" \"IndirectEmissions\"";
+    let CustomValues {
+        values,
+        edges,
+        leafs,
+        ..
+    } = parse_custom_values(input, Lng::De).unwrap();
+    assert_eq!(
+        values,
+        vec![(Id::from("Kantine".to_string()), Value::tons(1.5))]
+    );
+    assert_eq!(
+        edges,
+        vec![
+            (
+                Id::from("Kantine".to_string()),
+                Id::from("verbrauch".to_string())
+            ),
+            (
+                Id::from("verbrauch".to_string()),
+                Id::from(klick_domain::OutputValueId::IndirectEmissions)
+            ),
+        ]
+    );
+    assert_eq!(leafs, vec![Id::from("Kantine".to_string())]);
+}
+
+#[test]
+fn parse_custom_values_with_invalid_graph() {
+    let input = "\"TotalEmissions\" 1,5 \"IndirectEmissions\"";
+    assert!(parse_custom_values(input, Lng::De).is_err());
+}
+
+#[test]
+fn edge_defined_with_unit() {
+    let input = "\"Lachgas\" 2,5 kg N₂O \"N2oEmissions\"";
+    let output = parse_emission(input, Lng::De).unwrap();
+    let edges = vec![CustomEmission::EdgeDefined(EdgeDefined {
+        unit: FloatType::kilograms(),
+        gas: Gas::N2o,
+        ..edge_in_tons(1, "Lachgas", "N2oEmissions", 2.5)
+    })];
+    assert_eq!(output, edges);
+}
+
+#[test]
+fn edge_defined_with_factor() {
+    let input = "\"Notstromdiesel\" 1200 l * 2.65 kg/l \"IndirectEmissions\"";
+    let output = parse_emission(input, Lng::En).unwrap();
+    let edges = vec![CustomEmission::EdgeDefined(EdgeDefined {
+        unit: FloatType::liters(),
+        factor: Some(Float::kilograms_per_liter(2.65)),
+        ..edge_in_tons(1, "Notstromdiesel", "IndirectEmissions", 1200.0)
+    })];
+    assert_eq!(output, edges);
+}
+
+#[test]
+fn co2_equivalents() {
+    let co2_equivalents = |input: &str| {
+        let emissions = parse_emission(input, Lng::De).unwrap();
+        let CustomEmission::EdgeDefined(edge) = &emissions[0] else {
+            panic!("expected an emission value");
+        };
+        f64::from(edge.co2_equivalents().unwrap().round(6))
+    };
+    assert_eq!(co2_equivalents("\"a\" 1,5 \"b\""), 1.5);
+    assert_eq!(co2_equivalents("\"a\" 1,5 t CO₂e \"b\""), 1.5);
+    assert_eq!(co2_equivalents("\"a\" 500 kg \"b\""), 0.5);
+    assert_eq!(co2_equivalents("\"a\" 1000 kg CH₄ \"b\""), 28.0);
+    assert_eq!(co2_equivalents("\"a\" 1000 kg N2O \"b\""), 273.0);
+    assert_eq!(co2_equivalents("\"a\" 1200 l * 2,65 kg/l \"b\""), 3.18);
+    assert_eq!(co2_equivalents("\"a\" 2 m³ * 500 kg/m3 \"b\""), 1.0);
+    assert_eq!(co2_equivalents("\"a\" 10000 kWh * 0,4 kg/kWh \"b\""), 4.0);
+    assert_eq!(co2_equivalents("\"a\" 10000 kWh * 400 g/kWh \"b\""), 4.0);
+    assert_eq!(co2_equivalents("\"a\" 100 m³ * 0,5 kg/m³ CH₄ \"b\""), 1.4);
+}
+
+#[test]
+fn invalid_units() {
+    for input in [
+        "\"a\" 1 parsec \"b\"",
+        "\"a\" 1200 l \"b\"",
+        "\"a\" 1200 l * 2,65 \"b\"",
+        "\"a\" 1200 l * 2,65 g/kWh \"b\"",
+        "\"a\" 1200 kg * 2,65 kg/l \"b\"",
+        "\"a\" 1200 kg SF6 \"b\"",
+        "\"a\" 1200 kg CH₄ N₂O \"b\"",
+    ] {
+        assert!(parse_emission(input, Lng::De).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn custom_values_with_units() {
+    let input = "\"Notstromdiesel\" 1000 l * 2,5 kg/l \"IndirectEmissions\"";
+    let CustomValues { values, .. } = parse_custom_values(input, Lng::De).unwrap();
+    assert_eq!(
+        values,
+        vec![(Id::from("Notstromdiesel".to_string()), Value::tons(2.5))]
+    );
+}
+
+#[test]
+fn comments() {
+    let input = "# Kantine\n\"Kantine\" 1,5 \"verbrauch\" # laut Rechnung 2023\n  # \"verbrauch\" \"DirectEmissions\"\n\"verbrauch\" \"IndirectEmissions\"";
+    let output = parse_emission(input, Lng::De).unwrap();
+    assert_eq!(output.len(), 2);
+    assert_eq!(output[0].line(), 2);
+    assert_eq!(output[1].line(), 4);
+    assert_eq!(output[1].target(), "IndirectEmissions");
+}
+
+#[test]
+fn hash_within_quotes() {
+    let input = "\"Pumpe #2\" 1 \"TotalEmissions\"";
+    let output = parse_emission(input, Lng::De).unwrap();
+    assert_eq!(output[0].source(), "Pumpe #2");
+}
+
+#[test]
+fn labels_and_citations() {
+    let input = "\"kantine\" as \"Kantine/Sozialgebäude\" \"IndirectEmissions\"\n\"diesel\" as \"Notstromdiesel\" 1200 l * 2,65 kg/l \"kantine\" cite \"UBA 2023\"";
+    let output = parse_emission(input, Lng::De).unwrap();
+    assert_eq!(
+        output,
+        vec![
+            CustomEmission::EdgeUndefined(EdgeUndefined {
+                line: 1,
+                source: "kantine".to_string(),
+                target: "IndirectEmissions".to_string(),
+                label: Some("Kantine/Sozialgebäude".to_string()),
+            }),
+            CustomEmission::EdgeDefined(EdgeDefined {
+                label: Some("Notstromdiesel".to_string()),
+                unit: FloatType::liters(),
+                factor: Some(Float::kilograms_per_liter(2.65)),
+                citation: Some("UBA 2023".to_string()),
+                ..edge_in_tons(2, "diesel", "kantine", 1200.0)
+            }),
+        ]
+    );
+
+    let CustomValues {
+        labels, citations, ..
+    } = custom_values(&output);
+    assert_eq!(
+        labels.get(&Id::from("kantine".to_string())).unwrap(),
+        "Kantine/Sozialgebäude"
+    );
+    assert_eq!(
+        labels.get(&Id::from("diesel".to_string())).unwrap(),
+        "Notstromdiesel"
+    );
+    assert_eq!(
+        citations,
+        vec![(Id::from("diesel".to_string()), "UBA 2023".to_string())]
+    );
+}
+
+#[test]
+fn invalid_labels_and_citations() {
+    for input in [
+        "\"a\" as \"b\"",
+        "\"a\" as 1 \"b\"",
+        "\"a\" as \"\" 1 \"b\"",
+        "\"a\" 1 \"b\" cite",
+        "\"a\" 1 \"b\" cite \"c\" \"d\"",
+        "\"a\" \"b\" cite \"c\"",
+        "\"a\" 1 \"b",
+    ] {
+        assert!(parse_emission(input, Lng::De).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn recommendations() {
+    let input = "\"diesel\" 1200 l * 2,5 kg/l \"IndirectEmissions\" recommend -30 % cite \"UBA\"\n\"heizung\" 4 \"IndirectEmissions\" recommend 500 l * 2 kg/l\n\"lachgas\" 1 kg N₂O \"N2oEmissions\" recommend -100%";
+    let output = parse_emission(input, Lng::De).unwrap();
+    let recommendations = output
+        .iter()
+        .map(|emission| match emission {
+            CustomEmission::EdgeDefined(edge) => edge.recommendation,
+            CustomEmission::EdgeUndefined(_) => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        recommendations,
+        vec![
+            Some(CustomRecommendation::Reduction(Percent::new(30.0))),
+            Some(CustomRecommendation::Absolute(Tons::new(1.0))),
+            Some(CustomRecommendation::Reduction(Percent::new(100.0))),
+        ]
+    );
+
+    let CustomValues {
+        recommendations, ..
+    } = custom_values(&output);
+    assert_eq!(
+        recommendations[0],
+        (
+            Id::from("diesel".to_string()),
+            CustomRecommendation::Reduction(Percent::new(30.0))
+        )
+    );
+    assert_eq!(recommendations.len(), 3);
+}
+
+#[test]
+fn invalid_recommendations() {
+    for input in [
+        "\"a\" 1 \"b\" recommend",
+        "\"a\" 1 \"b\" recommend 30 %",
+        "\"a\" 1 \"b\" recommend -130 %",
+        "\"a\" 1 \"b\" recommend 1 parsec",
+        "\"a\" 1 \"b\" cite \"c\" recommend 1",
+        "\"a\" \"b\" recommend 1",
+    ] {
+        assert!(parse_emission(input, Lng::De).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn collect_all_line_errors() {
+    let input = "\"a\" 1 \"TotalEmissions\"\n\"b\" 1 parsec \"TotalEmissions\"\n\n  c 1 \"TotalEmissions\"\n\"d";
+    let diagnostics = parse_emission(input, Lng::En).unwrap_err();
+    let positions = diagnostics
+        .iter()
+        .map(|d| (d.line, d.columns.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(positions, vec![(2, 4..12), (4, 2..3), (5, 0..2)]);
+    assert!(diagnostics[0].message.contains("parsec"));
+}
+
+#[test]
+fn columns_are_counted_in_characters() {
+    let input = "\"Fällmittel\" 1 \"TotalEmissions\" \"x\"";
+    let diagnostics = parse_emission(input, Lng::De).unwrap_err();
+    assert_eq!(diagnostics[0].columns, 32..35);
+}
+
+#[test]
+fn collect_all_graph_errors() {
+    let input = "\"TotalEmissions\" 1 \"DirectEmissions\"\n\"a\" 1 \"a\"\n\"b\" \"c\"\n\"c\" 2 \"TotalEmissions\"";
+    let diagnostics = parse_custom_values(input, Lng::En).unwrap_err();
+    let lines = diagnostics.iter().map(|d| d.line).collect::<Vec<_>>();
+    assert_eq!(lines, vec![1, 2, 3, 4, 4, 1]);
+    assert_eq!(diagnostics[1].columns, 0..9);
+}
+
+#[test]
+fn node_names() {
+    let input = "\"b\" 1 \"a\"\n\"a\" \"TotalEmissions\"\n\"c\" invalid \"d\"";
+    assert_eq!(custom_node_names(input), vec!["a", "b"]);
+}
+
+#[test]
+fn custom_emissions_roundtrip() {
+    let input = "# Betrieb\n\
+        \"diesel\" as \"Notstromdiesel\" 1200,5 l * 2,65 kg/l \"IndirectEmissions\" recommend -30 %\n\
+        \"Lachgasmessung\" 12,5 kg N₂O \"N2oEmissions\" cite \"Messung 2023\"";
+    let emissions = parse_custom_emissions(input, Lng::De).unwrap();
+    assert_eq!(emissions.edges.len(), 2);
+    assert_eq!(format_custom_emissions(&emissions, Lng::De), input);
+
+    let input_en = format_custom_emissions(&emissions, Lng::En);
+    assert!(input_en.contains("1200.5 l * 2.65 kg/l"));
+    assert_eq!(
+        parse_custom_emissions(&input_en, Lng::En).unwrap(),
+        emissions
+    );
+
+    assert_eq!(
+        custom_values_from_structure(&emissions, Lng::En).unwrap(),
+        parse_custom_values(input, Lng::De).unwrap()
+    );
+}
+
+#[test]
+fn invalid_custom_emissions() {
+    let diagnostics = parse_custom_emissions("\"a\" 1 l \"N2oEmissions\"", Lng::En).unwrap_err();
+    assert_eq!(diagnostics.len(), 1);
+
+    let emissions = CustomEmissions::from_text("\"a\" \"b\"", DecimalSeparator::Point).unwrap();
+    let diagnostics = custom_values_from_structure(&emissions, Lng::En).unwrap_err();
+    assert_eq!(diagnostics[0].line, 1);
+
+    let mut emissions = CustomEmissions::from_text(
+        "\"a\" 1 \"TotalEmissions\"\n# comment\n\"b\" 2 l * 0.8 kg/l \"TotalEmissions\"",
+        DecimalSeparator::Point,
+    )
+    .unwrap();
+    emissions.edges[1].value.as_mut().unwrap().unit = Some("kWh".to_string());
+    let diagnostics = custom_values_from_structure(&emissions, Lng::En).unwrap_err();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].line, 3);
+    assert_eq!(
+        diagnostics[0].message,
+        "The unit \"kWh\" does not match the emission factor in \"kg/l\" on line \"3\""
+    );
+}
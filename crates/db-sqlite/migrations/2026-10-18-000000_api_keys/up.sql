@@ -0,0 +1,16 @@
+CREATE TABLE IF NOT EXISTS api_keys (
+    rowid         INTEGER NOT NULL,
+    api_key_id    TEXT    NOT NULL,
+    account_rowid INTEGER NOT NULL,
+    name          TEXT,
+    secret_hash   TEXT    NOT NULL, -- SHA-256 hash of the secret
+    scopes        TEXT,             -- Comma separated list, NULL grants all scopes
+    created_at    INTEGER NOT NULL,
+    expires_at    INTEGER,
+    --
+    UNIQUE        (api_key_id),
+    UNIQUE        (secret_hash),
+    --
+    PRIMARY KEY   (rowid),
+    FOREIGN KEY   (account_rowid) REFERENCES accounts(rowid) ON DELETE CASCADE
+);
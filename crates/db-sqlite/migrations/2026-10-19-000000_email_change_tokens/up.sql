@@ -0,0 +1,13 @@
+CREATE TABLE IF NOT EXISTS email_change_tokens (
+    rowid         INTEGER NOT NULL,
+    account_rowid INTEGER NOT NULL,
+    new_email     TEXT    NOT NULL COLLATE NOCASE,
+    nonce         TEXT    NOT NULL,
+    expires_at    INTEGER NOT NULL,
+    --
+    UNIQUE        (account_rowid),
+    UNIQUE        (nonce),
+    --
+    PRIMARY KEY   (rowid),
+    FOREIGN KEY   (account_rowid) REFERENCES accounts(rowid) ON DELETE CASCADE
+);
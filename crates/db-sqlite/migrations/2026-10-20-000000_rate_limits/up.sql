@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS rate_limits (
+    rowid         INTEGER NOT NULL,
+    key           TEXT    NOT NULL, -- e.g. 'login:ip:127.0.0.1'
+    attempts      INTEGER NOT NULL,
+    window_start  INTEGER NOT NULL,
+    blocked_until INTEGER,
+    --
+    UNIQUE        (key),
+    --
+    PRIMARY KEY   (rowid)
+);
@@ -45,18 +45,55 @@ pub fn insert_or_update_account(
     Ok(())
 }
 
-pub fn delete_account_from_db(
+pub fn update_account_email(
     conn: &mut SqliteConnection,
-    email: &EmailAddress,
+    current_email: &EmailAddress,
+    new_email: &EmailAddress,
 ) -> anyhow::Result<()> {
     use schema::accounts::dsl;
 
-    diesel::delete(dsl::accounts)
-        .filter(dsl::email.eq(email.as_str()))
+    let count = diesel::update(dsl::accounts)
+        .filter(dsl::email.eq(current_email.as_str()))
+        .set((
+            dsl::email.eq(new_email.as_str()),
+            dsl::email_confirmed.eq(true),
+        ))
         .execute(conn)?;
+    if count == 0 {
+        anyhow::bail!("account not found");
+    }
     Ok(())
 }
 
+pub fn delete_account_from_db(
+    conn: &mut SqliteConnection,
+    email: &EmailAddress,
+) -> anyhow::Result<()> {
+    use schema::{account_tokens, accounts, api_keys, email_change_tokens, projects};
+
+    // Foreign key constraints are not enforced by SQLite by default,
+    // so we have to delete the dependent records explicitly.
+    conn.transaction(|conn| {
+        let account_rowid = resolve_account_rowid_created_by_email(conn, email)?;
+        diesel::delete(account_tokens::table)
+            .filter(account_tokens::account_rowid.eq(account_rowid))
+            .execute(conn)?;
+        diesel::delete(email_change_tokens::table)
+            .filter(email_change_tokens::account_rowid.eq(account_rowid))
+            .execute(conn)?;
+        diesel::delete(api_keys::table)
+            .filter(api_keys::account_rowid.eq(account_rowid))
+            .execute(conn)?;
+        diesel::delete(projects::table)
+            .filter(projects::account_rowid.eq(account_rowid))
+            .execute(conn)?;
+        diesel::delete(accounts::table)
+            .filter(accounts::rowid.eq(account_rowid))
+            .execute(conn)?;
+        Ok(())
+    })
+}
+
 pub fn resolve_account_rowid_created_by_email(
     conn: &mut SqliteConnection,
     email: &EmailAddress,
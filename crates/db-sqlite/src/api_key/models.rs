@@ -0,0 +1,78 @@
+use diesel::prelude::*;
+use time::OffsetDateTime;
+
+use klick_domain::{ApiKey, ApiKeyScope, EmailAddress};
+
+use crate::schema;
+
+#[derive(Debug, Queryable)]
+#[diesel(table_name = schema::api_keys)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct ApiKeyQuery {
+    pub api_key_id: String,
+    pub name: Option<String>,
+    pub scopes: Option<String>,
+    pub created_at: i64,
+    pub expires_at: Option<i64>,
+    // Joined columns
+    pub account_email: String,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = schema::api_keys)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct NewApiKey<'a> {
+    pub api_key_id: &'a str,
+    pub account_rowid: i64,
+    pub name: Option<&'a str>,
+    pub secret_hash: &'a str,
+    pub scopes: Option<String>,
+    pub created_at: i64,
+    pub expires_at: Option<i64>,
+}
+
+impl TryFrom<ApiKeyQuery> for (ApiKey, EmailAddress) {
+    type Error = anyhow::Error;
+
+    fn try_from(from: ApiKeyQuery) -> Result<Self, Self::Error> {
+        let ApiKeyQuery {
+            api_key_id,
+            name,
+            scopes,
+            created_at,
+            expires_at,
+            account_email,
+        } = from;
+        let id = api_key_id.parse()?;
+        let scopes = scopes.as_deref().map(scopes_from_str).transpose()?;
+        let created_at = OffsetDateTime::from_unix_timestamp(created_at)?;
+        let expires_at = expires_at
+            .map(OffsetDateTime::from_unix_timestamp)
+            .transpose()?;
+        let api_key = ApiKey {
+            id,
+            name,
+            scopes,
+            created_at,
+            expires_at,
+        };
+        let owner = EmailAddress::new_unchecked(account_email);
+        Ok((api_key, owner))
+    }
+}
+
+pub fn scopes_to_string(scopes: &[ApiKeyScope]) -> String {
+    scopes
+        .iter()
+        .map(ApiKeyScope::as_str)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn scopes_from_str(scopes: &str) -> anyhow::Result<Vec<ApiKeyScope>> {
+    scopes
+        .split(',')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().map_err(anyhow::Error::from))
+        .collect()
+}
@@ -0,0 +1,96 @@
+use diesel::{prelude::*, sqlite::SqliteConnection};
+
+use klick_domain::{ApiKey, ApiKeyId, EmailAddress, HashedApiKeySecret};
+use klick_interfaces::ApiKeyRecord;
+
+use crate::{account, api_key::models, schema};
+
+pub fn insert_api_key(
+    conn: &mut SqliteConnection,
+    record: &ApiKeyRecord,
+    owner: &EmailAddress,
+) -> anyhow::Result<()> {
+    use schema::api_keys::dsl;
+
+    let ApiKeyRecord {
+        api_key,
+        hashed_secret,
+    } = record;
+    let account_rowid = account::queries::resolve_account_rowid_created_by_email(conn, owner)?;
+    let api_key_id = api_key.id.to_string();
+    let model = models::NewApiKey {
+        api_key_id: &api_key_id,
+        account_rowid,
+        name: api_key.name.as_deref(),
+        secret_hash: hashed_secret.as_str(),
+        scopes: api_key.scopes.as_deref().map(models::scopes_to_string),
+        created_at: api_key.created_at.unix_timestamp(),
+        expires_at: api_key.expires_at.map(time::OffsetDateTime::unix_timestamp),
+    };
+    diesel::insert_into(dsl::api_keys)
+        .values(model)
+        .execute(conn)?;
+    Ok(())
+}
+
+pub fn find_api_key_by_hash(
+    conn: &mut SqliteConnection,
+    hashed_secret: &HashedApiKeySecret,
+) -> anyhow::Result<Option<(ApiKey, EmailAddress)>> {
+    use schema::{accounts::dsl as a_dsl, api_keys::dsl as k_dsl};
+
+    let result = k_dsl::api_keys
+        .inner_join(a_dsl::accounts)
+        .select((
+            k_dsl::api_key_id,
+            k_dsl::name,
+            k_dsl::scopes,
+            k_dsl::created_at,
+            k_dsl::expires_at,
+            a_dsl::email,
+        ))
+        .filter(k_dsl::secret_hash.eq(hashed_secret.as_str()))
+        .first::<models::ApiKeyQuery>(conn)
+        .optional()?;
+    result.map(TryFrom::try_from).transpose()
+}
+
+pub fn all_api_keys_by_owner(
+    conn: &mut SqliteConnection,
+    owner: &EmailAddress,
+) -> anyhow::Result<Vec<ApiKey>> {
+    use schema::{accounts::dsl as a_dsl, api_keys::dsl as k_dsl};
+
+    let results = k_dsl::api_keys
+        .inner_join(a_dsl::accounts)
+        .select((
+            k_dsl::api_key_id,
+            k_dsl::name,
+            k_dsl::scopes,
+            k_dsl::created_at,
+            k_dsl::expires_at,
+            a_dsl::email,
+        ))
+        .filter(a_dsl::email.eq(owner.as_str()))
+        .order_by(k_dsl::created_at)
+        .load::<models::ApiKeyQuery>(conn)?;
+    results
+        .into_iter()
+        .map(|record| <(ApiKey, EmailAddress)>::try_from(record).map(|(api_key, _)| api_key))
+        .collect()
+}
+
+pub fn delete_api_key(
+    conn: &mut SqliteConnection,
+    id: &ApiKeyId,
+    owner: &EmailAddress,
+) -> anyhow::Result<bool> {
+    use schema::api_keys::dsl;
+
+    let account_rowid = account::queries::resolve_account_rowid_created_by_email(conn, owner)?;
+    let count = diesel::delete(dsl::api_keys)
+        .filter(dsl::api_key_id.eq(id.to_string()))
+        .filter(dsl::account_rowid.eq(account_rowid))
+        .execute(conn)?;
+    Ok(count > 0)
+}
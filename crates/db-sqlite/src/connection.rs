@@ -8,10 +8,16 @@ use thiserror::Error;
 use time::OffsetDateTime;
 
 use klick_boundary as boundary;
-use klick_domain::{self as domain, AccountToken, EmailAddress, EmailNonce, ProjectId};
-use klick_interfaces::{AccountRecord, AccountRepo, AccountTokenRepo, ProjectRepo};
+use klick_domain::{
+    self as domain, AccountToken, ApiKey, ApiKeyId, EmailAddress, EmailChangeToken, EmailNonce,
+    HashedApiKeySecret, ProjectId,
+};
+use klick_interfaces::{
+    AccountRecord, AccountRepo, AccountTokenRepo, ApiKeyRecord, ApiKeyRepo, EmailChangeTokenRepo,
+    ProjectRepo, RateLimitRecord, RateLimitRepo,
+};
 
-use crate::{account, account_token, project};
+use crate::{account, account_token, api_key, email_change_token, project, rate_limit};
 
 type Project = domain::Project<boundary::JsonFormData>;
 
@@ -87,6 +93,14 @@ impl AccountRepo for Connection {
         account::queries::insert_or_update_account(&mut self.0.lock(), record.try_into()?)
     }
 
+    fn change_email_address(
+        &self,
+        current_email: &EmailAddress,
+        new_email: &EmailAddress,
+    ) -> anyhow::Result<()> {
+        account::queries::update_account_email(&mut self.0.lock(), current_email, new_email)
+    }
+
     fn delete_account(&self, email: &EmailAddress) -> anyhow::Result<()> {
         account::queries::delete_account_from_db(&mut self.0.lock(), email)
     }
@@ -113,6 +127,50 @@ impl AccountTokenRepo for Connection {
     }
 }
 
+impl EmailChangeTokenRepo for Connection {
+    fn replace_email_change_token(&self, token: &EmailChangeToken) -> anyhow::Result<()> {
+        email_change_token::queries::replace_email_change_token(&mut self.0.lock(), token)
+    }
+
+    fn consume_email_change_token(
+        &self,
+        email_nonce: &EmailNonce,
+    ) -> anyhow::Result<EmailChangeToken> {
+        email_change_token::queries::consume_email_change_token(&mut self.0.lock(), email_nonce)
+    }
+
+    fn get_email_change_token_by_account(
+        &self,
+        current_email: &EmailAddress,
+    ) -> anyhow::Result<EmailChangeToken> {
+        email_change_token::queries::email_change_token_by_account(
+            &mut self.0.lock(),
+            current_email,
+        )
+    }
+}
+
+impl ApiKeyRepo for Connection {
+    fn save_api_key(&self, record: &ApiKeyRecord, owner: &EmailAddress) -> anyhow::Result<()> {
+        api_key::queries::insert_api_key(&mut self.0.lock(), record, owner)
+    }
+
+    fn find_api_key_by_hash(
+        &self,
+        hashed_secret: &HashedApiKeySecret,
+    ) -> anyhow::Result<Option<(ApiKey, EmailAddress)>> {
+        api_key::queries::find_api_key_by_hash(&mut self.0.lock(), hashed_secret)
+    }
+
+    fn all_api_keys_by_owner(&self, owner: &EmailAddress) -> anyhow::Result<Vec<ApiKey>> {
+        api_key::queries::all_api_keys_by_owner(&mut self.0.lock(), owner)
+    }
+
+    fn delete_api_key(&self, id: &ApiKeyId, owner: &EmailAddress) -> anyhow::Result<bool> {
+        api_key::queries::delete_api_key(&mut self.0.lock(), id, owner)
+    }
+}
+
 impl ProjectRepo<boundary::JsonFormData> for Connection {
     fn find_project(&self, id: &ProjectId) -> Result<Option<Project>, anyhow::Error> {
         project::queries::find_project(&mut self.0.lock(), id)
@@ -123,10 +181,37 @@ impl ProjectRepo<boundary::JsonFormData> for Connection {
     }
 
     fn save_project(&self, project: Project, owner: &EmailAddress) -> Result<(), anyhow::Error> {
-        project::queries::save_project(&mut self.0.lock(), project, owner)
+        project::queries::insert_project(&mut self.0.lock(), project, owner)
+    }
+
+    fn update_project(
+        &self,
+        project: Project,
+        owner: &EmailAddress,
+        modified_at: Option<OffsetDateTime>,
+    ) -> anyhow::Result<bool> {
+        project::queries::update_project(&mut self.0.lock(), project, owner, modified_at)
     }
 
     fn delete_project(&self, id: &ProjectId) -> Result<(), anyhow::Error> {
         project::queries::delete_project(&mut self.0.lock(), id)
     }
 }
+
+impl RateLimitRepo for Connection {
+    fn find_rate_limit(&self, key: &str) -> anyhow::Result<Option<RateLimitRecord>> {
+        rate_limit::queries::find_rate_limit(&mut self.0.lock(), key)
+    }
+
+    fn save_rate_limit(&self, key: &str, record: &RateLimitRecord) -> anyhow::Result<()> {
+        rate_limit::queries::insert_or_update_rate_limit(&mut self.0.lock(), key, record)
+    }
+
+    fn delete_rate_limit(&self, key: &str) -> anyhow::Result<()> {
+        rate_limit::queries::delete_rate_limit(&mut self.0.lock(), key)
+    }
+
+    fn delete_stale_rate_limits(&self, before: OffsetDateTime) -> anyhow::Result<usize> {
+        rate_limit::queries::delete_stale_rate_limits(&mut self.0.lock(), before)
+    }
+}
@@ -0,0 +1,51 @@
+use diesel::prelude::*;
+use time::OffsetDateTime;
+
+use klick_domain::{EmailAddress, EmailChangeToken, EmailNonce, Nonce};
+
+use crate::schema;
+
+#[derive(Queryable)]
+#[diesel(table_name = schema::email_change_tokens)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct EmailChangeTokenQuery {
+    pub new_email: String,
+    pub nonce: String,
+    pub expires_at: i64,
+    // Joined columns
+    pub account_email: String,
+}
+
+#[derive(Clone, Insertable, AsChangeset)]
+#[diesel(table_name = schema::email_change_tokens)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct NewEmailChangeToken<'a> {
+    pub account_rowid: i64,
+    pub new_email: &'a str,
+    pub nonce: &'a str,
+    pub expires_at: i64,
+}
+
+impl TryFrom<EmailChangeTokenQuery> for EmailChangeToken {
+    type Error = anyhow::Error;
+    fn try_from(from: EmailChangeTokenQuery) -> Result<Self, Self::Error> {
+        let EmailChangeTokenQuery {
+            new_email,
+            nonce,
+            expires_at,
+            account_email,
+        } = from;
+
+        let current_email = EmailAddress::new_unchecked(account_email);
+        let email = EmailAddress::new_unchecked(new_email);
+        let nonce = nonce.parse::<Nonce>()?;
+        let email_nonce = EmailNonce { email, nonce };
+        let expires_at = OffsetDateTime::from_unix_timestamp(expires_at)?;
+
+        Ok(Self {
+            current_email,
+            email_nonce,
+            expires_at,
+        })
+    }
+}
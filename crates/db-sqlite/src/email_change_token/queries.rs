@@ -0,0 +1,74 @@
+use diesel::{prelude::*, sqlite::SqliteConnection};
+
+use klick_domain::{EmailAddress, EmailChangeToken, EmailNonce};
+
+use crate::{account, email_change_token::models, schema};
+
+pub fn replace_email_change_token(
+    conn: &mut SqliteConnection,
+    token: &EmailChangeToken,
+) -> anyhow::Result<()> {
+    use schema::email_change_tokens::dsl;
+
+    let account_rowid =
+        account::queries::resolve_account_rowid_created_by_email(conn, &token.current_email)?;
+    let nonce = &token.email_nonce.nonce.to_string();
+    let model = models::NewEmailChangeToken {
+        account_rowid,
+        new_email: token.email_nonce.email.as_str(),
+        nonce,
+        expires_at: token.expires_at.unix_timestamp(),
+    };
+
+    diesel::insert_into(dsl::email_change_tokens)
+        .values(model.clone())
+        .on_conflict(dsl::account_rowid)
+        .do_update()
+        .set(model)
+        .execute(conn)?;
+    Ok(())
+}
+
+pub fn consume_email_change_token(
+    conn: &mut SqliteConnection,
+    email_nonce: &EmailNonce,
+) -> anyhow::Result<EmailChangeToken> {
+    use schema::{accounts::dsl as a_dsl, email_change_tokens::dsl as t_dsl};
+
+    let token = t_dsl::email_change_tokens
+        .inner_join(a_dsl::accounts)
+        .select((
+            t_dsl::new_email,
+            t_dsl::nonce,
+            t_dsl::expires_at,
+            a_dsl::email,
+        ))
+        .filter(t_dsl::nonce.eq(email_nonce.nonce.to_string()))
+        .filter(t_dsl::new_email.eq(email_nonce.email.as_str()))
+        .first::<models::EmailChangeTokenQuery>(conn)?;
+    let token = EmailChangeToken::try_from(token)?;
+
+    diesel::delete(t_dsl::email_change_tokens)
+        .filter(t_dsl::nonce.eq(email_nonce.nonce.to_string()))
+        .execute(conn)?;
+    Ok(token)
+}
+
+pub fn email_change_token_by_account(
+    conn: &mut SqliteConnection,
+    current_email: &EmailAddress,
+) -> anyhow::Result<EmailChangeToken> {
+    use schema::{accounts::dsl as a_dsl, email_change_tokens::dsl as t_dsl};
+
+    let token = t_dsl::email_change_tokens
+        .inner_join(a_dsl::accounts)
+        .select((
+            t_dsl::new_email,
+            t_dsl::nonce,
+            t_dsl::expires_at,
+            a_dsl::email,
+        ))
+        .filter(a_dsl::email.eq(current_email.as_str()))
+        .first::<models::EmailChangeTokenQuery>(conn)?;
+    EmailChangeToken::try_from(token)
+}
@@ -1,7 +1,10 @@
 mod account;
 mod account_token;
+mod api_key;
 mod connection;
+mod email_change_token;
 mod project;
+mod rate_limit;
 mod schema;
 
 pub use self::connection::Connection;
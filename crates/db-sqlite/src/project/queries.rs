@@ -1,4 +1,5 @@
 use diesel::{prelude::*, sqlite::SqliteConnection};
+use time::OffsetDateTime;
 
 use klick_boundary as boundary;
 use klick_domain::{self as domain, EmailAddress, ProjectId};
@@ -60,7 +61,7 @@ pub fn all_projects_by_owner(
     Ok(projects)
 }
 
-pub fn save_project(
+pub fn insert_project(
     conn: &mut SqliteConnection,
     project: Project,
     owner: &EmailAddress,
@@ -77,14 +78,47 @@ pub fn save_project(
         data: &data,
     };
     diesel::insert_into(dsl::projects)
-        .values(changeset.clone())
-        .on_conflict(dsl::project_id)
-        .do_update()
-        .set(changeset)
+        .values(changeset)
         .execute(conn)?;
     Ok(())
 }
 
+pub fn update_project(
+    conn: &mut SqliteConnection,
+    project: Project,
+    owner: &EmailAddress,
+    modified_at: Option<OffsetDateTime>,
+) -> Result<bool, anyhow::Error> {
+    use schema::projects::dsl;
+
+    let project_id = project.id.to_string();
+    let account_rowid = account::queries::resolve_account_rowid_created_by_email(conn, owner)?;
+    let current = dsl::projects
+        .filter(dsl::project_id.eq(&project_id))
+        .filter(dsl::account_rowid.eq(account_rowid))
+        .select(models::ProjectQuery::as_select())
+        .first(conn)
+        .optional()?;
+    let Some(current) = current else {
+        return Ok(false);
+    };
+    let current_data = current.data.clone();
+    if Project::try_from(current)?.modified_at != modified_at {
+        return Ok(false);
+    }
+    let data = models::project_to_json_string(project)?;
+
+    // The modification time is part of the serialized data,
+    // so the update only succeeds if nobody changed it in the meantime.
+    let count = diesel::update(dsl::projects)
+        .filter(dsl::project_id.eq(&project_id))
+        .filter(dsl::account_rowid.eq(account_rowid))
+        .filter(dsl::data.eq(&current_data))
+        .set(dsl::data.eq(&data))
+        .execute(conn)?;
+    Ok(count > 0)
+}
+
 pub fn delete_project(conn: &mut SqliteConnection, id: &ProjectId) -> Result<(), anyhow::Error> {
     use schema::projects::dsl;
 
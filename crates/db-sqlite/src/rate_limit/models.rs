@@ -0,0 +1,63 @@
+use diesel::prelude::*;
+use time::OffsetDateTime;
+
+use klick_interfaces::RateLimitRecord;
+
+use crate::schema;
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = schema::rate_limits)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct RateLimitQuery {
+    pub attempts: i32,
+    pub window_start: i64,
+    pub blocked_until: Option<i64>,
+}
+
+#[derive(Clone, Insertable, AsChangeset)]
+#[diesel(table_name = schema::rate_limits)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+#[diesel(treat_none_as_null = true)]
+pub struct RateLimitChangeset<'a> {
+    pub key: &'a str,
+    pub attempts: i32,
+    pub window_start: i64,
+    pub blocked_until: Option<i64>,
+}
+
+impl TryFrom<RateLimitQuery> for RateLimitRecord {
+    type Error = anyhow::Error;
+    fn try_from(from: RateLimitQuery) -> Result<Self, Self::Error> {
+        let RateLimitQuery {
+            attempts,
+            window_start,
+            blocked_until,
+        } = from;
+        let attempts = u32::try_from(attempts)?;
+        let window_start = OffsetDateTime::from_unix_timestamp(window_start)?;
+        let blocked_until = blocked_until
+            .map(OffsetDateTime::from_unix_timestamp)
+            .transpose()?;
+        Ok(Self {
+            attempts,
+            window_start,
+            blocked_until,
+        })
+    }
+}
+
+impl<'a> RateLimitChangeset<'a> {
+    pub fn new(key: &'a str, record: &RateLimitRecord) -> anyhow::Result<Self> {
+        let RateLimitRecord {
+            attempts,
+            window_start,
+            blocked_until,
+        } = record;
+        Ok(Self {
+            key,
+            attempts: i32::try_from(*attempts)?,
+            window_start: window_start.unix_timestamp(),
+            blocked_until: blocked_until.map(OffsetDateTime::unix_timestamp),
+        })
+    }
+}
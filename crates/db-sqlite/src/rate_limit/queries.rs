@@ -0,0 +1,62 @@
+use diesel::{prelude::*, sqlite::SqliteConnection};
+use time::OffsetDateTime;
+
+use klick_interfaces::RateLimitRecord;
+
+use crate::{rate_limit::models, schema};
+
+pub fn find_rate_limit(
+    conn: &mut SqliteConnection,
+    key: &str,
+) -> anyhow::Result<Option<RateLimitRecord>> {
+    use schema::rate_limits::dsl;
+
+    let result = dsl::rate_limits
+        .filter(dsl::key.eq(key))
+        .select(models::RateLimitQuery::as_select())
+        .first(conn)
+        .optional()?;
+    result.map(TryFrom::try_from).transpose()
+}
+
+pub fn insert_or_update_rate_limit(
+    conn: &mut SqliteConnection,
+    key: &str,
+    record: &RateLimitRecord,
+) -> anyhow::Result<()> {
+    use schema::rate_limits::dsl;
+
+    let model = models::RateLimitChangeset::new(key, record)?;
+    diesel::insert_into(dsl::rate_limits)
+        .values(model.clone())
+        .on_conflict(dsl::key)
+        .do_update()
+        .set(model)
+        .execute(conn)?;
+    Ok(())
+}
+
+pub fn delete_rate_limit(conn: &mut SqliteConnection, key: &str) -> anyhow::Result<()> {
+    use schema::rate_limits::dsl;
+
+    diesel::delete(dsl::rate_limits.filter(dsl::key.eq(key))).execute(conn)?;
+    Ok(())
+}
+
+pub fn delete_stale_rate_limits(
+    conn: &mut SqliteConnection,
+    before: OffsetDateTime,
+) -> anyhow::Result<usize> {
+    use schema::rate_limits::dsl;
+
+    let before = before.unix_timestamp();
+    let count = diesel::delete(dsl::rate_limits)
+        .filter(dsl::window_start.lt(before))
+        .filter(
+            dsl::blocked_until
+                .is_null()
+                .or(dsl::blocked_until.lt(before)),
+        )
+        .execute(conn)?;
+    Ok(count)
+}
@@ -19,6 +19,29 @@ table! {
     }
 }
 
+table! {
+    api_keys (rowid) {
+        rowid -> BigInt,
+        api_key_id -> Text,
+        account_rowid -> BigInt,
+        name -> Nullable<Text>,
+        secret_hash -> Text,
+        scopes -> Nullable<Text>,
+        created_at -> BigInt,
+        expires_at -> Nullable<BigInt>,
+    }
+}
+
+table! {
+    email_change_tokens (rowid) {
+        rowid -> BigInt,
+        account_rowid -> BigInt,
+        new_email -> Text,
+        nonce -> Text,
+        expires_at -> BigInt,
+    }
+}
+
 table! {
     projects (rowid) {
         rowid -> BigInt,
@@ -28,7 +51,26 @@ table! {
     }
 }
 
+table! {
+    rate_limits (rowid) {
+        rowid -> BigInt,
+        key -> Text,
+        attempts -> Integer,
+        window_start -> BigInt,
+        blocked_until -> Nullable<BigInt>,
+    }
+}
+
 joinable!(account_tokens -> accounts (account_rowid));
+joinable!(api_keys -> accounts (account_rowid));
+joinable!(email_change_tokens -> accounts (account_rowid));
 joinable!(projects -> accounts (account_rowid));
 
-allow_tables_to_appear_in_same_query!(accounts, account_tokens, projects);
+allow_tables_to_appear_in_same_query!(
+    accounts,
+    account_tokens,
+    api_keys,
+    email_change_tokens,
+    projects,
+    rate_limits
+);
@@ -80,3 +80,56 @@ fn delete_outdated_unconfirmed_accounts() {
         .unwrap()
         .is_none());
 }
+
+#[test]
+fn update_project_of_owner_only_if_unmodified() {
+    let db = Connection::establish(":memory:").unwrap();
+    db.run_embedded_database_migrations().unwrap();
+
+    let now = OffsetDateTime::now_utc();
+    let account = |email: &str| AccountRecord {
+        account: Account {
+            email_address: email.parse().unwrap(),
+            email_confirmed: true,
+            created_at: now,
+        },
+        password: "very-secret".parse::<Password>().unwrap().to_hashed(),
+    };
+    let owner = account("owner@bar.baz");
+    let other = account("other@bar.baz");
+    db.save_account(&owner).unwrap();
+    db.save_account(&other).unwrap();
+    let owner = &owner.account.email_address;
+    let other = &other.account.email_address;
+
+    let project = Project {
+        id: ProjectId::new(),
+        created_at: now,
+        modified_at: None,
+        data: JsonFormData::default(),
+    };
+    db.save_project(project.clone(), owner).unwrap();
+
+    // -- no takeover by another account
+    assert!(db.save_project(project.clone(), other).is_err());
+    let updated = Project {
+        modified_at: Some(now),
+        ..project.clone()
+    };
+    assert!(!db.update_project(updated.clone(), other, None).unwrap());
+    assert!(db.all_projects_by_owner(other).unwrap().is_empty());
+
+    // -- update of the owner
+    assert!(db.update_project(updated.clone(), owner, None).unwrap());
+    let found = db.find_project(&project.id).unwrap().unwrap();
+    assert_eq!(found.modified_at, updated.modified_at);
+
+    // -- outdated update
+    let outdated = Project {
+        modified_at: Some(now + Duration::seconds(1)),
+        ..project
+    };
+    assert!(!db.update_project(outdated, owner, None).unwrap());
+    let found = db.find_project(&updated.id).unwrap().unwrap();
+    assert_eq!(found.modified_at, updated.modified_at);
+}
@@ -0,0 +1,263 @@
+use std::{
+    fmt::{self, Write as _},
+    str::FromStr,
+};
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApiKeyId(Uuid);
+
+impl ApiKeyId {
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    #[must_use]
+    pub const fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    #[must_use]
+    pub const fn to_uuid(self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for ApiKeyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ApiKeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("invalid API key ID")]
+pub struct ApiKeyIdParseError;
+
+impl FromStr for ApiKeyId {
+    type Err = ApiKeyIdParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<Uuid>().map(Self).map_err(|_| ApiKeyIdParseError)
+    }
+}
+
+/// Permission that can be granted to an API key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ApiKeyScope {
+    ReadProjects,
+    WriteProjects,
+    Calculate,
+}
+
+impl ApiKeyScope {
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::ReadProjects => "read-projects",
+            Self::WriteProjects => "write-projects",
+            Self::Calculate => "calculate",
+        }
+    }
+}
+
+impl fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("invalid API key scope")]
+pub struct ApiKeyScopeParseError;
+
+impl FromStr for ApiKeyScope {
+    type Err = ApiKeyScopeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read-projects" => Ok(Self::ReadProjects),
+            "write-projects" => Ok(Self::WriteProjects),
+            "calculate" => Ok(Self::Calculate),
+            _ => Err(ApiKeyScopeParseError),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub name: Option<String>,
+    /// `None` grants all scopes.
+    pub scopes: Option<Vec<ApiKeyScope>>,
+    pub created_at: OffsetDateTime,
+    pub expires_at: Option<OffsetDateTime>,
+}
+
+impl ApiKey {
+    #[must_use]
+    pub fn has_scope(&self, scope: ApiKeyScope) -> bool {
+        self.scopes
+            .as_ref()
+            .map_or(true, |scopes| scopes.contains(&scope))
+    }
+
+    #[must_use]
+    pub fn is_expired(&self, now: OffsetDateTime) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// The secret part of an API key.
+///
+/// It is only shown once to the user
+/// and only its hash is persisted.
+pub struct ApiKeySecret(String);
+
+impl ApiKeySecret {
+    const PREFIX: &'static str = "klick_";
+
+    #[must_use]
+    pub fn new() -> Self {
+        let random = format!(
+            "{}{}",
+            Uuid::new_v4().as_simple(),
+            Uuid::new_v4().as_simple()
+        );
+        Self(format!("{}{random}", Self::PREFIX))
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    // API keys are random and long enough,
+    // so a fast hash function without salt is sufficient
+    // and allows to look up the key by its hash.
+    #[must_use]
+    pub fn to_hashed(&self) -> HashedApiKeySecret {
+        let digest = Sha256::digest(self.0.as_bytes());
+        let hash = digest.iter().fold(String::new(), |mut hash, b| {
+            let _ = write!(hash, "{b:02x}");
+            hash
+        });
+        HashedApiKeySecret(hash)
+    }
+}
+
+impl Default for ApiKeySecret {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ApiKeySecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ApiKeySecret(***)")
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("invalid API key")]
+pub struct ApiKeySecretParseError;
+
+impl FromStr for ApiKeySecret {
+    type Err = ApiKeySecretParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.starts_with(Self::PREFIX) {
+            return Err(ApiKeySecretParseError);
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashedApiKeySecret(String);
+
+impl HashedApiKeySecret {
+    #[must_use]
+    pub const fn from_hash(hash: String) -> Self {
+        Self(hash)
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_secret() {
+        let secret = ApiKeySecret::new();
+        let parsed = secret.as_str().parse::<ApiKeySecret>().unwrap();
+        assert_eq!(secret.to_hashed(), parsed.to_hashed());
+        assert!(Uuid::new_v4().to_string().parse::<ApiKeySecret>().is_err());
+    }
+
+    #[test]
+    fn hash_secret() {
+        let secret = ApiKeySecret::new();
+        let hash = secret.to_hashed();
+        assert_eq!(hash.as_str().len(), 64);
+        assert_ne!(hash.as_str(), secret.as_str());
+        assert_ne!(hash, ApiKeySecret::new().to_hashed());
+    }
+
+    #[test]
+    fn scopes() {
+        let mut key = ApiKey {
+            id: ApiKeyId::new(),
+            name: None,
+            scopes: None,
+            created_at: OffsetDateTime::now_utc(),
+            expires_at: None,
+        };
+        assert!(key.has_scope(ApiKeyScope::WriteProjects));
+        key.scopes = Some(vec![ApiKeyScope::Calculate]);
+        assert!(key.has_scope(ApiKeyScope::Calculate));
+        assert!(!key.has_scope(ApiKeyScope::WriteProjects));
+    }
+
+    #[test]
+    fn expiry() {
+        let now = OffsetDateTime::now_utc();
+        let mut key = ApiKey {
+            id: ApiKeyId::new(),
+            name: None,
+            scopes: None,
+            created_at: now,
+            expires_at: None,
+        };
+        assert!(!key.is_expired(now));
+        key.expires_at = Some(now);
+        assert!(key.is_expired(now));
+        assert!(!key.is_expired(now - time::Duration::seconds(1)));
+    }
+
+    #[test]
+    fn scope_from_str() {
+        for scope in [
+            ApiKeyScope::ReadProjects,
+            ApiKeyScope::WriteProjects,
+            ApiKeyScope::Calculate,
+        ] {
+            assert_eq!(scope.to_string().parse::<ApiKeyScope>().unwrap(), scope);
+        }
+    }
+}
@@ -1,11 +1,16 @@
 mod account;
+mod api_key;
 mod email;
 mod nonce;
 mod password;
 
 pub use self::{
     account::*,
+    api_key::{
+        ApiKey, ApiKeyId, ApiKeyIdParseError, ApiKeyScope, ApiKeyScopeParseError, ApiKeySecret,
+        ApiKeySecretParseError, HashedApiKeySecret,
+    },
     email::{EmailAddress, ParseError as EmailAddressParseError},
-    nonce::{AccountToken, EmailNonce, EmailNonceDecodingError, Nonce},
+    nonce::{AccountToken, EmailChangeToken, EmailNonce, EmailNonceDecodingError, Nonce},
     password::{HashedPassword, ParseError as PasswordParseError, Password},
 };
@@ -93,6 +93,17 @@ pub struct AccountToken {
     pub expires_at: OffsetDateTime,
 }
 
+/// A pending change of the email address of an account.
+///
+/// The email nonce refers to the new address
+/// that has to be confirmed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EmailChangeToken {
+    pub current_email: EmailAddress,
+    pub email_nonce: EmailNonce,
+    pub expires_at: OffsetDateTime,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
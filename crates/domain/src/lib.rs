@@ -4,12 +4,14 @@ mod values;
 
 pub use self::{
     authentication::{
-        Account, AccountToken, EmailAddress, EmailAddressParseError, EmailNonce,
-        EmailNonceDecodingError, HashedPassword, Nonce, Password, PasswordParseError,
+        Account, AccountToken, ApiKey, ApiKeyId, ApiKeyIdParseError, ApiKeyScope,
+        ApiKeyScopeParseError, ApiKeySecret, ApiKeySecretParseError, EmailAddress,
+        EmailAddressParseError, EmailChangeToken, EmailNonce, EmailNonceDecodingError,
+        HashedApiKeySecret, HashedPassword, Nonce, Password, PasswordParseError,
     },
     project::{Project, ProjectId, ProjectIdParseError},
     values::{
-        constants,
+        chp_units, constants, electricity_contracts, measures, operating_materials,
         specs::{InputValueId, OutputValueId},
         units::{self, Value, ValueType},
         CalculationOutcome, Edge, Edges, ValueId, Values,
@@ -0,0 +1,95 @@
+use thiserror::Error;
+
+use super::{
+    records,
+    units::{Ch4ChpEmissionFactorCalcMethod, Percent, Qubicmeters, Value},
+};
+
+/// A combined heat and power plant unit
+/// that burns a part of the sewage gas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChpUnit {
+    pub calculation_method: Ch4ChpEmissionFactorCalcMethod,
+    pub custom_factor: Option<Percent>,
+    /// Share of the sewage gas produced
+    pub gas_share: Option<Percent>,
+    /// Volume of the sewage gas (takes precedence over the share)
+    pub gas_volume: Option<Qubicmeters>,
+}
+
+impl ChpUnit {
+    /// A single unit can't be a mix of units.
+    #[must_use]
+    pub const fn is_mix(&self) -> bool {
+        matches!(self.calculation_method, Ch4ChpEmissionFactorCalcMethod::Mix)
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("invalid CHP unit in line {0}")]
+pub struct ChpUnitsParseError(pub usize);
+
+/// Read the CHP units of a
+/// [`SensitivityCH4ChpUnits`](crate::InputValueId::SensitivityCH4ChpUnits) value.
+pub fn from_value(value: &Value) -> Result<Vec<ChpUnit>, ChpUnitsParseError> {
+    let Value::Text(text) = value else {
+        return Err(ChpUnitsParseError(0));
+    };
+    records::parse(
+        text,
+        |[calculation_method, custom_factor, gas_share, gas_volume]| {
+            Some(ChpUnit {
+                calculation_method: records::parse_enum(calculation_method)?,
+                custom_factor: records::parse_optional(custom_factor, Percent::new).ok()?,
+                gas_share: records::parse_optional(gas_share, Percent::new).ok()?,
+                gas_volume: records::parse_optional(gas_volume, Qubicmeters::new).ok()?,
+            })
+        },
+    )
+    .map_err(ChpUnitsParseError)
+}
+
+#[must_use]
+pub fn to_value(units: &[ChpUnit]) -> Value {
+    let text = records::format(units.iter().map(|unit| {
+        [
+            unit.calculation_method.as_ref().to_string(),
+            records::format_optional(unit.custom_factor),
+            records::format_optional(unit.gas_share),
+            records::format_optional(unit.gas_volume),
+        ]
+    }));
+    Value::text(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let units = vec![
+            ChpUnit {
+                calculation_method: Ch4ChpEmissionFactorCalcMethod::Custom,
+                custom_factor: Some(Percent::new(1.25)),
+                gas_share: Some(Percent::new(40.0)),
+                gas_volume: None,
+            },
+            ChpUnit {
+                calculation_method: Ch4ChpEmissionFactorCalcMethod::JetEngine,
+                custom_factor: None,
+                gas_share: None,
+                gas_volume: Some(Qubicmeters::new(120_000.0)),
+            },
+        ];
+        let value = to_value(&units);
+        assert_eq!(value, Value::text("Custom;1.25;40;\nJetEngine;;;120000"));
+        assert_eq!(from_value(&value).unwrap(), units);
+    }
+
+    #[test]
+    fn invalid_line() {
+        let value = Value::text("GasolineEngine;;;\nSteamEngine;;;");
+        assert_eq!(from_value(&value).unwrap_err().0, 2);
+    }
+}
@@ -1,14 +1,26 @@
 use crate::units::{
-    Factor, GramsPerKilowatthour, KilogramsPerLiter, KilogramsPerQubicmeter, LitersPerTonKilometer,
-    Percent, QubicmetersPerHour,
+    Factor, GramsPerKilowatthour, Hours, KilogramsPerLiter, KilogramsPerQubicmeter,
+    LitersPerTonKilometer, MilligramsPerLiter, Percent, QubicmetersPerHour,
 };
 
 /// `[g ch4 / (population values * year)]`
 pub const EMISSION_FACTOR_CH4_PLANT: f64 = 230.0;
 
 /// `[%]` of chemical oxygen demand effluent
+///
+/// Also applied to the untreated wastewater of sewer overflows.
 pub const EMISSION_FACTOR_CH4_WATER: Percent = Percent::new(0.9);
 
+/// `[m]` assumed inner diameter of the pressure mains of a sewer network
+pub const PRESSURE_MAIN_DIAMETER: f64 = 0.3;
+
+/// `[mg/l]` dissolved methane that is formed per hour of retention in a pressure main
+pub const METHANE_FORMATION_RATE_PRESSURE_MAIN: MilligramsPerLiter = MilligramsPerLiter::new(1.5);
+
+/// `[mg/l]` upper limit of the dissolved methane in a pressure main
+pub const METHANE_CONCENTRATION_PRESSURE_MAIN_MAX: MilligramsPerLiter =
+    MilligramsPerLiter::new(25.0);
+
 /// `[%]` ch4 factor
 pub const EMISSION_FACTOR_CH4_CHP: Percent = Percent::new(1.0);
 
@@ -29,6 +41,23 @@ pub const EMISSION_FACTOR_SLUDGE_BAGS: QubicmetersPerHour = QubicmetersPerHour::
 /// `[%]` of the total digester gas production
 pub const EMISSION_FACTOR_SLUDGE_STORAGE: Percent = Percent::new(2.0);
 
+/// `[%]` of the methane that is destroyed in an open flare
+///
+/// # References
+///
+/// - [CDM Tool 06: Project emissions from flaring](https://cdm.unfccc.int/methodologies/PAmethodologies/tools/am-tool-06-v4.0.pdf)
+pub const FLARE_EFFICIENCY_OPEN: Percent = Percent::new(50.0);
+
+/// `[%]` of the methane that is destroyed in an enclosed flare
+///
+/// # References
+///
+/// - [CDM Tool 06: Project emissions from flaring](https://cdm.unfccc.int/methodologies/PAmethodologies/tools/am-tool-06-v4.0.pdf)
+pub const FLARE_EFFICIENCY_ENCLOSED: Percent = Percent::new(90.0);
+
+/// `[%]` of the methane that escapes during the upgrading to biomethane
+pub const EMISSION_FACTOR_CH4_UPGRADING: Percent = Percent::new(1.0);
+
 /// `[kg co2 / kg]` solution
 pub const EMISSION_FACTOR_FECL3: Factor = Factor::new(0.395);
 
@@ -41,12 +70,56 @@ pub const EMISSION_FACTOR_CAOH2: Factor = Factor::new(1.0553);
 /// `[kg co2 / kg]` solution
 pub const EMISSION_FACTOR_POLYMERS: Factor = Factor::new(2.2);
 
+/// `[kg co2e / kg]` methanol
+///
+/// # Calculation
+///
+/// `0,74` `[kg CO₂e / kg]` production from natural gas
+/// plus `1,375` `[kg CO₂ / kg]` (`44/32`) for the fossil carbon
+/// that is oxidized during the denitrification.
+pub const EMISSION_FACTOR_METHANOL: Factor = Factor::new(2.115);
+
+/// `[kg co2 / kg]` solution
+pub const EMISSION_FACTOR_POLYALUMINIUM_CHLORIDE: Factor = Factor::new(0.4);
+
+/// `[kg co2 / kg]` active substance
+pub const EMISSION_FACTOR_SODIUM_HYDROXIDE: Factor = Factor::new(1.12);
+
+/// `[%]` of sodium hydroxide in the delivered caustic soda
+pub const CONCENTRATION_SODIUM_HYDROXIDE: Percent = Percent::new(50.0);
+
+/// `[kg co2e / kg]` acetic acid
+///
+/// # Calculation
+///
+/// `1,6` `[kg CO₂e / kg]` production by methanol carbonylation
+/// plus `1,467` `[kg CO₂ / kg]` (`88/60`) for the fossil carbon
+/// that is oxidized during the denitrification.
+pub const EMISSION_FACTOR_ACETIC_ACID: Factor = Factor::new(3.067);
+
+/// `[%]` of acetic acid in the delivered solution
+pub const CONCENTRATION_ACETIC_ACID: Percent = Percent::new(80.0);
+
+/// `[kg co2 / kg]` powdered activated carbon from hard coal
+pub const EMISSION_FACTOR_ACTIVATED_CARBON: Factor = Factor::new(8.4);
+
 pub const GWP_N2O: Factor = Factor::new(273.0);
 
 pub const GWP_CH4: Factor = Factor::new(28.0);
 
 pub const CONVERSION_FACTOR_N_TO_N2O: Factor = Factor::new(44.0 / 28.0);
 
+/// `[mg/m^3]` of N₂O per `ppm` for standard cubic meters
+///
+/// # Calculation
+///
+/// Molar mass `44,013` `[g/mol]` / molar volume `22,414` `[l/mol]`.
+pub const CONVERSION_FACTOR_N2O_PPM_TO_MG_PER_M3: f64 = 44.013 / 22.414;
+
+/// Longest interval between two samples of a N₂O measurement
+/// that is still counted as measured time.
+pub const N2O_MEASUREMENT_MAX_GAP: Hours = Hours::new(1.0);
+
 /// `[kg/m^3]` for standard cubic meters (GESTIS substance database)
 pub const CONVERSION_FACTOR_CH4_M3_TO_KG: KilogramsPerQubicmeter =
     KilogramsPerQubicmeter::new(0.7175);
@@ -72,6 +145,35 @@ pub const EMISSION_FACTOR_DIESEL: KilogramsPerLiter = KilogramsPerLiter::new(2.6
 /// `[l/tkm]`
 pub const FUEL_CONSUMPTION: LitersPerTonKilometer = LitersPerTonKilometer::new(0.02);
 
+/// `[t co2e / t]` dewatered sludge (25% dry matter)
+///
+/// # Calculation
+///
+/// `0,99` `[kg N₂O / t dry matter]` × `0,25` × GWP `273`.
+pub const EMISSION_FACTOR_SLUDGE_MONO_INCINERATION: Factor = Factor::new(0.068);
+
+/// `[t co2e / t]` dewatered sludge (25% dry matter)
+///
+/// # Calculation
+///
+/// `0,35` `[kg N₂O / t dry matter]` × `0,25` × GWP `273`.
+pub const EMISSION_FACTOR_SLUDGE_CO_INCINERATION: Factor = Factor::new(0.024);
+
+/// `[t co2e / t]` dewatered sludge (25% dry matter)
+///
+/// # Calculation
+///
+/// `4,5%` nitrogen of the dry matter, `1%` of it emitted as N₂O-N (IPCC 2019):
+/// `45` `[kg N / t dry matter]` × `0,25` × `0,01` × `44/28` × GWP `273`.
+pub const EMISSION_FACTOR_SLUDGE_AGRICULTURE: Factor = Factor::new(0.048);
+
+/// `[t co2e / t]` dewatered sludge
+///
+/// # Calculation
+///
+/// `10` `[kg CH₄ / t]` × GWP `28`.
+pub const EMISSION_FACTOR_SLUDGE_LANDFILL: Factor = Factor::new(0.28);
+
 /// `[%]` of the nitrogen inflow
 pub const EMISSION_FACTOR_N2O_OPTIMISTIC: Percent = Percent::new(0.3);
 
@@ -85,3 +187,24 @@ pub const EMISSION_FACTOR_GAS: KilogramsPerQubicmeter = KilogramsPerQubicmeter::
 pub const EMISSION_FACTOR_BIOGAS: KilogramsPerQubicmeter = KilogramsPerQubicmeter::new(0.165_481_5);
 pub const EMISSION_FACTOR_HEAT_NETWORK: GramsPerKilowatthour = GramsPerKilowatthour::new(243.9);
 pub const EMISSION_FACTOR_STROM_MIX: GramsPerKilowatthour = GramsPerKilowatthour::new(468.0);
+
+/// `[kWh / l]` lower heating value of heating oil
+pub const HEATING_VALUE_OIL: f64 = 10.0;
+
+/// `[kWh / m³]` lower heating value of natural gas
+pub const HEATING_VALUE_GAS: f64 = 10.0;
+
+/// Coefficient of performance of a heat pump
+/// that uses the heat of the wastewater
+pub const HEAT_PUMP_COEFFICIENT_OF_PERFORMANCE: Factor = Factor::new(3.5);
+
+/// `[t co2e / kWp]` embodied emissions of a photovoltaic system
+/// (modules, inverter and mounting system)
+pub const EMBODIED_EMISSIONS_PHOTOVOLTAIC: Factor = Factor::new(1.0);
+
+/// `[t co2e / m²]` embodied emissions of a membrane cover
+/// for digesters or sludge storage containers
+pub const EMBODIED_EMISSIONS_COVER: Factor = Factor::new(0.05);
+
+/// `[t co2e / m³]` embodied emissions of reinforced concrete
+pub const EMBODIED_EMISSIONS_CONCRETE: Factor = Factor::new(0.3);
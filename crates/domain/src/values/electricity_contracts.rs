@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use super::{
+    records,
+    units::{GramsPerKilowatthour, Kilowatthours, Value},
+};
+
+/// A contract for purchased electricity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ElectricityContract {
+    pub energy: Kilowatthours,
+    /// Emission factor of the supplier (e.g. a green electricity tariff)
+    pub emission_factor: Option<GramsPerKilowatthour>,
+    /// The electricity is covered by guarantees of origin
+    pub guarantee_of_origin: bool,
+}
+
+#[derive(Debug, Error)]
+#[error("invalid electricity contract in line {0}")]
+pub struct ElectricityContractsParseError(pub usize);
+
+/// Read the contracts of a
+/// [`ProfileElectricityContracts`](crate::InputValueId::ProfileElectricityContracts) value.
+pub fn from_value(
+    value: &Value,
+) -> Result<Vec<ElectricityContract>, ElectricityContractsParseError> {
+    let Value::Text(text) = value else {
+        return Err(ElectricityContractsParseError(0));
+    };
+    records::parse(text, |[energy, emission_factor, guarantee_of_origin]| {
+        Some(ElectricityContract {
+            energy: f64::from_str(energy).map(Kilowatthours::new).ok()?,
+            emission_factor: records::parse_optional(emission_factor, GramsPerKilowatthour::new)
+                .ok()?,
+            guarantee_of_origin: bool::from_str(guarantee_of_origin).ok()?,
+        })
+    })
+    .map_err(ElectricityContractsParseError)
+}
+
+#[must_use]
+pub fn to_value(contracts: &[ElectricityContract]) -> Value {
+    let text = records::format(contracts.iter().map(|contract| {
+        [
+            f64::from(contract.energy).to_string(),
+            records::format_optional(contract.emission_factor),
+            contract.guarantee_of_origin.to_string(),
+        ]
+    }));
+    Value::text(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let contracts = vec![
+            ElectricityContract {
+                energy: Kilowatthours::new(120_000.0),
+                emission_factor: None,
+                guarantee_of_origin: true,
+            },
+            ElectricityContract {
+                energy: Kilowatthours::new(50_000.5),
+                emission_factor: Some(GramsPerKilowatthour::new(35.0)),
+                guarantee_of_origin: false,
+            },
+        ];
+        let value = to_value(&contracts);
+        assert_eq!(value, Value::text("120000;;true\n50000.5;35;false"));
+        assert_eq!(from_value(&value).unwrap(), contracts);
+    }
+
+    #[test]
+    fn contract_without_energy() {
+        let value = Value::text("120000;;true\n;35;false");
+        assert_eq!(from_value(&value).unwrap_err().0, 2);
+    }
+}
@@ -0,0 +1,81 @@
+use super::{
+    constants::*,
+    specs::{InputValueId as In, OutputValueId as Out},
+    units::Factor,
+};
+
+/// A quantity of components or construction materials
+/// from which the embodied emissions of a measure are estimated.
+///
+/// The quantity is given in the unit of the input value spec.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstructionQuantity {
+    pub input: In,
+    /// `[t co2e / unit of the input]`
+    pub emission_factor: Factor,
+}
+
+/// A recommended measure with its embodied (construction) emissions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Measure {
+    pub name: &'static str,
+    /// The annual savings of the measure.
+    pub savings: Out,
+    /// Directly entered embodied emissions `[t co2e]`.
+    ///
+    /// If present, the estimation from the [`Measure::quantities`] is ignored.
+    pub embodied_emissions: In,
+    pub quantities: &'static [ConstructionQuantity],
+}
+
+/// Catalogue of all measures that can carry embodied emissions.
+pub const MEASURES: &[Measure] = &[
+    Measure {
+        name: "Photovoltaic expansion",
+        savings: Out::PhotovoltaicExpansionSavings,
+        embodied_emissions: In::RecommendationPhotovoltaicEmbodiedEmissions,
+        quantities: &[ConstructionQuantity {
+            input: In::RecommendationPhotovoltaicPeakPower,
+            emission_factor: EMBODIED_EMISSIONS_PHOTOVOLTAIC,
+        }],
+    },
+    Measure {
+        name: "Wind energy expansion",
+        savings: Out::WindExpansionSavings,
+        embodied_emissions: In::RecommendationWindEmbodiedEmissions,
+        quantities: &[],
+    },
+    Measure {
+        name: "Water energy expansion",
+        savings: Out::WaterExpansionSavings,
+        embodied_emissions: In::RecommendationWaterEmbodiedEmissions,
+        quantities: &[],
+    },
+    Measure {
+        name: "Heat pump",
+        savings: Out::HeatPumpSavings,
+        embodied_emissions: In::RecommendationHeatPumpEmbodiedEmissions,
+        quantities: &[],
+    },
+    Measure {
+        name: "Enclosed flare",
+        savings: Out::EnclosedFlareSavings,
+        embodied_emissions: In::RecommendationFlareEmbodiedEmissions,
+        quantities: &[],
+    },
+    Measure {
+        name: "Digester cover",
+        savings: Out::DigesterCoverSavings,
+        embodied_emissions: In::RecommendationDigesterCoverEmbodiedEmissions,
+        quantities: &[
+            ConstructionQuantity {
+                input: In::RecommendationDigesterCoverArea,
+                emission_factor: EMBODIED_EMISSIONS_COVER,
+            },
+            ConstructionQuantity {
+                input: In::RecommendationDigesterCoverConcrete,
+                emission_factor: EMBODIED_EMISSIONS_CONCRETE,
+            },
+        ],
+    },
+];
@@ -2,7 +2,12 @@ use std::collections::HashMap;
 
 use derive_more::From;
 
+pub mod chp_units;
 pub mod constants;
+pub mod electricity_contracts;
+pub mod measures;
+pub mod operating_materials;
+mod records;
 pub mod specs;
 pub mod units;
 
@@ -0,0 +1,122 @@
+use super::{
+    constants::*,
+    specs::{InputValueId as In, OutputValueId as Out},
+    units::Factor,
+};
+
+/// The unit an emission factor refers to.
+///
+/// The consumed quantity is always entered in tons of the delivered product.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaterialUnit {
+    /// Tons of the delivered product
+    Tons,
+    /// Tons of the active substance
+    /// which makes up the share of the delivered product
+    /// that is entered in the concentration input
+    /// (the default is the typical concentration of the product).
+    TonsOfActiveSubstance { concentration: In },
+}
+
+/// An operating material.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OperatingMaterial {
+    pub name: &'static str,
+    /// Consumed quantity `[t]`
+    pub input: In,
+    /// Emissions `[t co2e]`
+    pub output: Out,
+    pub unit: MaterialUnit,
+    /// `[t co2e / unit]`
+    pub emission_factor: Factor,
+}
+
+impl OperatingMaterial {
+    /// The input of the share of the active substance, if any.
+    #[must_use]
+    pub const fn concentration(&self) -> Option<In> {
+        match self.unit {
+            MaterialUnit::Tons => None,
+            MaterialUnit::TonsOfActiveSubstance { concentration } => Some(concentration),
+        }
+    }
+
+    /// All inputs of this material.
+    pub fn inputs(&self) -> impl Iterator<Item = In> {
+        std::iter::once(self.input).chain(self.concentration())
+    }
+}
+
+/// Catalogue of all supported operating materials.
+///
+/// Each entry connects the consumed quantity with the emissions
+/// that are shown below [`Out::OperatingMaterials`].
+pub const OPERATING_MATERIALS: &[OperatingMaterial] = &[
+    OperatingMaterial {
+        name: "Ferric chloride",
+        input: In::ProfileOperatingMaterialFeCl3,
+        output: Out::Fecl3,
+        unit: MaterialUnit::Tons,
+        emission_factor: EMISSION_FACTOR_FECL3,
+    },
+    OperatingMaterial {
+        name: "Ferric chloride sulfate",
+        input: In::ProfileOperatingMaterialFeClSO4,
+        output: Out::Feclso4,
+        unit: MaterialUnit::Tons,
+        emission_factor: EMISSION_FACTOR_FECLSO4,
+    },
+    OperatingMaterial {
+        name: "Calcium hydroxide",
+        input: In::ProfileOperatingMaterialCaOH2,
+        output: Out::Caoh2,
+        unit: MaterialUnit::Tons,
+        emission_factor: EMISSION_FACTOR_CAOH2,
+    },
+    OperatingMaterial {
+        name: "Synthetic polymers",
+        input: In::ProfileOperatingMaterialSyntheticPolymers,
+        output: Out::SyntheticPolymers,
+        unit: MaterialUnit::Tons,
+        emission_factor: EMISSION_FACTOR_POLYMERS,
+    },
+    OperatingMaterial {
+        name: "Methanol",
+        input: In::ProfileOperatingMaterialMethanol,
+        output: Out::Methanol,
+        unit: MaterialUnit::Tons,
+        emission_factor: EMISSION_FACTOR_METHANOL,
+    },
+    OperatingMaterial {
+        name: "Polyaluminium chloride",
+        input: In::ProfileOperatingMaterialPolyaluminiumChloride,
+        output: Out::PolyaluminiumChloride,
+        unit: MaterialUnit::Tons,
+        emission_factor: EMISSION_FACTOR_POLYALUMINIUM_CHLORIDE,
+    },
+    OperatingMaterial {
+        name: "Sodium hydroxide",
+        input: In::ProfileOperatingMaterialSodiumHydroxide,
+        output: Out::SodiumHydroxide,
+        unit: MaterialUnit::TonsOfActiveSubstance {
+            concentration: In::ProfileOperatingMaterialSodiumHydroxideConcentration,
+        },
+        emission_factor: EMISSION_FACTOR_SODIUM_HYDROXIDE,
+    },
+    OperatingMaterial {
+        name: "Acetic acid",
+        input: In::ProfileOperatingMaterialAceticAcid,
+        output: Out::AceticAcid,
+        unit: MaterialUnit::TonsOfActiveSubstance {
+            concentration: In::ProfileOperatingMaterialAceticAcidConcentration,
+        },
+        emission_factor: EMISSION_FACTOR_ACETIC_ACID,
+    },
+    OperatingMaterial {
+        name: "Powdered activated carbon",
+        input: In::ProfileOperatingMaterialActivatedCarbon,
+        output: Out::ActivatedCarbon,
+        unit: MaterialUnit::Tons,
+        emission_factor: EMISSION_FACTOR_ACTIVATED_CARBON,
+    },
+];
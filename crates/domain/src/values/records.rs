@@ -0,0 +1,68 @@
+//! Lists of records that are stored as a single text value.
+//!
+//! Each record is written on its own line
+//! and its fields are separated by `;`.
+//! Missing optional fields are left empty.
+
+use std::{num::ParseFloatError, str::FromStr};
+
+use strum::IntoEnumIterator;
+
+const FIELD_SEPARATOR: char = ';';
+
+/// Parse all non-empty lines into records.
+///
+/// On failure the number of the invalid line is returned.
+pub(crate) fn parse<T, const N: usize>(
+    text: &str,
+    parse_record: impl Fn([&str; N]) -> Option<T>,
+) -> Result<Vec<T>, usize> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let fields: Vec<_> = line.split(FIELD_SEPARATOR).map(str::trim).collect();
+            <[&str; N]>::try_from(fields)
+                .ok()
+                .and_then(&parse_record)
+                .ok_or(index + 1)
+        })
+        .collect()
+}
+
+pub(crate) fn format<const N: usize>(records: impl IntoIterator<Item = [String; N]>) -> String {
+    records
+        .into_iter()
+        .map(|fields| fields.join(&FIELD_SEPARATOR.to_string()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parse an optional number field.
+pub(crate) fn parse_optional<T>(
+    field: &str,
+    new: impl Fn(f64) -> T,
+) -> Result<Option<T>, ParseFloatError> {
+    if field.is_empty() {
+        return Ok(None);
+    }
+    f64::from_str(field).map(new).map(Some)
+}
+
+pub(crate) fn format_optional<T>(value: Option<T>) -> String
+where
+    f64: From<T>,
+{
+    value
+        .map(f64::from)
+        .map(|v| v.to_string())
+        .unwrap_or_default()
+}
+
+/// Parse an enum field by its variant name.
+pub(crate) fn parse_enum<T>(field: &str) -> Option<T>
+where
+    T: IntoEnumIterator + AsRef<str>,
+{
+    T::iter().find(|variant| variant.as_ref() == field)
+}
@@ -74,6 +74,53 @@ spec! {
             max = 90.0;
             default = 62.0;
         },
+        ProfileSewageGasFlared {
+            unit = Qubicmeters;
+            optional;
+            min = 0.0;
+            max = 100_000_000.0;
+            default = 0.0;
+        },
+        ProfileFlareEfficiency {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+            default = constants::FLARE_EFFICIENCY_OPEN.into();
+        },
+        ProfileSewageGasVented {
+            unit = Qubicmeters;
+            optional;
+            min = 0.0;
+            max = 100_000_000.0;
+            default = 0.0;
+        },
+        ProfileSewageGasUpgraded {
+            unit = Qubicmeters;
+            optional;
+            min = 0.0;
+            max = 100_000_000.0;
+            default = 0.0;
+        },
+        ProfileUpgradingMethaneSlip {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+            default = constants::EMISSION_FACTOR_CH4_UPGRADING.into();
+        },
+        ProfileUpgradingPowerConsumption {
+            unit = Kilowatthours;
+            optional;
+            min = 0.0;
+            default = 0.0;
+        },
+        ProfileHeatExported {
+            unit = Kilowatthours;
+            optional;
+            min = 0.0;
+            default = 0.0;
+        },
         ProfileGasSupply {
             unit = Qubicmeters;
             optional;
@@ -104,6 +151,17 @@ spec! {
             max = 2500.0;
             default = 485.0;
         },
+        // A list of contracts for purchased electricity (see `electricity_contracts`)
+        ProfileElectricityContracts {
+            unit = String;
+            optional;
+        },
+        ProfileElectricityResidualMixEmissionFactor {
+            unit = GramsPerKilowatthour;
+            optional;
+            min = 0.0;
+            max = 2500.0;
+        },
         ProfileHeatingOil {
             unit = Liters;
             optional;
@@ -140,6 +198,55 @@ spec! {
             min = 0.0;
             max = 50000.0;
         },
+        ProfileOperatingMaterialMethanol {
+            unit = Tons;
+            optional;
+            min = 0.0;
+            max = 100_000.0;
+            default = 0.0;
+        },
+        ProfileOperatingMaterialPolyaluminiumChloride {
+            unit = Tons;
+            optional;
+            min = 0.0;
+            max = 100_000.0;
+            default = 0.0;
+        },
+        ProfileOperatingMaterialSodiumHydroxide {
+            unit = Tons;
+            optional;
+            min = 0.0;
+            max = 100_000.0;
+            default = 0.0;
+        },
+        ProfileOperatingMaterialSodiumHydroxideConcentration {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+            default = constants::CONCENTRATION_SODIUM_HYDROXIDE.into();
+        },
+        ProfileOperatingMaterialAceticAcid {
+            unit = Tons;
+            optional;
+            min = 0.0;
+            max = 100_000.0;
+            default = 0.0;
+        },
+        ProfileOperatingMaterialAceticAcidConcentration {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+            default = constants::CONCENTRATION_ACETIC_ACID.into();
+        },
+        ProfileOperatingMaterialActivatedCarbon {
+            unit = Tons;
+            optional;
+            min = 0.0;
+            max = 100_000.0;
+            default = 0.0;
+        },
         ProfileSludgeBagsAreOpen{
             unit = bool;
             optional;
@@ -162,6 +269,34 @@ spec! {
             max = 2000.0;
             default = 0.0;
         },
+        ProfileSludgeDisposalRoute {
+            unit = SludgeDisposalRoute;
+            optional;
+        },
+        ProfileSludgeDisposalShareMonoIncineration {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+        },
+        ProfileSludgeDisposalShareCoIncineration {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+        },
+        ProfileSludgeDisposalShareAgriculture {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+        },
+        ProfileSludgeDisposalShareLandfill {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+        },
         ProfileSludgeDigesterCount {
             unit = Count;
             optional;
@@ -169,6 +304,34 @@ spec! {
             max = 9.0;
             default = 0;
         },
+        ProfileSewerPressureMainLength {
+            unit = Kilometers;
+            optional;
+            min = 0.0;
+            max = 1_000.0;
+            default = 0.0;
+        },
+        ProfileSewerPressureMainRetentionTime {
+            unit = Hours;
+            optional;
+            min = 0.0;
+            max = 72.0;
+            default = 0.0;
+        },
+        ProfileSewerPumpingStationEnergy {
+            unit = Kilowatthours;
+            optional;
+            min = 0.0;
+            max = 100_000_000.0;
+            default = 0.0;
+        },
+        ProfileSewerOverflowVolume {
+            unit = Qubicmeters;
+            optional;
+            min = 0.0;
+            max = 100_000_000.0;
+            default = 0.0;
+        },
 
         // ------    ------ //
         //   Sensitivity    //
@@ -186,6 +349,34 @@ spec! {
             max = 100.0;
             default = constants::EMISSION_FACTOR_N2O_DEFAULT.into();
         },
+        SensitivityN2OMeasuredFactor {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+        },
+        SensitivityN2OMeasurementPeriod {
+            unit = String;
+            optional;
+        },
+        SensitivityN2OMeasurementSamples {
+            unit = Count;
+            optional;
+            min = 0.0;
+            max = 100_000_000.0;
+        },
+        SensitivityN2OMeasurementCoverage {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+        },
+        SensitivityN2OMeasurementAnnualCoverage {
+            unit = Percent;
+            optional;
+            min = 0.0;
+            max = 100.0;
+        },
         SensitivityN2OSideStreamFactor {
             unit = Percent;
             optional;
@@ -205,6 +396,11 @@ spec! {
             max = 100.0;
             default = constants::EMISSION_FACTOR_CH4_CHP_DEFAULT.into();
         },
+        // A list of CHP units (see `chp_units`)
+        SensitivityCH4ChpUnits {
+            unit = String;
+            optional;
+        },
         SensitivityCO2FossilCustomFactor {
             unit = Percent;
             optional;
@@ -250,6 +446,11 @@ spec! {
             optional;
             default = true;
         },
+        RecommendationFlareIsOpen {
+            unit = bool;
+            optional;
+            default = true;
+        },
         RecommendationProcessEnergySaving {
             unit = Percent;
             optional;
@@ -270,6 +471,19 @@ spec! {
             min = 0.0;
             default = 0.0;
         },
+        RecommendationHeatPumpThermalOutput {
+            unit = Kilowatthours;
+            optional;
+            min = 0.0;
+            default = 0.0;
+        },
+        RecommendationHeatPumpCoefficientOfPerformance {
+            unit = Factor;
+            optional;
+            min = 1.0;
+            max = 10.0;
+            default = constants::HEAT_PUMP_COEFFICIENT_OF_PERFORMANCE.into();
+        },
         RecommendationPhotovoltaicEnergyExpansion {
             unit = Kilowatthours;
             optional;
@@ -309,6 +523,54 @@ spec! {
             max = 100.0;
             default = 100.0;
         },
+        RecommendationPhotovoltaicPeakPower {
+            unit = Kilowatts;
+            optional;
+            min = 0.0;
+            default = 0.0;
+        },
+        RecommendationPhotovoltaicEmbodiedEmissions {
+            unit = Tons;
+            optional;
+            min = 0.0;
+        },
+        RecommendationWindEmbodiedEmissions {
+            unit = Tons;
+            optional;
+            min = 0.0;
+        },
+        RecommendationWaterEmbodiedEmissions {
+            unit = Tons;
+            optional;
+            min = 0.0;
+        },
+        RecommendationHeatPumpEmbodiedEmissions {
+            unit = Tons;
+            optional;
+            min = 0.0;
+        },
+        RecommendationFlareEmbodiedEmissions {
+            unit = Tons;
+            optional;
+            min = 0.0;
+        },
+        RecommendationDigesterCoverArea {
+            unit = SquareMeters;
+            optional;
+            min = 0.0;
+            default = 0.0;
+        },
+        RecommendationDigesterCoverConcrete {
+            unit = Qubicmeters;
+            optional;
+            min = 0.0;
+            default = 0.0;
+        },
+        RecommendationDigesterCoverEmbodiedEmissions {
+            unit = Tons;
+            optional;
+            min = 0.0;
+        },
     }
 }
 
@@ -338,6 +600,15 @@ spec! {
         Ch4Water {
           unit = Tons;
         },
+        Ch4Flare {
+          unit = Tons;
+        },
+        Ch4Vented {
+          unit = Tons;
+        },
+        Ch4Upgrading {
+          unit = Tons;
+        },
         Ch4CombinedHeatAndPowerPlant {
           unit = Tons;
         },
@@ -359,21 +630,66 @@ spec! {
         SyntheticPolymers {
           unit = Tons;
         },
+        Methanol {
+          unit = Tons;
+        },
+        PolyaluminiumChloride {
+          unit = Tons;
+        },
+        SodiumHydroxide {
+          unit = Tons;
+        },
+        AceticAcid {
+          unit = Tons;
+        },
+        ActivatedCarbon {
+          unit = Tons;
+        },
         ElectricityMix {
           unit = Tons;
         },
+        ElectricityMixMarketBased {
+          unit = Tons;
+        },
         OilEmissions {
           unit = Tons;
         },
         GasEmissions {
           unit = Tons;
         },
+        UpgradingElectricity {
+          unit = Tons;
+        },
         OperatingMaterials {
           unit = Tons;
         },
         SewageSludgeTransport {
           unit = Tons;
         },
+        SludgeDisposalMonoIncineration {
+          unit = Tons;
+        },
+        SludgeDisposalCoIncineration {
+          unit = Tons;
+        },
+        SludgeDisposalAgriculture {
+          unit = Tons;
+        },
+        SludgeDisposalLandfill {
+          unit = Tons;
+        },
+        Ch4PressureMains {
+          unit = Tons;
+        },
+        Ch4SewerOverflows {
+          unit = Tons;
+        },
+        SewerPumpingStationElectricity {
+          unit = Tons;
+        },
+        SewerNetworkEmissions {
+          unit = Tons;
+        },
         TotalEmissions {
           unit = Tons;
         },
@@ -398,6 +714,24 @@ spec! {
         FossilEnergySavings {
           unit = Tons;
         },
+        EnclosedFlareSavings {
+          unit = Tons;
+        },
+        DigesterCoverSavings {
+          unit = Tons;
+        },
+        HeatPumpSavings {
+          unit = Tons;
+        },
+        BiomethaneInjectionCredit {
+          unit = Tons;
+        },
+        HeatExportCredit {
+          unit = Tons;
+        },
+        AvoidedEmissions {
+          unit = Tons;
+        },
         IndirectEmissions {
           unit = Tons;
         },
@@ -430,6 +764,10 @@ spec! {
         AdditionalCustomEmissions {
           unit = Tons;
           optional;
+        },
+        AdditionalCustomEmissionsSavings {
+          unit = Tons;
+          optional;
         }
     }
 }
@@ -1096,6 +1096,9 @@ values! {
             Length {
                 Kilometers, 1.0, "km";
             }
+            Area {
+                SquareMeters, 1.0, "m²";
+            }
             Mass {
                 Grams, 1.0, "g";
                 Kilograms, 1_000.0, "kg";
@@ -1109,8 +1112,12 @@ values! {
             Energy {
                 Kilowatthours, 1.0, "kWh";
             }
+            Power {
+                Kilowatts, 1.0, "kW";
+            }
             SpecificEnergyDensity {
                 GramsPerKilowatthour, 1.0, "g/kWh";
+                KilogramsPerKilowatthour, 1_000.0, "kg/kWh";
             }
             FuelConsumption {
                 LitersPerKilometer, 1.0, "l/km";
@@ -1139,6 +1146,7 @@ values! {
               #[default]
               Ipcc2019,
               Custom,
+              Measured,
             }
             #[derive(Default, FromPrimitive, ToPrimitive, AsRefStr, strum_macros::EnumIter)]
             Ch4ChpEmissionFactorCalcMethod {
@@ -1147,6 +1155,15 @@ values! {
                 GasolineEngine,
                 JetEngine,
                 Custom,
+                Mix,
+            }
+            #[derive(Default, FromPrimitive, ToPrimitive, AsRefStr, strum_macros::EnumIter)]
+            SludgeDisposalRoute {
+                #[default]
+                MonoIncineration,
+                CoIncineration,
+                Agriculture,
+                Landfill,
             }
         }
     }
@@ -6,6 +6,14 @@ pub trait Gateway {
 
 #[derive(Debug)]
 pub enum Event {
-    AccountWasCreated { email_nonce: EmailNonce },
-    AccountResetPasswordRequested { email_nonce: EmailNonce },
+    AccountWasCreated {
+        email_nonce: EmailNonce,
+    },
+    AccountResetPasswordRequested {
+        email_nonce: EmailNonce,
+    },
+    /// The nonce refers to the new email address.
+    AccountEmailChangeRequested {
+        email_nonce: EmailNonce,
+    },
 }
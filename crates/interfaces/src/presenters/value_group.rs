@@ -13,6 +13,7 @@ pub enum ValueGroupId {
     SludgeTreatment,
     SideStreamTreatment,
     OperatingMaterials,
+    SewerNetwork,
     N2OEmissions,
     CH4ChpEmissions,
     CH4SludgeEmissions,
@@ -3,6 +3,12 @@ use klick_domain::{Account, EmailAddress, HashedPassword};
 pub trait Repo {
     fn find_account(&self, email: &EmailAddress) -> anyhow::Result<Option<Record>>;
     fn save_account(&self, record: &Record) -> anyhow::Result<()>;
+    fn change_email_address(
+        &self,
+        current_email: &EmailAddress,
+        new_email: &EmailAddress,
+    ) -> anyhow::Result<()>;
+    /// Delete the account including its tokens, API keys and projects.
     fn delete_account(&self, email: &EmailAddress) -> anyhow::Result<()>;
 }
 
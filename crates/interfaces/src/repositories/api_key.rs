@@ -0,0 +1,21 @@
+use klick_domain::{ApiKey, ApiKeyId, EmailAddress, HashedApiKeySecret};
+
+pub trait Repo {
+    fn save_api_key(&self, record: &Record, owner: &EmailAddress) -> anyhow::Result<()>;
+    fn find_api_key_by_hash(
+        &self,
+        hashed_secret: &HashedApiKeySecret,
+    ) -> anyhow::Result<Option<(ApiKey, EmailAddress)>>;
+    fn all_api_keys_by_owner(&self, owner: &EmailAddress) -> anyhow::Result<Vec<ApiKey>>;
+    /// Returns `false` if the owner has no such key.
+    fn delete_api_key(&self, id: &ApiKeyId, owner: &EmailAddress) -> anyhow::Result<bool>;
+}
+
+// NOTE:
+// Like the password of an account,
+// the hashed secret is not part of the entity.
+#[derive(Debug)]
+pub struct Record {
+    pub api_key: ApiKey,
+    pub hashed_secret: HashedApiKeySecret,
+}
@@ -0,0 +1,14 @@
+use klick_domain::{EmailAddress, EmailChangeToken, EmailNonce};
+
+pub trait Repo {
+    /// Replace a pending email change of the account.
+    fn replace_email_change_token(&self, token: &EmailChangeToken) -> anyhow::Result<()>;
+    fn consume_email_change_token(
+        &self,
+        email_nonce: &EmailNonce,
+    ) -> anyhow::Result<EmailChangeToken>;
+    fn get_email_change_token_by_account(
+        &self,
+        current_email: &EmailAddress,
+    ) -> anyhow::Result<EmailChangeToken>;
+}
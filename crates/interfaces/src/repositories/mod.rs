@@ -1,9 +1,15 @@
 mod account;
 mod account_token;
+mod api_key;
+mod email_change_token;
 mod project;
+mod rate_limit;
 
 pub use self::{
     account::{Record as AccountRecord, Repo as AccountRepo},
     account_token::Repo as AccountTokenRepo,
+    api_key::{Record as ApiKeyRecord, Repo as ApiKeyRepo},
+    email_change_token::Repo as EmailChangeTokenRepo,
     project::Repo as ProjectRepo,
+    rate_limit::{Record as RateLimitRecord, Repo as RateLimitRepo},
 };
@@ -1,8 +1,21 @@
+use time::OffsetDateTime;
+
 use klick_domain::{EmailAddress, Project, ProjectId};
 
 pub trait Repo<T> {
     fn find_project(&self, id: &ProjectId) -> anyhow::Result<Option<Project<T>>>;
     fn all_projects_by_owner(&self, owner: &EmailAddress) -> anyhow::Result<Vec<Project<T>>>;
+    /// Fails if a project with the same ID already exists.
     fn save_project(&self, project: Project<T>, owner: &EmailAddress) -> anyhow::Result<()>;
+    /// Replaces the project if it belongs to the owner
+    /// and was last modified at `modified_at`.
+    ///
+    /// Returns `false` if the owner has no such project.
+    fn update_project(
+        &self,
+        project: Project<T>,
+        owner: &EmailAddress,
+        modified_at: Option<OffsetDateTime>,
+    ) -> anyhow::Result<bool>;
     fn delete_project(&self, id: &ProjectId) -> anyhow::Result<()>;
 }
@@ -0,0 +1,18 @@
+use time::OffsetDateTime;
+
+pub trait Repo {
+    fn find_rate_limit(&self, key: &str) -> anyhow::Result<Option<Record>>;
+    fn save_rate_limit(&self, key: &str, record: &Record) -> anyhow::Result<()>;
+    fn delete_rate_limit(&self, key: &str) -> anyhow::Result<()>;
+    /// Delete all records whose window started and whose block ended
+    /// before the given point in time.
+    fn delete_stale_rate_limits(&self, before: OffsetDateTime) -> anyhow::Result<usize>;
+}
+
+/// Attempts counted for a rate limit key (e.g. an IP or email address).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub attempts: u32,
+    pub window_start: OffsetDateTime,
+    pub blocked_until: Option<OffsetDateTime>,
+}
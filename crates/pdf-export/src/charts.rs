@@ -0,0 +1,372 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use num_traits::ToPrimitive;
+use thiserror::Error;
+
+use klick_app_charts as charts;
+use klick_custom_values_parser::CustomValues;
+use klick_domain::{
+    required_output_value_id as required,
+    units::{Ch4ChpEmissionFactorCalcMethod, Factor, N2oEmissionFactorCalcMethod, Tons},
+    CalculationOutcome, InputValueId as In, OutputValueId as Out, Value, ValueId as Id,
+};
+use klick_presenter::{self as presenter, Lng, ValueLabel};
+
+pub(crate) const BAR_CHART_WIDTH: f64 = 1100.0;
+pub(crate) const BAR_CHART_HEIGHT: f64 = 300.0;
+pub(crate) const DIFF_BAR_CHART_HEIGHT: f64 = 450.0;
+pub(crate) const SANKEY_CHART_WIDTH: f64 = 1200.0;
+pub(crate) const SANKEY_CHART_HEIGHT: f64 = 800.0;
+const DONUT_CHART_HEIGHT: f64 = 500.0;
+const STACKED_BAR_CHART_HEIGHT: f64 = 350.0;
+
+/// Charts of a project.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chart {
+    Sankey,
+    RecommendationSankey,
+    N2oScenarios,
+    Ch4Scenarios,
+    RecommendationDiff,
+    EmissionShares,
+    EmissionGroups,
+}
+
+impl Chart {
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Sankey => "sankey",
+            Self::RecommendationSankey => "recommendation-sankey",
+            Self::N2oScenarios => "n2o-scenarios",
+            Self::Ch4Scenarios => "ch4-scenarios",
+            Self::RecommendationDiff => "recommendation-diff",
+            Self::EmissionShares => "emission-shares",
+            Self::EmissionGroups => "emission-groups",
+        }
+    }
+
+    /// Size (width, height) of the chart in the PDF report.
+    #[must_use]
+    pub const fn default_size(&self) -> (f64, f64) {
+        match self {
+            Self::Sankey | Self::RecommendationSankey => (SANKEY_CHART_WIDTH, SANKEY_CHART_HEIGHT),
+            Self::N2oScenarios | Self::Ch4Scenarios => (BAR_CHART_WIDTH, BAR_CHART_HEIGHT),
+            Self::RecommendationDiff => (BAR_CHART_WIDTH, DIFF_BAR_CHART_HEIGHT),
+            Self::EmissionShares => (BAR_CHART_WIDTH, DONUT_CHART_HEIGHT),
+            Self::EmissionGroups => (BAR_CHART_WIDTH, STACKED_BAR_CHART_HEIGHT),
+        }
+    }
+}
+
+impl fmt::Display for Chart {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("unknown chart '{0}'")]
+pub struct UnknownChartError(String);
+
+impl FromStr for Chart {
+    type Err = UnknownChartError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Self::Sankey,
+            Self::RecommendationSankey,
+            Self::N2oScenarios,
+            Self::Ch4Scenarios,
+            Self::RecommendationDiff,
+            Self::EmissionShares,
+            Self::EmissionGroups,
+        ]
+        .into_iter()
+        .find(|chart| chart.name() == s)
+        .ok_or_else(|| UnknownChartError(s.to_string()))
+    }
+}
+
+/// Render a chart of the form data as SVG.
+///
+/// Returns `None` if the form data is not sufficient for the chart.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn render_chart(
+    form_data: &HashMap<Id, Value>,
+    chart: Chart,
+    width: f64,
+    height: f64,
+    lang: Lng,
+) -> Option<String> {
+    let CustomValues {
+        values: custom_values,
+        edges: custom_edges,
+        leafs: custom_leafs,
+        labels: custom_labels,
+        recommendations: custom_recommendations,
+        ..
+    } = crate::custom_values(form_data, lang);
+    let values: HashMap<_, _> = form_data.clone().into_iter().chain(custom_values).collect();
+    let custom_edges = if custom_edges.is_empty() {
+        None
+    } else {
+        Some(&*custom_edges)
+    };
+    match chart {
+        Chart::Sankey => {
+            let outcome = klick_usecases::calculate_emissions(&values, custom_edges, custom_leafs);
+            let sankey_data = outcome.output.zip(outcome.graph)?;
+            Some(sankey(sankey_data, &custom_labels, width, height, lang))
+        }
+        Chart::RecommendationSankey => {
+            let outcome = klick_usecases::calculate_recommendation(
+                values,
+                custom_edges,
+                custom_leafs,
+                &custom_recommendations,
+            );
+            let sankey_data = outcome.output.zip(outcome.graph)?;
+            Some(sankey(sankey_data, &custom_labels, width, height, lang))
+        }
+        Chart::N2oScenarios => {
+            let outcome = klick_usecases::calculate_emissions(&values, custom_edges, custom_leafs);
+            n2o_scenarios(&outcome, width, height, lang)
+        }
+        Chart::Ch4Scenarios => {
+            let outcome = klick_usecases::calculate_emissions(&values, custom_edges, custom_leafs);
+            ch4_chp_scenarios(&outcome, width, height, lang)
+        }
+        Chart::RecommendationDiff => {
+            let sensitivity =
+                klick_usecases::calculate_emissions(&values, custom_edges, custom_leafs.clone());
+            let recommendation = klick_usecases::calculate_recommendation(
+                values,
+                custom_edges,
+                custom_leafs,
+                &custom_recommendations,
+            );
+            let data = presenter::recommendation_diff_bar_chart(
+                sensitivity.output?,
+                recommendation.output?,
+                lang,
+            );
+            diff_bar_chart(data, width, height, lang)
+        }
+        Chart::EmissionShares => {
+            let outcome = klick_usecases::calculate_emissions(&values, custom_edges, custom_leafs);
+            Some(emission_shares(&outcome.output?, width, height, lang))
+        }
+        Chart::EmissionGroups => {
+            let profile_values = form_data
+                .iter()
+                .filter_map(|(id, value)| match id {
+                    Id::In(id) => Some((*id, value.clone())),
+                    _ => None,
+                })
+                .collect();
+            let profile = klick_usecases::calculate_profile(profile_values);
+            let sensitivity = klick_usecases::calculate_sensitivity(
+                values.clone(),
+                custom_edges,
+                custom_leafs.clone(),
+            );
+            let recommendation = klick_usecases::calculate_recommendation(
+                values,
+                custom_edges,
+                custom_leafs,
+                &custom_recommendations,
+            );
+            let data = presenter::emission_groups_stacked_bar_chart(
+                profile.output.as_ref(),
+                sensitivity.output.as_ref(),
+                recommendation.output.as_ref(),
+                lang,
+            );
+            emission_groups(data, width, height, lang)
+        }
+    }
+}
+
+pub(crate) fn n2o_scenarios(
+    outcome: &CalculationOutcome,
+    width: f64,
+    height: f64,
+    lang: Lng,
+) -> Option<String> {
+    let scenarios: &Vec<(N2oEmissionFactorCalcMethod, HashMap<Id, Value>)> =
+        outcome.sensitivity_n2o_calculations.as_ref()?;
+    let selected = outcome
+        .input
+        .get(&In::SensitivityN2OCalculationMethod.into())
+        .cloned()
+        .map(Value::as_n2o_emission_factor_calc_method_unchecked)
+        .as_ref()
+        .and_then(ToPrimitive::to_u64);
+    let data = scenarios
+        .iter()
+        .map(|(method, values)| charts::BarChartRadioInputArguments {
+            label: Some(method.label(lang)),
+            value: required!(Out::TotalEmissions, values).unwrap().into(),
+            emission_factor: required!(Out::N2oCalculatedEmissionFactor, values)
+                .unwrap()
+                .into(),
+        })
+        .collect();
+    let emission_factor_label = Some("N₂O EF");
+    Some(charts::ssr::bar_chart_radio_input(
+        data,
+        width,
+        height,
+        selected,
+        emission_factor_label,
+        lang,
+    ))
+}
+
+pub(crate) fn ch4_chp_scenarios(
+    outcome: &CalculationOutcome,
+    width: f64,
+    height: f64,
+    lang: Lng,
+) -> Option<String> {
+    let scenarios: &Vec<(Ch4ChpEmissionFactorCalcMethod, Tons, Factor)> =
+        outcome.sensitivity_ch4_chp_calculations.as_ref()?;
+    let selected = outcome
+        .input
+        .get(&In::SensitivityCH4ChpCalculationMethod.into())
+        .cloned()
+        .map(Value::as_ch4_chp_emission_factor_calc_method_unchecked)
+        .as_ref()
+        .and_then(ToPrimitive::to_u64);
+    let data = scenarios
+        .iter()
+        .map(
+            |(method, emissions, emission_factor)| charts::BarChartRadioInputArguments {
+                label: Some(method.label(lang)),
+                value: (*emissions).into(),
+                emission_factor: (*emission_factor).into(),
+            },
+        )
+        .collect();
+    let emission_factor_label = Some("CH₄ EF");
+    Some(charts::ssr::bar_chart_radio_input(
+        data,
+        width,
+        height,
+        selected,
+        emission_factor_label,
+        lang,
+    ))
+}
+
+/// Bar chart of differences.
+///
+/// Returns `None` if there are no relevant differences.
+pub(crate) fn diff_bar_chart(
+    data: Vec<(String, f64, Option<f64>)>,
+    width: f64,
+    height: f64,
+    lang: Lng,
+) -> Option<String> {
+    let data = data
+        .into_iter()
+        .filter(|(_, value, _)| f64::abs(*value) > 0.1)
+        .map(|(label, value, percentage)| charts::BarChartArguments {
+            label,
+            value,
+            percentage,
+        })
+        .collect::<Vec<_>>();
+    if data.is_empty() {
+        return None;
+    }
+    Some(charts::ssr::bar_chart(data, width, height, lang))
+}
+
+pub(crate) fn emission_shares(
+    values: &HashMap<Id, Value>,
+    width: f64,
+    height: f64,
+    lang: Lng,
+) -> String {
+    let segment = |(label, value, color)| charts::DonutChartSegment {
+        label,
+        value,
+        color: charts::Color::new(color),
+    };
+    let data = presenter::emission_shares_donut_chart(values, lang)
+        .into_iter()
+        .map(|(group, segments)| charts::DonutChartArguments {
+            group: segment(group),
+            segments: segments.into_iter().map(segment).collect(),
+        })
+        .collect();
+    charts::ssr::donut_chart(data, width, height, lang)
+}
+
+/// Stacked bar chart of emission groups.
+///
+/// Returns `None` if there are no scenarios.
+pub(crate) fn emission_groups(
+    data: Vec<(String, Vec<presenter::EmissionShare>)>,
+    width: f64,
+    height: f64,
+    lang: Lng,
+) -> Option<String> {
+    let data = data
+        .into_iter()
+        .map(|(label, segments)| charts::StackedBarChartArguments {
+            label,
+            segments: segments
+                .into_iter()
+                .map(|(label, value, color)| charts::StackedBarChartSegment {
+                    label,
+                    value,
+                    color: charts::Color::new(color),
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    if data.is_empty() {
+        return None;
+    }
+    Some(charts::ssr::stacked_bar_chart(data, width, height, lang))
+}
+
+pub(crate) fn sankey(
+    (co2_equivalents, graph): (HashMap<Id, Value>, Vec<(Id, Id)>),
+    custom_labels: &HashMap<Id, String>,
+    width: f64,
+    height: f64,
+    lang: Lng,
+) -> String {
+    let (nodes, edges) =
+        presenter::create_sankey_chart_data(co2_equivalents, &graph, custom_labels, lang);
+
+    let mut sankey = charts::SankeyData::new();
+    let node_count = nodes.len();
+    let node_ids: Vec<_> = nodes
+        .into_iter()
+        .map(|(value, label, color, edge_color)| {
+            sankey.insert_node(
+                value,
+                label,
+                Some(charts::Color::new(color)),
+                Some(charts::Color::new(edge_color)),
+            )
+        })
+        .collect();
+    assert_eq!(node_ids.len(), node_count);
+
+    for (from_idx, to_idx) in edges {
+        let from = node_ids[from_idx];
+        let to = node_ids[to_idx];
+        sankey.insert_edge(from, to);
+    }
+
+    let number_format = move |n| lang.format_number_with_fixed_precision(n, 0);
+
+    charts::ssr::sankey_chart(sankey, width, height, number_format, 16.0, None)
+}
@@ -9,20 +9,27 @@ use std::{
 };
 
 use anyhow::{bail, Context as _};
-use num_traits::ToPrimitive;
 use serde::Serialize;
 use tera::{Context, Tera};
 use time::{format_description::FormatItem, macros::format_description, OffsetDateTime, UtcOffset};
 
-use klick_app_charts as charts;
 use klick_boundary as boundary;
+use klick_custom_values_parser::{custom_values_from_structure, CustomValues};
 use klick_domain::{
-    self as domain, optional_output_value_id as optional, required_output_value_id as required,
-    units::{Ch4ChpEmissionFactorCalcMethod, Factor, N2oEmissionFactorCalcMethod, Tons},
-    CalculationOutcome, InputValueId as In, OutputValueId as Out, Value, ValueId as Id,
+    self as domain, optional_output_value_id as optional, CalculationOutcome, InputValueId as In,
+    Value, ValueId as Id,
 };
 use klick_presenter::{self as presenter, Formatting, Lng, ValueLabel};
 
+mod charts;
+
+use self::charts::{
+    BAR_CHART_HEIGHT, BAR_CHART_WIDTH, DIFF_BAR_CHART_HEIGHT, SANKEY_CHART_HEIGHT,
+    SANKEY_CHART_WIDTH,
+};
+
+pub use self::charts::{render_chart, Chart, UnknownChartError};
+
 const MARKDOWN_TEMPLATE: &str = include_str!("../templates/report.md.template");
 const MARKDOWN_TEMPLATE_NAME: &str = "report.md";
 const LATEX_TABLE_TEMPLATE: &str = include_str!("../templates/table.tex.template");
@@ -41,7 +48,27 @@ pub fn export_to_pdf(form_data: &HashMap<Id, Value>) -> anyhow::Result<Vec<u8>>
     log::debug!("Create PDF report");
     let lang = Lng::De;
     let date = current_date_as_string()?;
-    let outcome = klick_usecases::calculate_emissions(form_data, None, vec![]); // FIXME make this static, not another evaluation of all models
+    let CustomValues {
+        values: custom_values,
+        edges: custom_edges,
+        leafs: custom_leafs,
+        labels: custom_labels,
+        citations: custom_citations,
+        recommendations: custom_recommendations,
+    } = custom_values(form_data, lang);
+    let values: HashMap<_, _> = form_data.clone().into_iter().chain(custom_values).collect();
+    let custom_edges = if custom_edges.is_empty() {
+        None
+    } else {
+        Some(&*custom_edges)
+    };
+    let outcome = klick_usecases::calculate_emissions(&values, custom_edges, custom_leafs.clone()); // FIXME make this static, not another evaluation of all models
+    let recommendation_outcome = klick_usecases::calculate_recommendation(
+        values,
+        custom_edges,
+        custom_leafs,
+        &custom_recommendations,
+    );
 
     let mut n2o_scenarios_svg_file = tempfile::Builder::new().suffix(".svg").tempfile()?;
     let mut ch4_chp_scenarios_svg_file = tempfile::Builder::new().suffix(".svg").tempfile()?;
@@ -56,7 +83,13 @@ pub fn export_to_pdf(form_data: &HashMap<Id, Value>) -> anyhow::Result<Vec<u8>>
     let sankey_data = outcome.output.clone().zip(outcome.graph.clone());
 
     let plant_profile_sankey_svg_file_path = if let Some(output) = &sankey_data {
-        let sankey_chart = render_svg_sankey_chart(output.clone(), lang);
+        let sankey_chart = charts::sankey(
+            output.clone(),
+            &custom_labels,
+            SANKEY_CHART_WIDTH,
+            SANKEY_CHART_HEIGHT,
+            lang,
+        );
         profile_sankey_svg_file.write_all(sankey_chart.as_bytes())?;
         Some(profile_sankey_svg_file.path().display().to_string())
     } else {
@@ -64,15 +97,32 @@ pub fn export_to_pdf(form_data: &HashMap<Id, Value>) -> anyhow::Result<Vec<u8>>
     };
 
     let sensitivity_sankey_svg_file_path = if let Some(output) = &sankey_data {
-        let sankey_chart = render_svg_sankey_chart(output.clone(), lang);
+        let sankey_chart = charts::sankey(
+            output.clone(),
+            &custom_labels,
+            SANKEY_CHART_WIDTH,
+            SANKEY_CHART_HEIGHT,
+            lang,
+        );
         sensitivity_sankey_svg_file.write_all(sankey_chart.as_bytes())?;
         Some(sensitivity_sankey_svg_file.path().display().to_string())
     } else {
         None
     };
 
-    let recommendation_sankey_svg_file_path = if let Some(output) = &sankey_data {
-        let sankey_chart = render_svg_sankey_chart(output.clone(), lang);
+    let recommendation_sankey_data = recommendation_outcome
+        .output
+        .clone()
+        .zip(recommendation_outcome.graph.clone());
+
+    let recommendation_sankey_svg_file_path = if let Some(output) = recommendation_sankey_data {
+        let sankey_chart = charts::sankey(
+            output,
+            &custom_labels,
+            SANKEY_CHART_WIDTH,
+            SANKEY_CHART_HEIGHT,
+            lang,
+        );
         recommendation_sankey_svg_file.write_all(sankey_chart.as_bytes())?;
         Some(recommendation_sankey_svg_file.path().display().to_string())
     } else {
@@ -80,97 +130,53 @@ pub fn export_to_pdf(form_data: &HashMap<Id, Value>) -> anyhow::Result<Vec<u8>>
     };
 
     log::debug!("Render bar charts");
-    let selected_n2o_scenario = &outcome
-        .input
-        .get(&In::SensitivityN2OCalculationMethod.into())
-        .cloned()
-        .map(Value::as_n2o_emission_factor_calc_method_unchecked)
-        .as_ref()
-        .and_then(ToPrimitive::to_u64);
-    let selected_ch4_chp_scenario = &outcome
-        .input
-        .get(&In::SensitivityCH4ChpCalculationMethod.into())
-        .cloned()
-        .map(Value::as_ch4_chp_emission_factor_calc_method_unchecked)
-        .as_ref()
-        .and_then(ToPrimitive::to_u64);
-
-    let n2o_scenarios_svg_file_path = if let Some(scenarios) = &outcome.sensitivity_n2o_calculations
+    let n2o_scenarios_svg_file_path = if let Some(svg_chart) =
+        charts::n2o_scenarios(&outcome, BAR_CHART_WIDTH, BAR_CHART_HEIGHT, lang)
     {
-        let svg_chart =
-            render_n2o_scenarios_svg_bar_chart(scenarios.clone(), *selected_n2o_scenario, lang);
         n2o_scenarios_svg_file.write_all(svg_chart.as_bytes())?;
         Some(n2o_scenarios_svg_file.path().display().to_string())
     } else {
         None
     };
 
-    let ch4_chp_scenarios_svg_file_path: Option<String> =
-        if let Some(scenarios) = &outcome.sensitivity_ch4_chp_calculations {
-            let svg_chart = render_ch4_chp_scenarios_svg_bar_chart(
-                scenarios.clone(),
-                *selected_ch4_chp_scenario,
-                lang,
-            );
-            ch4_chp_scenarios_svg_file.write_all(svg_chart.as_bytes())?;
-            Some(ch4_chp_scenarios_svg_file.path().display().to_string())
-        } else {
-            None
-        };
+    let ch4_chp_scenarios_svg_file_path = if let Some(svg_chart) =
+        charts::ch4_chp_scenarios(&outcome, BAR_CHART_WIDTH, BAR_CHART_HEIGHT, lang)
+    {
+        ch4_chp_scenarios_svg_file.write_all(svg_chart.as_bytes())?;
+        Some(ch4_chp_scenarios_svg_file.path().display().to_string())
+    } else {
+        None
+    };
 
-    let sensitivity_barchart_svg_file_path: Option<String> = if let Some(data) = outcome
+    let sensitivity_barchart_svg_file_path = if let Some(svg_chart) = outcome
         .output
         .clone()
         .and_then(|old| outcome.output.as_ref().map(|o| (o.clone(), old)))
-        .map(|(new, old)| {
-            presenter::sensitivity_diff_bar_chart(old, new, lang)
-                .into_iter()
-                .filter(|(_, value, _)| f64::abs(*value) > 0.1)
-                .map(|(label, value, percentage)| charts::BarChartArguments {
-                    label,
-                    value,
-                    percentage,
-                })
-                .collect::<Vec<_>>()
+        .and_then(|(new, old)| {
+            let data = presenter::sensitivity_diff_bar_chart(old, new, lang);
+            charts::diff_bar_chart(data, BAR_CHART_WIDTH, DIFF_BAR_CHART_HEIGHT, lang)
         }) {
-        if data.is_empty() {
-            None
-        } else {
-            let svg_chart = charts::ssr::bar_chart(data, BAR_CHART_WIDTH, 450.0);
-            sensitivity_barchart_svg_file.write_all(svg_chart.as_bytes())?;
-            Some(sensitivity_barchart_svg_file.path().display().to_string())
-        }
+        sensitivity_barchart_svg_file.write_all(svg_chart.as_bytes())?;
+        Some(sensitivity_barchart_svg_file.path().display().to_string())
     } else {
         None
     };
 
-    let recommendation_barchart_svg_file_path: Option<String> = if let Some(data) = outcome
+    let recommendation_barchart_svg_file_path = if let Some(svg_chart) = outcome
         .output
         .clone()
-        .and_then(|old| outcome.output.as_ref().map(|o| (o.clone(), old)))
-        .map(|(new, old)| {
-            presenter::recommendation_diff_bar_chart(old, new, lang)
-                .into_iter()
-                .filter(|(_, value, _)| f64::abs(*value) > 0.1)
-                .map(|(label, value, percentage)| charts::BarChartArguments {
-                    label,
-                    value,
-                    percentage,
-                })
-                .collect::<Vec<_>>()
+        .zip(recommendation_outcome.output.clone())
+        .and_then(|(old, new)| {
+            let data = presenter::recommendation_diff_bar_chart(old, new, lang);
+            charts::diff_bar_chart(data, BAR_CHART_WIDTH, DIFF_BAR_CHART_HEIGHT, lang)
         }) {
-        if data.is_empty() {
-            None
-        } else {
-            let svg_chart = charts::ssr::bar_chart(data, BAR_CHART_WIDTH, 450.0);
-            recommendation_barchart_svg_file.write_all(svg_chart.as_bytes())?;
-            Some(
-                recommendation_barchart_svg_file
-                    .path()
-                    .display()
-                    .to_string(),
-            )
-        }
+        recommendation_barchart_svg_file.write_all(svg_chart.as_bytes())?;
+        Some(
+            recommendation_barchart_svg_file
+                .path()
+                .display()
+                .to_string(),
+        )
     } else {
         None
     };
@@ -178,6 +184,7 @@ pub fn export_to_pdf(form_data: &HashMap<Id, Value>) -> anyhow::Result<Vec<u8>>
     let markdown = render_markdown_template(
         date,
         outcome,
+        &recommendation_outcome,
         plant_profile_sankey_svg_file_path,
         sensitivity_sankey_svg_file_path,
         sensitivity_barchart_svg_file_path,
@@ -185,6 +192,8 @@ pub fn export_to_pdf(form_data: &HashMap<Id, Value>) -> anyhow::Result<Vec<u8>>
         n2o_scenarios_svg_file_path,
         ch4_chp_scenarios_svg_file_path,
         recommendation_barchart_svg_file_path,
+        &custom_labels,
+        custom_citations,
         lang,
     )?;
 
@@ -204,6 +213,7 @@ pub fn export_to_pdf(form_data: &HashMap<Id, Value>) -> anyhow::Result<Vec<u8>>
 fn render_markdown_template(
     date: String,
     outcome: CalculationOutcome,
+    recommendation_outcome: &CalculationOutcome,
     plant_profile_sankey_svg_file_path: Option<String>,
     sensitivity_sankey_svg_file_path: Option<String>,
     sensitivity_barchart_svg_file_path: Option<String>,
@@ -211,10 +221,13 @@ fn render_markdown_template(
     n2o_scenarios_svg_file_path: Option<String>,
     ch4_chp_scenarios_svg_file_path: Option<String>,
     recommendation_barchart_svg_file_path: Option<String>,
+    custom_labels: &HashMap<Id, String>,
+    custom_citations: Vec<(Id, String)>,
     lang: Lng,
 ) -> anyhow::Result<String> {
-    let plant_profile_table_data =
+    let mut plant_profile_table_data =
         presenter::plant_profile_as_table(&outcome.input, Formatting::LaTeX, lang);
+    plant_profile_table_data.relabel(custom_labels);
     let plant_profile_table = create_latex_table(&plant_profile_table_data)?;
 
     let sensitivity_table_data =
@@ -227,6 +240,50 @@ fn render_markdown_template(
         .cloned()
         .map_or_else(|| "Klärwerk".to_string(), Value::as_text_unchecked);
 
+    let avoided_emissions_table = outcome
+        .output
+        .as_ref()
+        .map(|output| {
+            let table_data = presenter::avoided_emissions_as_table(output, Formatting::LaTeX, lang);
+            create_latex_table(&table_data)
+        })
+        .transpose()?;
+
+    let scope2_electricity_table = outcome
+        .output
+        .as_ref()
+        .map(|output| {
+            let table_data =
+                presenter::scope2_electricity_as_table(output, Formatting::LaTeX, lang);
+            create_latex_table(&table_data)
+        })
+        .transpose()?;
+
+    let n2o_measurement_campaign_table = outcome
+        .input
+        .contains_key(&In::SensitivityN2OMeasuredFactor.into())
+        .then(|| {
+            let table_data = presenter::n2o_measurement_campaign_as_table(
+                &outcome.input,
+                Formatting::LaTeX,
+                lang,
+            );
+            create_latex_table(&table_data)
+        })
+        .transpose()?;
+
+    let embodied_emissions_table = recommendation_outcome
+        .output
+        .as_ref()
+        .map(|output| klick_usecases::calculate_amortisations(&outcome.input, output))
+        .filter(|amortisations| !amortisations.is_empty())
+        .map(|amortisations| {
+            let table_data =
+                presenter::embodied_emissions_as_table(&amortisations, Formatting::LaTeX, lang);
+            create_latex_table(&table_data)
+        })
+        .transpose()?;
+
     let plant_profile_sankey_header = outcome
         .output
         .map(|output| {
@@ -234,12 +291,27 @@ fn render_markdown_template(
         })
         .unwrap_or_default();
 
+    let custom_emission_citations = custom_citations
+        .into_iter()
+        .map(|(id, citation)| {
+            let label = custom_labels
+                .get(&id)
+                .cloned()
+                .unwrap_or_else(|| id.label(lang));
+            Citation { label, citation }
+        })
+        .collect();
+
     let data = TemplateData {
         date,
         plant_profile_table,
         sensitivity_parameters_table,
         plant_name,
         plant_profile_sankey_header,
+        avoided_emissions_table,
+        scope2_electricity_table,
+        n2o_measurement_campaign_table,
+        embodied_emissions_table,
         n2o_scenarios_svg_file_path,
         ch4_chp_scenarios_svg_file_path,
         plant_profile_sankey_svg_file_path,
@@ -247,6 +319,7 @@ fn render_markdown_template(
         sensitivity_barchart_svg_file_path,
         recommendation_sankey_svg_file_path,
         recommendation_barchart_svg_file_path,
+        custom_emission_citations,
     };
 
     let rendered = TEMPLATES.render(MARKDOWN_TEMPLATE_NAME, &Context::from_serialize(data)?)?;
@@ -263,6 +336,10 @@ struct TemplateData {
     sensitivity_parameters_table: String,
     plant_name: String,
     plant_profile_sankey_header: String,
+    avoided_emissions_table: Option<String>,
+    scope2_electricity_table: Option<String>,
+    n2o_measurement_campaign_table: Option<String>,
+    embodied_emissions_table: Option<String>,
     n2o_scenarios_svg_file_path: Option<String>,
     ch4_chp_scenarios_svg_file_path: Option<String>,
     plant_profile_sankey_svg_file_path: Option<String>,
@@ -270,93 +347,36 @@ struct TemplateData {
     sensitivity_barchart_svg_file_path: Option<String>,
     recommendation_sankey_svg_file_path: Option<String>,
     recommendation_barchart_svg_file_path: Option<String>,
+    custom_emission_citations: Vec<Citation>,
 }
 
-const BAR_CHART_WIDTH: f64 = 1100.0;
-const BAR_CHART_HEIGHT: f64 = 300.0;
-
-fn render_n2o_scenarios_svg_bar_chart(
-    n2o_scenarios: Vec<(N2oEmissionFactorCalcMethod, HashMap<Id, Value>)>,
-    selected: Option<u64>,
-    lang: Lng,
-) -> String {
-    let data = n2o_scenarios
-        .into_iter()
-        .map(|(method, values)| charts::BarChartRadioInputArguments {
-            label: Some(method.label(lang)),
-            value: required!(Out::TotalEmissions, values).unwrap().into(),
-            emission_factor: required!(Out::N2oCalculatedEmissionFactor, values)
-                .unwrap()
-                .into(),
-        })
-        .collect();
-    let emission_factor_label = Some("N₂O EF");
-    charts::ssr::bar_chart_radio_input(
-        data,
-        BAR_CHART_WIDTH,
-        BAR_CHART_HEIGHT,
-        selected,
-        emission_factor_label,
-        lang,
-    )
-}
-
-fn render_ch4_chp_scenarios_svg_bar_chart(
-    scenarios: Vec<(Ch4ChpEmissionFactorCalcMethod, Tons, Factor)>,
-    selected: Option<u64>,
-    lang: Lng,
-) -> String {
-    let data = scenarios
-        .into_iter()
-        .map(
-            |(method, emissions, emission_factor)| charts::BarChartRadioInputArguments {
-                label: Some(method.label(lang)),
-                value: emissions.into(),
-                emission_factor: emission_factor.into(),
-            },
-        )
-        .collect();
-    let emission_factor_label = Some("CH₄ EF");
-    charts::ssr::bar_chart_radio_input(
-        data,
-        BAR_CHART_WIDTH,
-        BAR_CHART_HEIGHT,
-        selected,
-        emission_factor_label,
-        lang,
-    )
+#[derive(Serialize, Debug)]
+struct Citation {
+    label: String,
+    citation: String,
 }
 
-fn render_svg_sankey_chart(
-    (co2_equivalents, graph): (HashMap<Id, Value>, Vec<(Id, Id)>),
-    lang: Lng,
-) -> String {
-    let (nodes, edges) = presenter::create_sankey_chart_data(co2_equivalents, &graph, lang);
-
-    let mut sankey = charts::SankeyData::new();
-    let node_count = nodes.len();
-    let node_ids: Vec<_> = nodes
-        .into_iter()
-        .map(|(value, label, color, edge_color)| {
-            sankey.insert_node(
-                value,
-                label,
-                Some(charts::Color::new(color)),
-                Some(charts::Color::new(edge_color)),
-            )
-        })
-        .collect();
-    assert_eq!(node_ids.len(), node_count);
-
-    for (from_idx, to_idx) in edges {
-        let from = node_ids[from_idx];
-        let to = node_ids[to_idx];
-        sankey.insert_edge(from, to);
-    }
-
-    let number_format = |n| Lng::De.format_number_with_fixed_precision(n, 0);
-
-    charts::ssr::sankey_chart(sankey, 1200.0, 800.0, number_format, 16.0, None)
+/// Custom emissions of the form data.
+///
+/// Invalid custom emissions are ignored,
+/// because they are also ignored by the calculation.
+fn custom_values(form_data: &HashMap<Id, Value>, lang: Lng) -> CustomValues {
+    let Some(value) = form_data.get(&In::SensitivityAdditionalCustomEmissions.into()) else {
+        return CustomValues::default();
+    };
+    let emissions = match boundary::CustomEmissions::from_value(value) {
+        Ok(emissions) => emissions,
+        Err(err) => {
+            log::warn!("Ignore invalid custom emissions: {err}");
+            return CustomValues::default();
+        }
+    };
+    custom_values_from_structure(&emissions, lang).unwrap_or_else(|diagnostics| {
+        for diagnostic in diagnostics {
+            log::warn!("Ignore invalid custom emissions: {diagnostic}");
+        }
+        CustomValues::default()
+    })
 }
 
 fn render_pdf(mut markdown: String) -> anyhow::Result<Vec<u8>> {
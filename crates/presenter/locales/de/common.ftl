@@ -10,19 +10,49 @@ gas-supply = Gasbezug
 biogas-purchase = Biogas Bezug
 heating-oil-purchase = Heizölbezug
 sewage-gas-produced = Klärgas
+sewage-gas-flared = Abgefackeltes Klärgas
+sewage-gas-vented = Abgeblasenes Klärgas
+sewage-gas-upgraded = Aufbereitetes Klärgas
+upgrading-power-consumption = Strombedarf Aufbereitung
+heat-exported = Wärmeabgabe
+electricity-contract = Stromvertrag
+contract-energy = Strommenge
+contract-emission-factor = EF Versorger
+guarantee-of-origin = Herkunftsnachweis
+residual-mix-emission-factor = EF Residualmix
 number-of-digesters = Anzahl Faultürme
+pressure-main-length = Länge
+retention-time = Aufenthaltszeit
+pumping-station-energy = Strombedarf
+overflow-volume = Entlastungsvolumen
 mass-dewatered = Masse entwässert
 distance = Entfernung
+share = Anteil
+chp-unit = BHKW
+chp-unit-calculation-method = Motortyp
+chp-unit-custom-factor = CH₄-EF benutzerdefiniert
+chp-unit-gas-share = Anteil Klärgas
+chp-unit-gas-volume = Klärgasmenge
 ferrous-chloride = Lösung
 ferrous-chloride-sulfate = Lösung
 calcium-hydroxide = Branntkalk
 synthetic-polymers = Polymere
+methanol = Methanol
+polyaluminium-chloride = Lösung
+sodium-hydroxide = Natronlauge
+acetic-acid = Essigsäure
+activated-carbon = Aktivkohle
 district-heating = Jahresleistung
+heat-pump-thermal-output = Jahresleistung
 self-use-water-energy = Jahresleistung
 self-use-wind-energy = Jahresleistung
 self-use-pv-energy = Jahresleistung
 fossil-energy-saving = Jahreseinsparung
 process-energy-saving = Jahreseinsparung
+embodied-emissions = Graue Emissionen
+photovoltaic-peak-power = Nennleistung
+digester-cover-area = Fläche
+digester-cover-concrete = Beton
 
 description-project-name = In diesem Feld können Sie einen Namen für Ihr Projekt hinterlegen. In der <b>angemeldeten</b> Version, dient der Projektname der Speicherung Ihrer Eingaben/Ergebnisse unter dem Reiter „Projekte“. Wenn Sie sich <b>nicht angemeldet</b> haben, wird der Projektname ausschließlich nur auf Ihrer Festplatte gespeichert und in Ihrem lokalen Browser verarbeitet. Weitere Informationen zur Datenverarbeitung finden Sie in den <b>FAQ</b>.
 description-plant-name = Die Angabe des Namens und/oder Orts sind freiwillig. Alternativ kann für das Feld ein Platzhalter eingetragen werden. Sämtliche Eintragungen können nur von Ihnen (nicht der UTBW) eingesehen oder gespeichert werden.
@@ -36,30 +66,69 @@ description-cod-effluent = Der Jahresmittelwert des chemischen Sauerstoffbedarf
 description-total-power-consumption = Der Gesamt-Strombedarf Ihrer Kläranlage in Kilowattstunden (kWh) pro Jahr (a).
 description-self-generated-power = Anteil der Eigenstromerzeugung in Kilowattstunden (kWh) pro Jahr (a). Falls kein Eigenstrom erzeugt wird, dieses Feld bitte freilassen.
 description-emission-factor-electricity-mix = Angabe des Emissionsfaktors des von extern bezogenen Strommixes in Gramm (g) CO₂ pro Kilowattstunde (kWh). Falls dieser Wert nicht verfügbar ist, bitte den Referenzwert stehen lassen.
+description-electricity-contracts = Die Stromlieferverträge Ihrer Kläranlage mit der jeweils bezogenen Strommenge, dem Emissionsfaktor des Versorgers und ggf. Herkunftsnachweisen. Die Verträge decken den extern bezogenen Strom in ihrer Reihenfolge ab.
+description-contract-energy = Die über diesen Stromliefervertrag bezogene Strommenge in Kilowattstunden (kWh) pro Jahr (a). Die Verträge decken den extern bezogenen Strom in ihrer Reihenfolge ab, der nicht abgedeckte Rest wird mit dem Residualmix-EF bewertet.
+description-contract-emission-factor = Der vom Versorger für diesen Vertrag ausgewiesene Emissionsfaktor in Gramm (g) CO₂ pro Kilowattstunde (kWh), z.B. für einen Ökostromtarif. Falls dieser Wert nicht verfügbar ist, wird der Residualmix-EF verwendet.
+description-guarantee-of-origin = Falls der Strom dieses Vertrags durch Herkunftsnachweise gedeckt ist, dieses Feld bitte anklicken. Der Strom wird dann in der marktbasierten Bilanz mit 0 g CO₂/kWh bewertet.
+description-residual-mix-emission-factor = Angabe des Emissionsfaktors des Residualmixes in Gramm (g) CO₂ pro Kilowattstunde (kWh), d.h. des Strommixes ohne die über Herkunftsnachweise vermarkteten Strommengen. Er wird in der marktbasierten Bilanz für den nicht durch Verträge gedeckten Strom verwendet. Falls dieser Wert nicht verfügbar ist, wird näherungsweise der Strommix-EF verwendet, wodurch die marktbasierten Emissionen unterschätzt werden.
 description-gas-supply = Menge an Gas (Erdgas/Biogas) in Kubikmeter (m³) pro Jahr (a) die von einem externen Versorger bezogen werden. Falls an Ihrer Kläranlage kein Gas von extern bezogen wird, dieses Feld bitte freilassen.
 description-biogas-purchase = Falls Ihre Kläranlage Biogas von extern bezieht, dieses Feld bitte anklicken.
 description-heating-oil-purchase = Menge an Heizöl (z.B. für die Beheizung von Gebäuden) in Litern (L) pro Jahr (a) die von einem externen Versorger bezogen werden. Falls an Ihrer Kläranlage kein Heizöl von extern bezogen wird, dieses Feld bitte freilassen.
 description-sewage-gas-produced = Das an Ihrer Kläranlage erzeugte Klärgas in Kubikmeter (m³) pro Jahr (a). Falls an Ihrer Kläranlage kein Klärgas erzeugt wird, dieses Feld bitte freilassen.
 description-methane-percentage = Der Methangehalt des an Ihrer Kläranlage erzeugten Klärgases in Prozent (%). Falls an Ihrer Kläranlage kein Klärgas erzeugt wird, dieses Feld bitte freilassen.
+description-sewage-gas-flared = Die Menge des erzeugten Klärgases in Kubikmeter (m³) pro Jahr (a), die über die Fackel verbrannt wird und nicht das BHKW erreicht.
+description-flare-efficiency = Der Anteil des Methans in Prozent (%), der in der Fackel verbrannt wird. Für offene Fackeln wird ein Wirkungsgrad von 50 % angenommen, für geschlossene Fackeln von 90 %.
+description-sewage-gas-vented = Die Menge des erzeugten Klärgases in Kubikmeter (m³) pro Jahr (a), die ungenutzt abgeblasen wird oder durch Leckagen entweicht.
+description-sewage-gas-upgraded = Die Menge des erzeugten Klärgases in Kubikmeter (m³) pro Jahr (a), die zu Biomethan aufbereitet und in das Gasnetz eingespeist wird. Das eingespeiste Biomethan wird als Gutschrift ausgewiesen und nicht mit den Gesamtemissionen verrechnet.
+description-upgrading-methane-slip = Der Anteil des Methans in Prozent (%), der bei der Aufbereitung zu Biomethan entweicht.
+description-upgrading-power-consumption = Der Strombedarf der Biomethanaufbereitung in Kilowattstunden (kWh) pro Jahr (a). Dieser Strombedarf sollte nicht im Gesamtstrombedarf enthalten sein.
+description-heat-exported = Die an Dritte abgegebene Wärme in Kilowattstunden (kWh) pro Jahr (a). Die abgegebene Wärme wird als Gutschrift ausgewiesen und nicht mit den Gesamtemissionen verrechnet.
 description-number-of-digesters = Falls auf Ihrer Kläranlage eine Faulung vorhanden ist, dann geben Sie bitte die Anzahl der Faultürme ein. Falls nicht lassen Sie das Feld bitte offen oder tragen eine 0 ein.
+description-pressure-main-length = Die Gesamtlänge der Druckleitungen im Kanalnetz in Kilometer (km). In Druckleitungen bildet sich unter Luftabschluss gelöstes Methan, das an den Auslässen ausgast.
+description-pressure-main-retention-time = Die mittlere Aufenthaltszeit des Abwassers in den Druckleitungen in Stunden (h). Je länger die Aufenthaltszeit, desto mehr Methan wird gebildet.
+description-pumping-station-energy = Der Strombedarf der Pumpwerke im Kanalnetz in Kilowattstunden (kWh) pro Jahr (a). Dieser Strombedarf sollte nicht im Gesamtstrombedarf der Kläranlage enthalten sein.
+description-overflow-volume = Das Volumen des ungeklärt entlasteten Abwassers (z.B. Mischwasserentlastungen) in Kubikmeter (m³) pro Jahr (a).
 description-sludge-bags-are-open = Falls die Schlammtaschen des Faulturms / der Faultürme Ihrer Kläranlage geschlossen sind und nicht zur Umgebungsluft offen sind, dann dieses Feld bitte anklicken.
 description-sludge-storage-containers-are-open = Falls die Schlammstapelbehälter Ihrer Kläranlage dicht abgedeckt sind, dann dieses Feld bitte anklicken.
 description-mass-dewatered = Angabe der Menge an Klärschlamm in Tonnen (t) die zur Entsorgung anfallen.
 description-distance = Entfernung von Ihrer Kläranlage zum Entsorgungsort des Klärschlamms in Kilometer (km). Die Angabe ist unabhängig von der Entsorgungsart (z.B. Verbrennung) oder der Transportform (z.B. entwässert/trocken). Falls der Klärschlamm auf Ihrer Kläranlage entsorgt wird, dieses Feld bitte freilassen.
+description-sludge-disposal-route = Der Entsorgungsweg des Klärschlamms. Der gesamte Klärschlamm zur Entsorgung wird diesem Weg zugeordnet, sofern keine Anteile je Entsorgungsweg angegeben sind.
+description-sludge-disposal-share-mono-incineration = Anteil des Klärschlamms in Prozent (%), der in einer Monoverbrennungsanlage verbrannt wird.
+description-sludge-disposal-share-co-incineration = Anteil des Klärschlamms in Prozent (%), der mitverbrannt wird (z.B. in Zementwerken oder Kohlekraftwerken).
+description-sludge-disposal-share-agriculture = Anteil des Klärschlamms in Prozent (%), der landwirtschaftlich verwertet wird.
+description-sludge-disposal-share-landfill = Anteil des Klärschlamms in Prozent (%), der deponiert wird.
 description-total-nitrogen-side-stream = Falls auf Ihrer Kläranlage eine Prozesswasserbehandlung vorhanden ist, dann geben Sie bitte deren jährliche Gesamtsticksoffmenge in Tonnen [t/a] ein. Falls nicht lassen Sie das Feld bitte offen oder tragen eine 0 ein.
 description-ferrous-chloride = Angabe der pro Jahr (a) eingesetzten Menge an Eisen(III)-chlorid (FeCl3) in Tonnen (t).
 description-ferrous-chloride-sulfate = Angabe der pro Jahr (a) eingesetzten Menge an Eisenchloridsulfat (FeClSO4) in Tonnen (t).
 description-calcium-hydroxide = Angabe der pro Jahr (a) eingesetzten Menge an Kalkhydrat (Ca(OH)2) in Tonnen (t).
 description-synthetic-polymers = Angabe der pro Jahr (a) eingesetzten Menge an synthetischen Polymeren in Tonnen (t).
+description-methanol = Angabe der pro Jahr (a) für die Denitrifikation dosierten Menge an Methanol (CH3OH) in Tonnen (t).
+description-polyaluminium-chloride = Angabe der pro Jahr (a) eingesetzten Menge an Polyaluminiumchlorid-Lösung (PAC) in Tonnen (t).
+description-sodium-hydroxide = Angabe der pro Jahr (a) eingesetzten Menge an Natronlauge in Tonnen (t).
+description-sodium-hydroxide-concentration = Angabe des Anteils an Natriumhydroxid (NaOH) in der gelieferten Natronlauge in Prozent (%). Falls dieser Wert nicht verfügbar ist, wird der typische Wert von 50 % verwendet.
+description-acetic-acid = Angabe der pro Jahr (a) für die Denitrifikation dosierten Menge an Essigsäure-Lösung in Tonnen (t).
+description-acetic-acid-concentration = Angabe des Anteils an Essigsäure in der gelieferten Lösung in Prozent (%). Falls dieser Wert nicht verfügbar ist, wird der typische Wert von 80 % verwendet.
+description-activated-carbon = Angabe der pro Jahr (a) in der 4. Reinigungsstufe eingesetzten Menge an Pulveraktivkohle (PAK) in Tonnen (t).
 description-sensitivity-sludge-bags-custom-factor = Über dieses Eingabefeld können Sie (z.B. basierend auf einer eigenen Abschätzung oder einer Messkampagne) einen Wert für den EF CH₄ eintragen.
 description-sensitivity-sludge-storage-custom-factor = Über dieses Eingabefeld können Sie (z.B. basierend auf einer eigenen Abschätzung oder einer Messkampagne) einen Wert für den EF CH₄ eintragen.
 description-sensitivity-CH4-chp-custom-factor = Über dieses Eingabefeld können Sie (z.B. basierend auf einer eigenen Abschätzung oder einer Messkampagne) einen Wert für den EF CH₄ eintragen.
+description-sensitivity-ch4-chp-units = Die einzelnen BHKW-Aggregate Ihrer Kläranlage mit Motortyp und verwerteter Klärgasmenge. Sie werden im Mischbetrieb berücksichtigt.
+description-sensitivity-ch4-chp-unit-calculation-method = Der Motortyp dieses BHKW-Aggregats.
+description-sensitivity-ch4-chp-unit-gas-share = Anteil des erzeugten Klärgases in Prozent (%), der in diesem BHKW-Aggregat verwertet wird. Ohne Angabe wird das verbleibende Klärgas gleichmäßig auf die Aggregate ohne Angabe verteilt.
+description-sensitivity-ch4-chp-unit-gas-volume = Menge an Klärgas in Kubikmeter (m³) pro Jahr (a), die in diesem BHKW-Aggregat verwertet wird. Die Menge hat Vorrang vor dem Anteil.
 description-sensitivity-CO2-fossil-custom-factor = Über dieses Eingabefeld können Sie (z.B. basierend auf einer eigenen Abschätzung oder einer Messkampagne) einen Wert für den EF CO₂ eintragen.
 description-sensitivity-N2O-custom-factor = Über dieses Eingabefeld können Sie (z.B. anhand einer eigenen Abschätzung oder einer Messkampagne) einen Wert für den EF N₂O eintragen. Weiter muss die Auswahlmöglichkeit (Benutzerdefiniert) manuell ausgewählt werden, um den eingegebenen Wert zu verwenden.
+description-sensitivity-N2O-measured-factor = Der standortspezifische EF N₂O, der aus einer importierten Online-Messung (Zeitstempel, N₂O-Konzentration in ppm, Abluftvolumenstrom in m³/h) berechnet wurde. Um den Wert zu verwenden, muss die Auswahlmöglichkeit (Messung) ausgewählt werden.
+description-sensitivity-N2O-measurement-period = Der Zeitraum der importierten N₂O-Messung.
+description-sensitivity-N2O-measurement-samples = Die Anzahl der Messwerte der importierten N₂O-Messung.
+description-sensitivity-N2O-measurement-coverage = Anteil des Messzeitraums in Prozent (%), der durch lückenlose Messwerte abgedeckt ist.
+description-sensitivity-N2O-measurement-annual-coverage = Anteil des Jahres in Prozent (%), der durch lückenlose Messwerte abgedeckt ist. Je geringer die Abdeckung, desto unsicherer ist die Hochrechnung auf das Jahr.
 description-sensitivity-N2O-side-stream-factor = Über dieses Eingabefeld können Sie (z.B. anhand einer eigenen Abschätzung oder einer Messkampagne) einen Wert für den EF der Prozesswasserbehandlung eintragen.
 description-sensitivity-N2O-calculation-method = Dieses Eingabefeld gibt an, mit welchem Verfahren die EF für den CO₂-Emissionshaltungswert berechnet wird.
 description-sensitivity-ch4-chp-calculation-method = Dieses Eingabefeld gibt an, wie die EF für den CO₂-Emissionshaltungswert berechnet wird.
 description-district-heating = Angabe der Abgabeleistung an Fern-/Nahwärme in Kilowattstunden (kWh) pro Jahr (a).
+description-heat-pump-thermal-output = Angabe der Wärmeleistung einer Wärmepumpe zur Abwasserwärmenutzung in Kilowattstunden (kWh) pro Jahr (a). Die Wärme ersetzt zuerst Heizöl und anschließend Gas.
+description-heat-pump-coefficient-of-performance = Die Leistungszahl (COP) der Wärmepumpe, d.h. das Verhältnis von abgegebener Wärme zu aufgenommenem Strom.
 description-scenario-estimated-self-water-energy-usage = Geschätzte Eigennutzung der Wasserkraftleistung in Prozent (%).
 description-water-energy-expansion = Angabe des Zubaus an Wasserkraftleistung in Kilowattstunden (kWh) pro Jahr (a).
 description-scenario-estimated-self-wind-energy-usage = Geschätzte Eigennutzung der Windkraftleistung in Prozent (%).
@@ -68,7 +137,17 @@ description-scenario-estimated-self-photovolatic-usage = Geschätzte Eigennutzun
 description-photovoltaic-energy-expansion = Angabe des Zubaus an Photovoltaikleistung in Kilowattstunden (kWh) pro Jahr (a).
 description-fossil-energy-saving = Angabe der geschätzten Energieeinsparung bei fossilen Energieträgern (z.B. Heizöl/Erdgas) in Prozent (%).
 description-process-energy-saving = Angabe der geschätzten Energieeinsparung bei Kläranlagen-Prozessen in Prozent (%).
+description-photovoltaic-peak-power = Angabe der installierten Nennleistung der neuen Photovoltaikanlage in Kilowatt-Peak (kWp). Daraus werden die grauen Emissionen der Anlage abgeschätzt.
+description-photovoltaic-embodied-emissions = Angabe der grauen Emissionen (Herstellung und Bau) der neuen Photovoltaikanlage in Tonnen CO₂-Äquivalenten (t CO₂-Äq.). Der Wert hat Vorrang vor der Abschätzung aus der Nennleistung.
+description-wind-embodied-emissions = Angabe der grauen Emissionen (Herstellung und Bau) der neuen Windkraftanlage in Tonnen CO₂-Äquivalenten (t CO₂-Äq.).
+description-water-embodied-emissions = Angabe der grauen Emissionen (Herstellung und Bau) der neuen Wasserkraftanlage in Tonnen CO₂-Äquivalenten (t CO₂-Äq.).
+description-heat-pump-embodied-emissions = Angabe der grauen Emissionen (Herstellung und Bau) der Wärmepumpe in Tonnen CO₂-Äquivalenten (t CO₂-Äq.).
+description-flare-embodied-emissions = Angabe der grauen Emissionen (Herstellung und Bau) der geschlossenen Fackel in Tonnen CO₂-Äquivalenten (t CO₂-Äq.).
+description-digester-cover-area = Angabe der Fläche der neuen Abdeckung von Schlammtaschen oder Schlammstapelbehältern in Quadratmetern (m²). Daraus werden die grauen Emissionen der Abdeckung abgeschätzt.
+description-digester-cover-concrete = Angabe des für die Abdeckung verbauten Betons in Kubikmetern (m³). Daraus werden die grauen Emissionen der Abdeckung abgeschätzt.
+description-digester-cover-embodied-emissions = Angabe der grauen Emissionen (Herstellung und Bau) der Abdeckung in Tonnen CO₂-Äquivalenten (t CO₂-Äq.). Der Wert hat Vorrang vor der Abschätzung aus Fläche und Beton.
 description-additional-custom-emissions = Mit diesem Formularfeld können dem Sankey-Diagramm weitere benutzerdefinierte Emissionen hinzugefügt werden.
 description-recommendation-sludge-bags-are-open = Schlammtaschen sind geöffnet
 description-recommendation-sludge-storage-containers-are-open = Schlammstapelbehälter sind geöffnet
 description-recommendation-recommendation-n2o-side-stream-cover-is-open = Offene Prozesswasserbehandlung
+description-recommendation-flare-is-open = Offene Fackel durch eine geschlossene Fackel ersetzen
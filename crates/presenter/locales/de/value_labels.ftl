@@ -9,16 +9,34 @@ ProfileEffluentNitrogen = Gesamtstickstoff
 ProfileEffluentChemicalOxygenDemand = Chemischer Sauerstoffbedarf
 ProfileSewageGasProduced = Erzeugtes Klärgas
 ProfileMethaneFraction = Methangehalt
+ProfileSewageGasFlared = Abgefackeltes Klärgas
+ProfileFlareEfficiency = Wirkungsgrad der Fackel
+ProfileSewageGasVented = Abgeblasenes Klärgas
+ProfileSewageGasUpgraded = Aufbereitetes Klärgas (Biomethan)
+ProfileUpgradingMethaneSlip = Methanschlupf der Aufbereitung
+ProfileUpgradingPowerConsumption = Strombedarf der Aufbereitung
+ProfileHeatExported = Wärmeabgabe
 ProfileGasSupply = Gasbezug (Versorger)
 ProfilePurchaseOfBiogas = Bezug von Biogas
 ProfileTotalPowerConsumption = Strombedarf gesamt
 ProfileOnSitePowerGeneration = Eigenstromerzeugung
 ProfileEmissionFactorElectricityMix = Strommix-EF (Versorger)
+ProfileElectricityContracts = Stromverträge
+ProfileElectricityResidualMixEmissionFactor = Residualmix EF
 ProfileHeatingOil = Heizölbezug
 ProfileSideStreamTotalNitrogen = Gesamtstickstoff
 ProfileSludgeDisposal = Klärschlamm zur Entsorgung
 ProfileSludgeTransportDistance = Transportdistanz
+ProfileSludgeDisposalRoute = Entsorgungsweg
+ProfileSludgeDisposalShareMonoIncineration = Anteil Monoverbrennung
+ProfileSludgeDisposalShareCoIncineration = Anteil Mitverbrennung
+ProfileSludgeDisposalShareAgriculture = Anteil Landwirtschaft
+ProfileSludgeDisposalShareLandfill = Anteil Deponie
 ProfileSludgeDigesterCount = Anzahl Faultürme
+ProfileSewerPressureMainLength = Länge Druckleitungen
+ProfileSewerPressureMainRetentionTime = Aufenthaltszeit Druckleitungen
+ProfileSewerPumpingStationEnergy = Strombedarf Pumpwerke
+ProfileSewerOverflowVolume = Entlastungsvolumen
 ProfileSludgeBagsAreOpen= Schlammtaschen sind offen
 ProfileScenarioSludgeBagsAreOpen = Schlammtaschen sind offen
 ProfileSludgeStorageContainersAreOpen = Schlammlagerung ist offen
@@ -27,22 +45,47 @@ ProfileOperatingMaterialFeCl3 = Eisen(III)-chlorid-Lösung
 ProfileOperatingMaterialFeClSO4 = Eisenchloridsulfat-Lösung
 ProfileOperatingMaterialCaOH2 = Kalkhydrat
 ProfileOperatingMaterialSyntheticPolymers = Synthetische Polymere
+ProfileOperatingMaterialMethanol = Methanol
+ProfileOperatingMaterialPolyaluminiumChloride = Polyaluminiumchlorid-Lösung
+ProfileOperatingMaterialSodiumHydroxide = Natronlauge
+ProfileOperatingMaterialSodiumHydroxideConcentration = NaOH-Gehalt Natronlauge
+ProfileOperatingMaterialAceticAcid = Essigsäure
+ProfileOperatingMaterialAceticAcidConcentration = Essigsäuregehalt
+ProfileOperatingMaterialActivatedCarbon = Pulveraktivkohle
 RecommendationN2OSideStreamFactor = N₂O-EF Prozesswasser
 RecommendationN2OSideStreamCoverIsOpen = Abdeckung mit Abluftbehandlung Prozesswasserbehandlungsanlage
+RecommendationFlareIsOpen = Geschlossene Fackel
 RecommendationProcessEnergySaving = Energieeinsparung bei Prozessen
 RecommendationFossilEnergySaving = Energieeinsparung bei fossilen Energiequellen
 RecommendationDistrictHeating = Abgabe Fern-/Nahwärme (an Dritte)
+RecommendationHeatPumpThermalOutput = Wärmepumpe (Abwasserwärme)
+RecommendationHeatPumpCoefficientOfPerformance = Leistungszahl (COP)
 RecommendationPhotovoltaicEnergyExpansion = Zubau PV
 RecommendationEstimatedSelfPhotovolaticUsage = Geschätzte Eigennutzung
 RecommendationWindEnergyExpansion = Zubau Wind
 RecommendationEstimatedSelfWindEnergyUsage = Geschätzte Eigennutzung
 RecommendationWaterEnergyExpansion = Zubau Wasserkraft
 RecommendationEstimatedSelfWaterEnergyUsage = Geschätzte Eigennutzung
+RecommendationPhotovoltaicPeakPower = Nennleistung PV
+RecommendationPhotovoltaicEmbodiedEmissions = Graue Emissionen PV
+RecommendationWindEmbodiedEmissions = Graue Emissionen Wind
+RecommendationWaterEmbodiedEmissions = Graue Emissionen Wasserkraft
+RecommendationHeatPumpEmbodiedEmissions = Graue Emissionen Wärmepumpe
+RecommendationFlareEmbodiedEmissions = Graue Emissionen geschlossene Fackel
+RecommendationDigesterCoverArea = Fläche der Abdeckung
+RecommendationDigesterCoverConcrete = Beton der Abdeckung
+RecommendationDigesterCoverEmbodiedEmissions = Graue Emissionen Abdeckung
 SensitivityN2OCalculationMethod = N₂O Berechnungsmethode
 SensitivityN2OCustomFactor = N₂O-EF Benutzerdefiniert
+SensitivityN2OMeasuredFactor = N₂O-EF Messung
+SensitivityN2OMeasurementPeriod = Messzeitraum
+SensitivityN2OMeasurementSamples = Anzahl Messwerte
+SensitivityN2OMeasurementCoverage = Abdeckung Messzeitraum
+SensitivityN2OMeasurementAnnualCoverage = Abdeckung Jahr
 SensitivityN2OSideStreamFactor = N₂O-EF Prozesswasser
 SensitivityCH4ChpCalculationMethod = BHKW Berechnungsmethode
 SensitivityCH4ChpCustomFactor = BHKW CH₄-EF benutzerdefiniert
+SensitivityCH4ChpUnits = BHKW-Aggregate
 SensitivityCO2FossilCustomFactor = CO₂-EF (fossil)
 SensitivitySludgeBagsCustomFactor = CH₄-EF Schlammtaschen
 SensitivitySludgeStorageCustomFactor = CH₄-EF Schlammlagerung
@@ -53,10 +96,17 @@ Optimistic = Optimistisch
 Pesimistic = Pessimistisch
 Ipcc2019 = IPCC 2019
 Custom = Benutzerdefiniert
+Measured = Messung (standortspezifisch)
 
 MicroGasTurbines = Mikrograsturbinen
 GasolineEngine = Ottomotor
 JetEngine = Zündstrahlmotor
+Mix = Mischbetrieb (BHKW-Aggregate)
+
+MonoIncineration = Monoverbrennung
+CoIncineration = Mitverbrennung
+Agriculture = Landwirtschaftliche Verwertung
+Landfill = Deponierung
 
 N2oPlant = N₂O Anlage
 N2oWater = N₂O Gewässer
@@ -67,17 +117,35 @@ Ch4SludgeStorageContainers = CH₄ Schlupf Schlammlagerung
 Ch4SludgeBags = CH₄ Schlupf Schlammtasche
 Ch4Water = CH₄ Gewässer
 Ch4CombinedHeatAndPowerPlant = CH₄ BHKW
+Ch4Flare = CH₄ Fackel
+Ch4Vented = CH₄ Abgeblasenes Klärgas
+Ch4Upgrading = CH₄ Biomethanaufbereitung
 Ch4Emissions = Methanemissionen
 FossilEmissions = Fossile CO₂-Emissionen
 Fecl3 = Eisen(III)-chlorid-Lösung
 Feclso4 = Eisenchloridsulfat-Lösung
 Caoh2 = Kalkhydrat
 SyntheticPolymers = Synthetische Polymere
+Methanol = Methanol
+PolyaluminiumChloride = Polyaluminiumchlorid-Lösung
+SodiumHydroxide = Natronlauge
+AceticAcid = Essigsäure
+ActivatedCarbon = Pulveraktivkohle
 ElectricityMix = Strommix
+ElectricityMixMarketBased = Strommix (marktbasiert)
 OilEmissions = Heizöl
 GasEmissions = Gas
+UpgradingElectricity = Strom Biomethanaufbereitung
 OperatingMaterials = Betriebsstoffe
 SewageSludgeTransport = Klärschlamm Transport
+SludgeDisposalMonoIncineration = Klärschlamm Monoverbrennung
+SludgeDisposalCoIncineration = Klärschlamm Mitverbrennung
+SludgeDisposalAgriculture = Klärschlamm Landwirtschaft
+SludgeDisposalLandfill = Klärschlamm Deponie
+Ch4PressureMains = CH₄ Druckleitungen
+Ch4SewerOverflows = CH₄ Mischwasserentlastung
+SewerPumpingStationElectricity = Strom Pumpwerke
+SewerNetworkEmissions = Kanalnetz
 TotalEmissions = Gesamtemissionen
 DirectEmissions = Direkte Emissionen
 IndirectEmissions = Indirekte Emissionen
@@ -88,5 +156,12 @@ WindExpansionSavings = Windkraft
 WaterExpansionSavings = Wasserkraft
 DistrictHeatingSavings = Abwärmenutzung
 FossilEnergySavings = Fossilen Energiequellen
-ExcessEnergyCo2Equivalent = Energiebedingte Emissionen
+EnclosedFlareSavings = Geschlossene Fackel
+DigesterCoverSavings = Abdeckung Schlammtaschen/-stapelbehälter
+HeatPumpSavings = Wärmepumpe
+ExcessEnergyCo2Equivalent = Stromüberschuss
+BiomethaneInjectionCredit = Biomethaneinspeisung
+HeatExportCredit = Wärmeabgabe
+AvoidedEmissions = Vermiedene Emissionen (Gutschriften)
 AdditionalCustomEmissions = Benutzerdef. Emissionen
+AdditionalCustomEmissionsSavings = Benutzerdef. Emissionen (Einsparung)
@@ -10,19 +10,49 @@ gas-supply = Gas supply
 biogas-purchase = Biogas purchase
 heating-oil-purchase = Heating oil purchase
 sewage-gas-produced = Sewage gas
+sewage-gas-flared = Flared sewage gas
+sewage-gas-vented = Vented sewage gas
+sewage-gas-upgraded = Upgraded sewage gas
+upgrading-power-consumption = Power consumption upgrading
+heat-exported = Heat export
+electricity-contract = Electricity contract
+contract-energy = Energy
+contract-emission-factor = Supplier EF
+guarantee-of-origin = Guarantee of origin
+residual-mix-emission-factor = Residual mix EF
 number-of-digesters = Number of digesters
+pressure-main-length = Length
+retention-time = Retention time
+pumping-station-energy = Electricity
+overflow-volume = Overflow volume
 mass-dewatered = Mass dewatered
 distance = Distance
+share = Share
+chp-unit = CHP
+chp-unit-calculation-method = Engine Type
+chp-unit-custom-factor = CH₄-EF Custom
+chp-unit-gas-share = Share of Sewage Gas
+chp-unit-gas-volume = Sewage Gas Volume
 ferrous-chloride = Ferrous chloride
 ferrous-chloride-sulfate = Ferrous chloride sulfate
 calcium-hydroxide = Calcium hydroxide
 synthetic-polymers = Synthetic polymers
+methanol = Methanol
+polyaluminium-chloride = Polyaluminium chloride
+sodium-hydroxide = Caustic soda
+acetic-acid = Acetic acid
+activated-carbon = Activated carbon
 district-heating = District heating
+heat-pump-thermal-output = Thermal output
 self-use-water-energy = Self-use water energy
 self-use-wind-energy = Self-use wind energy
 self-use-pv-energy = Self-use PV energy
 fossil-energy-saving = Fossil energy saving
 process-energy-saving = Process energy saving
+embodied-emissions = Embodied emissions
+photovoltaic-peak-power = Peak power
+digester-cover-area = Area
+digester-cover-concrete = Concrete
 
 description-project-name = In this field, you can enter a name for your project. In the <b>logged in</b> version, the project name is used to save your inputs/results under the "Projects" tab. If you are <b>not logged in</b>, the project name is only saved on your hard drive and processed in your local browser. For more information on data processing please see the <b>FAQs</b>.
 description-plant-name = The indication of name and/or location is voluntary. Alternatively, a placeholder can be entered for the field. All entries can only be viewed or saved by you (not UTBW).
@@ -36,30 +66,69 @@ description-cod-effluent = The annual average chemical oxygen demand (COD) of th
 description-total-power-consumption = The total power consumption of your sewage treatment plant in kilowatt-hours (kWh) per year (a).
 description-self-generated-power = Amount of self-generated power in kilowatt-hours (kWh) per year (a). If no self-generated power is produced, please leave this field blank.
 description-emission-factor-electricity-mix = Indication of the emission factor of the externally sourced electricity mix in grams (g) CO₂ per kilowatt-hour (kWh). If this value is not available, please leave the reference value.
+description-electricity-contracts = The electricity supply contracts of your sewage treatment plant with the electricity purchased, the emission factor of the supplier and any guarantees of origin. The contracts cover the externally sourced electricity in their order.
+description-contract-energy = The electricity purchased under this supply contract in kilowatt hours (kWh) per year (a). The contracts cover the externally sourced electricity in their order, the uncovered rest is valued with the residual mix EF.
+description-contract-emission-factor = The emission factor stated by the supplier for this contract in grams (g) CO₂ per kilowatt-hour (kWh), e.g. for a green electricity tariff. If this value is not available, the residual mix EF is used.
+description-guarantee-of-origin = If the electricity of this contract is covered by guarantees of origin, please tick this field. The electricity is then valued with 0 g CO₂/kWh in the market-based balance.
+description-residual-mix-emission-factor = Indication of the emission factor of the residual mix in grams (g) CO₂ per kilowatt-hour (kWh), i.e. the electricity mix without the electricity sold with guarantees of origin. It is used in the market-based balance for the electricity not covered by contracts. If this value is not available, the electricity mix EF is used as an approximation, which underestimates the market-based emissions.
 description-gas-supply = Amount of gas (natural gas/biogas) in cubic meters (m³) per year (a) sourced from an external supplier. If no gas is sourced externally at your sewage treatment plant, please leave this field blank.
 description-biogas-purchase = If your sewage treatment plant purchases biogas from external sources, please tick this field.
 description-heating-oil-purchase = Amount of heating oil (e.g., for heating buildings) in liters (L) per year (a) sourced from an external supplier. If no heating oil is sourced externally at your sewage treatment plant, please leave this field blank.
 description-sewage-gas-produced = The amount of sewage gas produced at your sewage treatment plant in cubic meters (m³) per year (a). If no sewage gas is produced at your sewage treatment plant, please leave this field blank.
 description-methane-percentage = The methane content of the sewage gas produced at your sewage treatment plant in percent (%). If no sewage gas is produced at your sewage treatment plant, please leave this field blank.
+description-sewage-gas-flared = The amount of produced sewage gas in cubic meters (m³) per year (a) that is burned in the flare and does not reach the combined heat and power plant.
+description-flare-efficiency = The share of methane in percent (%) that is destroyed in the flare. An efficiency of 50 % is assumed for open flares and 90 % for enclosed flares.
+description-sewage-gas-vented = The amount of produced sewage gas in cubic meters (m³) per year (a) that is vented unused or escapes through leaks.
+description-sewage-gas-upgraded = The amount of produced sewage gas in cubic meters (m³) per year (a) that is upgraded to biomethane and injected into the gas grid. The injected biomethane is reported as a credit and is not netted against the total emissions.
+description-upgrading-methane-slip = The share of methane in percent (%) that escapes during the upgrading to biomethane.
+description-upgrading-power-consumption = The power consumption of the biomethane upgrading in kilowatt hours (kWh) per year (a). This power consumption should not be included in the total power consumption.
+description-heat-exported = The heat supplied to third parties in kilowatt hours (kWh) per year (a). The exported heat is reported as a credit and is not netted against the total emissions.
 description-number-of-digesters = If there is a digestion process at your sewage treatment plant, please enter the number of digesters. If not, please leave this field blank or enter 0.
+description-pressure-main-length = The total length of the pressure mains of the sewer network in kilometers (km). Dissolved methane is formed in pressure mains under anaerobic conditions and is released at their outlets.
+description-pressure-main-retention-time = The average retention time of the wastewater in the pressure mains in hours (h). The longer the retention time, the more methane is formed.
+description-pumping-station-energy = The electricity consumption of the pumping stations of the sewer network in kilowatt hours (kWh) per year (a). This consumption should not be included in the total power consumption of the plant.
+description-overflow-volume = The volume of untreated wastewater that is discharged (e.g. combined sewer overflows) in cubic meters (m³) per year (a).
 description-sludge-bags-are-open = If the sludge pockets of the digester(s) of your sewage treatment plant are closed and not open to the ambient air, please check this box.
 description-sludge-storage-containers-are-open = If the sludge storage tanks of your sewage treatment plant are tightly covered, please click this box.
 description-mass-dewatered = The amount of sewage sludge in tons (t) that has to be disposed of.
 description-distance = Distance from your sewage treatment plant to the disposal site of sewage sludge in kilometers (km). The information is independent of the disposal method (e.g., incineration) or the transport form (e.g., dewatered/dry). If sewage sludge is disposed of at your sewage treatment plant, please leave this field blank.
+description-sludge-disposal-route = The disposal route of the sewage sludge. All sewage sludge for disposal is assigned to this route unless shares per disposal route are given.
+description-sludge-disposal-share-mono-incineration = Share of the sewage sludge in percent (%) that is incinerated in a mono-incineration plant.
+description-sludge-disposal-share-co-incineration = Share of the sewage sludge in percent (%) that is co-incinerated (e.g. in cement kilns or coal power plants).
+description-sludge-disposal-share-agriculture = Share of the sewage sludge in percent (%) that is used in agriculture.
+description-sludge-disposal-share-landfill = Share of the sewage sludge in percent (%) that is landfilled.
 description-total-nitrogen-side-stream = If there is a sidestream treatment process at your sewage treatment plant, please enter its annual total nitrogen amount in tons [t/a]. If not, please leave this field blank or enter 0.
 description-ferrous-chloride = Amount of ferric chloride (FeCl3) used per year (a) in tons (t).
 description-ferrous-chloride-sulfate = Amount of ferric chloride sulfate (FeClSO4) used per year (a) in tons (t).
 description-calcium-hydroxide = Amount of calcium hydroxide (Ca(OH)2) used per year (a) in tons (t).
 description-synthetic-polymers = Amount of synthetic polymers used per year (a) in tons (t).
+description-methanol = Amount of methanol (CH3OH) dosed for denitrification per year (a) in tons (t).
+description-polyaluminium-chloride = Amount of polyaluminium chloride solution (PAC) used per year (a) in tons (t).
+description-sodium-hydroxide = Amount of caustic soda used per year (a) in tons (t).
+description-sodium-hydroxide-concentration = Share of sodium hydroxide (NaOH) in the delivered caustic soda in percent (%). If this value is not available, the typical value of 50 % is used.
+description-acetic-acid = Amount of acetic acid solution dosed for denitrification per year (a) in tons (t).
+description-acetic-acid-concentration = Share of acetic acid in the delivered solution in percent (%). If this value is not available, the typical value of 80 % is used.
+description-activated-carbon = Amount of powdered activated carbon (PAC) used in the 4th treatment stage per year (a) in tons (t).
 description-sensitivity-sludge-bags-custom-factor = Using this input field, you can enter a value for the EF CH₄ (e.g. based on your own estimate or a measurement campaign).
 description-sensitivity-sludge-storage-custom-factor = Using this input field, you can enter a value for the EF CH₄ (e.g. based on your own estimate or a measurement campaign).
 description-sensitivity-CH4-chp-custom-factor = Using this input field, you can enter a value for the EF CH₄ (e.g. based on your own estimate or a measurement campaign).
+description-sensitivity-ch4-chp-units = The individual CHP units of your sewage treatment plant with their engine type and the sewage gas they use. They are considered in the mix.
+description-sensitivity-ch4-chp-unit-calculation-method = The engine type of this CHP unit.
+description-sensitivity-ch4-chp-unit-gas-share = Share of the sewage gas produced in percent (%) that is used in this CHP unit. Without a value, the remaining sewage gas is distributed equally among the units without a value.
+description-sensitivity-ch4-chp-unit-gas-volume = Volume of sewage gas in cubic meters (m³) per year (a) that is used in this CHP unit. The volume takes precedence over the share.
 description-sensitivity-CO2-fossil-custom-factor = Using this input field, you can enter a value for the EF CO₂ (e.g. based on your own estimate or a measurement campaign).
 description-sensitivity-N2O-custom-factor = You can use this input field to enter a value for the EF N₂O (e.g. based on your own estimate or a measurement campaign). You must then manually select the option (User-defined) to use the entered value.
+description-sensitivity-N2O-measured-factor = The site-specific EF N₂O calculated from an imported online measurement (timestamp, N₂O concentration in ppm, off-gas flow in m³/h). You must select the option (Measured) to use the value.
+description-sensitivity-N2O-measurement-period = The period of the imported N₂O measurement.
+description-sensitivity-N2O-measurement-samples = The number of samples of the imported N₂O measurement.
+description-sensitivity-N2O-measurement-coverage = Share of the measurement period in percent (%) that is covered by continuous samples.
+description-sensitivity-N2O-measurement-annual-coverage = Share of the year in percent (%) that is covered by continuous samples. The lower the coverage, the more uncertain is the extrapolation to the whole year.
 description-sensitivity-N2O-side-stream-factor = Using this input field, you can enter a value for the EF of the process water treatment (e.g. based on your own estimate or a measurement campaign).
 description-sensitivity-N2O-calculation-method = Select the calculation method for the EF N₂O.
 description-sensitivity-ch4-chp-calculation-method = Select the calculation method for the EF CH₄.
 description-district-heating = Amount of heat supplied to district heating networks in kilowatt-hours (kWh) per year (a).
+description-heat-pump-thermal-output = Thermal output of a heat pump that recovers heat from the wastewater in kilowatt-hours (kWh) per year (a). The heat replaces heating oil first and then gas.
+description-heat-pump-coefficient-of-performance = The coefficient of performance (COP) of the heat pump, i.e. the ratio of the heat output to the power consumption.
 description-scenario-estimated-self-water-energy-usage = Estimated self-use of hydropower capacity in percent (%).
 description-water-energy-expansion = Expansion of hydropower capacity in kilowatt-hours (kWh) per year (a).
 description-scenario-estimated-self-wind-energy-usage = Estimated self-use of wind power output in percent (%).
@@ -68,7 +137,17 @@ description-scenario-estimated-self-photovolatic-usage = Estimated self-use of p
 description-photovoltaic-energy-expansion = Expansion of photovoltaic capacity in kilowatt-hours (kWh) per year (a).
 description-fossil-energy-saving = Estimated energy savings for fossil energy carriers (e.g., heating oil/natural gas) in percent (%).
 description-process-energy-saving = Estimated energy savings for sewage treatment plant processes in percent (%).
+description-photovoltaic-peak-power = Installed peak power of the new photovoltaic system in kilowatt peak (kWp). The embodied emissions of the system are estimated from it.
+description-photovoltaic-embodied-emissions = Embodied emissions (manufacturing and construction) of the new photovoltaic system in tonnes of CO₂ equivalents (t CO₂-eq.). The value takes precedence over the estimation from the peak power.
+description-wind-embodied-emissions = Embodied emissions (manufacturing and construction) of the new wind turbine in tonnes of CO₂ equivalents (t CO₂-eq.).
+description-water-embodied-emissions = Embodied emissions (manufacturing and construction) of the new hydropower plant in tonnes of CO₂ equivalents (t CO₂-eq.).
+description-heat-pump-embodied-emissions = Embodied emissions (manufacturing and construction) of the heat pump in tonnes of CO₂ equivalents (t CO₂-eq.).
+description-flare-embodied-emissions = Embodied emissions (manufacturing and construction) of the enclosed flare in tonnes of CO₂ equivalents (t CO₂-eq.).
+description-digester-cover-area = Area of the new cover of sludge bags or sludge storage containers in square meters (m²). The embodied emissions of the cover are estimated from it.
+description-digester-cover-concrete = Concrete used for the cover in cubic meters (m³). The embodied emissions of the cover are estimated from it.
+description-digester-cover-embodied-emissions = Embodied emissions (manufacturing and construction) of the cover in tonnes of CO₂ equivalents (t CO₂-eq.). The value takes precedence over the estimation from the area and the concrete.
 description-additional-custom-emissions = Using this form field, custom emissions can be added to the Sankey diagram.
 description-recommendation-sludge-bags-are-open = Sludge bags are open.
 description-recommendation-sludge-storage-containers-are-open = Sludge storage is open.
-description-recommendation-recommendation-n2o-side-stream-cover-is-open = Side stream cover is open.
\ No newline at end of file
+description-recommendation-recommendation-n2o-side-stream-cover-is-open = Side stream cover is open.
+description-recommendation-flare-is-open = Replace the open flare with an enclosed flare.
\ No newline at end of file
@@ -9,16 +9,34 @@ ProfileEffluentNitrogen = Total Nitrogen
 ProfileEffluentChemicalOxygenDemand = Chemical Oxygen Demand
 ProfileSewageGasProduced = Sewage Gas Produced
 ProfileMethaneFraction = Methane Fraction
+ProfileSewageGasFlared = Sewage Gas Flared
+ProfileFlareEfficiency = Flare Efficiency
+ProfileSewageGasVented = Sewage Gas Vented
+ProfileSewageGasUpgraded = Sewage Gas Upgraded (Biomethane)
+ProfileUpgradingMethaneSlip = Methane Slip of the Upgrading
+ProfileUpgradingPowerConsumption = Power Consumption of the Upgrading
+ProfileHeatExported = Heat Export
 ProfileGasSupply = Gas Supply
 ProfilePurchaseOfBiogas = Purchase of Biogas
 ProfileTotalPowerConsumption = Total Power Consumption
 ProfileOnSitePowerGeneration = On-Site Power Generation
 ProfileEmissionFactorElectricityMix = Electricity Mix EF (Supplier)
+ProfileElectricityContracts = Electricity Contracts
+ProfileElectricityResidualMixEmissionFactor = Residual Mix EF
 ProfileHeatingOil = Heating Oil
 ProfileSideStreamTotalNitrogen = Total Nitrogen
 ProfileSludgeDisposal = Sludge for Disposal
 ProfileSludgeTransportDistance = Transport Distance
+ProfileSludgeDisposalRoute = Disposal Route
+ProfileSludgeDisposalShareMonoIncineration = Share Mono-Incineration
+ProfileSludgeDisposalShareCoIncineration = Share Co-Incineration
+ProfileSludgeDisposalShareAgriculture = Share Agriculture
+ProfileSludgeDisposalShareLandfill = Share Landfill
 ProfileSludgeDigesterCount = Number of Digesters
+ProfileSewerPressureMainLength = Length of Pressure Mains
+ProfileSewerPressureMainRetentionTime = Retention Time of Pressure Mains
+ProfileSewerPumpingStationEnergy = Electricity Pumping Stations
+ProfileSewerOverflowVolume = Overflow Volume
 ProfileSludgeBagsAreOpen= Sludge Bags are Open
 ProfileScenarioSludgeBagsAreOpen = Sludge Bags are Open
 ProfileSludgeStorageContainersAreOpen = Sludge Storage is Open
@@ -27,22 +45,47 @@ ProfileOperatingMaterialFeCl3 = Ferric Chloride Solution
 ProfileOperatingMaterialFeClSO4 = Ferric Chloride Sulfate Solution
 ProfileOperatingMaterialCaOH2 = Calcium Hydroxide
 ProfileOperatingMaterialSyntheticPolymers = Synthetic Polymers
+ProfileOperatingMaterialMethanol = Methanol
+ProfileOperatingMaterialPolyaluminiumChloride = Polyaluminium Chloride Solution
+ProfileOperatingMaterialSodiumHydroxide = Caustic Soda
+ProfileOperatingMaterialSodiumHydroxideConcentration = Caustic Soda NaOH Content
+ProfileOperatingMaterialAceticAcid = Acetic Acid
+ProfileOperatingMaterialAceticAcidConcentration = Acetic Acid Content
+ProfileOperatingMaterialActivatedCarbon = Powdered Activated Carbon
 RecommendationN2OSideStreamFactor = N₂O-EF Side Stream
 RecommendationN2OSideStreamCoverIsOpen = Process Water Treatment Plant Cover
+RecommendationFlareIsOpen = Enclosed Flare
 RecommendationProcessEnergySaving = Process Energy Saving
 RecommendationFossilEnergySaving = Fossil Energy Saving
 RecommendationDistrictHeating = District Heating Supply
+RecommendationHeatPumpThermalOutput = Heat Pump (Wastewater Heat)
+RecommendationHeatPumpCoefficientOfPerformance = Coefficient of Performance (COP)
 RecommendationPhotovoltaicEnergyExpansion = Photovoltaic Expansion
 RecommendationEstimatedSelfPhotovolaticUsage = Estimated Self-Usage
 RecommendationWindEnergyExpansion = Wind Energy Expansion
 RecommendationEstimatedSelfWindEnergyUsage = Estimated Self-Usage
 RecommendationWaterEnergyExpansion = Water Energy Expansion
 RecommendationEstimatedSelfWaterEnergyUsage = Estimated Self-Usage
+RecommendationPhotovoltaicPeakPower = PV Peak Power
+RecommendationPhotovoltaicEmbodiedEmissions = Embodied Emissions PV
+RecommendationWindEmbodiedEmissions = Embodied Emissions Wind
+RecommendationWaterEmbodiedEmissions = Embodied Emissions Hydropower
+RecommendationHeatPumpEmbodiedEmissions = Embodied Emissions Heat Pump
+RecommendationFlareEmbodiedEmissions = Embodied Emissions Enclosed Flare
+RecommendationDigesterCoverArea = Cover Area
+RecommendationDigesterCoverConcrete = Cover Concrete
+RecommendationDigesterCoverEmbodiedEmissions = Embodied Emissions Cover
 SensitivityN2OCalculationMethod = N₂O Calculation Method
 SensitivityN2OCustomFactor = N₂O-EF Custom
+SensitivityN2OMeasuredFactor = N₂O-EF Measured
+SensitivityN2OMeasurementPeriod = Measurement period
+SensitivityN2OMeasurementSamples = Number of samples
+SensitivityN2OMeasurementCoverage = Coverage of the measurement period
+SensitivityN2OMeasurementAnnualCoverage = Coverage of the year
 SensitivityN2OSideStreamFactor = N₂O-EF Side Stream
 SensitivityCH4ChpCalculationMethod = CHP Calculation Method
 SensitivityCH4ChpCustomFactor = CHP CH₄-EF Custom
+SensitivityCH4ChpUnits = CHP Units
 SensitivityCO2FossilCustomFactor = CO₂-EF (fossil)
 SensitivitySludgeBagsCustomFactor = CH₄-EF Sludge Bags
 SensitivitySludgeStorageCustomFactor = CH₄-EF Sludge Storage
@@ -53,10 +96,17 @@ Optimistic = Optimistic
 Pesimistic = Pessimistic
 Ipcc2019 = IPCC 2019
 Custom = Custom
+Measured = Measured (site-specific)
 
 MicroGasTurbines = Micro Gas Turbines
 GasolineEngine = Gasoline Engine
 JetEngine = Jet Engine
+Mix = Mix of CHP units
+
+MonoIncineration = Mono-Incineration
+CoIncineration = Co-Incineration
+Agriculture = Agricultural Use
+Landfill = Landfill
 
 N2oPlant = N₂O Plant
 N2oWater = N₂O Water
@@ -67,17 +117,35 @@ Ch4SludgeStorageContainers = CH₄ Emissions from Sludge Storage
 Ch4SludgeBags = CH₄ Emissions from Sludge Bags
 Ch4Water = CH₄ Water
 Ch4CombinedHeatAndPowerPlant = CH₄ Combined Heat and Power Plant
+Ch4Flare = CH₄ Flare
+Ch4Vented = CH₄ Vented Sewage Gas
+Ch4Upgrading = CH₄ Biomethane Upgrading
 Ch4Emissions = Methane Emissions
 FossilEmissions = Fossil CO₂ Emissions
 Fecl3 = Ferric Chloride Solution
 Feclso4 = Ferric Chloride Sulfate Solution
 Caoh2 = Calcium Hydroxide
 SyntheticPolymers = Synthetic Polymers
+Methanol = Methanol
+PolyaluminiumChloride = Polyaluminium Chloride Solution
+SodiumHydroxide = Caustic Soda
+AceticAcid = Acetic Acid
+ActivatedCarbon = Powdered Activated Carbon
 ElectricityMix = Electricity Mix
+ElectricityMixMarketBased = Electricity Mix (market-based)
 OilEmissions = Heating Oil
 GasEmissions = Gas
+UpgradingElectricity = Electricity Biomethane Upgrading
 OperatingMaterials = Operating Materials
 SewageSludgeTransport = Sewage Sludge Transport
+SludgeDisposalMonoIncineration = Sludge Mono-Incineration
+SludgeDisposalCoIncineration = Sludge Co-Incineration
+SludgeDisposalAgriculture = Sludge Agriculture
+SludgeDisposalLandfill = Sludge Landfill
+Ch4PressureMains = CH₄ Pressure Mains
+Ch4SewerOverflows = CH₄ Sewer Overflows
+SewerPumpingStationElectricity = Electricity Pumping Stations
+SewerNetworkEmissions = Sewer Network
 TotalEmissions = Total Emissions
 DirectEmissions = Direct Emissions
 IndirectEmissions = Indirect Emissions
@@ -88,5 +156,12 @@ WindExpansionSavings = Wind Energy Expansion
 WaterExpansionSavings = Water Energy Expansion
 DistrictHeatingSavings = District Heating
 FossilEnergySavings = Fossil Energy
-ExcessEnergyCo2Equivalent = Energy-Related Emissions
+EnclosedFlareSavings = Enclosed Flare
+DigesterCoverSavings = Sludge Bag/Storage Cover
+HeatPumpSavings = Heat Pump
+ExcessEnergyCo2Equivalent = Excess Electricity
+BiomethaneInjectionCredit = Biomethane Injection
+HeatExportCredit = Heat Export
+AvoidedEmissions = Avoided Emissions (Credits)
 AdditionalCustomEmissions = Custom Emissions
+AdditionalCustomEmissionsSavings = Custom Emissions (Savings)
@@ -18,10 +18,14 @@ pub fn recommendation_diff_bar_chart(
         Out::Ch4SludgeStorageContainers,
         Out::Ch4Plant,
         Out::N2oSideStream,
+        Out::Ch4Flare,
+        Out::EnclosedFlareSavings,
         Out::FossilEnergySavings,
+        Out::HeatPumpSavings,
         Out::ProcessEnergySavings,
         Out::PhotovoltaicExpansionSavings,
         Out::DistrictHeatingSavings,
+        Out::AdditionalCustomEmissionsSavings,
         Out::AdditionalCustomEmissions,
         Out::TotalEmissions,
     ];
@@ -40,6 +44,8 @@ pub fn sensitivity_diff_bar_chart(
         Out::Ch4SludgeStorageContainers,
         Out::Ch4Plant,
         Out::Ch4CombinedHeatAndPowerPlant,
+        Out::Ch4Flare,
+        Out::Ch4Vented,
         Out::FossilEmissions,
         Out::N2oSideStream,
         Out::AdditionalCustomEmissions,
@@ -0,0 +1,186 @@
+use std::{collections::HashMap, hash::BuildHasher};
+
+use klick_domain::{units::Tons, OutputValueId as Out, Value, ValueId as Id};
+use klick_usecases::sankey_edges;
+
+use crate::{Lng, ValueColor, ValueLabel};
+
+/// A labeled and colored share of the emissions.
+pub type EmissionShare = (String, f64, &'static str);
+
+/// Shares of the emission groups of `TotalEmissions`
+/// together with the shares of their sources.
+///
+/// Groups and sources without emissions are skipped.
+/// Emissions of a group that are not assigned to one of its sources
+/// (e.g. custom emissions) are added as additional share.
+#[must_use]
+pub fn emission_shares_donut_chart<S>(
+    values: &HashMap<Id, Value, S>,
+    lng: Lng,
+) -> Vec<(EmissionShare, Vec<EmissionShare>)>
+where
+    S: BuildHasher,
+{
+    emission_groups(Out::TotalEmissions)
+        .into_iter()
+        .filter_map(|group| {
+            let group_value = tons(values, group);
+            if group_value <= 0.0 {
+                return None;
+            }
+            let mut sources = emission_groups(group)
+                .into_iter()
+                .map(|source| {
+                    (
+                        source.label(lng),
+                        tons(values, source),
+                        source.color_light(),
+                    )
+                })
+                .filter(|(_, value, _)| *value > 0.0)
+                .collect::<Vec<_>>();
+            let unassigned = group_value - sources.iter().map(|(_, v, _)| v).sum::<f64>();
+            if unassigned > f64::EPSILON * group_value {
+                sources.push((
+                    Out::AdditionalCustomEmissions.label(lng),
+                    unassigned,
+                    group.color_light(),
+                ));
+            }
+            Some(((group.label(lng), group_value, group.color()), sources))
+        })
+        .collect()
+}
+
+/// Emission groups of `TotalEmissions` for the profile,
+/// the sensitivity and the recommendation.
+///
+/// Missing scenarios are skipped.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn emission_groups_stacked_bar_chart(
+    profile: Option<&HashMap<Id, Value>>,
+    sensitivity: Option<&HashMap<Id, Value>>,
+    recommendation: Option<&HashMap<Id, Value>>,
+    lng: Lng,
+) -> Vec<(String, Vec<EmissionShare>)> {
+    let scenario_labels = match lng {
+        Lng::De => ["Profil", "Sensitivität", "Empfehlung"],
+        Lng::En => ["Profile", "Sensitivity", "Recommendation"],
+    };
+    let groups = emission_groups(Out::TotalEmissions);
+    scenario_labels
+        .into_iter()
+        .zip([profile, sensitivity, recommendation])
+        .filter_map(|(label, values)| {
+            let values = values?;
+            let shares = groups
+                .iter()
+                .map(|group| (group.label(lng), tons(values, *group), group.color()))
+                .collect();
+            Some((label.to_string(), shares))
+        })
+        .collect()
+}
+
+/// Direct sources of `group` ordered by
+/// their first occurrence in the [`sankey_edges`].
+fn emission_groups(group: Out) -> Vec<Out> {
+    let edges = sankey_edges();
+    let position = |id: Out| {
+        edges
+            .iter()
+            .position(|(source, target)| *source == id || *target == id)
+    };
+    let mut sources = edges
+        .iter()
+        .filter(|(_, target)| *target == group)
+        .map(|(source, _)| *source)
+        .collect::<Vec<_>>();
+    sources.sort_by_key(|id| position(*id));
+    sources
+}
+
+fn tons<S>(values: &HashMap<Id, Value, S>, id: Out) -> f64
+where
+    S: BuildHasher,
+{
+    values
+        .get(&id.into())
+        .cloned()
+        .and_then(Value::as_tons)
+        .unwrap_or_else(Tons::zero)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(values: &[(Out, f64)]) -> HashMap<Id, Value> {
+        values
+            .iter()
+            .map(|(id, v)| ((*id).into(), Value::from(Tons::new(*v))))
+            .collect()
+    }
+
+    #[test]
+    fn groups_of_total_emissions() {
+        assert_eq!(
+            emission_groups(Out::TotalEmissions),
+            [
+                Out::DirectEmissions,
+                Out::IndirectEmissions,
+                Out::OtherIndirectEmissions,
+                Out::SewerNetworkEmissions
+            ]
+        );
+        assert_eq!(
+            emission_groups(Out::DirectEmissions),
+            [Out::Ch4Emissions, Out::N2oEmissions, Out::FossilEmissions]
+        );
+        assert_eq!(emission_groups(Out::N2oPlant), []);
+    }
+
+    #[test]
+    fn donut_chart_shares() {
+        let values = values(&[
+            (Out::DirectEmissions, 10.0),
+            (Out::Ch4Emissions, 4.0),
+            (Out::N2oEmissions, 5.0),
+            (Out::IndirectEmissions, 2.0),
+            (Out::ElectricityMix, 2.0),
+            (Out::OtherIndirectEmissions, 0.0),
+        ]);
+        let shares = emission_shares_donut_chart(&values, Lng::En);
+        assert_eq!(shares.len(), 2);
+
+        let ((_, direct, color), sources) = &shares[0];
+        assert_eq!(*direct, 10.0);
+        assert_eq!(*color, Out::DirectEmissions.color());
+        let sources = sources.iter().map(|(_, v, _)| *v).collect::<Vec<_>>();
+        assert_eq!(sources, [4.0, 5.0, 1.0]);
+
+        let ((_, indirect, _), sources) = &shares[1];
+        assert_eq!(*indirect, 2.0);
+        assert_eq!(sources.len(), 1);
+    }
+
+    #[test]
+    fn stacked_bar_chart_skips_missing_scenarios() {
+        let profile = values(&[(Out::DirectEmissions, 3.0)]);
+        let recommendation = values(&[(Out::DirectEmissions, 1.0)]);
+        let bars =
+            emission_groups_stacked_bar_chart(Some(&profile), None, Some(&recommendation), Lng::De);
+        let labels = bars
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["Profil", "Empfehlung"]);
+        let (_, shares) = &bars[1];
+        assert_eq!(shares.len(), 4);
+        assert_eq!(shares[0].1, 1.0);
+        assert_eq!(shares[1].1, 0.0);
+    }
+}
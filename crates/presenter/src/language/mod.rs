@@ -121,6 +121,7 @@ impl Lng {
             Value::Enum(v) => match v {
                 Enum::N2oEmissionFactorCalcMethod(v) => v.label(*self).to_string(),
                 Enum::Ch4ChpEmissionFactorCalcMethod(v) => v.label(*self).to_string(),
+                Enum::SludgeDisposalRoute(v) => v.label(*self).to_string(),
             },
         }
     }
@@ -2,6 +2,7 @@ use fluent_templates::static_loader;
 
 mod bar_chart;
 mod csv;
+mod emission_shares;
 mod language;
 mod sankey_chart;
 mod tables;
@@ -14,8 +15,8 @@ mod value_units;
 pub use klick_domain::*;
 
 pub use self::{
-    bar_chart::*, csv::*, language::*, sankey_chart::*, tables::*, value_color::*,
-    value_input_field::*, value_labels::*, value_metadata::*, value_units::*,
+    bar_chart::*, csv::*, emission_shares::*, language::*, sankey_chart::*, tables::*,
+    value_color::*, value_input_field::*, value_labels::*, value_metadata::*, value_units::*,
 };
 
 static_loader! {
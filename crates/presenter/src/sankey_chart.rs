@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, hash::BuildHasher};
 
 use derive_more::From;
 
@@ -91,11 +91,15 @@ fn resolve_color(graph: &[(Id, Id)], pos: &Id) -> Option<(&'static str, &'static
 }
 
 #[must_use]
-pub fn create_sankey_chart_data(
+pub fn create_sankey_chart_data<S>(
     co2_equivalents: HashMap<Id, Value>,
     graph: &[(Id, Id)],
+    custom_labels: &HashMap<Id, String, S>,
     lang: Lng,
-) -> (Nodes, Vec<(usize, usize)>) {
+) -> (Nodes, Vec<(usize, usize)>)
+where
+    S: BuildHasher,
+{
     let node_ids = klick_usecases::emission_group_ids(graph);
 
     let nodes = node_ids
@@ -108,10 +112,13 @@ pub fn create_sankey_chart_data(
                 .unwrap_or_else(Tons::zero);
 
             let (label, color, color_light) = match id {
-                x @ Id::Custom(label) => resolve_color(graph, x).map_or_else(
-                    || (label.clone(), "black", "grey"),
-                    |(color, color_light)| (label.clone(), color, color_light),
-                ),
+                x @ Id::Custom(name) => {
+                    let label = custom_labels.get(x).unwrap_or(name).clone();
+                    resolve_color(graph, x).map_or_else(
+                        || (label.clone(), "black", "grey"),
+                        |(color, color_light)| (label.clone(), color, color_light),
+                    )
+                }
                 Id::Out(id) => (id.label(lang).to_string(), id.color(), id.color_light()),
                 Id::In(_) => {
                     return None;
@@ -1,13 +1,20 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, hash::BuildHasher};
 
 use serde::Serialize;
 
-use klick_domain::{InputValueId as In, Value, ValueId as Id};
+use klick_domain::{
+    chp_units, electricity_contracts,
+    operating_materials::{OperatingMaterial, OPERATING_MATERIALS},
+    InputValueId as In, OutputValueId as Out, Value, ValueId as Id,
+};
 use klick_interfaces::{
     self as interfaces, TablePresenter as _, ValueGroupId, ValueGroupPresenter as _,
 };
+use klick_usecases::MeasureAmortisation;
+
+use fluent_templates::Loader;
 
-use crate::{Lng, ValueLabel, ValueUnit};
+use crate::{Lng, ValueLabel, ValueUnit, LOCALES};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Formatting {
@@ -54,7 +61,16 @@ impl interfaces::TablePresenter for TablePresenter {
                 title,
                 rows: sections
                     .into_iter()
-                    .map(|id| {
+                    .flat_map(|id| {
+                        match id {
+                            Id::In(In::SensitivityCH4ChpUnits) => {
+                                return self.present_chp_units(data.get(&id));
+                            }
+                            Id::In(In::ProfileElectricityContracts) => {
+                                return self.present_electricity_contracts(data.get(&id));
+                            }
+                            _ => {}
+                        }
                         let label = self.formatting.fmt_label(id.clone(), self.lang).to_string();
                         let value = {
                             let mut value = data.get(&id).cloned();
@@ -73,12 +89,12 @@ impl interfaces::TablePresenter for TablePresenter {
                         };
                         let unit = self.formatting.fmt(id.clone()).map(Into::into);
 
-                        interfaces::TableRow {
+                        vec![interfaces::TableRow {
                             id,
                             label,
                             value,
                             unit,
-                        }
+                        }]
                     })
                     .collect(),
             })
@@ -87,6 +103,91 @@ impl interfaces::TablePresenter for TablePresenter {
     }
 }
 
+impl TablePresenter {
+    /// Present each electricity contract in its own row.
+    fn present_electricity_contracts(&self, value: Option<&Value>) -> Vec<interfaces::TableRow> {
+        let Some(contracts) = value.and_then(|value| electricity_contracts::from_value(value).ok())
+        else {
+            return vec![];
+        };
+        let lookup = |key| LOCALES.lookup(&self.lang.id(), key);
+        let kilowatthours = self.formatting.fmt(In::ProfileTotalPowerConsumption);
+        let grams_per_kilowatthour = self.formatting.fmt(In::ProfileEmissionFactorElectricityMix);
+        let with_unit = |value: f64, unit: Option<&str>| {
+            format!(
+                "{} {}",
+                self.lang.format_number(value),
+                unit.unwrap_or_default()
+            )
+        };
+        contracts
+            .iter()
+            .enumerate()
+            .map(|(index, contract)| {
+                let mut fields = vec![with_unit(contract.energy.into(), kilowatthours)];
+                if let Some(factor) = contract.emission_factor {
+                    fields.push(format!(
+                        "{} {}",
+                        lookup("contract-emission-factor"),
+                        with_unit(factor.into(), grams_per_kilowatthour)
+                    ));
+                }
+                if contract.guarantee_of_origin {
+                    fields.push(lookup("guarantee-of-origin"));
+                }
+                interfaces::TableRow {
+                    id: In::ProfileElectricityContracts.into(),
+                    label: format!("{} {}", lookup("electricity-contract"), index + 1),
+                    value: Some(fields.join(", ")),
+                    unit: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Present each CHP unit in its own row.
+    fn present_chp_units(&self, value: Option<&Value>) -> Vec<interfaces::TableRow> {
+        let Some(units) = value.and_then(|value| chp_units::from_value(value).ok()) else {
+            return vec![];
+        };
+        let lookup = |key| LOCALES.lookup(&self.lang.id(), key);
+        let percent = self.formatting.fmt(In::SensitivityCH4ChpCustomFactor);
+        let qubicmeters = self.formatting.fmt(In::ProfileSewageGasProduced);
+        let with_unit = |value: f64, unit: Option<&str>| {
+            format!(
+                "{} {}",
+                self.lang.format_number(value),
+                unit.unwrap_or_default()
+            )
+        };
+        units
+            .iter()
+            .enumerate()
+            .map(|(index, unit)| {
+                let mut fields = vec![unit.calculation_method.label(self.lang)];
+                if let Some(factor) = unit.custom_factor {
+                    fields.push(format!(
+                        "{} {}",
+                        lookup("chp-unit-custom-factor"),
+                        with_unit(factor.into(), percent)
+                    ));
+                }
+                if let Some(volume) = unit.gas_volume {
+                    fields.push(with_unit(volume.into(), qubicmeters));
+                } else if let Some(share) = unit.gas_share {
+                    fields.push(with_unit(share.into(), percent));
+                }
+                interfaces::TableRow {
+                    id: In::SensitivityCH4ChpUnits.into(),
+                    label: format!("{} {}", lookup("chp-unit"), index + 1),
+                    value: Some(fields.join(", ")),
+                    unit: None,
+                }
+            })
+            .collect()
+    }
+}
+
 pub struct ValueGroupPresenter {
     lang: Lng,
     formatting: Formatting,
@@ -134,16 +235,29 @@ impl interfaces::ValueGroupPresenter for ValueGroupPresenter {
                     Lng::De => "Energiebedarf",
                     Lng::En => "Energy requirements",
                 },
-                vec![
+                [
                     In::ProfileTotalPowerConsumption,
                     In::ProfileOnSitePowerGeneration,
                     In::ProfileEmissionFactorElectricityMix,
+                ]
+                .into_iter()
+                .chain([
+                    In::ProfileElectricityContracts,
+                    In::ProfileElectricityResidualMixEmissionFactor,
                     In::ProfileGasSupply,
                     In::ProfilePurchaseOfBiogas,
                     In::ProfileHeatingOil,
                     In::ProfileSewageGasProduced,
                     In::ProfileMethaneFraction,
-                ],
+                    In::ProfileSewageGasFlared,
+                    In::ProfileFlareEfficiency,
+                    In::ProfileSewageGasVented,
+                    In::ProfileSewageGasUpgraded,
+                    In::ProfileUpgradingMethaneSlip,
+                    In::ProfileUpgradingPowerConsumption,
+                    In::ProfileHeatExported,
+                ])
+                .collect(),
             ),
             G::SludgeTreatment => (
                 match self.lang {
@@ -154,6 +268,11 @@ impl interfaces::ValueGroupPresenter for ValueGroupPresenter {
                     In::ProfileSludgeDigesterCount,
                     In::ProfileSludgeDisposal,
                     In::ProfileSludgeTransportDistance,
+                    In::ProfileSludgeDisposalRoute,
+                    In::ProfileSludgeDisposalShareMonoIncineration,
+                    In::ProfileSludgeDisposalShareCoIncineration,
+                    In::ProfileSludgeDisposalShareAgriculture,
+                    In::ProfileSludgeDisposalShareLandfill,
                     In::ProfileSludgeBagsAreOpen,
                     In::ProfileSludgeStorageContainersAreOpen,
                 ],
@@ -170,11 +289,21 @@ impl interfaces::ValueGroupPresenter for ValueGroupPresenter {
                     Lng::De => "Eingesetzte Betriebsstoffe",
                     Lng::En => "Operating materials used",
                 },
+                OPERATING_MATERIALS
+                    .iter()
+                    .flat_map(OperatingMaterial::inputs)
+                    .collect(),
+            ),
+            G::SewerNetwork => (
+                match self.lang {
+                    Lng::De => "Kanalnetz",
+                    Lng::En => "Sewer network",
+                },
                 vec![
-                    In::ProfileOperatingMaterialFeCl3,
-                    In::ProfileOperatingMaterialFeClSO4,
-                    In::ProfileOperatingMaterialCaOH2,
-                    In::ProfileOperatingMaterialSyntheticPolymers,
+                    In::ProfileSewerPressureMainLength,
+                    In::ProfileSewerPressureMainRetentionTime,
+                    In::ProfileSewerPumpingStationEnergy,
+                    In::ProfileSewerOverflowVolume,
                 ],
             ),
             G::N2OEmissions => (
@@ -185,6 +314,7 @@ impl interfaces::ValueGroupPresenter for ValueGroupPresenter {
                 vec![
                     In::SensitivityN2OCalculationMethod,
                     In::SensitivityN2OCustomFactor,
+                    In::SensitivityN2OMeasuredFactor,
                     In::SensitivityN2OSideStreamFactor,
                 ],
             ),
@@ -196,6 +326,7 @@ impl interfaces::ValueGroupPresenter for ValueGroupPresenter {
                 vec![
                     In::SensitivityCH4ChpCalculationMethod,
                     In::SensitivityCH4ChpCustomFactor,
+                    In::SensitivityCH4ChpUnits,
                 ],
             ),
             G::CH4SludgeEmissions => (
@@ -251,6 +382,20 @@ pub struct TableRow {
     pub unit: Option<String>,
 }
 
+impl Table {
+    /// Replace the labels of the rows with the given IDs.
+    pub fn relabel<S>(&mut self, labels: &HashMap<Id, String, S>)
+    where
+        S: BuildHasher,
+    {
+        for row in self.sections.iter_mut().flat_map(|s| s.rows.iter_mut()) {
+            if let Some(label) = labels.get(&row.id) {
+                row.label.clone_from(label);
+            }
+        }
+    }
+}
+
 impl From<interfaces::Table> for Table {
     fn from(from: interfaces::Table) -> Self {
         let sections = from.sections.into_iter().map(Into::into).collect();
@@ -321,6 +466,7 @@ pub fn plant_profile_as_table(
         ValueGroupId::SludgeTreatment,
         ValueGroupId::SideStreamTreatment,
         ValueGroupId::OperatingMaterials,
+        ValueGroupId::SewerNetwork,
     ]
     .into_iter()
     .map(|id| presenter.present_value_group(id))
@@ -354,6 +500,145 @@ pub fn sensitivity_parameters_as_table(
     Table::from(table)
 }
 
+/// Avoided emissions that are credited to the plant.
+///
+/// The credits are not part of the total emissions.
+#[must_use]
+pub fn avoided_emissions_as_table(
+    values: &HashMap<Id, Value>,
+    formatting: Formatting,
+    lang: Lng,
+) -> Table {
+    let title = match lang {
+        Lng::De => "Gutschriften (nicht in den Gesamtemissionen enthalten)",
+        Lng::En => "Credits (not included in the total emissions)",
+    };
+    let ids = [
+        Out::BiomethaneInjectionCredit,
+        Out::HeatExportCredit,
+        Out::ExcessEnergyCo2Equivalent,
+        Out::AvoidedEmissions,
+    ]
+    .into_iter()
+    .map(Id::from)
+    .collect();
+    let sections = vec![(title.to_string(), ids)];
+
+    let table_presenter = TablePresenter { lang, formatting };
+    let table: interfaces::Table = table_presenter.present_table(values.clone(), sections);
+    Table::from(table)
+}
+
+/// Scope 2 emissions of the purchased electricity.
+///
+/// The location-based value is part of the total emissions,
+/// the market-based value takes the electricity contracts into account.
+#[must_use]
+pub fn scope2_electricity_as_table(
+    values: &HashMap<Id, Value>,
+    formatting: Formatting,
+    lang: Lng,
+) -> Table {
+    let title = match lang {
+        Lng::De => "Scope 2: Strombezug standort- und marktbasiert",
+        Lng::En => "Scope 2: Purchased electricity location- and market-based",
+    };
+    let ids = [Out::ElectricityMix, Out::ElectricityMixMarketBased]
+        .into_iter()
+        .map(Id::from)
+        .collect();
+    let sections = vec![(title.to_string(), ids)];
+
+    let table_presenter = TablePresenter { lang, formatting };
+    let table: interfaces::Table = table_presenter.present_table(values.clone(), sections);
+    Table::from(table)
+}
+
+#[must_use]
+pub fn n2o_measurement_campaign_as_table(
+    values: &HashMap<Id, Value>,
+    formatting: Formatting,
+    lang: Lng,
+) -> Table {
+    let title = match lang {
+        Lng::De => "Standortspezifischer N₂O-EF aus Online-Messung",
+        Lng::En => "Site-specific N₂O EF from online measurement",
+    };
+    let ids = [
+        In::SensitivityN2OMeasuredFactor,
+        In::SensitivityN2OMeasurementPeriod,
+        In::SensitivityN2OMeasurementSamples,
+        In::SensitivityN2OMeasurementCoverage,
+        In::SensitivityN2OMeasurementAnnualCoverage,
+    ]
+    .into_iter()
+    .map(Id::from)
+    .collect();
+    let sections = vec![(title.to_string(), ids)];
+
+    let table_presenter = TablePresenter { lang, formatting };
+    let table: interfaces::Table = table_presenter.present_table(values.clone(), sections);
+    Table::from(table)
+}
+
+/// Embodied emissions and amortisation time of the recommended measures.
+#[must_use]
+pub fn embodied_emissions_as_table(
+    amortisations: &[MeasureAmortisation],
+    formatting: Formatting,
+    lang: Lng,
+) -> Table {
+    let (embodied_emissions_label, annual_savings_label, amortisation_time_label) = match lang {
+        Lng::De => (
+            "Graue Emissionen",
+            "Jährliche Einsparung",
+            "Amortisationszeit",
+        ),
+        Lng::En => ("Embodied emissions", "Annual savings", "Amortisation time"),
+    };
+    let (tons, tons_per_year, years) = match formatting {
+        Formatting::Text => ("t", "t/a", "a"),
+        Formatting::LaTeX => (r"\si{\tonne}", r"\si{\tonne}/\mathrm{a}", r"\mathrm{a}"),
+    };
+    let sections = amortisations
+        .iter()
+        .map(|amortisation| {
+            let measure = amortisation.measure;
+            let rows = vec![
+                TableRow {
+                    id: measure.embodied_emissions.into(),
+                    label: embodied_emissions_label.to_string(),
+                    value: Some(
+                        lang.format_number_with_fixed_precision(amortisation.embodied_emissions, 1),
+                    ),
+                    unit: Some(tons.to_string()),
+                },
+                TableRow {
+                    id: measure.savings.into(),
+                    label: annual_savings_label.to_string(),
+                    value: Some(
+                        lang.format_number_with_fixed_precision(amortisation.annual_savings, 1),
+                    ),
+                    unit: Some(tons_per_year.to_string()),
+                },
+                TableRow {
+                    id: measure.savings.into(),
+                    label: amortisation_time_label.to_string(),
+                    value: amortisation
+                        .amortisation_time
+                        .map(|years| lang.format_number_with_fixed_precision(years, 1)),
+                    unit: Some(years.to_string()),
+                },
+            ];
+            TableSection {
+                title: formatting.fmt_label(measure.savings, lang),
+                rows,
+            }
+        })
+        .collect();
+    Table { sections }
+}
+
 #[must_use]
 pub fn co2_equivalents_as_table(
     (values, graph): &(HashMap<Id, Value>, Vec<(Id, Id)>),
@@ -16,6 +16,9 @@ const YELLOW_LIGHT: &str = "#fff5b2";
 const ORANGE: &str = "orange";
 const ORANGE_LIGHT: &str = "#ffe4b2";
 
+const BLUE: &str = "steelblue";
+const BLUE_LIGHT: &str = "lightsteelblue";
+
 const GREY: &str = "grey";
 const GREY_LIGHT: &str = "lightgrey";
 
@@ -31,6 +34,9 @@ impl ValueColor for OutputValueId {
             | Self::Ch4SludgeBags
             | Self::Ch4Water
             | Self::Ch4CombinedHeatAndPowerPlant
+            | Self::Ch4Flare
+            | Self::Ch4Vented
+            | Self::Ch4Upgrading
             | Self::Ch4Emissions
             | Self::DirectEmissions
             | Self::FossilEmissions => RED,
@@ -40,14 +46,29 @@ impl ValueColor for OutputValueId {
             | Self::Caoh2
             | Self::OperatingMaterials
             | Self::SewageSludgeTransport
+            | Self::SludgeDisposalMonoIncineration
+            | Self::SludgeDisposalCoIncineration
+            | Self::SludgeDisposalAgriculture
+            | Self::SludgeDisposalLandfill
             | Self::SyntheticPolymers
+            | Self::Methanol
+            | Self::PolyaluminiumChloride
+            | Self::SodiumHydroxide
+            | Self::AceticAcid
+            | Self::ActivatedCarbon
             | Self::OtherIndirectEmissions => YELLOW,
 
             Self::ElectricityMix
             | Self::OilEmissions
             | Self::GasEmissions
+            | Self::UpgradingElectricity
             | Self::IndirectEmissions => ORANGE,
 
+            Self::Ch4PressureMains
+            | Self::Ch4SewerOverflows
+            | Self::SewerPumpingStationElectricity
+            | Self::SewerNetworkEmissions => BLUE,
+
             Self::TotalEmissions => GREY,
 
             _ => todo!(),
@@ -65,6 +86,9 @@ impl ValueColor for OutputValueId {
             | Self::Ch4SludgeBags
             | Self::Ch4Water
             | Self::Ch4CombinedHeatAndPowerPlant
+            | Self::Ch4Flare
+            | Self::Ch4Vented
+            | Self::Ch4Upgrading
             | Self::Ch4Emissions
             | Self::DirectEmissions
             | Self::FossilEmissions => RED_LIGHT,
@@ -74,14 +98,29 @@ impl ValueColor for OutputValueId {
             | Self::Caoh2
             | Self::OperatingMaterials
             | Self::SewageSludgeTransport
+            | Self::SludgeDisposalMonoIncineration
+            | Self::SludgeDisposalCoIncineration
+            | Self::SludgeDisposalAgriculture
+            | Self::SludgeDisposalLandfill
             | Self::SyntheticPolymers
+            | Self::Methanol
+            | Self::PolyaluminiumChloride
+            | Self::SodiumHydroxide
+            | Self::AceticAcid
+            | Self::ActivatedCarbon
             | Self::OtherIndirectEmissions => YELLOW_LIGHT,
 
             Self::ElectricityMix
             | Self::OilEmissions
             | Self::GasEmissions
+            | Self::UpgradingElectricity
             | Self::IndirectEmissions => ORANGE_LIGHT,
 
+            Self::Ch4PressureMains
+            | Self::Ch4SewerOverflows
+            | Self::SewerPumpingStationElectricity
+            | Self::SewerNetworkEmissions => BLUE_LIGHT,
+
             Self::TotalEmissions => GREY_LIGHT,
 
             _ => self.color(),
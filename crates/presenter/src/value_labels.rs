@@ -22,6 +22,7 @@ impl ValueLabel for InputValueId {
         match self {
             Self::SensitivityN2OCalculationMethod => "$N_2O$ Berechnungsmethode",
             Self::SensitivityN2OCustomFactor => "$N_2O$-EF Benutzerdefiniert",
+            Self::SensitivityN2OMeasuredFactor => "$N_2O$-EF Messung",
             Self::SensitivityN2OSideStreamFactor => "$N_2O$-EF Prozesswasser",
             Self::SensitivityCH4ChpCustomFactor => "BHKW $CH_4$-EF benutzerdefiniert",
             Self::SensitivityCO2FossilCustomFactor => "$CO_2$-EF (fossil)",
@@ -47,6 +48,12 @@ impl ValueLabel for domain::units::Ch4ChpEmissionFactorCalcMethod {
     }
 }
 
+impl ValueLabel for domain::units::SludgeDisposalRoute {
+    fn label(&self, lng: Lng) -> String {
+        LOCALES.lookup(&lng.id(), self.as_ref())
+    }
+}
+
 impl ValueLabel for OutputValueId {
     fn label(&self, lng: Lng) -> String {
         LOCALES.lookup(&lng.id(), self.as_ref())
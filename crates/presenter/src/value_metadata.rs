@@ -50,7 +50,7 @@ impl Placeholder {
     }
 }
 
-fn metadata() -> [(Id, FieldMetaData); 48] {
+fn metadata() -> [(Id, FieldMetaData); 91] {
     use FieldMetaData as M;
     use Placeholder as P;
     [
@@ -144,6 +144,20 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                 description: "description-emission-factor-electricity-mix",
             },
         ),
+        (
+            Id::ProfileElectricityContracts,
+            M {
+                placeholder: P::none(),
+                description: "description-electricity-contracts",
+            },
+        ),
+        (
+            Id::ProfileElectricityResidualMixEmissionFactor,
+            M {
+                placeholder: P::text("residual-mix-emission-factor"),
+                description: "description-residual-mix-emission-factor",
+            },
+        ),
         (
             Id::ProfileGasSupply,
             M {
@@ -179,6 +193,55 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                 description: "description-methane-percentage",
             },
         ),
+        (
+            Id::ProfileSewageGasFlared,
+            M {
+                placeholder: P::text("sewage-gas-flared"),
+                description: "description-sewage-gas-flared",
+            },
+        ),
+        (
+            Id::ProfileFlareEfficiency,
+            M {
+                placeholder: P::default_value(),
+                description: "description-flare-efficiency",
+            },
+        ),
+        (
+            Id::ProfileSewageGasVented,
+            M {
+                placeholder: P::text("sewage-gas-vented"),
+                description: "description-sewage-gas-vented",
+            },
+        ),
+        (
+            Id::ProfileSewageGasUpgraded,
+            M {
+                placeholder: P::text("sewage-gas-upgraded"),
+                description: "description-sewage-gas-upgraded",
+            },
+        ),
+        (
+            Id::ProfileUpgradingMethaneSlip,
+            M {
+                placeholder: P::default_value(),
+                description: "description-upgrading-methane-slip",
+            },
+        ),
+        (
+            Id::ProfileUpgradingPowerConsumption,
+            M {
+                placeholder: P::text("upgrading-power-consumption"),
+                description: "description-upgrading-power-consumption",
+            },
+        ),
+        (
+            Id::ProfileHeatExported,
+            M {
+                placeholder: P::text("heat-exported"),
+                description: "description-heat-exported",
+            },
+        ),
         (
             Id::ProfileSludgeDigesterCount,
             M {
@@ -186,6 +249,34 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                 description: "description-number-of-digesters",
             },
         ),
+        (
+            Id::ProfileSewerPressureMainLength,
+            M {
+                placeholder: P::text("pressure-main-length"),
+                description: "description-pressure-main-length",
+            },
+        ),
+        (
+            Id::ProfileSewerPressureMainRetentionTime,
+            M {
+                placeholder: P::text("retention-time"),
+                description: "description-pressure-main-retention-time",
+            },
+        ),
+        (
+            Id::ProfileSewerPumpingStationEnergy,
+            M {
+                placeholder: P::text("pumping-station-energy"),
+                description: "description-pumping-station-energy",
+            },
+        ),
+        (
+            Id::ProfileSewerOverflowVolume,
+            M {
+                placeholder: P::text("overflow-volume"),
+                description: "description-overflow-volume",
+            },
+        ),
         (
             Id::ProfileSludgeBagsAreOpen,
             M {
@@ -214,6 +305,41 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                 description: "description-distance",
             },
         ),
+        (
+            Id::ProfileSludgeDisposalRoute,
+            M {
+                placeholder: P::none(),
+                description: "description-sludge-disposal-route",
+            },
+        ),
+        (
+            Id::ProfileSludgeDisposalShareMonoIncineration,
+            M {
+                placeholder: P::text("share"),
+                description: "description-sludge-disposal-share-mono-incineration",
+            },
+        ),
+        (
+            Id::ProfileSludgeDisposalShareCoIncineration,
+            M {
+                placeholder: P::text("share"),
+                description: "description-sludge-disposal-share-co-incineration",
+            },
+        ),
+        (
+            Id::ProfileSludgeDisposalShareAgriculture,
+            M {
+                placeholder: P::text("share"),
+                description: "description-sludge-disposal-share-agriculture",
+            },
+        ),
+        (
+            Id::ProfileSludgeDisposalShareLandfill,
+            M {
+                placeholder: P::text("share"),
+                description: "description-sludge-disposal-share-landfill",
+            },
+        ),
         (
             Id::ProfileSideStreamTotalNitrogen,
             M {
@@ -249,6 +375,55 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                 description: "description-synthetic-polymers",
             },
         ),
+        (
+            Id::ProfileOperatingMaterialMethanol,
+            M {
+                placeholder: P::text("methanol"),
+                description: "description-methanol",
+            },
+        ),
+        (
+            Id::ProfileOperatingMaterialPolyaluminiumChloride,
+            M {
+                placeholder: P::text("polyaluminium-chloride"),
+                description: "description-polyaluminium-chloride",
+            },
+        ),
+        (
+            Id::ProfileOperatingMaterialSodiumHydroxide,
+            M {
+                placeholder: P::text("sodium-hydroxide"),
+                description: "description-sodium-hydroxide",
+            },
+        ),
+        (
+            Id::ProfileOperatingMaterialSodiumHydroxideConcentration,
+            M {
+                placeholder: P::default_value(),
+                description: "description-sodium-hydroxide-concentration",
+            },
+        ),
+        (
+            Id::ProfileOperatingMaterialAceticAcid,
+            M {
+                placeholder: P::text("acetic-acid"),
+                description: "description-acetic-acid",
+            },
+        ),
+        (
+            Id::ProfileOperatingMaterialAceticAcidConcentration,
+            M {
+                placeholder: P::default_value(),
+                description: "description-acetic-acid-concentration",
+            },
+        ),
+        (
+            Id::ProfileOperatingMaterialActivatedCarbon,
+            M {
+                placeholder: P::text("activated-carbon"),
+                description: "description-activated-carbon",
+            },
+        ),
         // ------    ------ //
         //    Sensitivity   //
         // ------    ------ //
@@ -273,6 +448,13 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                 description: "description-sensitivity-CH4-chp-custom-factor",
             },
         ),
+        (
+            Id::SensitivityCH4ChpUnits,
+            M {
+                placeholder: P::none(),
+                description: "description-sensitivity-ch4-chp-units",
+            },
+        ),
         (
             Id::SensitivityCO2FossilCustomFactor,
             M {
@@ -287,6 +469,41 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                 description: "description-sensitivity-N2O-custom-factor",
             },
         ),
+        (
+            Id::SensitivityN2OMeasuredFactor,
+            M {
+                placeholder: P::none(),
+                description: "description-sensitivity-N2O-measured-factor",
+            },
+        ),
+        (
+            Id::SensitivityN2OMeasurementPeriod,
+            M {
+                placeholder: P::none(),
+                description: "description-sensitivity-N2O-measurement-period",
+            },
+        ),
+        (
+            Id::SensitivityN2OMeasurementSamples,
+            M {
+                placeholder: P::none(),
+                description: "description-sensitivity-N2O-measurement-samples",
+            },
+        ),
+        (
+            Id::SensitivityN2OMeasurementCoverage,
+            M {
+                placeholder: P::none(),
+                description: "description-sensitivity-N2O-measurement-coverage",
+            },
+        ),
+        (
+            Id::SensitivityN2OMeasurementAnnualCoverage,
+            M {
+                placeholder: P::none(),
+                description: "description-sensitivity-N2O-measurement-annual-coverage",
+            },
+        ),
         (
             Id::SensitivityN2OSideStreamFactor,
             M {
@@ -318,6 +535,20 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                 description: "description-district-heating",
             },
         ),
+        (
+            Id::RecommendationHeatPumpThermalOutput,
+            M {
+                placeholder: P::text("heat-pump-thermal-output"),
+                description: "description-heat-pump-thermal-output",
+            },
+        ),
+        (
+            Id::RecommendationHeatPumpCoefficientOfPerformance,
+            M {
+                placeholder: P::default_value(),
+                description: "description-heat-pump-coefficient-of-performance",
+            },
+        ),
         (
             Id::RecommendationEstimatedSelfWaterEnergyUsage,
             M {
@@ -403,6 +634,76 @@ fn metadata() -> [(Id, FieldMetaData); 48] {
                     "description-recommendation-recommendation-n2o-side-stream-cover-is-open",
             },
         ),
+        (
+            Id::RecommendationFlareIsOpen,
+            M {
+                placeholder: P::none(),
+                description: "description-recommendation-flare-is-open",
+            },
+        ),
+        (
+            Id::RecommendationPhotovoltaicPeakPower,
+            M {
+                placeholder: P::text("photovoltaic-peak-power"),
+                description: "description-photovoltaic-peak-power",
+            },
+        ),
+        (
+            Id::RecommendationPhotovoltaicEmbodiedEmissions,
+            M {
+                placeholder: P::text("embodied-emissions"),
+                description: "description-photovoltaic-embodied-emissions",
+            },
+        ),
+        (
+            Id::RecommendationWindEmbodiedEmissions,
+            M {
+                placeholder: P::text("embodied-emissions"),
+                description: "description-wind-embodied-emissions",
+            },
+        ),
+        (
+            Id::RecommendationWaterEmbodiedEmissions,
+            M {
+                placeholder: P::text("embodied-emissions"),
+                description: "description-water-embodied-emissions",
+            },
+        ),
+        (
+            Id::RecommendationHeatPumpEmbodiedEmissions,
+            M {
+                placeholder: P::text("embodied-emissions"),
+                description: "description-heat-pump-embodied-emissions",
+            },
+        ),
+        (
+            Id::RecommendationFlareEmbodiedEmissions,
+            M {
+                placeholder: P::text("embodied-emissions"),
+                description: "description-flare-embodied-emissions",
+            },
+        ),
+        (
+            Id::RecommendationDigesterCoverArea,
+            M {
+                placeholder: P::text("digester-cover-area"),
+                description: "description-digester-cover-area",
+            },
+        ),
+        (
+            Id::RecommendationDigesterCoverConcrete,
+            M {
+                placeholder: P::text("digester-cover-concrete"),
+                description: "description-digester-cover-concrete",
+            },
+        ),
+        (
+            Id::RecommendationDigesterCoverEmbodiedEmissions,
+            M {
+                placeholder: P::text("embodied-emissions"),
+                description: "description-digester-cover-embodied-emissions",
+            },
+        ),
     ]
 }
 
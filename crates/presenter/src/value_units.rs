@@ -26,6 +26,9 @@ pub trait ValueUnit {
 const LATEX_PERCENT: &str = "\\%";
 const LATEX_QUBICMETERS: &str = r"\si{\metre}^3";
 const LATEX_KILOMETERS: &str = r"\si{\kilo\metre}";
+const LATEX_HOURS: &str = r"\si{\hour}";
+const LATEX_SQUAREMETERS: &str = r"\si{\square\metre}";
+const LATEX_KILOWATTS_PEAK: &str = r"\si{\kilo\watt}_p";
 const LATEX_TONS: &str = r"\si{\tonne}";
 const LATEX_MILLIGRAMSPERLITER: &str = r"\si{\milli\gram\per\liter}";
 const LATEX_KILOWATTHOURS: &str = r"\si{\kilo\watt\hour}";
@@ -34,13 +37,48 @@ const LATEX_GRAMSPERKILOWATTHOUR: &str = r"\si{\gram\per\kilo\watt\hour}";
 const TEXT_PERCENT: &str = "%";
 const TEXT_QUBICMETERS: &str = "m³";
 const TEXT_KILOMETERS: &str = "km";
+const TEXT_HOURS: &str = "h";
+const TEXT_SQUAREMETERS: &str = "m²";
+const TEXT_KILOWATTS_PEAK: &str = "kWp";
 const TEXT_TONS: &str = "t";
 const TEXT_MILLIGRAMSPERLITER: &str = "mg/l";
 const TEXT_KILOWATTHOURS: &str = "kWh";
 const TEXT_GRAMSPERKILOWATTHOUR: &str = "g/kWh";
 const TEXT_QUBICMETERS_PER_HOUR: &str = "m³/h";
 
-impl ValueUnit for OutputValueId {}
+impl ValueUnit for OutputValueId {
+    fn unit_as_latex(&self) -> Option<&'static str> {
+        match self {
+            Self::ExcessEnergyCo2Equivalent
+            | Self::BiomethaneInjectionCredit
+            | Self::HeatExportCredit
+            | Self::AvoidedEmissions
+            | Self::ElectricityMix
+            | Self::ElectricityMixMarketBased
+            | Self::Ch4PressureMains
+            | Self::Ch4SewerOverflows
+            | Self::SewerPumpingStationElectricity
+            | Self::SewerNetworkEmissions => Some(LATEX_TONS),
+            _ => None,
+        }
+    }
+
+    fn unit_as_text(&self) -> Option<&'static str> {
+        match self {
+            Self::ExcessEnergyCo2Equivalent
+            | Self::BiomethaneInjectionCredit
+            | Self::HeatExportCredit
+            | Self::AvoidedEmissions
+            | Self::ElectricityMix
+            | Self::ElectricityMixMarketBased
+            | Self::Ch4PressureMains
+            | Self::Ch4SewerOverflows
+            | Self::SewerPumpingStationElectricity
+            | Self::SewerNetworkEmissions => Some(TEXT_TONS),
+            _ => None,
+        }
+    }
+}
 
 impl ValueUnit for InputValueId {
     fn unit_as_latex(&self) -> Option<&'static str> {
@@ -57,22 +95,56 @@ impl ValueUnit for InputValueId {
             }
             Self::ProfileSewageGasProduced => Some(LATEX_QUBICMETERS),
             Self::ProfileMethaneFraction => Some(LATEX_PERCENT),
+            Self::ProfileSewageGasFlared | Self::ProfileSewageGasVented => Some(LATEX_QUBICMETERS),
+            Self::ProfileFlareEfficiency => Some(LATEX_PERCENT),
+            Self::ProfileSewageGasUpgraded => Some(LATEX_QUBICMETERS),
+            Self::ProfileUpgradingMethaneSlip => Some(LATEX_PERCENT),
+            Self::ProfileUpgradingPowerConsumption | Self::ProfileHeatExported => {
+                Some(LATEX_KILOWATTHOURS)
+            }
+            Self::RecommendationHeatPumpThermalOutput => Some(LATEX_KILOWATTHOURS),
             Self::ProfileGasSupply => Some(LATEX_KILOWATTHOURS),
             Self::ProfilePurchaseOfBiogas => None,
             Self::ProfileTotalPowerConsumption => Some(LATEX_KILOWATTHOURS),
             Self::ProfileOnSitePowerGeneration => Some(LATEX_KILOWATTHOURS),
             Self::ProfileEmissionFactorElectricityMix => Some(LATEX_GRAMSPERKILOWATTHOUR),
+            Self::ProfileElectricityResidualMixEmissionFactor => Some(LATEX_GRAMSPERKILOWATTHOUR),
             Self::ProfileHeatingOil => Some(LATEX_TONS),
             Self::ProfileSideStreamTotalNitrogen => Some(LATEX_TONS),
             Self::ProfileSludgeBagsAreOpen => None, // FIXME implement latex representation
             Self::ProfileSludgeStorageContainersAreOpen => None, // FIXME implement latex representation
             Self::ProfileSludgeDisposal => Some(LATEX_TONS),
             Self::ProfileSludgeTransportDistance => Some(LATEX_KILOMETERS),
+            Self::ProfileSludgeDisposalRoute => None,
+            Self::ProfileSludgeDisposalShareMonoIncineration
+            | Self::ProfileSludgeDisposalShareCoIncineration
+            | Self::ProfileSludgeDisposalShareAgriculture
+            | Self::ProfileSludgeDisposalShareLandfill => Some(LATEX_PERCENT),
             Self::ProfileSludgeDigesterCount => None,
+            Self::ProfileSewerPressureMainLength => Some(LATEX_KILOMETERS),
+            Self::ProfileSewerPressureMainRetentionTime => Some(LATEX_HOURS),
+            Self::ProfileSewerPumpingStationEnergy => Some(LATEX_KILOWATTHOURS),
+            Self::ProfileSewerOverflowVolume => Some(LATEX_QUBICMETERS),
             Self::ProfileOperatingMaterialFeCl3
             | Self::ProfileOperatingMaterialFeClSO4
             | Self::ProfileOperatingMaterialCaOH2
-            | Self::ProfileOperatingMaterialSyntheticPolymers => Some(LATEX_TONS),
+            | Self::ProfileOperatingMaterialSyntheticPolymers
+            | Self::ProfileOperatingMaterialMethanol
+            | Self::ProfileOperatingMaterialPolyaluminiumChloride
+            | Self::ProfileOperatingMaterialSodiumHydroxide
+            | Self::ProfileOperatingMaterialAceticAcid
+            | Self::ProfileOperatingMaterialActivatedCarbon => Some(LATEX_TONS),
+            Self::ProfileOperatingMaterialSodiumHydroxideConcentration
+            | Self::ProfileOperatingMaterialAceticAcidConcentration => Some(LATEX_PERCENT),
+            Self::RecommendationPhotovoltaicPeakPower => Some(LATEX_KILOWATTS_PEAK),
+            Self::RecommendationDigesterCoverArea => Some(LATEX_SQUAREMETERS),
+            Self::RecommendationDigesterCoverConcrete => Some(LATEX_QUBICMETERS),
+            Self::RecommendationPhotovoltaicEmbodiedEmissions
+            | Self::RecommendationWindEmbodiedEmissions
+            | Self::RecommendationWaterEmbodiedEmissions
+            | Self::RecommendationHeatPumpEmbodiedEmissions
+            | Self::RecommendationFlareEmbodiedEmissions
+            | Self::RecommendationDigesterCoverEmbodiedEmissions => Some(LATEX_TONS),
             // FIXME
             _ => None,
         }
@@ -92,26 +164,63 @@ impl ValueUnit for InputValueId {
             }
             Self::ProfileSewageGasProduced => Some(TEXT_QUBICMETERS),
             Self::ProfileMethaneFraction => Some(TEXT_PERCENT),
+            Self::ProfileSewageGasFlared | Self::ProfileSewageGasVented => Some(TEXT_QUBICMETERS),
+            Self::ProfileFlareEfficiency => Some(TEXT_PERCENT),
+            Self::ProfileSewageGasUpgraded => Some(TEXT_QUBICMETERS),
+            Self::ProfileUpgradingMethaneSlip => Some(TEXT_PERCENT),
+            Self::ProfileUpgradingPowerConsumption | Self::ProfileHeatExported => {
+                Some(TEXT_KILOWATTHOURS)
+            }
+            Self::RecommendationHeatPumpThermalOutput => Some(TEXT_KILOWATTHOURS),
             Self::ProfileGasSupply => Some(TEXT_KILOWATTHOURS),
             Self::ProfilePurchaseOfBiogas => None,
             Self::ProfileTotalPowerConsumption => Some(TEXT_KILOWATTHOURS),
             Self::ProfileOnSitePowerGeneration => Some(TEXT_KILOWATTHOURS),
             Self::ProfileEmissionFactorElectricityMix => Some(TEXT_GRAMSPERKILOWATTHOUR),
+            Self::ProfileElectricityResidualMixEmissionFactor => Some(TEXT_GRAMSPERKILOWATTHOUR),
             Self::ProfileHeatingOil => Some(TEXT_TONS),
             Self::ProfileSideStreamTotalNitrogen => Some(TEXT_TONS),
             Self::ProfileSludgeBagsAreOpen => None,
             Self::ProfileSludgeStorageContainersAreOpen => None,
             Self::ProfileSludgeDisposal => Some(TEXT_TONS),
             Self::ProfileSludgeTransportDistance => Some(TEXT_KILOMETERS),
+            Self::ProfileSludgeDisposalRoute => None,
+            Self::ProfileSludgeDisposalShareMonoIncineration
+            | Self::ProfileSludgeDisposalShareCoIncineration
+            | Self::ProfileSludgeDisposalShareAgriculture
+            | Self::ProfileSludgeDisposalShareLandfill => Some(TEXT_PERCENT),
             Self::ProfileSludgeDigesterCount => None,
+            Self::ProfileSewerPressureMainLength => Some(TEXT_KILOMETERS),
+            Self::ProfileSewerPressureMainRetentionTime => Some(TEXT_HOURS),
+            Self::ProfileSewerPumpingStationEnergy => Some(TEXT_KILOWATTHOURS),
+            Self::ProfileSewerOverflowVolume => Some(TEXT_QUBICMETERS),
             Self::ProfileOperatingMaterialFeCl3
             | Self::ProfileOperatingMaterialFeClSO4
             | Self::ProfileOperatingMaterialCaOH2
-            | Self::ProfileOperatingMaterialSyntheticPolymers => Some(TEXT_TONS),
+            | Self::ProfileOperatingMaterialSyntheticPolymers
+            | Self::ProfileOperatingMaterialMethanol
+            | Self::ProfileOperatingMaterialPolyaluminiumChloride
+            | Self::ProfileOperatingMaterialSodiumHydroxide
+            | Self::ProfileOperatingMaterialAceticAcid
+            | Self::ProfileOperatingMaterialActivatedCarbon => Some(TEXT_TONS),
+            Self::ProfileOperatingMaterialSodiumHydroxideConcentration
+            | Self::ProfileOperatingMaterialAceticAcidConcentration => Some(TEXT_PERCENT),
+            Self::RecommendationPhotovoltaicPeakPower => Some(TEXT_KILOWATTS_PEAK),
+            Self::RecommendationDigesterCoverArea => Some(TEXT_SQUAREMETERS),
+            Self::RecommendationDigesterCoverConcrete => Some(TEXT_QUBICMETERS),
+            Self::RecommendationPhotovoltaicEmbodiedEmissions
+            | Self::RecommendationWindEmbodiedEmissions
+            | Self::RecommendationWaterEmbodiedEmissions
+            | Self::RecommendationHeatPumpEmbodiedEmissions
+            | Self::RecommendationFlareEmbodiedEmissions
+            | Self::RecommendationDigesterCoverEmbodiedEmissions => Some(TEXT_TONS),
             Self::SensitivityN2OCalculationMethod | Self::SensitivityCH4ChpCalculationMethod => {
                 None
             }
             Self::SensitivityN2OCustomFactor
+            | Self::SensitivityN2OMeasuredFactor
+            | Self::SensitivityN2OMeasurementCoverage
+            | Self::SensitivityN2OMeasurementAnnualCoverage
             | Self::SensitivityN2OSideStreamFactor
             | Self::SensitivityCH4ChpCustomFactor
             | Self::SensitivityCO2FossilCustomFactor
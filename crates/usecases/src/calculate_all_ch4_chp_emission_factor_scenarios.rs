@@ -1,4 +1,4 @@
-use klick_domain::units::*;
+use klick_domain::{chp_units::ChpUnit, units::*};
 
 use crate::calculate_ch4_chp;
 
@@ -13,29 +13,47 @@ pub fn calculate_all_ch4_chp_emission_factor_scenarios(
     sewage_gas_produced: Qubicmeters,
     methane_fraction: Percent,
     custom_factor: Option<Percent>,
+    units: &[ChpUnit],
 ) -> Vec<(Ch4ChpEmissionFactorCalcMethod, Tons, Factor)> {
-    let mut results = CH4_CHP_CALC_METHODS
+    let mut results: Vec<_> = CH4_CHP_CALC_METHODS
         .into_iter()
         .map(|method| {
-            let (result, factor) =
-                calculate_ch4_chp(Some(method), None, sewage_gas_produced, methane_fraction);
+            let (result, factor) = calculate_ch4_chp(
+                Some(method),
+                None,
+                &[],
+                sewage_gas_produced,
+                methane_fraction,
+            );
             (method, result, factor)
         })
         .collect();
 
-    let Some(factor) = custom_factor else {
-        return results;
-    };
+    if let Some(factor) = custom_factor {
+        // Custom
+        let method = Ch4ChpEmissionFactorCalcMethod::Custom;
+        let (result, factor) = calculate_ch4_chp(
+            Some(method),
+            Some(factor),
+            &[],
+            sewage_gas_produced,
+            methane_fraction,
+        );
+        results.push((method, result, factor));
+    }
 
-    // Custom
-    let method = Ch4ChpEmissionFactorCalcMethod::Custom;
-    let (result, factor) = calculate_ch4_chp(
-        Some(method),
-        Some(factor),
-        sewage_gas_produced,
-        methane_fraction,
-    );
-    results.push((method, result, factor));
+    if !units.is_empty() {
+        // Mix of all CHP units
+        let method = Ch4ChpEmissionFactorCalcMethod::Mix;
+        let (result, factor) = calculate_ch4_chp(
+            Some(method),
+            None,
+            units,
+            sewage_gas_produced,
+            methane_fraction,
+        );
+        results.push((method, result, factor));
+    }
 
     results
 }
@@ -54,5 +54,13 @@ pub fn calculate_all_n2o_emission_factor_scenarios(
     let custom_result = (n2o, result);
     results.push(custom_result);
 
+    // Measured
+    if values.contains_key(&In::SensitivityN2OMeasuredFactor.into()) {
+        let n2o = N2oEmissionFactorCalcMethod::Measured;
+        values.insert(id.into(), V::n2o_emission_factor_calc_method(n2o));
+        let result = calculate(&values, custom_edges)?;
+        results.push((n2o, result));
+    }
+
     Ok(results)
 }
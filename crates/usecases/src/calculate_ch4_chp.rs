@@ -1,25 +1,123 @@
-use klick_domain::{constants::*, units::*};
+use klick_domain::{chp_units::ChpUnit, constants::*, units::*};
 
 #[must_use]
 #[allow(clippy::missing_panics_doc)]
 pub fn calculate_ch4_chp(
     calculation_method: Option<Ch4ChpEmissionFactorCalcMethod>,
     custom_factor: Option<Percent>,
+    units: &[ChpUnit],
     sewage_gas_produced: Qubicmeters,
     methane_fraction: Percent,
 ) -> (Tons, Factor) {
-    let ch4_emission_factor = match calculation_method {
+    if calculation_method == Some(Ch4ChpEmissionFactorCalcMethod::Mix)
+        && units.iter().any(|unit| !unit.is_mix())
+    {
+        return calculate_ch4_chp_units(units, sewage_gas_produced, methane_fraction);
+    }
+    let ch4_emission_factor = ch4_chp_emission_factor(calculation_method, custom_factor);
+    let ch4_chp = calculate_ch4_slip(sewage_gas_produced, methane_fraction, ch4_emission_factor);
+    (ch4_chp, ch4_emission_factor)
+}
+
+/// Sum up the methane slip of all units.
+///
+/// Units without a volume or share get an equal part
+/// of the remaining sewage gas.
+/// If the volumes of the units exceed the sewage gas produced,
+/// they are reduced proportionally.
+/// Units that are a mix themselves are ignored.
+/// The returned factor is the average weighted by the gas volumes.
+#[must_use]
+#[allow(clippy::missing_panics_doc)]
+pub fn calculate_ch4_chp_units(
+    units: &[ChpUnit],
+    sewage_gas_produced: Qubicmeters,
+    methane_fraction: Percent,
+) -> (Tons, Factor) {
+    let units: Vec<_> = units
+        .iter()
+        .filter(|unit| {
+            if unit.is_mix() {
+                log::warn!(
+                    "Ignore CHP unit with calculation method {:?}",
+                    unit.calculation_method
+                );
+            }
+            !unit.is_mix()
+        })
+        .collect();
+    let mut assigned_volumes: Vec<_> = units
+        .iter()
+        .map(|unit| {
+            unit.gas_volume
+                .or_else(|| unit.gas_share.map(|share| sewage_gas_produced * share))
+        })
+        .collect();
+    let assigned_sum = assigned_volumes
+        .iter()
+        .flatten()
+        .fold(Qubicmeters::zero(), |sum, volume| sum + *volume);
+    if assigned_sum > sewage_gas_produced {
+        log::warn!(
+            "The sewage gas of the CHP units ({assigned_sum:?}) exceeds the sewage gas produced ({sewage_gas_produced:?})"
+        );
+        let reduction = Factor::new(f64::from(sewage_gas_produced) / f64::from(assigned_sum));
+        for volume in assigned_volumes.iter_mut().flatten() {
+            *volume = *volume * reduction;
+        }
+    }
+    let unassigned_count = assigned_volumes.iter().filter(|v| v.is_none()).count();
+    let mut remaining = sewage_gas_produced - assigned_sum;
+    if remaining.is_sign_negative() || unassigned_count == 0 {
+        remaining = Qubicmeters::zero();
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let remaining_per_unit = remaining * Factor::new(1.0 / (unassigned_count.max(1) as f64));
+
+    let mut ch4_chp = Tons::zero();
+    let mut total_volume = Qubicmeters::zero();
+    let mut weighted_factor = 0.0;
+    for (unit, volume) in units.into_iter().zip(assigned_volumes) {
+        let volume = volume.unwrap_or(remaining_per_unit);
+        let factor = ch4_chp_emission_factor(Some(unit.calculation_method), unit.custom_factor);
+        ch4_chp += calculate_ch4_slip(volume, methane_fraction, factor);
+        total_volume += volume;
+        weighted_factor += f64::from(volume) * f64::from(factor);
+    }
+    let total_volume = f64::from(total_volume);
+    let ch4_emission_factor = if total_volume > 0.0 {
+        Factor::new(weighted_factor / total_volume)
+    } else {
+        Factor::zero()
+    };
+    (ch4_chp, ch4_emission_factor)
+}
+
+fn ch4_chp_emission_factor(
+    calculation_method: Option<Ch4ChpEmissionFactorCalcMethod>,
+    custom_factor: Option<Percent>,
+) -> Factor {
+    match calculation_method {
         Some(Ch4ChpEmissionFactorCalcMethod::MicroGasTurbines) => Factor::new(0.01),
         Some(Ch4ChpEmissionFactorCalcMethod::GasolineEngine) | None => Factor::new(0.015), // FIXME None is a hack and it seems to not use the default value from units.rs
         Some(Ch4ChpEmissionFactorCalcMethod::JetEngine) => Factor::new(0.025),
         Some(Ch4ChpEmissionFactorCalcMethod::Custom) => {
             custom_factor.expect("custom CH4 EF").into()
         }
-    };
+        // The mix is calculated from the units (see `calculate_ch4_chp_units`),
+        // without any units it is treated like the default engine.
+        Some(Ch4ChpEmissionFactorCalcMethod::Mix) => {
+            ch4_chp_emission_factor(Some(Ch4ChpEmissionFactorCalcMethod::default()), None)
+        }
+    }
+}
 
-    let volume = sewage_gas_produced * methane_fraction * ch4_emission_factor;
+fn calculate_ch4_slip(
+    sewage_gas: Qubicmeters,
+    methane_fraction: Percent,
+    ch4_emission_factor: Factor,
+) -> Tons {
+    let volume = sewage_gas * methane_fraction * ch4_emission_factor;
     let mass = volume * CONVERSION_FACTOR_CH4_M3_TO_KG;
-    let ch4_chp = mass.convert_to::<Tons>();
-
-    (ch4_chp * GWP_CH4, ch4_emission_factor)
+    mass.convert_to::<Tons>() * GWP_CH4
 }
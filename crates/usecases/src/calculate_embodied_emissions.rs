@@ -0,0 +1,94 @@
+use std::{collections::HashMap, hash::BuildHasher};
+
+use klick_domain::{
+    measures::{Measure, MEASURES},
+    units::*,
+    ValueId as Id,
+};
+
+/// Embodied (construction) emissions of a recommended measure
+/// compared with its annual savings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasureAmortisation {
+    pub measure: &'static Measure,
+    pub embodied_emissions: Tons,
+    pub annual_savings: Tons,
+    /// `None` if the measure does not save any emissions.
+    pub amortisation_time: Option<Years>,
+}
+
+/// Calculate the embodied emissions of a measure.
+///
+/// Directly entered emissions take precedence over
+/// the estimation from the construction quantities.
+#[must_use]
+pub fn calculate_embodied_emissions<S>(measure: &Measure, input: &HashMap<Id, Value, S>) -> Tons
+where
+    S: BuildHasher,
+{
+    if let Some(tons) = input
+        .get(&measure.embodied_emissions.into())
+        .cloned()
+        .and_then(Value::as_tons)
+    {
+        return tons;
+    }
+    measure
+        .quantities
+        .iter()
+        .filter_map(|quantity| {
+            input
+                .get(&quantity.input.into())
+                .cloned()
+                .or_else(|| quantity.input.default_value())
+                .and_then(Value::as_float)
+                .map(|value| Tons::new(f64::from(value) * f64::from(quantity.emission_factor)))
+        })
+        .fold(Tons::zero(), |sum, tons| sum + tons)
+}
+
+/// Calculate the time that a measure needs to save its embodied emissions.
+///
+/// Returns `None` if the measure does not save any emissions.
+#[must_use]
+pub fn calculate_amortisation_time(
+    embodied_emissions: Tons,
+    annual_savings: Tons,
+) -> Option<Years> {
+    (annual_savings > Tons::zero())
+        .then(|| Years::new(f64::from(embodied_emissions) / f64::from(annual_savings)))
+}
+
+/// Calculate the amortisation of all measures that carry embodied emissions.
+///
+/// The annual savings are taken from the output of [`crate::calculate_recommendation`].
+#[must_use]
+pub fn calculate_amortisations<S, T>(
+    input: &HashMap<Id, Value, S>,
+    output: &HashMap<Id, Value, T>,
+) -> Vec<MeasureAmortisation>
+where
+    S: BuildHasher,
+    T: BuildHasher,
+{
+    MEASURES
+        .iter()
+        .filter_map(|measure| {
+            let embodied_emissions = calculate_embodied_emissions(measure, input);
+            if embodied_emissions <= Tons::zero() {
+                return None;
+            }
+            let annual_savings = output
+                .get(&measure.savings.into())
+                .cloned()
+                .and_then(Value::as_tons)
+                .unwrap_or_else(Tons::zero);
+            Some(MeasureAmortisation {
+                measure,
+                embodied_emissions,
+                annual_savings,
+                amortisation_time: calculate_amortisation_time(embodied_emissions, annual_savings),
+            })
+        })
+        .collect()
+}
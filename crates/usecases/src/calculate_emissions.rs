@@ -1,9 +1,14 @@
 use std::{collections::HashMap, hash::BuildHasher};
 
 use klick_domain::{
-    constants::*, optional_input_value_id as optional, required_input_value_id as required,
-    units::*, CalculationOutcome, Edge, Edges, InputValueId as In, OutputValueId as Out,
-    Value as V, ValueId as Id, Values,
+    chp_units::{self, ChpUnit},
+    constants::*,
+    electricity_contracts::{self, ElectricityContract},
+    operating_materials::OPERATING_MATERIALS,
+    optional_input_value_id as optional, required_input_value_id as required,
+    units::*,
+    CalculationOutcome, Edge, Edges, InputValueId as In, OutputValueId as Out, Value as V,
+    ValueId as Id, Values,
 };
 
 use crate::{
@@ -59,15 +64,31 @@ pub fn calculate_emissions(
     let sensitivity_ch4_chp_calculations = {
         log::debug!("Calculate all CH4 CHP emission factor scenarios");
 
-        let sewage_gas_produced = required!(In::ProfileSewageGasProduced, &input).unwrap();
-        let methane_fraction = required!(In::ProfileMethaneFraction, &input).unwrap();
+        let sewage_gas_produced = required!(In::ProfileSewageGasProduced, &input).ok();
+        let methane_fraction = required!(In::ProfileMethaneFraction, &input).ok();
+        let sewage_gas_flared = required!(In::ProfileSewageGasFlared, &input).ok();
+        let sewage_gas_vented = required!(In::ProfileSewageGasVented, &input).ok();
+        let sewage_gas_upgraded = required!(In::ProfileSewageGasUpgraded, &input).ok();
         let custom_ch4_chp_emission_factor = optional!(In::SensitivityCH4ChpCustomFactor, &input);
-        let results = calculate_all_ch4_chp_emission_factor_scenarios(
-            sewage_gas_produced,
-            methane_fraction,
-            custom_ch4_chp_emission_factor,
-        );
-        Some(results)
+        let chp_units = chp_units(&extract_input_values(input).collect())
+            .map_err(|err| log::warn!("{err}"))
+            .unwrap_or_default();
+        sewage_gas_produced
+            .zip(methane_fraction)
+            .map(|(sewage_gas_produced, methane_fraction)| {
+                let sewage_gas_chp = calculate_chp_sewage_gas(
+                    sewage_gas_produced,
+                    sewage_gas_flared.unwrap_or_else(Qubicmeters::zero),
+                    sewage_gas_vented.unwrap_or_else(Qubicmeters::zero),
+                    sewage_gas_upgraded.unwrap_or_else(Qubicmeters::zero),
+                );
+                calculate_all_ch4_chp_emission_factor_scenarios(
+                    sewage_gas_chp,
+                    methane_fraction,
+                    custom_ch4_chp_emission_factor,
+                    &chp_units,
+                )
+            })
     };
 
     let input = input.clone();
@@ -148,9 +169,9 @@ pub fn sort_and_filter_nodes(edges: Vec<(Id, Id)>, target: Id) -> Vec<(Id, Id)>
 
 #[must_use]
 fn emission_graph(custom_edges: Option<&[Edge]>) -> Edges {
-    let mut edges = crate::emission_groups::SANKEY_EDGES
-        .iter()
-        .map(|(from, to)| (Id::from(*from), Id::from(*to)))
+    let mut edges = crate::emission_groups::sankey_edges()
+        .into_iter()
+        .map(|(from, to)| (Id::from(from), Id::from(to)))
         .collect::<Vec<_>>();
     if let Some(custom_edges) = custom_edges {
         edges.extend(
@@ -218,6 +239,13 @@ fn emissions_factors_and_methods(
 
     let sewage_gas_produced = required!(In::ProfileSewageGasProduced, &from)?;
     let methane_fraction = required!(In::ProfileMethaneFraction, &from)?;
+    let sewage_gas_flared = required!(In::ProfileSewageGasFlared, &from)?;
+    let flare_efficiency = required!(In::ProfileFlareEfficiency, &from)?;
+    let sewage_gas_vented = required!(In::ProfileSewageGasVented, &from)?;
+    let sewage_gas_upgraded = required!(In::ProfileSewageGasUpgraded, &from)?;
+    let upgrading_methane_slip = required!(In::ProfileUpgradingMethaneSlip, &from)?;
+    let upgrading_power_consumption = required!(In::ProfileUpgradingPowerConsumption, &from)?;
+    let heat_exported = required!(In::ProfileHeatExported, &from)?;
     let total_power_consumption = required!(In::ProfileTotalPowerConsumption, &from)?;
     let on_site_power_generation = required!(In::ProfileOnSitePowerGeneration, &from)?;
     let emission_factor_electricity_mix =
@@ -225,6 +253,9 @@ fn emissions_factors_and_methods(
     let heating_oil = required!(In::ProfileHeatingOil, &from)?;
     let gas_supply = required!(In::ProfileGasSupply, &from)?;
     let purchase_of_biogas = required!(In::ProfilePurchaseOfBiogas, &from)?;
+    let emission_factor_electricity_residual_mix =
+        optional!(In::ProfileElectricityResidualMixEmissionFactor, &from);
+    let electricity_contracts = electricity_contracts(from)?;
 
     let sludge_bags_are_open_recommendation =
         required!(In::RecommendationSludgeBagsAreOpen, &from)?;
@@ -240,24 +271,59 @@ fn emissions_factors_and_methods(
         optional!(In::SensitivitySludgeStorageCustomFactor, &from);
     let sewage_sludge_for_disposal = required!(In::ProfileSludgeDisposal, &from)?;
     let transport_distance = required!(In::ProfileSludgeTransportDistance, &from)?;
+    let sludge_disposal_route = optional!(In::ProfileSludgeDisposalRoute, &from);
+    let sludge_disposal_shares = [
+        optional!(In::ProfileSludgeDisposalShareMonoIncineration, &from),
+        optional!(In::ProfileSludgeDisposalShareCoIncineration, &from),
+        optional!(In::ProfileSludgeDisposalShareAgriculture, &from),
+        optional!(In::ProfileSludgeDisposalShareLandfill, &from),
+    ];
     let digester_count = required!(In::ProfileSludgeDigesterCount, &from)?;
+    let pressure_main_length = required!(In::ProfileSewerPressureMainLength, &from)?;
+    let pressure_main_retention_time = required!(In::ProfileSewerPressureMainRetentionTime, &from)?;
+    let pumping_station_energy = required!(In::ProfileSewerPumpingStationEnergy, &from)?;
+    let sewer_overflow_volume = required!(In::ProfileSewerOverflowVolume, &from)?;
 
     let side_stream_treatment_total_nitrogen =
         required!(In::ProfileSideStreamTotalNitrogen, &from)?;
     let total_nitrogen = side_stream_treatment_total_nitrogen;
 
     let side_stream_cover_is_open = required!(In::RecommendationN2OSideStreamCoverIsOpen, &from)?;
+    let flare_is_open = required!(In::RecommendationFlareIsOpen, &from)?;
 
-    let operating_material_fecl3 = required!(In::ProfileOperatingMaterialFeCl3, &from)?;
-    let operating_material_feclso4 = required!(In::ProfileOperatingMaterialFeClSO4, &from)?;
-    let operating_material_caoh2 = required!(In::ProfileOperatingMaterialCaOH2, &from)?;
-    let operating_material_synthetic_polymers =
-        required!(In::ProfileOperatingMaterialSyntheticPolymers, &from)?;
-
-    let fecl3 = operating_material_fecl3;
-    let feclso4 = operating_material_feclso4;
-    let caoh2 = operating_material_caoh2;
-    let synthetic_polymers = operating_material_synthetic_polymers;
+    let operating_materials = OPERATING_MATERIALS
+        .iter()
+        .map(|material| {
+            let quantity = from
+                .get(&material.input)
+                .cloned()
+                .or_else(|| material.input.default_value())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "required quantity of {} ({:?}) is missing",
+                        material.name,
+                        material.input
+                    )
+                })?
+                .as_tons_unchecked();
+            let concentration = material
+                .concentration()
+                .map(|id| {
+                    from.get(&id)
+                        .cloned()
+                        .or_else(|| id.default_value())
+                        .ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "required concentration of {} ({id:?}) is missing",
+                                material.name
+                            )
+                        })
+                        .map(V::as_percent_unchecked)
+                })
+                .transpose()?;
+            Ok((material, quantity, concentration))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
     let emission_factor_n2o_side_stream =
         required!(In::SensitivityN2OSideStreamFactor, &from)?.convert_to::<Factor>();
@@ -269,6 +335,9 @@ fn emissions_factors_and_methods(
 
     let process_energy_savings = required!(In::RecommendationProcessEnergySaving, &from)?;
     let fossil_energy_savings = required!(In::RecommendationFossilEnergySaving, &from)?;
+    let heat_pump_thermal_output = required!(In::RecommendationHeatPumpThermalOutput, &from)?;
+    let heat_pump_coefficient_of_performance =
+        required!(In::RecommendationHeatPumpCoefficientOfPerformance, &from)?;
     let district_heating = required!(In::RecommendationDistrictHeating, &from)?;
     let photovoltaic_energy_expansion =
         required!(In::RecommendationPhotovoltaicEnergyExpansion, &from)?;
@@ -283,6 +352,12 @@ fn emissions_factors_and_methods(
 
     let n2o_calculation_method = required!(In::SensitivityN2OCalculationMethod, from)?;
     let n2o_custom_factor = optional!(In::SensitivityN2OCustomFactor, from);
+    let n2o_measured_factor = optional!(In::SensitivityN2OMeasuredFactor, from);
+    if n2o_calculation_method == N2oEmissionFactorCalcMethod::Measured
+        && n2o_measured_factor.is_none()
+    {
+        anyhow::bail!("The measured N2O emission factor is missing");
+    }
 
     // FIXME:
     // The default method does not produce the expected outcome.
@@ -293,6 +368,7 @@ fn emissions_factors_and_methods(
         .cloned()
         .map(V::as_ch4_chp_emission_factor_calc_method_unchecked);
     let ch4_chp_custom_factor = optional!(In::SensitivityCH4ChpCustomFactor, from);
+    let chp_units = chp_units(from)?;
 
     // -------    ------ //
     //     Calculate     //
@@ -301,6 +377,7 @@ fn emissions_factors_and_methods(
     let n2o_emission_factor = calculate_n2o_emission_factor(
         n2o_calculation_method,
         n2o_custom_factor,
+        n2o_measured_factor,
         nitrogen_influent,
         nitrogen_effluent,
     );
@@ -334,6 +411,26 @@ fn emissions_factors_and_methods(
         Tons::zero()
     };
 
+    // Slippage that is avoided by covering open sludge bags or storage containers.
+    let ch4_slippage_covered_sludge_bags =
+        if sludge_bags_are_open_profile && !sludge_bags_are_open_recommendation {
+            calculate_ch4_slippage_sludge_bags(digester_count, methane_fraction, sludge_bags_factor)
+        } else {
+            Tons::zero()
+        };
+
+    let ch4_slippage_covered_sludge_storage = if sludge_storage_containers_are_open_profile
+        && !sludge_storage_containers_are_open_recommendation
+    {
+        calculate_ch4_slippage_sludge_storage(
+            sewage_gas_produced,
+            methane_fraction,
+            sludge_storage_containers_factor,
+        )
+    } else {
+        Tons::zero()
+    };
+
     let n2o_plant = n2o_plant * GWP_N2O;
     let n2o_water = n2o_water * GWP_N2O;
 
@@ -362,27 +459,99 @@ fn emissions_factors_and_methods(
         Tons::zero()
     };
 
+    let digester_cover_savings = if with_digestion {
+        (ch4_slippage_covered_sludge_bags + ch4_slippage_covered_sludge_storage) * GWP_CH4
+    } else {
+        Tons::zero()
+    };
+
     let ch4_water = ch4_water.convert_to::<Tons>() * GWP_CH4;
 
+    let sewage_gas_chp = calculate_chp_sewage_gas(
+        sewage_gas_produced,
+        sewage_gas_flared,
+        sewage_gas_vented,
+        sewage_gas_upgraded,
+    );
+
     let (ch4_chp, ch4_emission_factor) = if with_digestion {
         calculate_ch4_chp(
             ch4_chp_calculation_method,
             ch4_chp_custom_factor,
-            sewage_gas_produced,
+            &chp_units,
+            sewage_gas_chp,
             methane_fraction,
         )
     } else {
         (Tons::zero(), Factor::zero())
     };
 
+    let (ch4_flare, enclosed_flare_savings) = if with_digestion {
+        let ch4_flare = calculate_ch4_flare(sewage_gas_flared, methane_fraction, flare_efficiency);
+        if flare_is_open {
+            (ch4_flare, Tons::zero())
+        } else {
+            let enclosed_flare_efficiency = if flare_efficiency > FLARE_EFFICIENCY_ENCLOSED {
+                flare_efficiency
+            } else {
+                FLARE_EFFICIENCY_ENCLOSED
+            };
+            let ch4_enclosed_flare = calculate_ch4_flare(
+                sewage_gas_flared,
+                methane_fraction,
+                enclosed_flare_efficiency,
+            );
+            (ch4_enclosed_flare, ch4_flare - ch4_enclosed_flare)
+        }
+    } else {
+        (Tons::zero(), Tons::zero())
+    };
+
+    let ch4_vented = if with_digestion {
+        calculate_ch4_vented(sewage_gas_vented, methane_fraction)
+    } else {
+        Tons::zero()
+    };
+
+    let (ch4_upgrading, biomethane_injection_credit) = if with_digestion {
+        calculate_biomethane_upgrading(
+            sewage_gas_upgraded,
+            methane_fraction,
+            upgrading_methane_slip,
+        )
+    } else {
+        (Tons::zero(), Tons::zero())
+    };
+
+    let upgrading_electricity = if with_digestion {
+        (upgrading_power_consumption * emission_factor_electricity_mix).convert_to::<Tons>()
+    } else {
+        Tons::zero()
+    };
+
     let ch4_plant = if with_digestion {
         Tons::zero()
     } else {
         calculate_ch4_plant(population_equivalent)
     };
 
+    let ch4_pressure_mains =
+        calculate_ch4_pressure_mains(pressure_main_length, pressure_main_retention_time);
+    let ch4_sewer_overflows =
+        calculate_ch4_sewer_overflows(sewer_overflow_volume, chemical_oxygen_demand_influent);
+    let sewer_pumping_station_electricity =
+        (pumping_station_energy * emission_factor_electricity_mix).convert_to::<Tons>();
+
+    let (heat_pump_power_consumption, heating_oil_replaced, gas_supply_replaced) =
+        calculate_heat_pump(
+            heat_pump_thermal_output,
+            heat_pump_coefficient_of_performance,
+            heating_oil - heating_oil * fossil_energy_savings,
+            gas_supply - gas_supply * fossil_energy_savings,
+        );
+
     let power_production_consumption_difference =
-        total_power_consumption - on_site_power_generation;
+        total_power_consumption + heat_pump_power_consumption - on_site_power_generation;
 
     let excess_energy_co2_equivalent =
         if power_production_consumption_difference.is_sign_negative() {
@@ -403,10 +572,17 @@ fn emissions_factors_and_methods(
     let oil_emissions = calculate_oil_emissions(heating_oil);
     let gas_emissions = calculate_gas_emissions(gas_supply, purchase_of_biogas);
 
-    let synthetic_polymers = synthetic_polymers * EMISSION_FACTOR_POLYMERS;
-    let fecl3 = fecl3 * EMISSION_FACTOR_FECL3;
-    let feclso4 = feclso4 * EMISSION_FACTOR_FECLSO4;
-    let caoh2 = caoh2 * EMISSION_FACTOR_CAOH2;
+    let operating_materials = operating_materials
+        .into_iter()
+        .map(|(material, quantity, concentration)| {
+            let emissions = calculate_operating_material_emissions(
+                quantity,
+                concentration,
+                material.emission_factor,
+            );
+            (material.output, emissions)
+        })
+        .collect::<Vec<_>>();
 
     let sewage_sludge_transport = (sewage_sludge_for_disposal
         * FUEL_CONSUMPTION
@@ -414,6 +590,13 @@ fn emissions_factors_and_methods(
         * EMISSION_FACTOR_DIESEL)
         .convert_to();
 
+    let [sludge_disposal_mono_incineration, sludge_disposal_co_incineration, sludge_disposal_agriculture, sludge_disposal_landfill] =
+        calculate_sludge_disposal(
+            sewage_sludge_for_disposal,
+            sludge_disposal_route,
+            sludge_disposal_shares,
+        );
+
     let process_energy_savings = calculate_process_energy_savings(
         external_energy,
         process_energy_savings,
@@ -435,11 +618,25 @@ fn emissions_factors_and_methods(
         * (EMISSION_FACTOR_STROM_MIX - EMISSION_FACTOR_HEAT_NETWORK))
         .convert_to::<Tons>();
 
+    // Credits are reported separately and never netted against the total emissions.
+    let heat_export_credit = (heat_exported * EMISSION_FACTOR_HEAT_NETWORK).convert_to::<Tons>();
+    let avoided_emissions =
+        biomethane_injection_credit + heat_export_credit + excess_energy_co2_equivalent;
+
     let fossil_energy_savings_emissions =
         calculate_oil_gas_savings(oil_emissions, gas_emissions, fossil_energy_savings);
 
-    let oil_emissions_with_savings_applied = oil_emissions - oil_emissions * fossil_energy_savings;
-    let gas_emissions_with_savings_applied = gas_emissions - gas_emissions * fossil_energy_savings;
+    let heat_pump_oil_savings = calculate_oil_emissions(heating_oil_replaced);
+    let heat_pump_gas_savings = calculate_gas_emissions(gas_supply_replaced, purchase_of_biogas);
+    let heat_pump_power_emissions =
+        (heat_pump_power_consumption * emission_factor_electricity_mix).convert_to::<Tons>();
+    let heat_pump_savings =
+        heat_pump_oil_savings + heat_pump_gas_savings - heat_pump_power_emissions;
+
+    let oil_emissions_with_savings_applied =
+        oil_emissions - oil_emissions * fossil_energy_savings - heat_pump_oil_savings;
+    let gas_emissions_with_savings_applied =
+        gas_emissions - gas_emissions * fossil_energy_savings - heat_pump_gas_savings;
 
     let energy_savings = process_energy_savings
         + photovoltaic_expansion_savings
@@ -454,11 +651,27 @@ fn emissions_factors_and_methods(
         electricity_mix = Tons::zero();
     }
 
+    let emission_factor_electricity_market_based = calculate_market_based_emission_factor(
+        external_energy,
+        emission_factor_electricity_mix,
+        emission_factor_electricity_residual_mix,
+        &electricity_contracts,
+    );
+    let electricity_mix_market_based = if f64::from(emission_factor_electricity_mix) > 0.0 {
+        electricity_mix
+            * Factor::new(
+                f64::from(emission_factor_electricity_market_based)
+                    / f64::from(emission_factor_electricity_mix),
+            )
+    } else {
+        (external_energy * emission_factor_electricity_market_based).convert_to::<Tons>()
+    };
+
     // -------    ------ //
     //   Pack variables  //
     // -------    ------ //
 
-    let values: [(Out, Tons); 24] = [
+    let values: [(Out, Tons); 38] = [
         (Out::N2oPlant, n2o_plant),
         (Out::N2oWater, n2o_water),
         (Out::N2oSideStream, n2o_side_stream),
@@ -470,15 +683,32 @@ fn emissions_factors_and_methods(
         (Out::Ch4SludgeBags, ch4_sludge_bags),
         (Out::Ch4Water, ch4_water),
         (Out::Ch4CombinedHeatAndPowerPlant, ch4_chp),
+        (Out::Ch4Flare, ch4_flare),
+        (Out::Ch4Vented, ch4_vented),
+        (Out::Ch4Upgrading, ch4_upgrading),
         (Out::FossilEmissions, fossil_emissions),
-        (Out::Fecl3, fecl3),
-        (Out::Feclso4, feclso4),
-        (Out::Caoh2, caoh2),
-        (Out::SyntheticPolymers, synthetic_polymers),
         (Out::ElectricityMix, electricity_mix),
+        (Out::ElectricityMixMarketBased, electricity_mix_market_based),
         (Out::OilEmissions, oil_emissions_with_savings_applied),
         (Out::GasEmissions, gas_emissions_with_savings_applied),
+        (Out::UpgradingElectricity, upgrading_electricity),
         (Out::SewageSludgeTransport, sewage_sludge_transport),
+        (
+            Out::SludgeDisposalMonoIncineration,
+            sludge_disposal_mono_incineration,
+        ),
+        (
+            Out::SludgeDisposalCoIncineration,
+            sludge_disposal_co_incineration,
+        ),
+        (Out::SludgeDisposalAgriculture, sludge_disposal_agriculture),
+        (Out::SludgeDisposalLandfill, sludge_disposal_landfill),
+        (Out::Ch4PressureMains, ch4_pressure_mains),
+        (Out::Ch4SewerOverflows, ch4_sewer_overflows),
+        (
+            Out::SewerPumpingStationElectricity,
+            sewer_pumping_station_electricity,
+        ),
         (Out::ProcessEnergySavings, process_energy_savings),
         (
             Out::PhotovoltaicExpansionSavings,
@@ -488,7 +718,13 @@ fn emissions_factors_and_methods(
         (Out::WaterExpansionSavings, water_expansion_savings),
         (Out::DistrictHeatingSavings, district_heating_savings),
         (Out::FossilEnergySavings, fossil_energy_savings_emissions),
+        (Out::EnclosedFlareSavings, enclosed_flare_savings),
+        (Out::DigesterCoverSavings, digester_cover_savings),
+        (Out::HeatPumpSavings, heat_pump_savings),
         (Out::ExcessEnergyCo2Equivalent, excess_energy_co2_equivalent),
+        (Out::BiomethaneInjectionCredit, biomethane_injection_credit),
+        (Out::HeatExportCredit, heat_export_credit),
+        (Out::AvoidedEmissions, avoided_emissions),
     ];
 
     let emission_factors = [
@@ -525,12 +761,83 @@ fn emissions_factors_and_methods(
     .collect();
 
     Ok((
-        values.to_vec(),
+        values.into_iter().chain(operating_materials).collect(),
         emission_factors.to_vec(),
         calculation_methods,
     ))
 }
 
+/// Read the configured CHP units.
+///
+/// Units with a custom calculation method but without a factor
+/// use the default factor.
+fn chp_units(input: &HashMap<In, Value>) -> anyhow::Result<Vec<ChpUnit>> {
+    let Some(value) = input.get(&In::SensitivityCH4ChpUnits) else {
+        return Ok(vec![]);
+    };
+    let units = chp_units::from_value(value)?
+        .into_iter()
+        .map(|unit| {
+            let custom_factor = unit.custom_factor.or_else(|| {
+                (unit.calculation_method == Ch4ChpEmissionFactorCalcMethod::Custom)
+                    .then_some(EMISSION_FACTOR_CH4_CHP_DEFAULT)
+            });
+            ChpUnit {
+                custom_factor,
+                ..unit
+            }
+        })
+        .collect();
+    Ok(units)
+}
+
+/// Read the configured electricity contracts.
+fn electricity_contracts(input: &HashMap<In, Value>) -> anyhow::Result<Vec<ElectricityContract>> {
+    let Some(value) = input.get(&In::ProfileElectricityContracts) else {
+        return Ok(vec![]);
+    };
+    Ok(electricity_contracts::from_value(value)?)
+}
+
+/// Market-based emission factor of the purchased electricity.
+///
+/// The contracts cover the external energy in the given order.
+/// Electricity with guarantees of origin is counted with `0 g/kWh`.
+/// Contracts without an own emission factor and the uncovered rest
+/// are counted with the residual mix factor.
+/// If no residual mix factor is given,
+/// the location-based factor is used as an approximation,
+/// which underestimates the market-based emissions
+/// because the green electricity is counted twice.
+#[must_use]
+pub fn calculate_market_based_emission_factor(
+    external_energy: Kilowatthours,
+    emission_factor_electricity_mix: GramsPerKilowatthour,
+    emission_factor_residual_mix: Option<GramsPerKilowatthour>,
+    contracts: &[ElectricityContract],
+) -> GramsPerKilowatthour {
+    let residual_factor = emission_factor_residual_mix.unwrap_or(emission_factor_electricity_mix);
+    let total = f64::from(external_energy);
+    if total <= 0.0 {
+        return residual_factor;
+    }
+    let residual_factor = f64::from(residual_factor);
+    let mut remaining = total;
+    let mut grams = 0.0;
+    for contract in contracts {
+        let energy = f64::from(contract.energy).min(remaining);
+        let factor = if contract.guarantee_of_origin {
+            0.0
+        } else {
+            contract.emission_factor.map_or(residual_factor, f64::from)
+        };
+        grams += energy * factor;
+        remaining -= energy;
+    }
+    grams += remaining * residual_factor;
+    GramsPerKilowatthour::new(grams / total)
+}
+
 #[must_use]
 pub fn calculate_ch4_slippage_sludge_bags(
     digester_count: Count,
@@ -563,11 +870,53 @@ pub fn calculate_ch4_slippage_sludge_storage(
     mass.convert_to()
 }
 
+/// Emissions of the sludge disposal routes in the order
+/// mono-incineration, co-incineration, agriculture and landfill.
+///
+/// If any share is given, the sludge is distributed according to the shares
+/// (missing shares count as `0%`, a total above `100%` is scaled down).
+/// Otherwise all sludge goes to the given route.
+#[must_use]
+pub fn calculate_sludge_disposal(
+    sewage_sludge_for_disposal: Tons,
+    route: Option<SludgeDisposalRoute>,
+    shares: [Option<Percent>; 4],
+) -> [Tons; 4] {
+    let factors = [
+        EMISSION_FACTOR_SLUDGE_MONO_INCINERATION,
+        EMISSION_FACTOR_SLUDGE_CO_INCINERATION,
+        EMISSION_FACTOR_SLUDGE_AGRICULTURE,
+        EMISSION_FACTOR_SLUDGE_LANDFILL,
+    ];
+    let fractions = if shares.iter().any(Option::is_some) {
+        let shares = shares.map(|share| f64::from(share.unwrap_or_default()));
+        let total = shares.iter().sum::<f64>().max(100.0);
+        shares.map(|share| Factor::new(share / total))
+    } else {
+        let index = match route {
+            Some(SludgeDisposalRoute::MonoIncineration) => 0,
+            Some(SludgeDisposalRoute::CoIncineration) => 1,
+            Some(SludgeDisposalRoute::Agriculture) => 2,
+            Some(SludgeDisposalRoute::Landfill) => 3,
+            None => return [Tons::zero(); 4],
+        };
+        let mut fractions = [Factor::zero(); 4];
+        fractions[index] = Factor::new(1.0);
+        fractions
+    };
+    let mut emissions = [Tons::zero(); 4];
+    for ((emission, fraction), factor) in emissions.iter_mut().zip(fractions).zip(factors) {
+        *emission = sewage_sludge_for_disposal * fraction * factor;
+    }
+    emissions
+}
+
 #[must_use]
 #[allow(clippy::missing_panics_doc)]
 pub fn calculate_n2o_emission_factor(
     calculation_method: N2oEmissionFactorCalcMethod,
     custom_factor: Option<Percent>,
+    measured_factor: Option<Percent>,
     nitrogen_influent: MilligramsPerLiter,
     nitrogen_effluent: MilligramsPerLiter,
 ) -> Factor {
@@ -579,6 +928,7 @@ pub fn calculate_n2o_emission_factor(
         N2oEmissionFactorCalcMethod::Pesimistic => EMISSION_FACTOR_N2O_PESIMISTIC.into(),
         N2oEmissionFactorCalcMethod::Ipcc2019 => EMISSION_FACTOR_N2O_IPCC2019.into(),
         N2oEmissionFactorCalcMethod::Custom => custom_factor.expect("custom N2O EF").into(),
+        N2oEmissionFactorCalcMethod::Measured => measured_factor.expect("measured N2O EF").into(),
     }
 }
 
@@ -641,6 +991,96 @@ pub fn calculate_n2o_side_stream(
     total_nitrogen * n2o_side_stream_emission_factor * CONVERSION_FACTOR_N_TO_N2O * GWP_N2O
 }
 
+/// The sewage gas that reaches the CHP,
+/// i.e. the produced gas without the flared, vented and upgraded gas.
+#[must_use]
+pub fn calculate_chp_sewage_gas(
+    sewage_gas_produced: Qubicmeters,
+    sewage_gas_flared: Qubicmeters,
+    sewage_gas_vented: Qubicmeters,
+    sewage_gas_upgraded: Qubicmeters,
+) -> Qubicmeters {
+    let sewage_gas_chp =
+        sewage_gas_produced - sewage_gas_flared - sewage_gas_vented - sewage_gas_upgraded;
+    if sewage_gas_chp.is_sign_negative() {
+        return Qubicmeters::zero();
+    }
+    sewage_gas_chp
+}
+
+/// The methane that is not destroyed in the flare.
+#[must_use]
+pub fn calculate_ch4_flare(
+    sewage_gas_flared: Qubicmeters,
+    methane_fraction: Percent,
+    flare_efficiency: Percent,
+) -> Tons {
+    let slip = Percent::new(100.0) - flare_efficiency;
+    let volume = sewage_gas_flared * methane_fraction * slip;
+    let mass = volume * CONVERSION_FACTOR_CH4_M3_TO_KG;
+    mass.convert_to::<Tons>() * GWP_CH4
+}
+
+#[must_use]
+pub fn calculate_ch4_vented(sewage_gas_vented: Qubicmeters, methane_fraction: Percent) -> Tons {
+    let volume = sewage_gas_vented * methane_fraction;
+    let mass = volume * CONVERSION_FACTOR_CH4_M3_TO_KG;
+    mass.convert_to::<Tons>() * GWP_CH4
+}
+
+/// The methane that is formed in the pressure mains of the sewer network
+/// and released at their outlets.
+///
+/// The pumped volume is derived from the volume of the pressure mains
+/// (see [`PRESSURE_MAIN_DIAMETER`]) and the retention time.
+#[must_use]
+pub fn calculate_ch4_pressure_mains(length: Kilometers, retention_time: Hours) -> Tons {
+    let retention_hours = f64::from(retention_time);
+    if retention_hours <= 0.0 {
+        return Tons::zero();
+    }
+    let cross_section = std::f64::consts::PI * PRESSURE_MAIN_DIAMETER.powi(2) / 4.0;
+    let pipe_volume = f64::from(length) * 1_000.0 * cross_section;
+    let hours_per_year = f64::from(Years::new(1.0).convert_to::<Hours>());
+    let pumped_volume = Qubicmeters::new(pipe_volume / retention_hours * hours_per_year);
+    let concentration = METHANE_FORMATION_RATE_PRESSURE_MAIN * Factor::new(retention_hours);
+    let concentration = if concentration > METHANE_CONCENTRATION_PRESSURE_MAIN_MAX {
+        METHANE_CONCENTRATION_PRESSURE_MAIN_MAX
+    } else {
+        concentration
+    };
+    let mass = concentration * pumped_volume;
+    mass.convert_to::<Tons>() * GWP_CH4
+}
+
+/// The methane of the untreated wastewater
+/// that is discharged by sewer overflows.
+#[must_use]
+pub fn calculate_ch4_sewer_overflows(
+    overflow_volume: Qubicmeters,
+    chemical_oxygen_demand_influent: MilligramsPerLiter,
+) -> Tons {
+    let mass = chemical_oxygen_demand_influent * overflow_volume * EMISSION_FACTOR_CH4_WATER;
+    mass.convert_to::<Tons>() * GWP_CH4
+}
+
+/// The methane slip of the upgrading unit
+/// and the credit for the injected biomethane
+/// that replaces natural gas.
+#[must_use]
+pub fn calculate_biomethane_upgrading(
+    sewage_gas_upgraded: Qubicmeters,
+    methane_fraction: Percent,
+    methane_slip: Percent,
+) -> (Tons, Tons) {
+    let methane = sewage_gas_upgraded * methane_fraction;
+    let slip = methane * methane_slip;
+    let ch4_upgrading = (slip * CONVERSION_FACTOR_CH4_M3_TO_KG).convert_to::<Tons>() * GWP_CH4;
+    let injected = methane - slip;
+    let credit = (injected * EMISSION_FACTOR_GAS).convert_to::<Tons>();
+    (ch4_upgrading, credit)
+}
+
 #[must_use]
 pub fn calculate_ch4_plant(population_equivalent: Count) -> Tons {
     Grams::new(u64::from(population_equivalent) as f64 * EMISSION_FACTOR_CH4_PLANT * GWP_CH4)
@@ -698,6 +1138,42 @@ pub fn calculate_water_expansion_savings(
         .convert_to::<Tons>()
 }
 
+/// The power consumption of a heat pump
+/// together with the heating oil and gas it replaces.
+///
+/// The heat replaces the heating oil first and then the gas,
+/// but never more than the given supply.
+#[must_use]
+pub fn calculate_heat_pump(
+    thermal_output: Kilowatthours,
+    coefficient_of_performance: Factor,
+    heating_oil: Liters,
+    gas_supply: Qubicmeters,
+) -> (Kilowatthours, Liters, Qubicmeters) {
+    let power_consumption =
+        thermal_output * Factor::new(1.0 / f64::from(coefficient_of_performance));
+    let heat = f64::from(thermal_output);
+    let oil = (heat / HEATING_VALUE_OIL).min(f64::from(heating_oil));
+    let remaining_heat = heat - oil * HEATING_VALUE_OIL;
+    let gas = (remaining_heat / HEATING_VALUE_GAS).min(f64::from(gas_supply));
+    (power_consumption, Liters::new(oil), Qubicmeters::new(gas))
+}
+
+/// Emissions of the consumed quantity of an operating material.
+///
+/// If a concentration is given, the emission factor
+/// refers to the active substance
+/// (see [`MaterialUnit`](klick_domain::operating_materials::MaterialUnit)).
+#[must_use]
+pub fn calculate_operating_material_emissions(
+    quantity: Tons,
+    concentration: Option<Percent>,
+    emission_factor: Factor,
+) -> Tons {
+    let quantity = concentration.map_or(quantity, |concentration| quantity * concentration);
+    quantity * emission_factor
+}
+
 #[must_use]
 pub fn calculate_oil_gas_savings(
     oil_emissions: Tons,
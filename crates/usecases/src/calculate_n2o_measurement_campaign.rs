@@ -0,0 +1,212 @@
+use std::{collections::HashMap, hash::BuildHasher};
+
+use anyhow::{anyhow, bail, ensure};
+use time::{macros::format_description, PrimitiveDateTime};
+
+use klick_domain::{
+    constants::*, required_input_value_id as required, units::*, InputValueId as In, Value as V,
+};
+
+/// A single sample of an off-gas N₂O measurement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct N2oMeasurement {
+    pub timestamp: PrimitiveDateTime,
+    /// N₂O concentration in the off-gas `[ppm]`
+    pub concentration: f64,
+    pub flow: QubicmetersPerHour,
+}
+
+/// Summary of a N₂O measurement campaign
+/// with the site-specific emission factor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct N2oMeasurementCampaign {
+    pub start: PrimitiveDateTime,
+    pub end: PrimitiveDateTime,
+    pub samples: u64,
+    /// Time that is covered by valid samples
+    pub measured_time: Hours,
+    /// Share of the campaign duration that is covered by valid samples
+    pub coverage: Percent,
+    /// Share of the year that is covered by valid samples
+    pub annual_coverage: Percent,
+    /// N₂O emitted during the measured time
+    pub n2o: Kilograms,
+    pub emission_factor: Percent,
+}
+
+impl N2oMeasurementCampaign {
+    /// The input values that store the campaign in a project.
+    #[must_use]
+    pub fn input_values(&self) -> Vec<(In, V)> {
+        let format = format_description!("[year]-[month]-[day]");
+        let period = match (
+            self.start.date().format(format),
+            self.end.date().format(format),
+        ) {
+            (Ok(start), Ok(end)) => format!("{start} – {end}"),
+            _ => String::new(),
+        };
+        vec![
+            (
+                In::SensitivityN2OMeasuredFactor,
+                V::percent(self.emission_factor.into()),
+            ),
+            (In::SensitivityN2OMeasurementPeriod, V::text(period)),
+            (In::SensitivityN2OMeasurementSamples, V::count(self.samples)),
+            (
+                In::SensitivityN2OMeasurementCoverage,
+                V::percent(self.coverage.into()),
+            ),
+            (
+                In::SensitivityN2OMeasurementAnnualCoverage,
+                V::percent(self.annual_coverage.into()),
+            ),
+        ]
+    }
+}
+
+/// Import a N₂O measurement time series and
+/// calculate the site-specific emission factor.
+///
+/// The nitrogen load is taken from the plant profile.
+pub fn import_n2o_measurement_campaign<S>(
+    csv: &str,
+    form_data: &HashMap<In, Value, S>,
+) -> anyhow::Result<N2oMeasurementCampaign>
+where
+    S: BuildHasher,
+{
+    let wastewater = required!(In::ProfileWastewater, form_data)?;
+    let nitrogen_influent = required!(In::ProfileInfluentNitrogen, form_data)?;
+    let measurements = parse_n2o_measurements(csv)?;
+    calculate_n2o_measurement_campaign(&measurements, wastewater, nitrogen_influent)
+}
+
+/// Parse a CSV time series with the columns
+/// timestamp, N₂O concentration `[ppm]` and off-gas flow `[m³/h]`.
+///
+/// Columns are separated by `,` or by `;` (then `,` is the decimal separator).
+/// Timestamps are formatted as `YYYY-MM-DD hh:mm[:ss]` (or with a `T` as separator).
+/// A leading header line is skipped.
+pub fn parse_n2o_measurements(csv: &str) -> anyhow::Result<Vec<N2oMeasurement>> {
+    let timestamp_format =
+        format_description!("[year]-[month]-[day] [hour]:[minute][optional [:[second]]]");
+    let mut measurements = Vec::new();
+    for (index, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let semicolon_separated = line.contains(';');
+        let columns = if semicolon_separated {
+            line.split(';').map(str::trim).collect::<Vec<_>>()
+        } else {
+            line.split(',').map(str::trim).collect::<Vec<_>>()
+        };
+        let timestamp =
+            PrimitiveDateTime::parse(&columns[0].replacen('T', " ", 1), timestamp_format);
+        if timestamp.is_err() && index == 0 {
+            // header (with any number of columns)
+            continue;
+        }
+        let [_, concentration, flow] = columns[..] else {
+            bail!(
+                "Line {}: expected 3 columns, found {}",
+                index + 1,
+                columns.len()
+            );
+        };
+        let number = |v: &str| {
+            let v = if semicolon_separated {
+                v.replace(',', ".")
+            } else {
+                v.to_string()
+            };
+            v.parse::<f64>()
+        };
+        let (Ok(timestamp), Ok(concentration), Ok(flow)) =
+            (timestamp, number(concentration), number(flow))
+        else {
+            bail!("Line {}: invalid measurement '{line}'", index + 1);
+        };
+        ensure!(
+            concentration >= 0.0 && flow >= 0.0,
+            "Line {}: negative values are not allowed",
+            index + 1
+        );
+        measurements.push(N2oMeasurement {
+            timestamp,
+            concentration,
+            flow: QubicmetersPerHour::new(flow),
+        });
+    }
+    Ok(measurements)
+}
+
+/// Calculate the site-specific N₂O emission factor of a measurement campaign.
+///
+/// The emissions are integrated between consecutive samples.
+/// Intervals longer than [`N2O_MEASUREMENT_MAX_GAP`] are treated as gaps.
+/// The emissions of the measured time are extrapolated to a whole year
+/// and related to the nitrogen load of the influent.
+pub fn calculate_n2o_measurement_campaign(
+    measurements: &[N2oMeasurement],
+    wastewater: Qubicmeters,
+    nitrogen_influent: MilligramsPerLiter,
+) -> anyhow::Result<N2oMeasurementCampaign> {
+    let mut measurements = measurements.to_vec();
+    measurements.sort_by_key(|m| m.timestamp);
+    let (Some(first), Some(last)) = (measurements.first(), measurements.last()) else {
+        bail!("The measurement contains no samples");
+    };
+    let (start, end) = (first.timestamp, last.timestamp);
+
+    let max_gap = f64::from(N2O_MEASUREMENT_MAX_GAP);
+    let mut measured_hours = 0.0;
+    let mut milligrams = 0.0;
+    for pair in measurements.windows(2) {
+        let hours = (pair[1].timestamp - pair[0].timestamp).as_seconds_f64() / 3600.0;
+        if hours <= 0.0 || hours > max_gap {
+            continue;
+        }
+        let sample = &pair[0];
+        milligrams += sample.concentration
+            * CONVERSION_FACTOR_N2O_PPM_TO_MG_PER_M3
+            * f64::from(sample.flow)
+            * hours;
+        measured_hours += hours;
+    }
+    ensure!(
+        measured_hours > 0.0,
+        "The measurement does not cover any time span"
+    );
+
+    let hours_per_year = f64::from(Years::new(1.0).convert_to::<Hours>());
+    let campaign_hours = (end - start).as_seconds_f64() / 3600.0;
+    let annual_n2o_kilograms = milligrams / 1_000_000.0 / measured_hours * hours_per_year;
+    // `[mg/l]` equals `[g/m³]`
+    let nitrogen_load_kilograms = f64::from(wastewater) * f64::from(nitrogen_influent) / 1_000.0;
+    ensure!(
+        nitrogen_load_kilograms > 0.0,
+        "The nitrogen load of the influent is missing"
+    );
+    let emission_factor =
+        annual_n2o_kilograms / f64::from(CONVERSION_FACTOR_N_TO_N2O) / nitrogen_load_kilograms
+            * 100.0;
+    if emission_factor > 100.0 {
+        return Err(anyhow!(
+            "The measured N₂O emissions exceed the nitrogen load of the influent"
+        ));
+    }
+
+    Ok(N2oMeasurementCampaign {
+        start,
+        end,
+        samples: measurements.len() as u64,
+        measured_time: Hours::new(measured_hours),
+        coverage: Percent::new(measured_hours / campaign_hours * 100.0),
+        annual_coverage: Percent::new((measured_hours / hours_per_year * 100.0).min(100.0)),
+        n2o: Kilograms::new(milligrams / 1_000_000.0),
+        emission_factor: Percent::new(emission_factor),
+    })
+}
@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use klick_domain::{CalculationOutcome, InputValueId as In, Value, ValueId as Id};
+use klick_domain::{
+    operating_materials::{OperatingMaterial, OPERATING_MATERIALS},
+    CalculationOutcome, InputValueId as In, Value, ValueId as Id,
+};
 
 use crate::calculate_emissions;
 
@@ -16,22 +19,36 @@ const PROFILE_IDS: &[In] = &[
     In::ProfileEffluentChemicalOxygenDemand,
     In::ProfileSewageGasProduced,
     In::ProfileMethaneFraction,
+    In::ProfileSewageGasFlared,
+    In::ProfileFlareEfficiency,
+    In::ProfileSewageGasVented,
+    In::ProfileSewageGasUpgraded,
+    In::ProfileUpgradingMethaneSlip,
+    In::ProfileUpgradingPowerConsumption,
+    In::ProfileHeatExported,
     In::ProfileGasSupply,
     In::ProfilePurchaseOfBiogas,
     In::ProfileTotalPowerConsumption,
     In::ProfileOnSitePowerGeneration,
     In::ProfileEmissionFactorElectricityMix,
+    In::ProfileElectricityContracts,
+    In::ProfileElectricityResidualMixEmissionFactor,
     In::ProfileHeatingOil,
     In::ProfileSideStreamTotalNitrogen,
-    In::ProfileOperatingMaterialFeCl3,
-    In::ProfileOperatingMaterialFeClSO4,
-    In::ProfileOperatingMaterialCaOH2,
-    In::ProfileOperatingMaterialSyntheticPolymers,
     In::ProfileSludgeBagsAreOpen,
     In::ProfileSludgeStorageContainersAreOpen,
     In::ProfileSludgeDisposal,
     In::ProfileSludgeTransportDistance,
+    In::ProfileSludgeDisposalRoute,
+    In::ProfileSludgeDisposalShareMonoIncineration,
+    In::ProfileSludgeDisposalShareCoIncineration,
+    In::ProfileSludgeDisposalShareAgriculture,
+    In::ProfileSludgeDisposalShareLandfill,
     In::ProfileSludgeDigesterCount,
+    In::ProfileSewerPressureMainLength,
+    In::ProfileSewerPressureMainRetentionTime,
+    In::ProfileSewerPumpingStationEnergy,
+    In::ProfileSewerOverflowVolume,
 ];
 
 pub fn calculate_profile(form_data: HashMap<In, Value>) -> CalculationOutcome {
@@ -43,7 +60,16 @@ pub fn calculate_profile(form_data: HashMap<In, Value>) -> CalculationOutcome {
     let custom_leafs = vec![];
     let custom_edges = None;
 
-    let profile_ids: HashSet<_> = PROFILE_IDS.iter().copied().map(Id::from).collect();
+    let profile_ids: HashSet<_> = PROFILE_IDS
+        .iter()
+        .copied()
+        .chain(
+            OPERATING_MATERIALS
+                .iter()
+                .flat_map(OperatingMaterial::inputs),
+        )
+        .map(Id::from)
+        .collect();
 
     let values: HashMap<_, _> = values
         .into_iter()
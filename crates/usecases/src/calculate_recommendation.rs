@@ -1,6 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
-use klick_domain::{CalculationOutcome, InputValueId as In, Value, ValueId as Id};
+use klick_domain::{
+    operating_materials::{OperatingMaterial, OPERATING_MATERIALS},
+    units::{Percent, Tons},
+    CalculationOutcome, InputValueId as In, OutputValueId as Out, Value, ValueId as Id,
+};
 
 use crate::calculate_emissions;
 
@@ -16,54 +20,130 @@ const RECOMMENDATION_IDS: &[In] = &[
     In::ProfileEffluentChemicalOxygenDemand,
     In::ProfileSewageGasProduced,
     In::ProfileMethaneFraction,
+    In::ProfileSewageGasFlared,
+    In::ProfileFlareEfficiency,
+    In::ProfileSewageGasVented,
+    In::ProfileSewageGasUpgraded,
+    In::ProfileUpgradingMethaneSlip,
+    In::ProfileUpgradingPowerConsumption,
+    In::ProfileHeatExported,
     In::ProfileGasSupply,
     In::ProfilePurchaseOfBiogas,
     In::ProfileTotalPowerConsumption,
     In::ProfileOnSitePowerGeneration,
     In::ProfileEmissionFactorElectricityMix,
+    In::ProfileElectricityContracts,
+    In::ProfileElectricityResidualMixEmissionFactor,
     In::ProfileHeatingOil,
     In::ProfileSideStreamTotalNitrogen,
-    In::ProfileOperatingMaterialFeCl3,
-    In::ProfileOperatingMaterialFeClSO4,
-    In::ProfileOperatingMaterialCaOH2,
-    In::ProfileOperatingMaterialSyntheticPolymers,
     In::ProfileSludgeBagsAreOpen,
     In::ProfileSludgeStorageContainersAreOpen,
     In::ProfileSludgeDisposal,
     In::ProfileSludgeTransportDistance,
+    In::ProfileSludgeDisposalRoute,
+    In::ProfileSludgeDisposalShareMonoIncineration,
+    In::ProfileSludgeDisposalShareCoIncineration,
+    In::ProfileSludgeDisposalShareAgriculture,
+    In::ProfileSludgeDisposalShareLandfill,
     In::ProfileSludgeDigesterCount,
+    In::ProfileSewerPressureMainLength,
+    In::ProfileSewerPressureMainRetentionTime,
+    In::ProfileSewerPumpingStationEnergy,
+    In::ProfileSewerOverflowVolume,
     In::SensitivityN2OCalculationMethod,
     In::SensitivityCH4ChpCalculationMethod,
     In::SensitivityN2OCustomFactor,
+    In::SensitivityN2OMeasuredFactor,
     In::SensitivityN2OSideStreamFactor,
     In::SensitivityCH4ChpCustomFactor,
+    In::SensitivityCH4ChpUnits,
     In::SensitivityCO2FossilCustomFactor,
     In::SensitivitySludgeBagsCustomFactor,
     In::SensitivitySludgeStorageCustomFactor,
     In::RecommendationSludgeBagsAreOpen,
     In::RecommendationSludgeStorageContainersAreOpen,
     In::RecommendationN2OSideStreamCoverIsOpen,
+    In::RecommendationFlareIsOpen,
     In::RecommendationProcessEnergySaving,
     In::RecommendationFossilEnergySaving,
     In::RecommendationDistrictHeating,
+    In::RecommendationHeatPumpThermalOutput,
+    In::RecommendationHeatPumpCoefficientOfPerformance,
     In::RecommendationPhotovoltaicEnergyExpansion,
     In::RecommendationEstimatedSelfPhotovolaticUsage,
     In::RecommendationWindEnergyExpansion,
     In::RecommendationEstimatedSelfWindEnergyUsage,
     In::RecommendationWaterEnergyExpansion,
     In::RecommendationEstimatedSelfWaterEnergyUsage,
+    In::RecommendationPhotovoltaicPeakPower,
+    In::RecommendationPhotovoltaicEmbodiedEmissions,
+    In::RecommendationWindEmbodiedEmissions,
+    In::RecommendationWaterEmbodiedEmissions,
+    In::RecommendationHeatPumpEmbodiedEmissions,
+    In::RecommendationFlareEmbodiedEmissions,
+    In::RecommendationDigesterCoverArea,
+    In::RecommendationDigesterCoverConcrete,
+    In::RecommendationDigesterCoverEmbodiedEmissions,
 ];
 
+/// Emissions of a custom leaf in the recommendation scenario.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CustomRecommendation {
+    /// Replaces the emissions.
+    Absolute(Tons),
+    /// Reduces the emissions by a percentage.
+    Reduction(Percent),
+}
+
+impl CustomRecommendation {
+    #[must_use]
+    pub fn apply(self, emissions: Tons) -> Tons {
+        match self {
+            Self::Absolute(recommended) => recommended,
+            Self::Reduction(reduction) => emissions - emissions * reduction,
+        }
+    }
+}
+
 pub fn calculate_recommendation(
     form_data: HashMap<Id, Value>,
     custom_edges: Option<&[(Id, Id)]>,
     custom_leafs: Vec<Id>,
+    custom_recommendations: &[(Id, CustomRecommendation)],
 ) -> CalculationOutcome {
-    let recommendation_ids: HashSet<_> = RECOMMENDATION_IDS.iter().copied().map(Id::from).collect();
-    let values = form_data
+    let recommendation_ids: HashSet<_> = RECOMMENDATION_IDS
+        .iter()
+        .copied()
+        .chain(
+            OPERATING_MATERIALS
+                .iter()
+                .flat_map(OperatingMaterial::inputs),
+        )
+        .map(Id::from)
+        .collect();
+    let mut values: HashMap<_, _> = form_data
         .into_iter()
         .filter(|(i, _)| recommendation_ids.contains(i) || i.is_custom())
         .collect();
 
-    calculate_emissions(&values, custom_edges, custom_leafs)
+    let mut custom_savings = Tons::zero();
+    for (id, recommendation) in custom_recommendations {
+        let Some(emissions) = values.get(id).cloned().and_then(Value::as_tons) else {
+            continue;
+        };
+        let recommended = recommendation.apply(emissions);
+        custom_savings += emissions - recommended;
+        values.insert(id.clone(), Value::from(recommended));
+    }
+
+    let mut outcome = calculate_emissions(&values, custom_edges, custom_leafs);
+    if let Some(output) = &mut outcome.output {
+        if !custom_recommendations.is_empty() {
+            output.insert(
+                Out::AdditionalCustomEmissionsSavings.into(),
+                Value::from(custom_savings),
+            );
+        }
+    }
+    outcome
 }
@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use klick_domain::{CalculationOutcome, InputValueId as In, Value, ValueId as Id};
+use klick_domain::{
+    operating_materials::{OperatingMaterial, OPERATING_MATERIALS},
+    CalculationOutcome, InputValueId as In, Value, ValueId as Id,
+};
 
 use crate::calculate_emissions;
 
@@ -16,27 +19,43 @@ const SENSITIVITY_IDS: &[In] = &[
     In::ProfileEffluentChemicalOxygenDemand,
     In::ProfileSewageGasProduced,
     In::ProfileMethaneFraction,
+    In::ProfileSewageGasFlared,
+    In::ProfileFlareEfficiency,
+    In::ProfileSewageGasVented,
+    In::ProfileSewageGasUpgraded,
+    In::ProfileUpgradingMethaneSlip,
+    In::ProfileUpgradingPowerConsumption,
+    In::ProfileHeatExported,
     In::ProfileGasSupply,
     In::ProfilePurchaseOfBiogas,
     In::ProfileTotalPowerConsumption,
     In::ProfileOnSitePowerGeneration,
     In::ProfileEmissionFactorElectricityMix,
+    In::ProfileElectricityContracts,
+    In::ProfileElectricityResidualMixEmissionFactor,
     In::ProfileHeatingOil,
     In::ProfileSideStreamTotalNitrogen,
-    In::ProfileOperatingMaterialFeCl3,
-    In::ProfileOperatingMaterialFeClSO4,
-    In::ProfileOperatingMaterialCaOH2,
-    In::ProfileOperatingMaterialSyntheticPolymers,
     In::ProfileSludgeBagsAreOpen,
     In::ProfileSludgeStorageContainersAreOpen,
     In::ProfileSludgeDisposal,
     In::ProfileSludgeTransportDistance,
+    In::ProfileSludgeDisposalRoute,
+    In::ProfileSludgeDisposalShareMonoIncineration,
+    In::ProfileSludgeDisposalShareCoIncineration,
+    In::ProfileSludgeDisposalShareAgriculture,
+    In::ProfileSludgeDisposalShareLandfill,
     In::ProfileSludgeDigesterCount,
+    In::ProfileSewerPressureMainLength,
+    In::ProfileSewerPressureMainRetentionTime,
+    In::ProfileSewerPumpingStationEnergy,
+    In::ProfileSewerOverflowVolume,
     In::SensitivityN2OCalculationMethod,
     In::SensitivityCH4ChpCalculationMethod,
     In::SensitivityN2OCustomFactor,
+    In::SensitivityN2OMeasuredFactor,
     In::SensitivityN2OSideStreamFactor,
     In::SensitivityCH4ChpCustomFactor,
+    In::SensitivityCH4ChpUnits,
     In::SensitivityCO2FossilCustomFactor,
     In::SensitivitySludgeBagsCustomFactor,
     In::SensitivitySludgeStorageCustomFactor,
@@ -47,7 +66,16 @@ pub fn calculate_sensitivity(
     custom_edges: Option<&[(Id, Id)]>,
     custom_leafs: Vec<Id>,
 ) -> CalculationOutcome {
-    let sensitivity_ids: HashSet<_> = SENSITIVITY_IDS.iter().copied().map(Id::from).collect();
+    let sensitivity_ids: HashSet<_> = SENSITIVITY_IDS
+        .iter()
+        .copied()
+        .chain(
+            OPERATING_MATERIALS
+                .iter()
+                .flat_map(OperatingMaterial::inputs),
+        )
+        .map(Id::from)
+        .collect();
     let values = form_data
         .into_iter()
         .filter(|(i, _)| sensitivity_ids.contains(i) || i.is_custom())
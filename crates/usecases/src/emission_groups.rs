@@ -5,34 +5,65 @@ use std::{
     ops::AddAssign,
 };
 
-use klick_domain::{OutputValueId as Out, OutputValueId};
+use klick_domain::{operating_materials::OPERATING_MATERIALS, OutputValueId as Out, OutputValueId};
 
-pub const SANKEY_EDGES: &[(Out, Out)] = &[
+/// Edges between the emission groups.
+///
+/// The edges of the operating materials are taken from
+/// the catalogue (see [`sankey_edges`]).
+const SANKEY_EDGES: &[(Out, Out)] = &[
     (Out::Ch4SludgeBags, Out::Ch4Emissions),
     (Out::Ch4SludgeStorageContainers, Out::Ch4Emissions),
     (Out::Ch4Plant, Out::Ch4Emissions),
     (Out::Ch4Water, Out::Ch4Emissions),
     (Out::Ch4CombinedHeatAndPowerPlant, Out::Ch4Emissions),
+    (Out::Ch4Flare, Out::Ch4Emissions),
+    (Out::Ch4Vented, Out::Ch4Emissions),
+    (Out::Ch4Upgrading, Out::Ch4Emissions),
     (Out::N2oPlant, Out::N2oEmissions),
     (Out::N2oWater, Out::N2oEmissions),
     (Out::N2oSideStream, Out::N2oEmissions),
-    (Out::SyntheticPolymers, Out::OperatingMaterials),
-    (Out::Feclso4, Out::OperatingMaterials),
-    (Out::Caoh2, Out::OperatingMaterials),
-    (Out::Fecl3, Out::OperatingMaterials),
     (Out::N2oEmissions, Out::DirectEmissions),
     (Out::Ch4Emissions, Out::DirectEmissions),
     (Out::FossilEmissions, Out::DirectEmissions),
     (Out::ElectricityMix, Out::IndirectEmissions),
     (Out::OilEmissions, Out::IndirectEmissions),
     (Out::GasEmissions, Out::IndirectEmissions),
+    (Out::UpgradingElectricity, Out::IndirectEmissions),
     (Out::OperatingMaterials, Out::OtherIndirectEmissions),
     (Out::SewageSludgeTransport, Out::OtherIndirectEmissions),
+    (
+        Out::SludgeDisposalMonoIncineration,
+        Out::OtherIndirectEmissions,
+    ),
+    (
+        Out::SludgeDisposalCoIncineration,
+        Out::OtherIndirectEmissions,
+    ),
+    (Out::SludgeDisposalAgriculture, Out::OtherIndirectEmissions),
+    (Out::SludgeDisposalLandfill, Out::OtherIndirectEmissions),
+    (Out::Ch4PressureMains, Out::SewerNetworkEmissions),
+    (Out::Ch4SewerOverflows, Out::SewerNetworkEmissions),
+    (
+        Out::SewerPumpingStationElectricity,
+        Out::SewerNetworkEmissions,
+    ),
+    (Out::SewerNetworkEmissions, Out::TotalEmissions),
     (Out::OtherIndirectEmissions, Out::TotalEmissions),
     (Out::DirectEmissions, Out::TotalEmissions),
     (Out::IndirectEmissions, Out::TotalEmissions),
 ];
 
+/// All edges of the Sankey diagram.
+#[must_use]
+pub fn sankey_edges() -> Vec<(Out, Out)> {
+    OPERATING_MATERIALS
+        .iter()
+        .map(|material| (material.output, Out::OperatingMaterials))
+        .chain(SANKEY_EDGES.iter().copied())
+        .collect()
+}
+
 pub fn emission_group_ids<ID>(edges: &[(ID, ID)]) -> HashSet<ID>
 where
     ID: Eq + Hash + Clone,
@@ -63,6 +94,9 @@ where
 
 #[must_use]
 pub fn get_all_internal_nodes() -> Vec<OutputValueId> {
-    let set: HashSet<_> = SANKEY_EDGES.iter().map(|(_, target)| *target).collect();
+    let set: HashSet<_> = sankey_edges()
+        .into_iter()
+        .map(|(_, target)| target)
+        .collect();
     set.iter().copied().collect()
 }
@@ -1,7 +1,9 @@
 mod calculate_all_ch4_chp_emission_factor_scenarios;
 mod calculate_all_n2o_emission_factor_scenarios;
 mod calculate_ch4_chp;
+mod calculate_embodied_emissions;
 mod calculate_emissions;
+mod calculate_n2o_measurement_campaign;
 mod calculate_profile;
 mod calculate_recommendation;
 mod calculate_sensitivity;
@@ -11,11 +13,13 @@ pub use self::{
     calculate_all_ch4_chp_emission_factor_scenarios::*,
     calculate_all_n2o_emission_factor_scenarios::*,
     calculate_ch4_chp::*,
+    calculate_embodied_emissions::*,
     calculate_emissions::*,
+    calculate_n2o_measurement_campaign::*,
     calculate_profile::*,
     calculate_recommendation::*,
     calculate_sensitivity::*,
-    emission_groups::{emission_group_ids, get_all_internal_nodes},
+    emission_groups::{emission_group_ids, get_all_internal_nodes, sankey_edges},
 };
 
 #[cfg(test)]
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
 use klick_domain::{
+    chp_units::{self, ChpUnit},
     constants::*,
+    electricity_contracts::{self, ElectricityContract},
     required_output_value_id as out,
     units::{Value as V, *},
     InputValueId as In, OutputValueId as Out, ValueId as Id,
@@ -788,6 +790,100 @@ fn calculate_with_n2o_emission_factor_method_custom_factor() {
     );
 }
 
+#[test]
+fn calculate_with_n2o_emission_factor_method_measured() {
+    let mut values = example_values();
+    values.insert(
+        In::SensitivityN2OCalculationMethod.into(),
+        V::n2o_emission_factor_calc_method(N2oEmissionFactorCalcMethod::Measured),
+    );
+    assert!(calculate(&values, None).is_err());
+
+    values.insert(In::SensitivityN2OMeasuredFactor.into(), V::percent(1.0));
+    let (values, _) = calculate(&values, None).unwrap();
+    assert_eq!(
+        out!(Out::N2oCalculatedEmissionFactor, values).unwrap(),
+        Factor::new(0.01)
+    );
+    assert_eq!(out!(Out::N2oPlant, values).unwrap(), Tons::new(861.060_915));
+}
+
+#[test]
+fn measured_n2o_emission_factor_is_a_scenario() {
+    let mut values = example_values();
+    let scenarios = calculate_all_n2o_emission_factor_scenarios(&values, None).unwrap();
+    assert_eq!(scenarios.len(), 5);
+
+    values.insert(In::SensitivityN2OMeasuredFactor.into(), V::percent(1.0));
+    let scenarios = calculate_all_n2o_emission_factor_scenarios(&values, None).unwrap();
+    assert_eq!(scenarios.len(), 6);
+    assert_eq!(scenarios[5].0, N2oEmissionFactorCalcMethod::Measured);
+}
+
+#[test]
+fn parse_n2o_measurement_csv() {
+    let csv = "Zeitstempel;N2O [ppm];Volumenstrom [m³/h]\n\
+               2024-03-01 00:15;12,5;20000\n\
+               2024-03-01 00:00:00;10;20000,5\n";
+    let measurements = parse_n2o_measurements(csv).unwrap();
+    assert_eq!(measurements.len(), 2);
+    assert_eq!(measurements[0].concentration, 12.5);
+    assert_eq!(measurements[1].flow, QubicmetersPerHour::new(20_000.5));
+
+    let csv = "2024-03-01T00:00,10.5,20000\n2024-03-01T00:15,11,20000\n";
+    let measurements = parse_n2o_measurements(csv).unwrap();
+    assert_eq!(measurements.len(), 2);
+    assert_eq!(measurements[0].concentration, 10.5);
+
+    let csv = "Zeit;N2O [ppm];Q [m³/h];Kommentar\n2024-03-01 00:00;10;20000\n";
+    let measurements = parse_n2o_measurements(csv).unwrap();
+    assert_eq!(measurements.len(), 1);
+    let csv = "Zeit;N2O [ppm]\n2024-03-01 00:00;10;20000\n";
+    assert_eq!(parse_n2o_measurements(csv).unwrap().len(), 1);
+
+    assert!(parse_n2o_measurements("timestamp,n2o,flow\n2024-03-01 00:00,x,1\n").is_err());
+    assert!(parse_n2o_measurements("2024-03-01 00:00,1\n").is_err());
+}
+
+#[test]
+fn calculate_n2o_measurement_campaign_test() {
+    let csv = "2024-03-01 00:00,10,20000\n\
+               2024-03-01 00:15,10,20000\n\
+               2024-03-01 00:30,10,20000\n\
+               2024-03-01 00:45,10,20000\n\
+               2024-03-01 01:00,10,20000\n\
+               2024-03-01 04:00,10,20000\n";
+    let measurements = parse_n2o_measurements(csv).unwrap();
+    let campaign = calculate_n2o_measurement_campaign(
+        &measurements,
+        Qubicmeters::new(2_135_250.0),
+        MilligramsPerLiter::new(94.0),
+    )
+    .unwrap();
+
+    assert_eq!(campaign.samples, 6);
+    assert_eq!(campaign.measured_time, Hours::new(1.0));
+    assert_eq!(campaign.coverage, Percent::new(25.0));
+    assert!((f64::from(campaign.n2o) - 0.392_727_759_436).abs() < 1e-9);
+    assert!((f64::from(campaign.emission_factor) - 1.090_748_129_168).abs() < 1e-9);
+
+    let values = campaign.input_values();
+    assert_eq!(
+        values[1],
+        (
+            In::SensitivityN2OMeasurementPeriod,
+            V::text("2024-03-01 – 2024-03-01")
+        )
+    );
+
+    assert!(calculate_n2o_measurement_campaign(
+        &[],
+        Qubicmeters::new(2_135_250.0),
+        MilligramsPerLiter::new(94.0),
+    )
+    .is_err());
+}
+
 #[test]
 fn calculate_ch4_slippage_sludge_bags_for_one_digester() {
     let expected = Tons::new(4.871_107_5);
@@ -867,6 +963,339 @@ fn calculate_gas_emissions_test() {
     );
 }
 
+#[test]
+fn calculate_ch4_chp_with_multiple_units() {
+    let sewage_gas = Qubicmeters::new(1_000_000.0);
+    let methane_fraction = Percent::new(60.0);
+    let single = |method, gas| calculate_ch4_chp(Some(method), None, &[], gas, methane_fraction).0;
+    let units = [
+        ChpUnit {
+            calculation_method: Ch4ChpEmissionFactorCalcMethod::GasolineEngine,
+            custom_factor: None,
+            gas_share: Some(Percent::new(60.0)),
+            gas_volume: None,
+        },
+        ChpUnit {
+            calculation_method: Ch4ChpEmissionFactorCalcMethod::MicroGasTurbines,
+            custom_factor: None,
+            gas_share: None,
+            gas_volume: None,
+        },
+    ];
+
+    let (ch4_chp, factor) = calculate_ch4_chp(
+        Some(Ch4ChpEmissionFactorCalcMethod::Mix),
+        None,
+        &units,
+        sewage_gas,
+        methane_fraction,
+    );
+    let expected = single(
+        Ch4ChpEmissionFactorCalcMethod::GasolineEngine,
+        Qubicmeters::new(600_000.0),
+    ) + single(
+        Ch4ChpEmissionFactorCalcMethod::MicroGasTurbines,
+        Qubicmeters::new(400_000.0),
+    );
+    assert_eq!(ch4_chp.round(6), expected.round(6));
+    assert_eq!(factor.round(6), Factor::new(0.013));
+
+    // The units are ignored if another method is selected
+    assert_eq!(
+        calculate_ch4_chp(
+            Some(Ch4ChpEmissionFactorCalcMethod::JetEngine),
+            None,
+            &units,
+            sewage_gas,
+            methane_fraction,
+        ),
+        calculate_ch4_chp(
+            Some(Ch4ChpEmissionFactorCalcMethod::JetEngine),
+            None,
+            &[],
+            sewage_gas,
+            methane_fraction,
+        )
+    );
+
+    let scenarios =
+        calculate_all_ch4_chp_emission_factor_scenarios(sewage_gas, methane_fraction, None, &units);
+    let (method, mix, _) = scenarios.last().unwrap();
+    assert_eq!(*method, Ch4ChpEmissionFactorCalcMethod::Mix);
+    assert_eq!(*mix, ch4_chp);
+}
+
+#[test]
+fn calculate_ch4_chp_with_units_exceeding_the_sewage_gas() {
+    let sewage_gas = Qubicmeters::new(1_000_000.0);
+    let methane_fraction = Percent::new(60.0);
+    let unit = |calculation_method, gas_volume| ChpUnit {
+        calculation_method,
+        custom_factor: None,
+        gas_share: None,
+        gas_volume: Some(Qubicmeters::new(gas_volume)),
+    };
+    let units = [
+        unit(Ch4ChpEmissionFactorCalcMethod::GasolineEngine, 1_200_000.0),
+        unit(Ch4ChpEmissionFactorCalcMethod::MicroGasTurbines, 800_000.0),
+        // Ignored, because a single unit can't be a mix
+        unit(Ch4ChpEmissionFactorCalcMethod::Mix, 500_000.0),
+    ];
+    let (ch4_chp, factor) = calculate_ch4_chp_units(&units, sewage_gas, methane_fraction);
+
+    let (expected, _) = calculate_ch4_chp_units(
+        &[
+            unit(Ch4ChpEmissionFactorCalcMethod::GasolineEngine, 600_000.0),
+            unit(Ch4ChpEmissionFactorCalcMethod::MicroGasTurbines, 400_000.0),
+        ],
+        sewage_gas,
+        methane_fraction,
+    );
+    assert_eq!(ch4_chp.round(6), expected.round(6));
+    assert_eq!(factor.round(6), Factor::new(0.013));
+}
+
+#[test]
+fn calculate_ch4_chp_with_units_from_input_values() {
+    let mut values = example_values();
+    let unit = |gas_share| ChpUnit {
+        calculation_method: Ch4ChpEmissionFactorCalcMethod::JetEngine,
+        custom_factor: None,
+        gas_share,
+        gas_volume: None,
+    };
+    values.insert(
+        In::SensitivityCH4ChpUnits.into(),
+        chp_units::to_value(&[unit(None), unit(Some(Percent::new(30.0)))]),
+    );
+
+    let mix = ch4_combined_heat_and_power_plant_computation_helper(
+        &values,
+        Ch4ChpEmissionFactorCalcMethod::Mix,
+    );
+    let jet_engine = ch4_combined_heat_and_power_plant_computation_helper(
+        &values,
+        Ch4ChpEmissionFactorCalcMethod::JetEngine,
+    );
+    assert_eq!(mix.round(6), jet_engine.round(6));
+}
+
+#[test]
+fn calculate_flared_and_vented_sewage_gas() {
+    let produced = Qubicmeters::new(1_000.0);
+    assert_eq!(
+        calculate_chp_sewage_gas(
+            produced,
+            Qubicmeters::new(300.0),
+            Qubicmeters::new(200.0),
+            Qubicmeters::new(100.0)
+        ),
+        Qubicmeters::new(400.0)
+    );
+    assert_eq!(
+        calculate_chp_sewage_gas(
+            produced,
+            Qubicmeters::new(800.0),
+            Qubicmeters::new(400.0),
+            Qubicmeters::zero()
+        ),
+        Qubicmeters::zero()
+    );
+
+    let methane_fraction = Percent::new(62.0);
+    let vented = calculate_ch4_vented(produced, methane_fraction);
+    assert_eq!(
+        calculate_ch4_flare(produced, methane_fraction, Percent::zero()),
+        vented
+    );
+    assert_eq!(
+        calculate_ch4_flare(produced, methane_fraction, Percent::new(50.0)).round(6),
+        (vented * Factor::new(0.5)).round(6)
+    );
+}
+
+#[test]
+fn calculate_emissions_with_flared_and_vented_sewage_gas() {
+    let values = example_values();
+    let tons = |outputs: &HashMap<Id, Value>, id: Out| {
+        outputs.get(&id.into()).cloned().unwrap().as_tons().unwrap()
+    };
+    let (without_flare, _) = calculate(&values, None).unwrap();
+    assert_eq!(tons(&without_flare, Out::Ch4Flare), Tons::zero());
+    assert_eq!(tons(&without_flare, Out::Ch4Vented), Tons::zero());
+
+    let mut values = values;
+    values.insert(In::ProfileSewageGasFlared.into(), V::qubicmeters(100_000.0));
+    values.insert(In::ProfileSewageGasVented.into(), V::qubicmeters(10_000.0));
+    let (open_flare, _) = calculate(&values, None).unwrap();
+    assert!(
+        tons(&open_flare, Out::Ch4CombinedHeatAndPowerPlant)
+            < tons(&without_flare, Out::Ch4CombinedHeatAndPowerPlant)
+    );
+    assert!(tons(&open_flare, Out::Ch4Flare) > Tons::zero());
+    assert!(tons(&open_flare, Out::Ch4Vented) > Tons::zero());
+    assert_eq!(tons(&open_flare, Out::EnclosedFlareSavings), Tons::zero());
+
+    values.insert(In::RecommendationFlareIsOpen.into(), V::bool(false));
+    let (enclosed_flare, _) = calculate(&values, None).unwrap();
+    let savings = tons(&enclosed_flare, Out::EnclosedFlareSavings);
+    assert!(savings > Tons::zero());
+    assert_eq!(
+        (tons(&enclosed_flare, Out::Ch4Flare) + savings).round(6),
+        tons(&open_flare, Out::Ch4Flare).round(6)
+    );
+}
+
+#[test]
+fn calculate_biomethane_upgrading_test() {
+    let (ch4_upgrading, credit) = calculate_biomethane_upgrading(
+        Qubicmeters::new(1_000.0),
+        Percent::new(60.0),
+        Percent::new(1.0),
+    );
+    assert_eq!(
+        ch4_upgrading.round(6),
+        calculate_ch4_vented(Qubicmeters::new(6.0), Percent::new(100.0)).round(6)
+    );
+    // 594 m³ methane × 2.04 kg/m³
+    assert_eq!(credit.round(6), Tons::new(1.211_76));
+}
+
+#[test]
+fn credits_are_not_part_of_the_total_emissions() {
+    let tons = |outputs: &HashMap<Id, Value>, id: Out| {
+        outputs.get(&id.into()).cloned().unwrap().as_tons().unwrap()
+    };
+    let mut values = example_values();
+    let (without_credits, _) = calculate(&values, None).unwrap();
+
+    values.insert(In::ProfileHeatExported.into(), V::kilowatthours(100_000.0));
+    let (with_heat_export, _) = calculate(&values, None).unwrap();
+    assert_eq!(
+        tons(&with_heat_export, Out::HeatExportCredit).round(4),
+        Tons::new(24.39)
+    );
+    assert_eq!(
+        tons(&with_heat_export, Out::AvoidedEmissions),
+        tons(&with_heat_export, Out::HeatExportCredit)
+            + tons(&with_heat_export, Out::BiomethaneInjectionCredit)
+            + tons(&with_heat_export, Out::ExcessEnergyCo2Equivalent)
+    );
+    assert_eq!(
+        tons(&with_heat_export, Out::TotalEmissions),
+        tons(&without_credits, Out::TotalEmissions)
+    );
+
+    values.insert(
+        In::ProfileSewageGasUpgraded.into(),
+        V::qubicmeters(100_000.0),
+    );
+    let (with_upgrading, _) = calculate(&values, None).unwrap();
+    assert!(tons(&with_upgrading, Out::BiomethaneInjectionCredit) > Tons::zero());
+    assert!(tons(&with_upgrading, Out::Ch4Upgrading) > Tons::zero());
+    assert!(
+        tons(&with_upgrading, Out::Ch4CombinedHeatAndPowerPlant)
+            < tons(&with_heat_export, Out::Ch4CombinedHeatAndPowerPlant)
+    );
+}
+
+#[test]
+fn calculate_heat_pump_test() {
+    let (power_consumption, heating_oil, gas_supply) = calculate_heat_pump(
+        Kilowatthours::new(35_000.0),
+        Factor::new(3.5),
+        Liters::new(1_000.0),
+        Qubicmeters::new(10_000.0),
+    );
+    assert_eq!(power_consumption.round(6), Kilowatthours::new(10_000.0));
+    assert_eq!(heating_oil.round(6), Liters::new(1_000.0));
+    assert_eq!(gas_supply.round(6), Qubicmeters::new(2_500.0));
+
+    // The replaced fuel is limited by the supply
+    let (_, heating_oil, gas_supply) = calculate_heat_pump(
+        Kilowatthours::new(1_000_000.0),
+        Factor::new(3.5),
+        Liters::new(1_000.0),
+        Qubicmeters::new(10_000.0),
+    );
+    assert_eq!(heating_oil, Liters::new(1_000.0));
+    assert_eq!(gas_supply, Qubicmeters::new(10_000.0));
+}
+
+#[test]
+fn heat_pump_recommendation() {
+    let tons = |outputs: &HashMap<Id, Value>, id: Out| {
+        outputs.get(&id.into()).cloned().unwrap().as_tons().unwrap()
+    };
+    let mut values = example_values();
+    values.insert(In::ProfileHeatingOil.into(), V::liters(1_000.0));
+    values.insert(In::ProfileGasSupply.into(), V::qubicmeters(10_000.0));
+    let (without_heat_pump, _) = calculate(&values, None).unwrap();
+    assert_eq!(tons(&without_heat_pump, Out::HeatPumpSavings), Tons::zero());
+
+    values.insert(
+        In::RecommendationHeatPumpThermalOutput.into(),
+        V::kilowatthours(35_000.0),
+    );
+    let (with_heat_pump, _) = calculate(&values, None).unwrap();
+    // 2.6763 t heating oil + 5.1 t gas - 4.2 t electricity
+    let savings = tons(&with_heat_pump, Out::HeatPumpSavings);
+    assert_eq!(savings.round(4), Tons::new(3.5763));
+    assert_eq!(
+        (tons(&without_heat_pump, Out::TotalEmissions)
+            - tons(&with_heat_pump, Out::TotalEmissions))
+        .round(4),
+        savings.round(4)
+    );
+}
+
+#[test]
+fn calculate_sludge_disposal_test() {
+    let sludge = Tons::new(1000.0);
+    let round = |emissions: [Tons; 4]| emissions.map(|t| t.round(3));
+
+    assert_eq!(
+        calculate_sludge_disposal(sludge, None, [None; 4]),
+        [Tons::zero(); 4]
+    );
+    assert_eq!(
+        round(calculate_sludge_disposal(
+            sludge,
+            Some(SludgeDisposalRoute::Landfill),
+            [None; 4]
+        )),
+        [Tons::zero(), Tons::zero(), Tons::zero(), Tons::new(280.0)]
+    );
+    // Shares take precedence over the route
+    assert_eq!(
+        round(calculate_sludge_disposal(
+            sludge,
+            Some(SludgeDisposalRoute::Landfill),
+            [
+                Some(Percent::new(50.0)),
+                None,
+                Some(Percent::new(25.0)),
+                None
+            ]
+        )),
+        [Tons::new(34.0), Tons::zero(), Tons::new(12.0), Tons::zero()]
+    );
+    // A total above 100% is scaled down
+    assert_eq!(
+        round(calculate_sludge_disposal(
+            sludge,
+            None,
+            [
+                Some(Percent::new(100.0)),
+                Some(Percent::new(100.0)),
+                None,
+                None
+            ]
+        )),
+        [Tons::new(34.0), Tons::new(12.0), Tons::zero(), Tons::zero()]
+    );
+}
+
 #[test]
 fn calculate_process_energy_savings_test() {
     assert_eq!(
@@ -903,6 +1332,363 @@ fn calculate_water_expansion_savings_test() {
     );
 }
 
+#[test]
+fn calculate_market_based_emission_factor_test() {
+    let location = GramsPerKilowatthour::new(400.0);
+    let contracts = [
+        ElectricityContract {
+            energy: Kilowatthours::new(500.0),
+            emission_factor: Some(GramsPerKilowatthour::new(100.0)),
+            guarantee_of_origin: true,
+        },
+        ElectricityContract {
+            energy: Kilowatthours::new(250.0),
+            emission_factor: Some(GramsPerKilowatthour::new(100.0)),
+            guarantee_of_origin: false,
+        },
+        ElectricityContract {
+            energy: Kilowatthours::new(1_000.0),
+            emission_factor: None,
+            guarantee_of_origin: false,
+        },
+    ];
+    assert_eq!(
+        calculate_market_based_emission_factor(
+            Kilowatthours::new(1_000.0),
+            location,
+            None,
+            &contracts
+        ),
+        GramsPerKilowatthour::new(125.0)
+    );
+    assert_eq!(
+        calculate_market_based_emission_factor(
+            Kilowatthours::new(2_000.0),
+            location,
+            None,
+            &contracts[..2]
+        ),
+        GramsPerKilowatthour::new(262.5)
+    );
+    assert_eq!(
+        calculate_market_based_emission_factor(Kilowatthours::zero(), location, None, &contracts),
+        location
+    );
+
+    // The residual mix replaces the location-based factor
+    // for contracts without an own factor and the uncovered rest
+    let residual = GramsPerKilowatthour::new(500.0);
+    assert_eq!(
+        calculate_market_based_emission_factor(
+            Kilowatthours::new(2_000.0),
+            location,
+            Some(residual),
+            &contracts
+        ),
+        GramsPerKilowatthour::new(325.0)
+    );
+    assert_eq!(
+        calculate_market_based_emission_factor(
+            Kilowatthours::zero(),
+            location,
+            Some(residual),
+            &contracts
+        ),
+        residual
+    );
+}
+
+#[test]
+fn market_based_electricity_mix() {
+    let tons = |outputs: &HashMap<Id, Value>, id: Out| {
+        outputs.get(&id.into()).cloned().unwrap().as_tons().unwrap()
+    };
+    let mut values = example_values();
+    let (without_contracts, _) = calculate(&values, None).unwrap();
+    assert_eq!(
+        tons(&without_contracts, Out::ElectricityMixMarketBased),
+        tons(&without_contracts, Out::ElectricityMix)
+    );
+
+    values.insert(
+        In::ProfileElectricityContracts.into(),
+        electricity_contracts::to_value(&[ElectricityContract {
+            energy: Kilowatthours::new(100_000_000.0),
+            emission_factor: None,
+            guarantee_of_origin: true,
+        }]),
+    );
+    let (with_contracts, _) = calculate(&values, None).unwrap();
+    assert_eq!(
+        tons(&with_contracts, Out::ElectricityMixMarketBased),
+        Tons::zero()
+    );
+    assert_eq!(
+        tons(&with_contracts, Out::ElectricityMix),
+        tons(&without_contracts, Out::ElectricityMix)
+    );
+    assert_eq!(
+        tons(&with_contracts, Out::TotalEmissions),
+        tons(&without_contracts, Out::TotalEmissions)
+    );
+}
+
+#[test]
+fn calculate_operating_material_emissions_test() {
+    assert_eq!(
+        calculate_operating_material_emissions(Tons::new(100.0), None, EMISSION_FACTOR_FECL3),
+        Tons::new(39.5)
+    );
+    assert_eq!(
+        calculate_operating_material_emissions(
+            Tons::new(10.0),
+            Some(CONCENTRATION_SODIUM_HYDROXIDE),
+            EMISSION_FACTOR_SODIUM_HYDROXIDE
+        )
+        .round(4),
+        Tons::new(5.6)
+    );
+    assert_eq!(
+        calculate_operating_material_emissions(Tons::new(10.0), None, EMISSION_FACTOR_METHANOL)
+            .round(4),
+        Tons::new(21.15)
+    );
+}
+
+#[test]
+fn operating_material_concentration_defaults_to_the_catalogue() {
+    let tons = |outputs: &HashMap<Id, Value>, id: Out| {
+        outputs.get(&id.into()).cloned().unwrap().as_tons().unwrap()
+    };
+    let mut values = example_values();
+    values.insert(In::ProfileOperatingMaterialAceticAcid.into(), V::tons(10.0));
+    let (outputs, _) = calculate(&values, None).unwrap();
+    assert_eq!(tons(&outputs, Out::AceticAcid).round(4), Tons::new(24.536));
+
+    values.insert(
+        In::ProfileOperatingMaterialAceticAcidConcentration.into(),
+        V::percent(60.0),
+    );
+    let (outputs, _) = calculate(&values, None).unwrap();
+    assert_eq!(tons(&outputs, Out::AceticAcid).round(4), Tons::new(18.402));
+}
+
+#[test]
+fn additional_operating_materials_are_part_of_the_sankey() {
+    let tons = |outputs: &HashMap<Id, Value>, id: Out| {
+        outputs.get(&id.into()).cloned().unwrap().as_tons().unwrap()
+    };
+    let mut values = example_values();
+    let (without_activated_carbon, _) = calculate(&values, None).unwrap();
+
+    values.insert(
+        In::ProfileOperatingMaterialActivatedCarbon.into(),
+        V::tons(10.0),
+    );
+    let (with_activated_carbon, graph) = calculate(&values, None).unwrap();
+    assert_eq!(
+        tons(&with_activated_carbon, Out::ActivatedCarbon),
+        Tons::new(84.0)
+    );
+    assert_eq!(
+        tons(&with_activated_carbon, Out::OperatingMaterials).round(4),
+        (tons(&without_activated_carbon, Out::OperatingMaterials) + Tons::new(84.0)).round(4)
+    );
+    assert!(graph.contains(&(Out::ActivatedCarbon.into(), Out::OperatingMaterials.into())));
+}
+
+#[test]
+fn calculate_ch4_pressure_mains_test() {
+    let length = Kilometers::new(10.0);
+    assert_eq!(
+        calculate_ch4_pressure_mains(length, Hours::new(0.0)),
+        Tons::zero()
+    );
+    // 1.238.416 m³ × 7,5 mg/l × GWP 28
+    assert_eq!(
+        calculate_ch4_pressure_mains(length, Hours::new(5.0)).round(3),
+        Tons::new(260.067)
+    );
+    // the dissolved methane is limited to 25 mg/l
+    assert_eq!(
+        calculate_ch4_pressure_mains(length, Hours::new(20.0)).round(3),
+        Tons::new(216.723)
+    );
+}
+
+#[test]
+fn calculate_ch4_sewer_overflows_test() {
+    // 1.000 m³ × 600 mg/l × 0,9 % × GWP 28
+    assert_eq!(
+        calculate_ch4_sewer_overflows(Qubicmeters::new(1_000.0), MilligramsPerLiter::new(600.0))
+            .round(6),
+        Tons::new(0.1512)
+    );
+}
+
+#[test]
+fn sewer_network_is_part_of_the_total_emissions() {
+    let tons = |outputs: &HashMap<Id, Value>, id: Out| {
+        outputs.get(&id.into()).cloned().unwrap().as_tons().unwrap()
+    };
+    let mut values = example_values();
+    let (without_sewer_network, _) = calculate(&values, None).unwrap();
+    assert_eq!(
+        tons(&without_sewer_network, Out::SewerNetworkEmissions),
+        Tons::zero()
+    );
+
+    values.insert(
+        In::ProfileSewerPumpingStationEnergy.into(),
+        V::kilowatthours(100_000.0),
+    );
+    values.insert(
+        In::ProfileSewerOverflowVolume.into(),
+        V::qubicmeters(10_000.0),
+    );
+    let (with_sewer_network, graph) = calculate(&values, None).unwrap();
+    let sewer_network = tons(&with_sewer_network, Out::SewerNetworkEmissions);
+    assert_eq!(
+        sewer_network,
+        tons(&with_sewer_network, Out::SewerPumpingStationElectricity)
+            + tons(&with_sewer_network, Out::Ch4SewerOverflows)
+    );
+    assert!(sewer_network > Tons::zero());
+    assert_eq!(
+        tons(&with_sewer_network, Out::TotalEmissions).round(4),
+        (tons(&without_sewer_network, Out::TotalEmissions) + sewer_network).round(4)
+    );
+    assert!(graph.contains(&(
+        Out::SewerNetworkEmissions.into(),
+        Out::TotalEmissions.into()
+    )));
+}
+
+#[test]
+fn digester_cover_recommendation() {
+    let tons = |outputs: &HashMap<Id, Value>, id: Out| {
+        outputs.get(&id.into()).cloned().unwrap().as_tons().unwrap()
+    };
+    let mut values = example_values();
+    let (open, _) = calculate(&values, None).unwrap();
+    assert_eq!(tons(&open, Out::DigesterCoverSavings), Tons::zero());
+
+    values.insert(In::RecommendationSludgeBagsAreOpen.into(), V::bool(false));
+    values.insert(
+        In::RecommendationSludgeStorageContainersAreOpen.into(),
+        V::bool(false),
+    );
+    let (covered, _) = calculate(&values, None).unwrap();
+    let savings = tons(&covered, Out::DigesterCoverSavings);
+    assert!(savings > Tons::zero());
+    assert_eq!(
+        savings,
+        tons(&open, Out::Ch4SludgeBags) + tons(&open, Out::Ch4SludgeStorageContainers)
+    );
+    assert_eq!(
+        (tons(&open, Out::TotalEmissions) - tons(&covered, Out::TotalEmissions)).round(4),
+        savings.round(4)
+    );
+}
+
+#[test]
+fn calculate_embodied_emissions_test() {
+    let measure = |savings| {
+        klick_domain::measures::MEASURES
+            .iter()
+            .find(|m| m.savings == savings)
+            .unwrap()
+    };
+    let photovoltaic = measure(Out::PhotovoltaicExpansionSavings);
+    let digester_cover = measure(Out::DigesterCoverSavings);
+
+    let mut values: HashMap<Id, Value> = HashMap::new();
+    assert_eq!(
+        calculate_embodied_emissions(photovoltaic, &values),
+        Tons::zero()
+    );
+
+    values.insert(
+        In::RecommendationPhotovoltaicPeakPower.into(),
+        V::kilowatts(100.0),
+    );
+    values.insert(
+        In::RecommendationDigesterCoverArea.into(),
+        V::square_meters(500.0),
+    );
+    values.insert(
+        In::RecommendationDigesterCoverConcrete.into(),
+        V::qubicmeters(100.0),
+    );
+    assert_eq!(
+        calculate_embodied_emissions(photovoltaic, &values).round(3),
+        Tons::new(100.0)
+    );
+    // 25 t cover + 30 t concrete
+    assert_eq!(
+        calculate_embodied_emissions(digester_cover, &values).round(3),
+        Tons::new(55.0)
+    );
+
+    values.insert(
+        In::RecommendationPhotovoltaicEmbodiedEmissions.into(),
+        V::tons(42.0),
+    );
+    assert_eq!(
+        calculate_embodied_emissions(photovoltaic, &values),
+        Tons::new(42.0)
+    );
+}
+
+#[test]
+fn calculate_amortisation_time_test() {
+    assert_eq!(
+        calculate_amortisation_time(Tons::new(100.0), Tons::new(20.0)),
+        Some(Years::new(5.0))
+    );
+    assert_eq!(
+        calculate_amortisation_time(Tons::new(100.0), Tons::zero()),
+        None
+    );
+}
+
+#[test]
+fn calculate_amortisations_test() {
+    let input = HashMap::from([
+        (
+            Id::from(In::RecommendationPhotovoltaicPeakPower),
+            V::kilowatts(100.0),
+        ),
+        (
+            In::RecommendationWindEmbodiedEmissions.into(),
+            V::tons(50.0),
+        ),
+    ]);
+    let output = HashMap::from([
+        (Id::from(Out::PhotovoltaicExpansionSavings), V::tons(20.0)),
+        (Out::WindExpansionSavings.into(), V::tons(0.0)),
+        (Out::HeatPumpSavings.into(), V::tons(10.0)),
+    ]);
+    let amortisations = calculate_amortisations(&input, &output);
+    assert_eq!(amortisations.len(), 2);
+
+    let photovoltaic = &amortisations[0];
+    assert_eq!(
+        photovoltaic.measure.savings,
+        Out::PhotovoltaicExpansionSavings
+    );
+    assert_eq!(photovoltaic.annual_savings, Tons::new(20.0));
+    assert_eq!(
+        photovoltaic.amortisation_time.map(|years| years.round(3)),
+        Some(Years::new(5.0))
+    );
+
+    let wind = &amortisations[1];
+    assert_eq!(wind.measure.savings, Out::WindExpansionSavings);
+    assert_eq!(wind.embodied_emissions, Tons::new(50.0));
+    assert_eq!(wind.amortisation_time, None);
+}
+
 #[test]
 fn calculate_oil_gas_savings_test() {
     assert_eq!(
@@ -934,3 +1720,45 @@ fn extrapolate_according_to_tu_wien_2016_large() {
         Factor::new(46.99662)
     );
 }
+
+#[test]
+fn apply_custom_recommendations() {
+    let diesel = Id::Custom("diesel".to_string());
+    let heating = Id::Custom("heating".to_string());
+    let mut values = example_values();
+    values.insert(diesel.clone(), V::tons(10.0));
+    values.insert(heating.clone(), V::tons(4.0));
+    let edges = [
+        (diesel.clone(), Out::OtherIndirectEmissions.into()),
+        (heating.clone(), Out::OtherIndirectEmissions.into()),
+    ];
+    let leafs = vec![diesel.clone(), heating.clone()];
+
+    let recommendations = [
+        (diesel, CustomRecommendation::Reduction(Percent::new(30.0))),
+        (heating, CustomRecommendation::Absolute(Tons::new(1.0))),
+    ];
+    let outcome = calculate_recommendation(
+        values.clone(),
+        Some(&edges),
+        leafs.clone(),
+        &recommendations,
+    );
+    let output = outcome.output.unwrap();
+    assert_eq!(
+        out!(Out::AdditionalCustomEmissions, output).unwrap(),
+        Tons::new(8.0)
+    );
+    assert_eq!(
+        out!(Out::AdditionalCustomEmissionsSavings, output).unwrap(),
+        Tons::new(6.0)
+    );
+
+    let outcome = calculate_recommendation(values, Some(&edges), leafs, &[]);
+    let output = outcome.output.unwrap();
+    assert_eq!(
+        out!(Out::AdditionalCustomEmissions, output).unwrap(),
+        Tons::new(14.0)
+    );
+    assert!(!output.contains_key(&Out::AdditionalCustomEmissionsSavings.into()));
+}
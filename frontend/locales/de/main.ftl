@@ -10,6 +10,7 @@ email-address                 = E-Mail Adresse
 password                      = Passwort
 communication-error           = Ein Kommunikationsfehler ist aufgetreten
 email-or-password-invalid     = Email-Addresse oder Passwort ungültig
+too-many-login-attempts       = Zu viele Anmeldeversuche, bitte versuchen Sie es später erneut
 email-not-confirmed           = Sie haben Ihre Email-Addresse noch nicht bestätigt
 something-went-wrong          = Tut uns leid, irgend etwas ist schief gelaufen
 
@@ -83,6 +84,8 @@ sensitivity-ch4-chp-aria = Ein Balkendiagramm welches verschiedene Szenarien zur
 sensitivity-ch4-chp-infobox-1-text = BHKW weisen je nach Modell und Alter unterschiedliche Methanschlupfe auf
 sensitivity-ch4-chp-infobox-1-cite-source = Auszug aus dem DWA-Merkblatt 230-1 (2022, S. 25)
 sensitivity-ch4-chp-infobox-1-cite = Auch bei der Gasverwertung entstehen prozessbedingte Methan-Emissionen: BHKW-Motoren arbeiten nach dem Vier-Takt-Prinzip. Dabei sind zum Gasaustausch für eine kurze Zeit beim Übergang vom vierten (Ausstoßen) in den ersten (Ansaugen) Takt sowohl das Einlass- als auch das Auslassventil (teilweise) geöffnet. Durch diese Überschneidung können unter Umständen geringe Mengen unverbrannten Faulgases in den Abgasstrom gelangen. Ottomotoren haben dabei einen Methanschlupf im Bereich von 1 % bis 2 % Zündstrahlmotoren (sind für Faulgas nicht relevant) liegen höher in der Größenordnung von 2 % bis 3 %. Mikrogasturbinen (typische Leistungsklasse von 30 kW bis 65 kW) können dagegen einen Methanschlupf < 1 % erreichen (STMWI 2016).
+chp-unit-add = BHKW-Aggregat hinzufügen
+chp-unit-remove = Aggregat entfernen
 
 chp_custom_factor-warning-title = Achtung
 chp_custom_factor-warning-text = Der benutzerdefinierte CH₄-Wert wird derzeit nicht zur Berechnung verwendet!
@@ -135,10 +138,21 @@ n2o_emissions-p-7 = Durch die Eingabe der jährlich behandelten Stickstoffmenge
               Sie den resultierenden Anteil an den Treibhausgasemissionen [CO₂-Äq./a] abschätzen.
 n2o_emissions-p-8 = Den hierfür verwendeten N₂O-EF können Sie über das Eingabefeld „N₂O-EF Prozesswasser“ unten frei
               wählen oder leer lassen, um mit einem mittleren EF von 2% (nach Vasilaki et al. 2019) zu rechnen.
+n2o_measurement-h4 = Standortspezifischer N₂O-EF aus Online-Messung
+n2o_measurement-p-1 = Liegt eine Online-Messung der Abluft vor, können Sie die Messwerte als CSV-Datei mit den Spalten
+              Zeitstempel (JJJJ-MM-TT hh:mm), N₂O-Konzentration [ppm] und Abluftvolumenstrom [m³/h] importieren.
+              Daraus wird ein standortspezifischer N₂O-EF berechnet, der als Szenario „Messung“ ausgewählt wird.
+n2o_measurement-import-error = Die Messdaten konnten nicht importiert werden
 ########################################### recommendation ###########################################
 
 recommendation-barchart-title = Änderungen durch Optionen der Handlungsmaßnahmen
 recommendation-barchart-description  = Die folgende Grafik zeigt die Änderungen der Treibhausgasemissionen [t CO₂ Äquivalente/Jahr] bzw. % der Gesamtemissionen durch die ausgewählten Handlungsmaßnahmen.
+recommendation-emission-shares-title = Anteile der Emissionsgruppen
+recommendation-emission-shares-description = Die folgende Grafik zeigt die Anteile der direkten, indirekten und weiteren indirekten Emissionen sowie ihrer Quellen an den Gesamtemissionen [t CO₂ Äquivalente/Jahr].
+recommendation-emission-groups-title = Vergleich der Emissionsgruppen
+recommendation-emission-groups-description = Die folgende Grafik vergleicht die Treibhausgasemissionen [t CO₂ Äquivalente/Jahr] der Emissionsgruppen im Anlagenprofil, in der Sensitivität und mit den ausgewählten Handlungsmaßnahmen.
+aria_label_donut_chart = Ein Ringdiagramm mit den Anteilen der Emissionsgruppen an den Gesamtemissionen.
+aria_label_stacked_bar_chart = Ein gestapeltes Balkendiagramm mit den Emissionsgruppen im Anlagenprofil, in der Sensitivität und mit den Handlungsmaßnahmen.
 
 # ch4_emissions_open_digesters.rs
 recommendation-methan-emissions  = Methanemissionen aus offenen Faultürmen und bei der Schlammlagerung
@@ -146,6 +160,10 @@ recommendation-ch4-open-digesters-p-1 = Das Schließen von Schlammtaschen an Fau
 recommendation-ch4-open-closing-sludge-bags = Schließen der Schlammtaschen
 recommendation-ch4-open-closing-sludge-storage = Schließen der Schlammlagerung
 
+# ch4_emissions_flare.rs
+recommendation-ch4-flare = Methanemissionen aus der Klärgasfackel
+recommendation-ch4-flare_p_1 = In offenen Fackeln wird nur ein Teil des Methans verbrannt. Durch den Ersatz der offenen Fackel durch eine geschlossene Fackel mit einem Wirkungsgrad von mindestens 90 % lassen sich die Methanemissionen deutlich verringern. Dies können Sie über die nachfolgende Checkbox bilanzieren.
+
 # excess_energy_co2_equivalent.rs
 recommenation-excess-energy      = Energiebedingte Emissionen
 recommenation-excess-energy-p-1 = <b>Energiesparmaßnahmen</b> und <b>Erneuerbare Energien</b> können maßgeblich zur Minderung indirekter Emissionen und zur Energieautarkie beitragen. Um die positiven Auswirkungen eines Zubaus der erneuerbaren Energien: Photovoltaik (PV), Wind-, Wasserkraft und/oder Abwärmenutzung aufzuzeigen, können nachfolgend verschiedene Szenarien bilanziert werden. Wenn Sie die jeweilige Technologie nicht bilanzieren wollen können Sie das jeweilige Feld freilassen.
@@ -197,7 +215,35 @@ project-reset-values = Werte zurücksetzen
 project-load-example-values = Beispielwerte laden
 project-load-from-file = Datei laden
 project-save-to-file = Datei speichern
+project-save-to-local = Lokal speichern (offline)
 project-export-csv = CSV-Datei exportieren
 project-load-from-online = Projekt aus Online-Speicher laden
 project-save-to-online = Projekt online speichern
 back-to-table = zurück zur Übersicht
+
+########################################### account ###########################################
+
+account-login-required-1      = Sie müssen sich
+account-login-required-link   = anmelden
+account-login-required-2      = um Ihr Konto verwalten zu können.
+data-export                   = Datenexport
+data-export-description       = Laden Sie alle zu Ihrem Konto gespeicherten Daten als ZIP-Archiv herunter. Die Projekte sind im JSON-Format der Version 9 enthalten, das auch im Tool importiert werden kann.
+data-export-download          = Daten herunterladen
+change-password               = Passwort ändern
+current-password              = Aktuelles Passwort
+new-password                  = Neues Passwort
+password-changed              = Ihr Passwort wurde erfolgreich geändert.
+change-email-address          = E-Mail-Adresse ändern
+new-email-address             = Neue E-Mail-Adresse
+change-email-address-hint     = Die Änderung wird erst wirksam, nachdem Sie die neue Adresse bestätigt haben.
+email-change-requested        = Wir haben Ihnen eine E-Mail an die neue Adresse gesendet. Bitte bestätigen Sie die Änderung über den enthaltenen Link.
+please-wait                   = Einen Moment bitte...
+new-email-address-confirmed   = Ihre neue E-Mail-Adresse ist nun bestätigt.
+confirmation-failed           = Es tut uns leid, aber das hat nicht geklappt.
+delete-account                = Konto löschen
+delete-account-description    = Ihr Konto und alle Ihre Projekte werden unwiderruflich gelöscht. Bitte bestätigen Sie den Vorgang mit Ihrem Passwort.
+delete-account-confirm        = Konto endgültig löschen
+
+# electricity_contracts.rs
+electricity-contract-add = Stromvertrag hinzufügen
+electricity-contract-remove = Vertrag entfernen
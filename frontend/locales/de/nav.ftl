@@ -3,6 +3,7 @@ projects = Projekte
 faqs = FAQs
 sign-up = Registrieren
 logout = Logout
+account = Konto
 wiki = Wiki
 open-source = Open Source
 imprint = Impressum
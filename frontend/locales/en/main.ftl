@@ -10,6 +10,7 @@ email-address                 = e-mail address
 password                      = Password
 communication-error           = A communication error has occurred
 email-or-password-invalid     = Email address or password invalid
+too-many-login-attempts       = Too many login attempts, please try again later
 email-not-confirmed           = You have not yet confirmed your email address
 something-went-wrong          = Sorry, something went wrong
 
@@ -77,6 +78,8 @@ sensitivity-ch4-chp-aria = A bar chart that graphically shows different scenario
 sensitivity-ch4-chp-infobox-1-text = CHP plants have different methane slips depending on the model and age
 sensitivity-ch4-chp-infobox-1-cite-source = Extract from DWA leaflet 230-1 (2022, p. 25)
 sensitivity-ch4-chp-infobox-1-cite = Process-related methane emissions also arise during gas utilization: CHP engines operate according to the four-stroke principle. In this case, both the inlet and outlet valves are (partially) opened for a short time during the transition from the fourth (exhaust) to the first (intake) stroke to allow gas exchange. This overlap can potentially cause small amounts of unburned digester gas to enter the exhaust gas stream. Gasoline engines have a methane slip in the range of 1% to 2%. Pilot jet engines (not relevant for digester gas) are higher, in the order of 2% to 3%. Micro gas turbines (typical power class from 30 kW to 65 kW), on the other hand, can achieve a methane slip of < 1% (STMWI 2016).
+chp-unit-add = Add CHP unit
+chp-unit-remove = Remove unit
 
 chp_custom_factor-warning-title = Warning
 chp_custom_factor-warning-text = The custom CH₄ value you specified is currently not used as scenario!
@@ -123,11 +126,20 @@ n2o_emissions-p-5 = In addition, you can enter and balance a user-defined value
 n2o_emissions-p-6 = Process water treatment in wastewater treatment plants can be associated with significant additional nitrous oxide emissions. Vasilaki et al. (2019) indicate in their meta-study a nitrous oxide EF of 1.7-5.1% of the total nitrogen in process water.
 n2o_emissions-p-7 = By entering the annual amount of nitrogen treated in the process water [t/a], you can estimate the resulting share of greenhouse gas emissions [CO₂ eq./a].
 n2o_emissions-p-8 = You can freely select the N₂O-EF used for this purpose using the input field “N₂O-EF process water” below or leave it blank to calculate with an average EF of 2% (according to Vasilaki et al. 2019).
+n2o_measurement-h4 = Site-specific N₂O EF from online measurement
+n2o_measurement-p-1 = If an online measurement of the off-gas is available, you can import the samples as a CSV file with the columns timestamp (YYYY-MM-DD hh:mm), N₂O concentration [ppm] and off-gas flow [m³/h]. A site-specific N₂O EF is calculated from it and selected as scenario “Measured”.
+n2o_measurement-import-error = The measurement data could not be imported
 
 ########################################### recommendation ###########################################
 
 recommendation-barchart-title    = Changes due to options in recommendations
 recommendation-barchart-description = The following graph shows the changes in greenhouse gas emissions [t CO₂ equivalents/year] or % of total emissions due to the selected action measures.
+recommendation-emission-shares-title = Shares of the emission groups
+recommendation-emission-shares-description = The following graph shows the shares of direct, indirect and other indirect emissions and their sources in the total emissions [t CO₂ equivalents/year].
+recommendation-emission-groups-title = Comparison of the emission groups
+recommendation-emission-groups-description = The following graph compares the greenhouse gas emissions [t CO₂ equivalents/year] of the emission groups in the plant profile, in the sensitivity and with the selected action measures.
+aria_label_donut_chart = A donut chart with the shares of the emission groups in the total emissions.
+aria_label_stacked_bar_chart = A stacked bar chart with the emission groups in the plant profile, in the sensitivity and with the action measures.
 
 # ch4_emissions_open_digesters.rs
 recommendation-methan-emissions  = Methane emissions from open digesters and during sludge storage
@@ -135,6 +147,10 @@ recommendation-ch4-open-digesters-p-1 = Closing sludge pockets in digestion towe
 recommendation-ch4-open-closing-sludge-bags = Close sludge bags
 recommendation-ch4-open-closing-sludge-storage = Close sludge storage
 
+# ch4_emissions_flare.rs
+recommendation-ch4-flare = Methane emissions from the sewage gas flare
+recommendation-ch4-flare_p_1 = Open flares destroy only part of the methane. Replacing the open flare with an enclosed flare with an efficiency of at least 90 % significantly reduces the methane emissions. You can do this using the checkbox below.
+
 # excess_energy_co2_equivalent.rs
 recommenation-excess-energy      = Excess Energy Emissions
 recommenation-excess-energy-p-1 = <b>Energy saving measures</b> and <b>Renewable energies</b> can make a significant contribution to reducing indirect emissions and achieving energy self-sufficiency. In order to demonstrate the positive effects of increasing renewable energies: photovoltaics (PV), wind, hydropower and/or waste heat utilization, various scenarios can be assessed below. If you do not want to assess the respective technology, you can leave the respective field blank.
@@ -186,7 +202,35 @@ project-reset-values = Reset values
 project-load-example-values = Load example
 project-load-from-file = File import
 project-save-to-file = File export
+project-save-to-local = Save locally (offline)
 project-export-csv = Export to CSV-file
 project-load-from-online = Load from cloud
 project-save-to-online = Save to cloud
-back-to-table = back to overview
\ No newline at end of file
+back-to-table = back to overview
+
+########################################### account ###########################################
+
+account-login-required-1      = You have to
+account-login-required-link   = log in
+account-login-required-2      = to manage your account.
+data-export                   = Data export
+data-export-description       = Download all data stored for your account as a ZIP archive. The projects are included in the JSON format of version 9, which can also be imported into the tool.
+data-export-download          = Download data
+change-password               = Change password
+current-password              = Current password
+new-password                  = New password
+password-changed              = Your password has been changed successfully.
+change-email-address          = Change e-mail address
+new-email-address             = New e-mail address
+change-email-address-hint     = The change only takes effect after you have confirmed the new address.
+email-change-requested        = We have sent an e-mail to the new address. Please confirm the change via the link it contains.
+please-wait                   = One moment please...
+new-email-address-confirmed   = Your new e-mail address is now confirmed.
+confirmation-failed           = Sorry, that did not work.
+delete-account                = Delete account
+delete-account-description    = Your account and all your projects will be deleted irrevocably. Please confirm with your password.
+delete-account-confirm        = Delete account permanently
+
+# electricity_contracts.rs
+electricity-contract-add = Add electricity contract
+electricity-contract-remove = Remove contract
@@ -3,6 +3,7 @@ projects = Projects
 faqs = FAQs
 sign-up = Sign-up
 logout = Logout
+account = Account
 wiki = Wiki
 open-source = Open Source
 imprint = Imprint
@@ -22,6 +22,7 @@ mod credentials;
 mod footer;
 mod forms;
 mod i18n;
+mod local_projects;
 mod nav;
 mod pages;
 mod sankey;
@@ -30,8 +31,8 @@ use self::{
     footer::Footer,
     nav::Nav,
     pages::{
-        ConfirmEmailAddress, Login, Page, PageSection, Projects, Register, ResetPassword,
-        ResetPasswordRequest, Tool,
+        Account, ConfirmEmailAddress, ConfirmEmailAddressChange, Login, Page, PageSection,
+        Projects, Register, ResetPassword, ResetPasswordRequest, Tool,
     },
 };
 
@@ -101,6 +102,8 @@ pub fn App() -> impl IntoView {
     let user_info = RwSignal::new(None::<UserInfo>);
     let logged_in = Signal::derive(move || user_info.get().is_some());
     let current_project = RwSignal::new(None::<boundary::Project>);
+    let local_projects = RwSignal::new(local_projects::load_local_projects());
+    let current_local_project = RwSignal::new(local_projects::load_current_local_project());
 
     let lng = current_lang();
 
@@ -139,8 +142,36 @@ pub fn App() -> impl IntoView {
         }
     });
 
+    let sync_projects = Action::new(move |api: &api::AuthorizedApi| {
+        let api = api.clone();
+        let snapshot = local_projects.get_untracked();
+        async move {
+            let synced = local_projects::sync(&api, snapshot.clone()).await;
+            let merged = local_projects
+                .with_untracked(|current| local_projects::merge(&snapshot, synced, current));
+            // Reload the opened project if it has been changed by the synchronization.
+            if let Some(id) = current_local_project.get_untracked() {
+                let before = snapshot.iter().find(|p| p.id == id);
+                let after = merged.iter().find(|p| p.id == id);
+                if let (Some(before), Some(after)) = (before, after) {
+                    if before.form_data != after.form_data || before.remote != after.remote {
+                        current_project.set(Some(after.to_project()));
+                    }
+                }
+            }
+            local_projects.set(merged);
+        }
+    });
+    let syncing = sync_projects.pending();
+
     // -- callbacks -- //
 
+    let on_sync = move |()| {
+        if let Some(api) = authorized_api.get_untracked() {
+            sync_projects.dispatch(api);
+        }
+    };
+
     let on_logout = move |()| {
         logout.dispatch(());
     };
@@ -168,6 +199,26 @@ pub fn App() -> impl IntoView {
         }
     });
 
+    Effect::new(move |_| {
+        if let Some(api) = authorized_api.get() {
+            log::debug!("API is now authorized: synchronize local projects");
+            sync_projects.dispatch(api);
+        }
+    });
+
+    Effect::new(move |_| {
+        local_projects.with(|projects| local_projects::save_local_projects(projects));
+    });
+
+    Effect::new(move |_| {
+        local_projects::save_current_local_project(current_local_project.get());
+    });
+
+    window_event_listener(ev::online, move |_| {
+        log::debug!("Connection restored: synchronize local projects");
+        on_sync(());
+    });
+
     view! {
       <div _ref=app_ref>
         <Nav
@@ -227,6 +278,8 @@ pub fn App() -> impl IntoView {
                     <Tool
                       api = authorized_api.into()
                       current_project
+                      local_projects
+                      current_local_project
                       current_section
                       accessibility_always_show_option
                     />
@@ -314,6 +367,21 @@ pub fn App() -> impl IntoView {
                     view! { <ConfirmEmailAddress api=unauthorized_api /> }
                 }
             />
+            <Route
+                path=Page::ConfirmEmailAddressChange.path()
+                view= move || {
+                    view! {
+                        <ConfirmEmailAddressChange
+                            api=unauthorized_api
+                            on_success=move |()| {
+                                if let Some(api) = authorized_api.get_untracked() {
+                                    fetch_user_info.dispatch(api);
+                                }
+                            }
+                        />
+                    }
+                }
+            />
             <Route
                 path=Page::Projects.path()
                 view = move || {
@@ -326,6 +394,33 @@ pub fn App() -> impl IntoView {
                       <Projects
                         api = authorized_api.into()
                         current_project
+                        local_projects
+                        current_local_project
+                        syncing = syncing.into()
+                        on_sync
+                      />
+                    </Main>
+                  }
+                }
+            />
+            <Route
+                path=Page::Account.path()
+                view = move || {
+                  set_current_page.update(|p|*p = Page::Account);
+                  view! {
+                    <Main>
+                      <header class="prose">
+                        <h1 class="mb-8">"Konto"</h1>
+                      </header>
+                      <Account
+                        api = authorized_api.into()
+                        on_delete_success = move |()| {
+                            log::info!("Account successfully deleted");
+                            authorized_api.set(None);
+                            user_info.set(None);
+                            let navigate = use_navigate();
+                            navigate(Page::Home.path(), NavigateOptions::default());
+                        }
                       />
                     </Main>
                   }
@@ -0,0 +1,262 @@
+//! Projects that are stored in the browser
+//! so that they can be edited without a connection to the server.
+
+use gloo_storage::{LocalStorage, SessionStorage, Storage};
+use serde::{Deserialize, Serialize};
+
+use klick_app_api::AuthorizedApi;
+use klick_boundary::{FormData, JsonFormData, Project, ProjectId, SavedProject, UnsavedProject};
+use klick_domain as domain;
+
+const LOCAL_PROJECTS_STORAGE_KEY: &str = "klick-local-projects";
+const CURRENT_LOCAL_PROJECT_STORAGE_KEY: &str = "klick-current-local-project";
+
+/// A project of the local library.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LocalProject {
+    /// The local ID that is independent of the server.
+    pub id: ProjectId,
+    pub form_data: JsonFormData,
+    /// The version on the server the local version is based on.
+    pub remote: Option<SavedProject>,
+    /// A newer version on the server that conflicts with local changes.
+    pub conflict: Option<SavedProject>,
+}
+
+impl LocalProject {
+    #[must_use]
+    pub fn new(form_data: JsonFormData) -> Self {
+        Self {
+            id: domain::ProjectId::new().into(),
+            form_data,
+            remote: None,
+            conflict: None,
+        }
+    }
+
+    #[must_use]
+    pub fn from_remote(project: SavedProject) -> Self {
+        Self {
+            form_data: project.form_data.clone(),
+            remote: Some(project),
+            ..Self::new(JsonFormData::default())
+        }
+    }
+
+    /// The project has been changed since the last synchronization.
+    #[must_use]
+    pub fn is_changed(&self) -> bool {
+        self.remote
+            .as_ref()
+            .map_or(true, |remote| remote.form_data != self.form_data)
+    }
+
+    #[must_use]
+    pub fn remote_id(&self) -> Option<ProjectId> {
+        self.remote.as_ref().map(|remote| remote.id)
+    }
+
+    /// Mark the project as synchronized with the server version.
+    pub fn synced(&mut self, project: SavedProject) {
+        self.form_data = project.form_data.clone();
+        self.remote = Some(project);
+        self.conflict = None;
+    }
+
+    /// Resolve a conflict by keeping the local changes.
+    ///
+    /// They are written to the server with the next synchronization.
+    pub fn keep_local_version(&mut self) {
+        if let Some(conflict) = self.conflict.take() {
+            self.remote = Some(conflict);
+        }
+    }
+
+    /// Resolve a conflict by discarding the local changes.
+    pub fn take_remote_version(&mut self) {
+        if let Some(conflict) = self.conflict.take() {
+            self.synced(conflict);
+        }
+    }
+
+    #[must_use]
+    pub fn to_project(&self) -> Project {
+        match &self.remote {
+            Some(remote) => SavedProject {
+                form_data: self.form_data.clone(),
+                ..remote.clone()
+            }
+            .into(),
+            None => UnsavedProject {
+                form_data: self.form_data.clone(),
+            }
+            .into(),
+        }
+    }
+}
+
+#[must_use]
+pub fn load_local_projects() -> Vec<LocalProject> {
+    LocalStorage::get(LOCAL_PROJECTS_STORAGE_KEY).unwrap_or_default()
+}
+
+pub fn save_local_projects(projects: &[LocalProject]) {
+    if let Err(err) = LocalStorage::set(LOCAL_PROJECTS_STORAGE_KEY, projects) {
+        log::warn!("Unable to save local projects: {err}");
+    }
+}
+
+#[must_use]
+pub fn load_current_local_project() -> Option<ProjectId> {
+    SessionStorage::get(CURRENT_LOCAL_PROJECT_STORAGE_KEY).ok()
+}
+
+pub fn save_current_local_project(id: Option<ProjectId>) {
+    let Some(id) = id else {
+        SessionStorage::delete(CURRENT_LOCAL_PROJECT_STORAGE_KEY);
+        return;
+    };
+    if let Err(err) = SessionStorage::set(CURRENT_LOCAL_PROJECT_STORAGE_KEY, id) {
+        log::warn!("Unable to save current local project: {err}");
+    }
+}
+
+/// Synchronize the local projects with the projects on the server.
+///
+/// Local changes are only written if the server version
+/// has not been modified since the last synchronization
+/// (compared by `modified_at`), otherwise a conflict is recorded.
+/// Unchanged projects are updated to the server version
+/// and projects that only exist on the server are added.
+pub async fn sync(api: &AuthorizedApi, projects: Vec<LocalProject>) -> Vec<LocalProject> {
+    let remote_projects = match api.all_projects().await {
+        Ok(projects) => projects,
+        Err(err) => {
+            log::warn!("Unable to synchronize projects: {err}");
+            return projects;
+        }
+    };
+    let mut synced = Vec::with_capacity(projects.len());
+    for mut project in projects {
+        let remote = project.remote_id().and_then(|id| {
+            remote_projects
+                .iter()
+                .find(|remote| remote.id == id)
+                .cloned()
+        });
+        match (project.remote.clone(), remote) {
+            (None, _) => {
+                create(api, &mut project).await;
+            }
+            (Some(_), None) => {
+                if !project.is_changed() {
+                    log::debug!("Remove project {:?} deleted on the server", project.id);
+                    continue;
+                }
+                project.remote = None;
+                create(api, &mut project).await;
+            }
+            (Some(base), Some(remote)) => {
+                if remote.modified_at == base.modified_at {
+                    if project.is_changed() {
+                        update(api, &mut project).await;
+                    }
+                } else if project.is_changed() {
+                    project.conflict = Some(remote);
+                } else {
+                    project.synced(remote);
+                }
+            }
+        }
+        synced.push(project);
+    }
+    for remote in remote_projects {
+        if !synced.iter().any(|p| p.remote_id() == Some(remote.id)) {
+            synced.push(LocalProject::from_remote(remote));
+        }
+    }
+    synced
+}
+
+async fn create(api: &AuthorizedApi, project: &mut LocalProject) {
+    let form_data = FormData::try_from(project.form_data.clone()).unwrap();
+    let result = match api.create_project(&form_data).await {
+        Ok(id) => api.read_project(&id).await,
+        Err(err) => Err(err),
+    };
+    match result {
+        Ok(created) => {
+            project.synced(created);
+        }
+        Err(err) => {
+            log::warn!("Unable to create project: {err}");
+        }
+    }
+}
+
+async fn update(api: &AuthorizedApi, project: &mut LocalProject) {
+    let Project::Saved(saved) = project.to_project() else {
+        return;
+    };
+    match api.update_project(&saved).await {
+        Ok(updated) => {
+            project.synced(updated);
+        }
+        Err(err) if err.is_conflict() => match api.read_project(&saved.id.into()).await {
+            Ok(remote) => {
+                project.conflict = Some(remote);
+            }
+            Err(err) => {
+                log::warn!("Unable to read conflicting project: {err}");
+            }
+        },
+        Err(err) => {
+            log::warn!("Unable to update project: {err}");
+        }
+    }
+}
+
+/// Merge the result of a synchronization of `snapshot`
+/// into the `current` projects.
+///
+/// Projects that have been edited, added or deleted
+/// in the meantime keep their local state.
+#[must_use]
+pub fn merge(
+    snapshot: &[LocalProject],
+    synced: Vec<LocalProject>,
+    current: &[LocalProject],
+) -> Vec<LocalProject> {
+    let mut merged = synced
+        .into_iter()
+        .filter_map(|mut project| {
+            let Some(before) = snapshot.iter().find(|p| p.id == project.id) else {
+                // added from the server
+                return Some(project);
+            };
+            let now = current.iter().find(|p| p.id == project.id)?;
+            if now.form_data != before.form_data {
+                project.form_data = now.form_data.clone();
+            }
+            Some(project)
+        })
+        .collect::<Vec<_>>();
+    for project in current {
+        if merged.iter().any(|p| p.id == project.id) {
+            continue;
+        }
+        match snapshot.iter().find(|p| p.id == project.id) {
+            // added in the meantime
+            None => merged.push(project.clone()),
+            // removed by the synchronization but edited in the meantime
+            Some(before) if before.form_data != project.form_data => {
+                merged.push(LocalProject {
+                    remote: None,
+                    ..project.clone()
+                });
+            }
+            Some(_) => {}
+        }
+    }
+    merged
+}
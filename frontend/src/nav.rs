@@ -162,6 +162,13 @@ fn UserMenu(user_info: UserInfo, #[prop(into)] on_logout: Callback<()>) -> impl
              aria-orientation="vertical"
              aria-labelledby="user-menu-button"
            >
+             <A
+               href=Page::Account.path()
+               class="block px-4 py-2 text-sm text-gray-700"
+               on:click = move |_| user_menu_is_active.set(false)
+             >
+              { move_tr!("account") }
+             </A>
              <a
                href="#"
                class="block px-4 py-2 text-sm text-gray-700"
@@ -210,6 +217,12 @@ fn MobileUserMenu(user_info: UserInfo, #[prop(into)] on_logout: Callback<()>) ->
           >
            { move_tr!("projects") }
           </A>
+          <A
+            href=Page::Account.path()
+            class="block px-4 py-2 text-base font-medium text-gray-500 hover:bg-gray-100 hover:text-gray-800"
+          >
+           { move_tr!("account") }
+          </A>
         </div>
       </div>
     }
@@ -0,0 +1,375 @@
+use gloo_file::{Blob, ObjectUrl};
+use leptos::*;
+use leptos_fluent::*;
+
+use klick_app_components::message::SuccessMessage;
+
+use crate::{
+    api::{self, AuthorizedApi},
+    credentials::{
+        DEFAULT_BUTTON_CLASS, DEFAULT_INPUT_CLASS, DISABLED_BUTTON_CLASS, DISABLED_INPUT_CLASS,
+    },
+    Page,
+};
+
+const EXPORT_FILE_NAME: &str = "klick-data-export.zip";
+
+#[component]
+pub fn Account(
+    api: Signal<Option<AuthorizedApi>>,
+    #[prop(into)] on_delete_success: Callback<()>,
+) -> impl IntoView {
+    move || match api.get() {
+        None => view! { <NotAuthorized /> }.into_view(),
+        Some(api) => view! { <Authorized api on_delete_success /> }.into_view(),
+    }
+}
+
+#[component]
+fn NotAuthorized() -> impl IntoView {
+    view! {
+      <p>
+        { move_tr!("account-login-required-1") } " "
+        <a class="underline" href=Page::Login.path() >{ move_tr!("account-login-required-link") }</a>
+        " " { move_tr!("account-login-required-2") }
+      </p>
+    }
+}
+
+#[component]
+fn Authorized(api: AuthorizedApi, on_delete_success: Callback<()>) -> impl IntoView {
+    let api = RwSignal::new(api);
+    view! {
+      <div class="space-y-10">
+        <DataExport api />
+        <ChangePassword api />
+        <ChangeEmailAddress api />
+        <DeleteAccount api on_delete_success />
+      </div>
+    }
+}
+
+#[component]
+fn DataExport(api: RwSignal<AuthorizedApi>) -> impl IntoView {
+    let download_link: NodeRef<html::A> = create_node_ref();
+    let error = RwSignal::new(None::<String>);
+    let pending = RwSignal::new(false);
+
+    let export_data = Action::new(move |(): &()| {
+        let api = api.get();
+        async move {
+            pending.set(true);
+            match api.export_account_data().await {
+                Ok(bytes) => {
+                    error.set(None);
+                    let blob = Blob::new_with_options(&*bytes, Some("application/zip"));
+                    let object_url = ObjectUrl::from(blob);
+                    let link = download_link.get().expect("<a> to exist");
+                    link.set_attribute("href", &object_url).unwrap();
+                    link.set_attribute("download", EXPORT_FILE_NAME).unwrap();
+                    link.click();
+                    link.remove_attribute("href").unwrap();
+                }
+                Err(err) => {
+                    log::warn!("Unable to export account data: {err}");
+                    error.set(Some(tr!("communication-error")));
+                }
+            }
+            pending.set(false);
+        }
+    });
+
+    view! {
+      <section>
+        <h2 class="text-lg font-semibold text-gray-900">{ move_tr!("data-export") }</h2>
+        <p class="my-4 text-gray-600">
+          { move_tr!("data-export-description") }
+        </p>
+        { move || error.get().map(|err| view! {
+          <p class="mb-4 text-red-700">{ err }</p>
+        })}
+        <div class="max-w-xs">
+          <button
+            prop:disabled=move || pending.get()
+            on:click=move |_| export_data.dispatch(())
+            class=move || if pending.get() { DISABLED_BUTTON_CLASS } else { DEFAULT_BUTTON_CLASS }
+          >
+            { move_tr!("data-export-download") }
+          </button>
+        </div>
+        <a class="hidden" node_ref=download_link></a>
+      </section>
+    }
+}
+
+#[component]
+fn ChangePassword(api: RwSignal<AuthorizedApi>) -> impl IntoView {
+    let error = RwSignal::new(None::<String>);
+    let pending = RwSignal::new(false);
+    let changed = RwSignal::new(false);
+
+    let change_password = Action::new(move |(old_password, new_password): &(String, String)| {
+        let api = api.get();
+        let old_password = old_password.clone();
+        let new_password = new_password.clone();
+        async move {
+            pending.set(true);
+            match api.change_password(old_password, new_password).await {
+                Ok(()) => {
+                    error.set(None);
+                    changed.set(true);
+                }
+                Err(err) => {
+                    let msg = error_message(err);
+                    log::warn!("Unable to change password: {msg}");
+                    error.set(Some(msg));
+                }
+            }
+            pending.set(false);
+        }
+    });
+
+    view! {
+      <section>
+        <h2 class="text-lg font-semibold text-gray-900">{ move_tr!("change-password") }</h2>
+        <Show
+          when = move || !changed.get()
+          fallback = || view! { <SuccessMessage message = move_tr!("password-changed") /> }
+        >
+          <ChangePasswordForm
+            action = change_password
+            error = error.into()
+            disabled = pending.into()
+          />
+        </Show>
+      </section>
+    }
+}
+
+#[component]
+fn ChangeEmailAddress(api: RwSignal<AuthorizedApi>) -> impl IntoView {
+    let error = RwSignal::new(None::<String>);
+    let pending = RwSignal::new(false);
+    let requested = RwSignal::new(false);
+
+    let change_email_address = Action::new(move |(new_email, password): &(String, String)| {
+        let api = api.get();
+        let new_email = new_email.clone();
+        let password = password.clone();
+        async move {
+            pending.set(true);
+            match api.change_email_address(new_email, password).await {
+                Ok(()) => {
+                    error.set(None);
+                    requested.set(true);
+                }
+                Err(err) => {
+                    let msg = error_message(err);
+                    log::warn!("Unable to change email address: {msg}");
+                    error.set(Some(msg));
+                }
+            }
+            pending.set(false);
+        }
+    });
+
+    view! {
+      <section>
+        <h2 class="text-lg font-semibold text-gray-900">{ move_tr!("change-email-address") }</h2>
+        <Show
+          when = move || !requested.get()
+          fallback = || view! {
+            <SuccessMessage message = move_tr!("email-change-requested") />
+          }
+        >
+          <ChangeEmailAddressForm
+            action = change_email_address
+            error = error.into()
+            disabled = pending.into()
+          />
+        </Show>
+      </section>
+    }
+}
+
+#[component]
+fn DeleteAccount(api: RwSignal<AuthorizedApi>, on_delete_success: Callback<()>) -> impl IntoView {
+    let error = RwSignal::new(None::<String>);
+    let pending = RwSignal::new(false);
+
+    let delete_account = Action::new(move |password: &String| {
+        let api = api.get();
+        let password = password.clone();
+        async move {
+            pending.set(true);
+            match api.delete_account(password).await {
+                Ok(()) => {
+                    error.set(None);
+                    on_delete_success.call(());
+                }
+                Err(err) => {
+                    let msg = error_message(err);
+                    log::warn!("Unable to delete account: {msg}");
+                    error.set(Some(msg));
+                }
+            }
+            pending.set(false);
+        }
+    });
+
+    view! {
+      <section>
+        <h2 class="text-lg font-semibold text-gray-900">{ move_tr!("delete-account") }</h2>
+        <DeleteAccountForm
+          action = delete_account
+          error = error.into()
+          disabled = pending.into()
+        />
+      </section>
+    }
+}
+
+fn error_message<T>(err: api::Error<T>) -> String {
+    match err {
+        api::Error::Fetch(js_err) => {
+            log::error!("{js_err:?}");
+            tr!("communication-error")
+        }
+        api::Error::Api(err) => err
+            .message
+            .unwrap_or_else(|| tr!("something-went-wrong")),
+    }
+}
+
+#[component]
+fn ChangePasswordForm(
+    action: Action<(String, String), ()>,
+    error: Signal<Option<String>>,
+    disabled: Signal<bool>,
+) -> impl IntoView {
+    let old_password = RwSignal::new(String::new());
+    let new_password = RwSignal::new(String::new());
+    let button_is_disabled = Signal::derive(move || {
+        disabled.get() || old_password.get().is_empty() || new_password.get().is_empty()
+    });
+
+    view! {
+        <form class="max-w-xs" on:submit=|ev| ev.prevent_default()>
+            { move || error.get().map(|err| view! {
+              <p class="my-4 text-red-700">{ err }</p>
+            })}
+            <div class="my-4">
+              <PasswordInput placeholder = move_tr!("current-password") value = old_password disabled />
+            </div>
+            <div class="mb-4">
+              <PasswordInput placeholder = move_tr!("new-password") value = new_password disabled />
+            </div>
+            <button
+              prop:disabled=move || button_is_disabled.get()
+              on:click=move |_| action.dispatch((old_password.get(), new_password.get()))
+              class=move || if button_is_disabled.get() { DISABLED_BUTTON_CLASS } else { DEFAULT_BUTTON_CLASS }
+            >
+              { move_tr!("change-password") }
+            </button>
+        </form>
+    }
+}
+
+#[component]
+fn ChangeEmailAddressForm(
+    action: Action<(String, String), ()>,
+    error: Signal<Option<String>>,
+    disabled: Signal<bool>,
+) -> impl IntoView {
+    let new_email = RwSignal::new(String::new());
+    let password = RwSignal::new(String::new());
+    let button_is_disabled = Signal::derive(move || {
+        disabled.get() || new_email.get().is_empty() || password.get().is_empty()
+    });
+
+    view! {
+        <form class="max-w-xs" on:submit=|ev| ev.prevent_default()>
+            <p class="my-4 text-gray-600">
+              { move_tr!("change-email-address-hint") }
+            </p>
+            { move || error.get().map(|err| view! {
+              <p class="mb-4 text-red-700">{ err }</p>
+            })}
+            <div class="mb-4">
+              <input
+                  type="email"
+                  class=move || if disabled.get() { DISABLED_INPUT_CLASS } else { DEFAULT_INPUT_CLASS }
+                  required
+                  placeholder=move_tr!("new-email-address")
+                  prop:disabled=move || disabled.get()
+                  on:input=move |ev| {
+                      let val = event_target_value(&ev);
+                      new_email.update(|e| *e = val);
+                  }
+              />
+            </div>
+            <div class="mb-4">
+              <PasswordInput placeholder = move_tr!("password") value = password disabled />
+            </div>
+            <button
+              prop:disabled=move || button_is_disabled.get()
+              on:click=move |_| action.dispatch((new_email.get(), password.get()))
+              class=move || if button_is_disabled.get() { DISABLED_BUTTON_CLASS } else { DEFAULT_BUTTON_CLASS }
+            >
+              { move_tr!("change-email-address") }
+            </button>
+        </form>
+    }
+}
+
+#[component]
+fn PasswordInput(
+    placeholder: Signal<String>,
+    value: RwSignal<String>,
+    disabled: Signal<bool>,
+) -> impl IntoView {
+    view! {
+      <input
+          type="password"
+          class=move || if disabled.get() { DISABLED_INPUT_CLASS } else { DEFAULT_INPUT_CLASS }
+          required
+          placeholder=placeholder
+          prop:disabled=move || disabled.get()
+          on:input=move |ev| {
+              let val = event_target_value(&ev);
+              value.update(|v| *v = val);
+          }
+      />
+    }
+}
+
+#[component]
+fn DeleteAccountForm(
+    action: Action<String, ()>,
+    error: Signal<Option<String>>,
+    disabled: Signal<bool>,
+) -> impl IntoView {
+    let password = RwSignal::new(String::new());
+    let button_is_disabled = Signal::derive(move || disabled.get() || password.get().is_empty());
+
+    view! {
+        <form class="max-w-xs" on:submit=|ev| ev.prevent_default()>
+            <p class="my-4 text-gray-600">
+              { move_tr!("delete-account-description") }
+            </p>
+            { move || error.get().map(|err| view! {
+              <p class="mb-4 text-red-700">{ err }</p>
+            })}
+            <div class="mb-4">
+              <PasswordInput placeholder = move_tr!("password") value = password disabled />
+            </div>
+            <button
+              prop:disabled=move || button_is_disabled.get()
+              on:click=move |_| action.dispatch(password.get())
+              class=move || if button_is_disabled.get() { DISABLED_BUTTON_CLASS } else { DEFAULT_BUTTON_CLASS }
+            >
+              { move_tr!("delete-account-confirm") }
+            </button>
+        </form>
+    }
+}
@@ -46,6 +46,10 @@ pub fn Login(
                             show_resent_confirmation_button.set(false);
                             tr!("communication-error")
                         }
+                        api::Error::Api(err) if err.status.as_u16() == 429 => {
+                            show_resent_confirmation_button.set(false);
+                            tr!("too-many-login-attempts")
+                        }
                         api::Error::Api(err) => match err.details {
                             Some(json_api::login::Error::Credentials) => {
                                 show_resent_confirmation_button.set(false);
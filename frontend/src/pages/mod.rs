@@ -1,4 +1,6 @@
+mod account;
 mod confirm_email_address;
+mod confirm_email_address_change;
 mod login;
 mod projects;
 mod register;
@@ -7,8 +9,8 @@ mod reset_password_request;
 mod tool;
 
 pub use self::{
-    confirm_email_address::*, login::*, projects::*, register::*, reset_password::*,
-    reset_password_request::*, tool::*,
+    account::*, confirm_email_address::*, confirm_email_address_change::*, login::*, projects::*,
+    register::*, reset_password::*, reset_password_request::*, tool::*,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,7 +25,9 @@ pub enum Page {
     ResetPasswordRequest,
     ResetPassword,
     ConfirmEmailAddress,
+    ConfirmEmailAddressChange,
     Projects,
+    Account,
 }
 
 impl Page {
@@ -39,7 +43,9 @@ impl Page {
             Self::ResetPasswordRequest => "/reset-password-request",
             Self::ResetPassword => "/reset-password",
             Self::ConfirmEmailAddress => "/confirm-email-address",
+            Self::ConfirmEmailAddressChange => "/confirm-email-address-change",
             Self::Projects => "/projects",
+            Self::Account => "/account",
         }
     }
 }
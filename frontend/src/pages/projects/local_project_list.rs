@@ -0,0 +1,158 @@
+use leptos::*;
+
+use klick_boundary::{FormData, ProjectId};
+use klick_domain::{InputValueId as Id, Value};
+
+use crate::local_projects::LocalProject;
+
+use super::{DISABLED_BUTTON_CLASS, ENABLED_BUTTON_CLASS};
+
+const SECONDARY_BUTTON_CLASS: &str = "rounded-md bg-gray-300 px-2 py-1 text-sm font-semibold text-gray-900 shadow-sm hover:bg-gray-50";
+
+#[component]
+pub fn LocalProjectList(
+    projects: Signal<Vec<LocalProject>>,
+    logged_in: Signal<bool>,
+    syncing: Signal<bool>,
+    #[prop(into)] on_new: Callback<(), ()>,
+    #[prop(into)] on_load: Callback<ProjectId, ()>,
+    #[prop(into)] on_delete: Callback<ProjectId, ()>,
+    #[prop(into)] on_keep_local: Callback<ProjectId, ()>,
+    #[prop(into)] on_take_remote: Callback<ProjectId, ()>,
+    #[prop(into)] on_sync: Callback<(), ()>,
+) -> impl IntoView {
+    let sync_disabled = Signal::derive(move || !logged_in.get() || syncing.get());
+
+    view! {
+      <div class="flex items-center justify-end gap-x-6">
+        <button
+          on:click = move |_| on_sync.call(())
+          type="button"
+          disabled = sync_disabled
+          class = move || if sync_disabled.get() { DISABLED_BUTTON_CLASS } else { ENABLED_BUTTON_CLASS }
+        >
+          { move || if syncing.get() { "Synchronisiere..." } else { "Jetzt synchronisieren" } }
+        </button>
+        <button
+          on:click = move |_| on_new.call(())
+          type="button"
+          class = ENABLED_BUTTON_CLASS
+        >
+          "Neues lokales Projekt"
+        </button>
+      </div>
+      <Show when = move || !logged_in.get()>
+        <p class="mt-4 text-sm text-gray-500">
+          "Lokale Projekte werden nach der Anmeldung mit dem Server synchronisiert."
+        </p>
+      </Show>
+      <div class="mt-8">
+      { move || {
+          let projects = projects.get();
+          if projects.is_empty() {
+              return view! {
+                <p>"Es wurden noch keine lokalen Projekte angelegt."</p>
+              }
+              .into_view();
+          }
+          view! {
+            <ul role="list" class="divide-y divide-gray-100">
+            {
+              projects.into_iter().map(|project| view! {
+                <li class="flex items-center justify-between gap-x-6 py-5">
+                  <Project
+                    project
+                    on_load
+                    on_delete
+                    on_keep_local
+                    on_take_remote
+                  />
+                </li>
+              }).collect::<Vec<_>>()
+            }
+            </ul>
+          }
+          .into_view()
+        }
+      }
+      </div>
+    }
+}
+
+#[component]
+fn Project(
+    project: LocalProject,
+    #[prop(into)] on_load: Callback<ProjectId, ()>,
+    #[prop(into)] on_delete: Callback<ProjectId, ()>,
+    #[prop(into)] on_keep_local: Callback<ProjectId, ()>,
+    #[prop(into)] on_take_remote: Callback<ProjectId, ()>,
+) -> impl IntoView {
+    let id = project.id;
+
+    let (status, status_class) = if project.conflict.is_some() {
+        (
+            "Konflikt: das Projekt wurde auf dem Server verändert",
+            "text-red-600",
+        )
+    } else if project.remote.is_none() {
+        ("nur lokal", "text-gray-500")
+    } else if project.is_changed() {
+        ("lokal verändert", "text-gray-500")
+    } else {
+        ("synchronisiert", "text-green-700")
+    };
+
+    let conflict_buttons = project.conflict.is_some().then(|| {
+        view! {
+          <button
+            type="button"
+            class=SECONDARY_BUTTON_CLASS
+            on:click = move |_| on_keep_local.call(id)
+          >
+            "Lokale Version behalten"
+          </button>
+          <button
+            type="button"
+            class=SECONDARY_BUTTON_CLASS
+            on:click = move |_| on_take_remote.call(id)
+          >
+            "Server-Version übernehmen"
+          </button>
+        }
+    });
+
+    let project_name = FormData::try_from(project.form_data)
+        .ok()
+        .and_then(|data| data.get(&Id::ProjectName).cloned())
+        .map(Value::as_text_unchecked)
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "Unbenannt".to_string());
+
+    view! {
+      <div class="min-w-0">
+        <p class="text-sm font-semibold leading-6 text-gray-900">
+          { project_name }
+        </p>
+        <p class=format!("mt-1 text-xs leading-5 {status_class}")>
+          { status }
+        </p>
+      </div>
+      <div class="flex flex-none items-center gap-x-4">
+        { conflict_buttons }
+        <button
+          type="button"
+          class=SECONDARY_BUTTON_CLASS
+          on:click = move |_| on_delete.call(id)
+        >
+          "löschen"
+        </button>
+        <button
+          type="button"
+          class=SECONDARY_BUTTON_CLASS
+          on:click = move |_| on_load.call(id)
+        >
+          "Projekt öffnen"
+        </button>
+      </div>
+    }
+}
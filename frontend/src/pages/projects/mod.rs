@@ -7,24 +7,114 @@ use web_time::Instant;
 
 use klick_boundary::{
     json_api::{DownloadId, DownloadStatus},
-    Project, ProjectId, SavedProject,
+    JsonFormData, Project, ProjectId, SavedProject,
 };
 
-use crate::{api::AuthorizedApi, Modal, Page};
+use crate::{api::AuthorizedApi, local_projects::LocalProject, Modal, Page};
 
+mod local_project_list;
 mod new_project;
 mod project_list;
 
-use self::{new_project::NewProject, project_list::ProjectList};
+use self::{
+    local_project_list::LocalProjectList, new_project::NewProject, project_list::ProjectList,
+};
 
 #[component]
 pub fn Projects(
     api: Signal<Option<AuthorizedApi>>,
     current_project: RwSignal<Option<Project>>,
+    local_projects: RwSignal<Vec<LocalProject>>,
+    current_local_project: RwSignal<Option<ProjectId>>,
+    syncing: Signal<bool>,
+    #[prop(into)] on_sync: Callback<(), ()>,
 ) -> impl IntoView {
-    move || match api.get() {
-        None => view! { <NotAuthorized /> }.into_view(),
-        Some(api) => view! { <Authorized api current_project /> }.into_view(),
+    let logged_in = Signal::derive(move || api.get().is_some());
+    let navigate = leptos_router::use_navigate();
+
+    let open_local_project = move |project: &LocalProject| {
+        current_local_project.set(Some(project.id));
+        current_project.set(Some(project.to_project()));
+        navigate(Page::Tool.path(), NavigateOptions::default());
+    };
+
+    let on_new = {
+        let open_local_project = open_local_project.clone();
+        move |()| {
+            let project = LocalProject::new(JsonFormData::default());
+            local_projects.update(|projects| projects.push(project.clone()));
+            open_local_project(&project);
+        }
+    };
+
+    let on_load = move |id| {
+        let Some(project) =
+            local_projects.with(|projects| projects.iter().find(|p| p.id == id).cloned())
+        else {
+            return;
+        };
+        open_local_project(&project);
+    };
+
+    let on_delete = move |id| {
+        local_projects.update(|projects| projects.retain(|p| p.id != id));
+        if current_local_project.get_untracked() == Some(id) {
+            current_local_project.set(None);
+        }
+    };
+
+    let on_keep_local = move |id| {
+        local_projects.update(|projects| {
+            if let Some(project) = projects.iter_mut().find(|p| p.id == id) {
+                project.keep_local_version();
+            }
+        });
+        on_sync.call(());
+    };
+
+    let on_take_remote = move |id| {
+        let mut project = None;
+        local_projects.update(|projects| {
+            if let Some(p) = projects.iter_mut().find(|p| p.id == id) {
+                p.take_remote_version();
+                project = Some(p.to_project());
+            }
+        });
+        if current_local_project.get_untracked() == Some(id) {
+            current_project.set(project);
+        }
+    };
+
+    view! {
+      <section>
+        <h2 class="mb-4 text-lg font-semibold text-gray-900">"Lokale Projekte"</h2>
+        <LocalProjectList
+          projects = local_projects.into()
+          logged_in
+          syncing
+          on_new
+          on_load
+          on_delete
+          on_keep_local
+          on_take_remote
+          on_sync
+        />
+      </section>
+      <section class="mt-12">
+        <h2 class="mb-4 text-lg font-semibold text-gray-900">"Online-Projekte"</h2>
+        { move || match api.get() {
+            None => view! { <NotAuthorized /> }.into_view(),
+            Some(api) => view! {
+              <Authorized
+                api
+                current_project
+                local_projects
+                current_local_project
+              />
+            }.into_view(),
+          }
+        }
+      </section>
     }
 }
 
@@ -43,8 +133,14 @@ const ENABLED_BUTTON_CLASS: &str = "rounded bg-primary px-2 py-1 text-sm font-se
 
 const DISABLED_BUTTON_CLASS: &str = "rounded bg-gray-100 px-2 py-1 text-sm font-semibold text-gray-300 shadow-sm hover:bg-indigo-500 focus-visible:outline focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-indigo-600 cursor-not-allowed";
 
+#[allow(clippy::too_many_lines)] // TODO
 #[component]
-fn Authorized(api: AuthorizedApi, current_project: RwSignal<Option<Project>>) -> impl IntoView {
+fn Authorized(
+    api: AuthorizedApi,
+    current_project: RwSignal<Option<Project>>,
+    local_projects: RwSignal<Vec<LocalProject>>,
+    current_local_project: RwSignal<Option<ProjectId>>,
+) -> impl IntoView {
     let show_new_project = RwSignal::new(false);
     let api = RwSignal::new(api);
 
@@ -112,6 +208,18 @@ fn Authorized(api: AuthorizedApi, current_project: RwSignal<Option<Project>>) ->
         let Some(project) = projects.get().iter().find(|p| p.id == id).cloned() else {
             return;
         };
+        // Local changes must not be overwritten by the server version.
+        let mut local_id = None;
+        local_projects.update(|projects| {
+            if let Some(local) = projects
+                .iter_mut()
+                .find(|p| p.remote_id() == Some(id) && !p.is_changed() && p.conflict.is_none())
+            {
+                local.synced(project.clone());
+                local_id = Some(local.id);
+            }
+        });
+        current_local_project.set(local_id);
         current_project.set(Some(project.into()));
         let nav_options = NavigateOptions::default();
         navigate(Page::Tool.path(), nav_options);
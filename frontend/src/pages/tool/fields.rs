@@ -1,6 +1,6 @@
 use leptos::*;
 
-use num_traits::ToPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use strum::IntoEnumIterator;
 
 use klick_app_components::forms::{self, *};
@@ -8,6 +8,7 @@ use klick_boundary::FormData;
 use klick_domain::{units::*, InputValueId as Id, Value, ValueType};
 use klick_presenter::{
     metadata_of, FieldMetaData, InputValueFieldType, InputValueFieldTypeHint, Lng, Placeholder,
+    ValueLabel,
 };
 
 use crate::{current_lang, label_signal};
@@ -184,8 +185,31 @@ pub fn create_field_type(
             let on_change = match enum_kind {
                 EnumType::N2oEmissionFactorCalcMethod => Callback::new(move |_: Option<usize>| {}),
                 EnumType::Ch4ChpEmissionFactorCalcMethod => {
-                    Callback::new(move |_: Option<usize>| {})
+                    Callback::new(move |idx: Option<usize>| {
+                        let value = idx
+                            .and_then(Ch4ChpEmissionFactorCalcMethod::from_usize)
+                            .map(Value::ch4_chp_emission_factor_calc_method);
+                        write.update(|d| {
+                            if let Some(value) = value {
+                                d.insert(id, value);
+                            } else {
+                                d.remove(&id);
+                            }
+                        });
+                    })
                 }
+                EnumType::SludgeDisposalRoute => Callback::new(move |idx: Option<usize>| {
+                    let value = idx
+                        .and_then(SludgeDisposalRoute::from_usize)
+                        .map(Value::sludge_disposal_route);
+                    write.update(|d| {
+                        if let Some(value) = value {
+                            d.insert(id, value);
+                        } else {
+                            d.remove(&id);
+                        }
+                    });
+                }),
             };
 
             let input = match enum_kind {
@@ -205,6 +229,14 @@ pub fn create_field_type(
                             .and_then(|v| v.to_usize())
                     })
                 }),
+                EnumType::SludgeDisposalRoute => Signal::derive(move || {
+                    read.with(|d| {
+                        d.get(&id)
+                            .cloned()
+                            .map(Value::as_sludge_disposal_route_unchecked)
+                            .and_then(|v| v.to_usize())
+                    })
+                }),
             };
 
             let options = match enum_kind {
@@ -212,9 +244,22 @@ pub fn create_field_type(
                     .map(|c| format!("{c:?}"))
                     .collect(),
 
-                EnumType::Ch4ChpEmissionFactorCalcMethod => Ch4ChpEmissionFactorCalcMethod::iter()
-                    .map(|c| format!("{c:?}"))
-                    .collect(),
+                EnumType::Ch4ChpEmissionFactorCalcMethod => {
+                    let lang = current_lang().get_untracked();
+                    // A single unit can't be a mix,
+                    // so the last option is omitted.
+                    Ch4ChpEmissionFactorCalcMethod::iter()
+                        .filter(|method| *method != Ch4ChpEmissionFactorCalcMethod::Mix)
+                        .map(|method| method.label(lang))
+                        .collect()
+                }
+
+                EnumType::SludgeDisposalRoute => {
+                    let lang = current_lang().get_untracked();
+                    SludgeDisposalRoute::iter()
+                        .map(|route| route.label(lang))
+                        .collect()
+                }
             };
 
             FieldType::DropDown {
@@ -50,7 +50,8 @@ pub fn FormDataOverview(
                     <dd class="py-1 px-3 flex items-center">
                       {
                         match id {
-                          Id::Custom(_) => None,
+                          Id::Custom(_)
+                          | Id::In(In::ProfileElectricityContracts | In::SensitivityCH4ChpUnits) => None,
                           Id::In(id) if matches!(id.value_type(), ValueType::Enum(_)) => {
                               None
                           }
@@ -9,18 +9,18 @@ use leptos_fluent::*;
 
 use klick_app_components::message::*;
 use klick_boundary::{
-    self as boundary, export_to_vec_pretty, import_from_slice, FormData, JsonFormData, Project,
-    ProjectId, SavedProject, UnsavedProject,
+    self as boundary, export_to_vec_pretty, import_from_slice, CustomEmissions, FormData,
+    JsonFormData, Project, ProjectId, SavedProject, UnsavedProject,
 };
-use klick_custom_values_parser::{self as custom_emission_parser, CustomEmission};
+use klick_custom_values_parser::{self as custom_emission_parser, CustomValues};
 use klick_domain::{
     optional_input_value_id as optional_in, units::Tons, CalculationOutcome, InputValueId as In,
     Value, ValueId as Id,
 };
 use klick_presenter as presenter;
-use klick_usecases::get_all_internal_nodes;
+use klick_usecases::CustomRecommendation;
 
-use crate::{api::AuthorizedApi, current_lang, SECTION_ID_TOOL_HOME};
+use crate::{api::AuthorizedApi, current_lang, local_projects::LocalProject, SECTION_ID_TOOL_HOME};
 
 mod breadcrumbs;
 mod example_data;
@@ -62,6 +62,8 @@ const DEFAULT_UNNAMED_PROJECT_TITLE: &str = "Unbenannt";
 pub fn Tool(
     api: Signal<Option<AuthorizedApi>>,
     current_project: RwSignal<Option<Project>>,
+    local_projects: RwSignal<Vec<LocalProject>>,
+    current_local_project: RwSignal<Option<ProjectId>>,
     current_section: RwSignal<PageSection>,
     accessibility_always_show_option: Option<RwSignal<bool>>,
 ) -> impl IntoView {
@@ -79,28 +81,37 @@ pub fn Tool(
     let is_logged_in = Memo::new(move |_| api.get().is_some());
     let save_result_message = RwSignal::new(None);
 
-    let custom_emissions_message = RwSignal::new(String::new());
     let custom_edges = RwSignal::new(vec![]);
     let custom_values = RwSignal::<Vec<(Id, Value)>>::new(vec![]);
     let custom_leafs = RwSignal::<Vec<Id>>::new(vec![]);
+    let custom_labels = RwSignal::<HashMap<Id, String>>::new(HashMap::new());
+    let custom_recommendations = RwSignal::<Vec<(Id, CustomRecommendation)>>::new(vec![]);
+
+    // The text of the custom emissions editor is only a view of the stored custom emissions,
+    // so it may contain errors that are not stored.
+    let custom_emissions_input = RwSignal::<Option<String>>::new(None);
+    let stored_custom_emissions = Memo::new(move |_| {
+        form_data.with(|d| {
+            d.get(&In::SensitivityAdditionalCustomEmissions)
+                .and_then(|value| CustomEmissions::from_value(value).ok())
+        })
+    });
+    let custom_emissions_have_errors = Memo::new(move |_| {
+        let lang = current_lang().get();
+        custom_emissions_input.with(|input| {
+            input.as_deref().is_some_and(|input| {
+                custom_emission_parser::parse_custom_values(input, lang).is_err()
+            })
+        })
+    });
 
     let clear_custom_values_and_edges = move || {
         custom_values.update(std::vec::Vec::clear);
         custom_edges.update(std::vec::Vec::clear);
+        custom_labels.update(HashMap::clear);
+        custom_recommendations.update(std::vec::Vec::clear);
     };
 
-    fn try_id_lookup(id: String) -> Id {
-        let id_lookup: HashMap<String, Id> = get_all_internal_nodes()
-            .iter()
-            .map(|&id| (format!("{id:?}"), id.into()))
-            .collect::<HashMap<_, _>>();
-        if let Some(r) = id_lookup.get(&id.to_string()) {
-            r.clone()
-        } else {
-            id.to_string().into()
-        }
-    }
-
     let profile_outcome = Memo::new(move |_| klick_usecases::calculate_profile(form_data.get()));
 
     let sensitivity_outcome = Memo::new(move |_| {
@@ -137,7 +148,9 @@ pub fn Tool(
         } else {
             Some(&*edges)
         };
-        klick_usecases::calculate_recommendation(values, custom_edges, leafs)
+        custom_recommendations.with(|recommendations| {
+            klick_usecases::calculate_recommendation(values, custom_edges, leafs, recommendations)
+        })
     });
 
     let show_side_stream_controls = Memo::new(move |_| {
@@ -150,6 +163,22 @@ pub fn Tool(
     let show_csv_export =
         Signal::derive(move || recommendation_outcome.with(|out| out.output.is_some()));
 
+    // Marks the local copy of a project as synchronized after saving it online.
+    let local_project_synced = move |project: SavedProject| {
+        let current_id = current_local_project.get_untracked();
+        local_projects.update(|projects| {
+            let local = match current_id {
+                Some(id) => projects.iter_mut().find(|p| p.id == id),
+                None => projects
+                    .iter_mut()
+                    .find(|p| p.remote_id() == Some(project.id)),
+            };
+            if let Some(local) = local {
+                local.synced(project);
+            }
+        });
+    };
+
     // -----   ----- //
     //    Actions    //
     // -----   ----- //
@@ -188,6 +217,7 @@ pub fn Tool(
                 };
                 match api.read_project(&id.into()).await {
                     Ok(p) => {
+                        local_project_synced(p.clone());
                         current_project.set(Some(p.into()));
                     }
                     Err(err) => {
@@ -222,13 +252,18 @@ pub fn Tool(
                         p.form_data = data.try_into().unwrap();
                         api.update_project(&p)
                             .await
-                            .map(|()| {
-                                current_project.set(Some(Project::Saved(p)));
+                            .map(|updated| {
+                                local_project_synced(updated.clone());
+                                current_project.set(Some(Project::Saved(updated)));
                                 "Das Projekt wurde gespeichert."
                             })
                             .map_err(|err| {
                                 log::warn!("Unable to update project: {err}");
-                                "Das Projekt konnte leider nicht gespeichert werden."
+                                if err.is_conflict() {
+                                    "Das Projekt wurde zwischenzeitlich verändert."
+                                } else {
+                                    "Das Projekt konnte leider nicht gespeichert werden."
+                                }
                             })
                     }
                     Project::Unsaved(p) => {
@@ -261,10 +296,23 @@ pub fn Tool(
     //   Callbacks   //
     // -----   ----- //
 
+    // Invalid custom emissions are not stored,
+    // so the project is not saved until they are fixed.
+    let reject_invalid_custom_emissions = move || {
+        let has_errors = custom_emissions_have_errors.get_untracked();
+        if has_errors {
+            save_result_message.set(Some(Err(
+                "Die benutzerdefinierten Emissionen enthalten Fehler.",
+            )));
+        }
+        has_errors
+    };
+
     let clear_form_data = {
         move |()| {
             load_form_data(FormData::default());
             current_project.set(None);
+            current_local_project.set(None);
         }
     };
 
@@ -275,19 +323,25 @@ pub fn Tool(
     };
 
     let download = {
-        move |()| -> ObjectUrl {
+        move |()| -> Option<ObjectUrl> {
+            if reject_invalid_custom_emissions() {
+                return None;
+            }
             let form_data = JsonFormData::try_from(form_data.get()).unwrap();
             let data = UnsavedProject { form_data };
             let json_bytes = export_to_vec_pretty(&data.into());
 
             let blob = Blob::new_with_options(&*json_bytes, Some("application/json"));
 
-            ObjectUrl::from(blob)
+            Some(ObjectUrl::from(blob))
         }
     };
 
     let save_project = {
         move |()| {
+            if reject_invalid_custom_emissions() {
+                return;
+            }
             let form_data = JsonFormData::try_from(form_data.get()).unwrap();
             let project = match current_project.get() {
                 Some(Project::Saved(p)) => {
@@ -311,6 +365,40 @@ pub fn Tool(
         }
     };
 
+    let save_local_project = {
+        move |()| {
+            if reject_invalid_custom_emissions() {
+                return;
+            }
+            let form_data = JsonFormData::try_from(form_data.get()).unwrap();
+            let remote = match current_project.get() {
+                Some(Project::Saved(p)) => Some(p),
+                Some(Project::Unsaved(_)) | None => None,
+            };
+            let current_id = current_local_project.get();
+            let remote_id = remote.as_ref().map(|p| p.id);
+            let mut id = None;
+            local_projects.update(|projects| {
+                let existing = projects.iter_mut().find(|p| {
+                    Some(p.id) == current_id || (remote_id.is_some() && p.remote_id() == remote_id)
+                });
+                if let Some(p) = existing {
+                    p.form_data = form_data;
+                    id = Some(p.id);
+                } else {
+                    let project = LocalProject {
+                        remote,
+                        ..LocalProject::new(form_data)
+                    };
+                    id = Some(project.id);
+                    projects.push(project);
+                }
+            });
+            current_local_project.set(id);
+            save_result_message.set(Some(Ok("Das Projekt wurde lokal gespeichert.")));
+        }
+    };
+
     let export_csv = {
         move |()| -> Option<ObjectUrl> {
             let lang = crate::current_lang().get();
@@ -343,62 +431,78 @@ pub fn Tool(
         form_data_to_session_storage(data);
     });
 
+    // Keep the opened local project up to date.
     Effect::new(move |_| {
-        let additional_custom_emissions_string = form_data.with(|values| {
-            values
-                .get(&In::SensitivityAdditionalCustomEmissions)
-                .cloned()
-                .map(Value::as_text_unchecked)
-        });
-
-        let Some(input) = additional_custom_emissions_string else {
-            custom_emissions_message.set(String::new());
-            clear_custom_values_and_edges();
+        let Some(id) = current_local_project.get() else {
             return;
         };
-        let res = custom_emission_parser::parse_emission(input.as_str(), current_lang().get());
-        let Ok(r) = res.map_err(|err| {
-            custom_emissions_message.set(err.to_string());
-            clear_custom_values_and_edges();
-        }) else {
+        let Ok(data) = JsonFormData::try_from(form_data.get()) else {
             return;
         };
+        let changed = local_projects
+            .with_untracked(|projects| projects.iter().any(|p| p.id == id && p.form_data != data));
+        if changed {
+            local_projects.update(|projects| {
+                if let Some(p) = projects.iter_mut().find(|p| p.id == id) {
+                    p.form_data = data;
+                }
+            });
+        }
+    });
 
-        let mut custom_edges_vec: Vec<(Id, Id)> = vec![];
-        let mut custom_values_vec: Vec<(Id, Value)> = vec![];
-        let mut custom_leafs_vec: Vec<Id> = vec![];
-
-        r.iter().for_each(|e: &CustomEmission| match &e {
-            CustomEmission::EdgeDefined(edge) => {
-                custom_edges_vec.push((
-                    edge.source.to_string().into(),
-                    try_id_lookup(edge.target.to_string()),
-                ));
-                custom_leafs_vec.push(edge.source.to_string().into());
-                custom_values_vec.push((edge.source.to_string().into(), Value::tons(edge.value)));
-            }
-            CustomEmission::EdgeUndefined(edge) => {
-                custom_edges_vec.push((
-                    edge.source.clone().into(),
-                    try_id_lookup(edge.target.to_string()),
-                ));
-            }
+    Effect::new(move |_| {
+        let lang = current_lang().get();
+        let stored = stored_custom_emissions.get();
+        let shown = custom_emissions_input.with_untracked(|input| {
+            input
+                .as_deref()
+                .and_then(|input| custom_emission_parser::parse_custom_emissions(input, lang).ok())
+                .filter(|emissions| !emissions.is_empty())
         });
-        let all_internal_nodes_names: Vec<String> = get_all_internal_nodes()
-            .iter()
-            .map(|x| format!("{x:?}").to_string())
-            .collect();
+        if shown != stored {
+            custom_emissions_input.set(stored.map(|emissions| {
+                custom_emission_parser::format_custom_emissions(&emissions, lang)
+            }));
+        }
+    });
 
-        match custom_emission_parser::check_graph(&r, all_internal_nodes_names) {
-            Ok(()) => {
-                custom_emissions_message.set(String::new());
-                custom_values.set(custom_values_vec);
-                custom_edges.set(custom_edges_vec);
-                custom_leafs.set(custom_leafs_vec);
+    Effect::new(move |_| {
+        let additional_custom_emissions = form_data.with(|values| {
+            values
+                .get(&In::SensitivityAdditionalCustomEmissions)
+                .map(CustomEmissions::from_value)
+        });
+
+        let emissions = match additional_custom_emissions {
+            Some(Ok(emissions)) => emissions,
+            Some(Err(err)) => {
+                log::warn!("Invalid custom emissions: {err}");
+                clear_custom_values_and_edges();
+                return;
             }
-            Err(e) => {
-                let lang = current_lang();
-                custom_emissions_message.set(e.format_error(lang.get()));
+            None => {
+                clear_custom_values_and_edges();
+                return;
+            }
+        };
+        match custom_emission_parser::custom_values_from_structure(&emissions, current_lang().get())
+        {
+            Ok(CustomValues {
+                values,
+                edges,
+                leafs,
+                labels,
+                recommendations,
+                ..
+            }) => {
+                custom_values.set(values);
+                custom_edges.set(edges);
+                custom_leafs.set(leafs);
+                custom_labels.set(labels);
+                custom_recommendations.set(recommendations);
+            }
+            Err(_) => {
+                // The diagnostics are shown by the editor.
                 clear_custom_values_and_edges();
             }
         }
@@ -422,12 +526,13 @@ pub fn Tool(
         PageSection::Sensitivity => view! {
             <SensitivityParameters
               form_data
+              custom_emissions_input
               current_section
               sensitivity_outcome = sensitivity_outcome.into()
               profile_outcome = profile_outcome.into()
               show_side_stream_controls = show_side_stream_controls.into()
               accessibility_always_show_option
-              custom_emissions_message
+              custom_labels = custom_labels.into()
               lang = current_lang().get()
             />
         }
@@ -438,8 +543,10 @@ pub fn Tool(
               current_section
               recommendation_outcome = recommendation_outcome.into()
               sensitivity_outcome = sensitivity_outcome.into()
+              profile_outcome = profile_outcome.into()
               show_side_stream_controls = show_side_stream_controls.into()
               accessibility_always_show_option
+              custom_labels = custom_labels.into()
               lang = current_lang()
             />
         }
@@ -482,6 +589,7 @@ pub fn Tool(
             clear = clear_form_data
             load = load_example_values
             save = save_project
+            save_local = save_local_project
             download
             export_csv
             upload_action
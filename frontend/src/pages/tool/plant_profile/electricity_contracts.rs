@@ -0,0 +1,192 @@
+use leptos::*;
+use leptos_fluent::*;
+
+use klick_app_components::forms::*;
+use klick_boundary::FormData;
+use klick_domain::{
+    electricity_contracts::{self, ElectricityContract},
+    units::{GramsPerKilowatthour, Kilowatthours},
+    InputValueId as Id,
+};
+use klick_presenter::FieldMetaData;
+
+use crate::current_lang;
+
+#[component]
+pub fn ElectricityContracts(
+    form_data: RwSignal<FormData>,
+    accessibility_always_show_option: Option<RwSignal<bool>>,
+) -> impl IntoView {
+    let contract_count = Memo::new(move |_| form_data.with(|d| read_contracts(d).len()));
+    let contracts_view = move || {
+        let field_sets = (0..contract_count.get())
+            .map(|index| contract_field_set(form_data, index))
+            .collect();
+        let (views, _, _) =
+            render_field_sets(field_sets, accessibility_always_show_option, current_lang());
+        views
+            .into_iter()
+            .enumerate()
+            .map(|(index, view)| {
+                view! {
+                  { view }
+                  <button
+                    type = "button"
+                    class="rounded bg-gray-100 px-2 py-1 text-sm font-semibold text-black shadow-sm"
+                    on:click = move |_| update_contracts(form_data, |contracts| { contracts.remove(index); })
+                  >
+                    { move_tr!("electricity-contract-remove") }
+                  </button>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    let add_contract = move |_| {
+        update_contracts(form_data, |contracts| {
+            contracts.push(ElectricityContract {
+                energy: Kilowatthours::zero(),
+                emission_factor: None,
+                guarantee_of_origin: false,
+            });
+        });
+    };
+
+    view! {
+      <div class="border-b border-gray-900/10 pb-12 mb-6">
+        <p class="text-sm">
+          { move || FieldMetaData::lookup(current_lang().get(), "description-electricity-contracts") }
+        </p>
+        { contracts_view }
+        <button
+          type = "button"
+          class="mt-4 rounded bg-primary px-2 py-1 text-sm font-semibold text-black shadow-sm"
+          on:click = add_contract
+        >
+          { move_tr!("electricity-contract-add") }
+        </button>
+      </div>
+    }
+}
+
+fn read_contracts(form_data: &FormData) -> Vec<ElectricityContract> {
+    form_data
+        .get(&Id::ProfileElectricityContracts)
+        .and_then(|value| electricity_contracts::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn update_contracts(form_data: RwSignal<FormData>, f: impl FnOnce(&mut Vec<ElectricityContract>)) {
+    form_data.update(|d| {
+        let mut contracts = read_contracts(d);
+        f(&mut contracts);
+        if contracts.is_empty() {
+            d.remove(&Id::ProfileElectricityContracts);
+        } else {
+            d.insert(
+                Id::ProfileElectricityContracts,
+                electricity_contracts::to_value(&contracts),
+            );
+        }
+    });
+}
+
+fn update_contract(
+    form_data: RwSignal<FormData>,
+    index: usize,
+    f: impl FnOnce(&mut ElectricityContract),
+) {
+    update_contracts(form_data, |contracts| {
+        if let Some(contract) = contracts.get_mut(index) {
+            f(contract);
+        }
+    });
+}
+
+fn contract_label(index: usize, key: &'static str) -> Signal<String> {
+    let lang = current_lang();
+    Signal::derive(move || {
+        let lang = lang.get();
+        format!(
+            "{} {} {}",
+            FieldMetaData::lookup(lang, "electricity-contract"),
+            index + 1,
+            FieldMetaData::lookup(lang, key)
+        )
+    })
+}
+
+fn contract_field_set(form_data: RwSignal<FormData>, index: usize) -> FieldSet {
+    let lang = current_lang().get_untracked();
+    let contract = move || form_data.with(|d| read_contracts(d).get(index).copied());
+    let energy = Field {
+        label: contract_label(index, "contract-energy"),
+        description: Some(FieldMetaData::lookup(lang, "description-contract-energy")),
+        required: false,
+        field_type: FieldType::Float {
+            initial_value: None,
+            placeholder: None,
+            limits: MinMax {
+                min: Id::ProfileTotalPowerConsumption.min(),
+                max: Id::ProfileTotalPowerConsumption.max(),
+            },
+            unit: "kWh",
+            on_change: Callback::new(move |v: Option<f64>| {
+                update_contract(form_data, index, |contract| {
+                    contract.energy = v.map_or_else(Kilowatthours::zero, Kilowatthours::new);
+                });
+            }),
+            input: Signal::derive(move || contract().map(|contract| contract.energy.into())),
+        },
+    };
+    let emission_factor = Field {
+        label: contract_label(index, "contract-emission-factor"),
+        description: Some(FieldMetaData::lookup(
+            lang,
+            "description-contract-emission-factor",
+        )),
+        required: false,
+        field_type: FieldType::Float {
+            initial_value: None,
+            placeholder: None,
+            limits: MinMax {
+                min: Id::ProfileElectricityResidualMixEmissionFactor.min(),
+                max: Id::ProfileElectricityResidualMixEmissionFactor.max(),
+            },
+            unit: "g/kWh",
+            on_change: Callback::new(move |v: Option<f64>| {
+                update_contract(form_data, index, |contract| {
+                    contract.emission_factor = v.map(GramsPerKilowatthour::new);
+                });
+            }),
+            input: Signal::derive(move || {
+                contract()
+                    .and_then(|contract| contract.emission_factor)
+                    .map(f64::from)
+            }),
+        },
+    };
+    let guarantee_of_origin = Field {
+        label: contract_label(index, "guarantee-of-origin"),
+        description: Some(FieldMetaData::lookup(
+            lang,
+            "description-guarantee-of-origin",
+        )),
+        required: false,
+        field_type: FieldType::Bool {
+            initial_value: None,
+            on_change: Callback::new(move |v: bool| {
+                update_contract(form_data, index, |contract| {
+                    contract.guarantee_of_origin = v;
+                });
+            }),
+            input: Signal::derive(move || {
+                contract().is_some_and(|contract| contract.guarantee_of_origin)
+            }),
+        },
+    };
+    FieldSet {
+        title: None,
+        fields: vec![energy, emission_factor, guarantee_of_origin],
+        draw_border: false,
+    }
+}
@@ -3,10 +3,17 @@ use leptos_fluent::*;
 
 use klick_app_components::forms::*;
 use klick_boundary::FormData;
-use klick_domain::{InputValueId as Id, Value};
+use klick_domain::{
+    operating_materials::{OperatingMaterial, OPERATING_MATERIALS},
+    InputValueId as Id, Value,
+};
 
 use crate::{pages::tool::fields::create_field, Lng};
 
+/// Position of the list of electricity contracts,
+/// which follows the field set with the residual mix.
+pub const ELECTRICITY_CONTRACTS_POSITION: usize = 6;
+
 #[allow(clippy::too_many_lines)]
 pub fn field_sets(form_data: RwSignal<FormData>, lang: Lng) -> Vec<FieldSet> {
     let read = form_data.into();
@@ -88,12 +95,32 @@ pub fn field_sets(form_data: RwSignal<FormData>, lang: Lng) -> Vec<FieldSet> {
                 Id::ProfileHeatingOil,
                 Id::ProfileSewageGasProduced,
                 Id::ProfileMethaneFraction,
+                Id::ProfileSewageGasFlared,
+                Id::ProfileFlareEfficiency,
+                Id::ProfileSewageGasVented,
+                Id::ProfileSewageGasUpgraded,
+                Id::ProfileUpgradingMethaneSlip,
+                Id::ProfileUpgradingPowerConsumption,
+                Id::ProfileHeatExported,
             ]
             .into_iter()
             .map(|id| create_field(write, read, id))
             .collect(),
             draw_border,
         },
+        FieldSet {
+            title: match lang {
+                Lng::De => Some("Stromlieferverträge"),
+                Lng::En => Some("Electricity supply contracts"),
+            },
+            fields: vec![create_field(
+                write,
+                read,
+                Id::ProfileElectricityResidualMixEmissionFactor,
+            )],
+            // The list of contracts follows (see `ELECTRICITY_CONTRACTS_POSITION`)
+            draw_border: false,
+        },
         FieldSet {
             title: match lang {
                 Lng::De => Some("Klärschlammbehandlung"),
@@ -151,6 +178,11 @@ pub fn field_sets(form_data: RwSignal<FormData>, lang: Lng) -> Vec<FieldSet> {
                 },
                 create_field(write, read, Id::ProfileSludgeDisposal),
                 create_field(write, read, Id::ProfileSludgeTransportDistance),
+                create_field(write, read, Id::ProfileSludgeDisposalRoute),
+                create_field(write, read, Id::ProfileSludgeDisposalShareMonoIncineration),
+                create_field(write, read, Id::ProfileSludgeDisposalShareCoIncineration),
+                create_field(write, read, Id::ProfileSludgeDisposalShareAgriculture),
+                create_field(write, read, Id::ProfileSludgeDisposalShareLandfill),
             ],
             draw_border,
         },
@@ -172,11 +204,23 @@ pub fn field_sets(form_data: RwSignal<FormData>, lang: Lng) -> Vec<FieldSet> {
                 Lng::De => Some("Eingesetzte Betriebsstoffe"),
                 Lng::En => Some("Operating materials used"),
             },
+            fields: OPERATING_MATERIALS
+                .iter()
+                .flat_map(OperatingMaterial::inputs)
+                .map(|id| create_field(write, read, id))
+                .collect(),
+            draw_border,
+        },
+        FieldSet {
+            title: match lang {
+                Lng::De => Some("Kanalnetz"),
+                Lng::En => Some("Sewer network"),
+            },
             fields: [
-                Id::ProfileOperatingMaterialFeCl3,
-                Id::ProfileOperatingMaterialFeClSO4,
-                Id::ProfileOperatingMaterialCaOH2,
-                Id::ProfileOperatingMaterialSyntheticPolymers,
+                Id::ProfileSewerPressureMainLength,
+                Id::ProfileSewerPressureMainRetentionTime,
+                Id::ProfileSewerPumpingStationEnergy,
+                Id::ProfileSewerOverflowVolume,
             ]
             .into_iter()
             .map(|id| create_field(write, read, id))
@@ -3,7 +3,10 @@ use std::rc::Rc;
 use leptos::*;
 use leptos_fluent::*;
 
-use klick_presenter::Lng;
+use klick_domain::{required_output_value_id as required, units::Tons, OutputValueId as Out};
+use klick_presenter::{
+    avoided_emissions_as_table, scope2_electricity_as_table, Formatting, Lng, Table, TableRow,
+};
 
 use klick_app_components::forms::render_field_sets;
 use klick_boundary::FormData;
@@ -15,8 +18,13 @@ use crate::{
     sankey::Sankey,
 };
 
+mod electricity_contracts;
 mod field_sets;
 
+use self::{
+    electricity_contracts::ElectricityContracts, field_sets::ELECTRICITY_CONTRACTS_POSITION,
+};
+
 pub use self::field_sets::field_sets;
 
 #[component]
@@ -32,8 +40,12 @@ pub fn PlantProfile(
     // -----   ----- //
 
     let field_sets = field_sets(form_data, lang);
-    let (field_views, missing_fields, labels) =
+    let (mut field_views, missing_fields, labels) =
         render_field_sets(field_sets, accessibility_always_show_option, current_lang());
+    field_views.insert(
+        ELECTRICITY_CONTRACTS_POSITION,
+        view! { <ElectricityContracts form_data accessibility_always_show_option /> }.into_view(),
+    );
     let labels = Rc::new(labels);
 
     // -----   ----- //
@@ -58,6 +70,25 @@ pub fn PlantProfile(
         })
     };
 
+    let avoided_emissions_table = move || {
+        profile_outcome.with(|outcome| {
+            let output = outcome.output.as_ref()?;
+            if required!(Out::AvoidedEmissions, output).ok()? <= Tons::zero() {
+                return None;
+            }
+            let table = avoided_emissions_as_table(output, Formatting::Text, current_lang().get());
+            Some(table_view(table))
+        })
+    };
+
+    let scope2_electricity_table = move || {
+        profile_outcome.with(|outcome| {
+            let output = outcome.output.as_ref()?;
+            let table = scope2_electricity_as_table(output, Formatting::Text, current_lang().get());
+            Some(table_view(table))
+        })
+    };
+
     view! {
       <div>
         { field_views }
@@ -83,6 +114,8 @@ pub fn PlantProfile(
             view!{ <Sankey data graph lang/> }
           }))
         }
+        { scope2_electricity_table }
+        { avoided_emissions_table }
         <Show when = move || profile_outcome.with(|outcome|outcome.output.is_some())>
           <button
             class="rounded bg-primary px-2 py-1 text-sm font-semibold text-black shadow-sm"
@@ -94,3 +127,34 @@ pub fn PlantProfile(
       </div>
     }
 }
+
+pub(crate) fn table_view(table: Table) -> impl IntoView {
+    let sections = table
+        .sections
+        .into_iter()
+        .map(|section| {
+            let rows = section
+                .rows
+                .into_iter()
+                .map(|TableRow { label, value, unit, .. }| {
+                    view! {
+                      <dt class="font-semibold text-right px-3 py-1 text-gray-500">{ label }</dt>
+                      <dd class="py-1 px-3">
+                        <span class="mr-2">{ value.unwrap_or_else(|| "-".to_string()) }</span>
+                        <span class="text-gray-400">{ unit }</span>
+                      </dd>
+                    }
+                })
+                .collect::<Vec<_>>();
+            view! {
+              <div class="font-semibold text-lg border-solid border-b text-gray-400">
+                { section.title }
+              </div>
+              <dl class="mx-3 my-2 grid grid-cols-2 text-sm">
+                { rows }
+              </dl>
+            }
+        })
+        .collect::<Vec<_>>();
+    view! { <div class="my-8">{ sections }</div> }
+}
@@ -13,9 +13,10 @@ const EXPORT_FILE_NAME_CSV: &str = "klimabilanzklaeranlage.csv";
 pub fn ProjectMenu(
     logged_in: Signal<bool>,
     #[prop(into)] save: Callback<()>,
+    #[prop(into)] save_local: Callback<()>,
     #[prop(into)] clear: Callback<()>,
     #[prop(into)] load: Callback<()>,
-    #[prop(into)] download: Callback<(), ObjectUrl>,
+    #[prop(into)] download: Callback<(), Option<ObjectUrl>>,
     #[prop(into)] export_csv: Callback<(), Option<ObjectUrl>>,
     upload_action: Action<File, ()>,
     show_csv_export: Signal<bool>,
@@ -86,7 +87,10 @@ pub fn ProjectMenu(
                       label = move_tr!("project-save-to-file").get()
                       on:click = move |ev| {
                         ev.prevent_default();
-                        let object_url = download.call(());
+                        let Some(object_url) = download.call(()) else {
+                            is_open.set(false);
+                            return;
+                        };
                         let link = download_link.get().expect("<a> to exist");
                         link.set_attribute("href", &object_url).unwrap();
                         link.set_attribute("download", &project_name.get()).unwrap();
@@ -97,6 +101,17 @@ pub fn ProjectMenu(
                       icon = icons::DocumentArrowDown
                     />
                   </Section>
+                  <Section>
+                    <Entry
+                      label = move_tr!("project-save-to-local").get()
+                      icon = icons::DocumentArrowDown
+                      on:click = move |ev| {
+                        ev.prevent_default();
+                        save_local.call(());
+                        is_open.set(false);
+                      }
+                    />
+                  </Section>
                   <Section>
                     <Entry
                       label = move_tr!("project-export-csv").get()
@@ -0,0 +1,118 @@
+use leptos::*;
+use leptos_fluent::*;
+
+use klick_app_components::forms::*;
+use klick_boundary::FormData;
+use klick_domain::{
+    required_output_value_id as required, InputValueId as Id, OutputValueId as Out, Value,
+};
+use klick_presenter::{Lng, ValueLabel};
+
+use crate::{
+    label_signal,
+    pages::tool::{fields::create_field, CalculationOutcome, Card},
+};
+
+pub fn options(
+    form_data: RwSignal<FormData>,
+    input_data: Signal<FormData>,
+    outcome: Signal<CalculationOutcome>,
+    accessibility_always_show_option: Option<RwSignal<bool>>,
+    lang: Lng,
+) -> impl IntoView {
+    let show_flare_controls = Signal::derive(move || {
+        form_data.with(|d| {
+            d.get(&Id::ProfileSewageGasFlared)
+                .cloned()
+                .map(Value::as_qubicmeters_unchecked)
+                .map(f64::from)
+                .unwrap_or_default()
+                > 0.0
+        })
+    });
+
+    let field_set = field_set(form_data.write_only(), input_data);
+    let (form1, _, _) = render_field_sets(
+        vec![field_set],
+        accessibility_always_show_option,
+        crate::current_lang(),
+    );
+
+    view! {
+      <div class = move || { if show_flare_controls.get() { None } else { Some("hidden") } } >
+       <Card id = "recommendation-ch4-flare" title = move_tr!("recommendation-ch4-flare").get() bg_color="bg-yellow" accessibility_always_show_option>
+        <p class="my-2">
+          <div inner_html={ move_tr!("recommendation-ch4-flare_p_1") }></div>
+        </p>
+        { form1 }
+        <div class="border-t pt-3 mt-4 border-gray-900/10">
+          { move || {
+              outcome.with(|outcome|outcome.output.as_ref().map(|out|{
+                let embodied_emissions =
+                    super::embodied_emissions_rows(outcome, &[Out::EnclosedFlareSavings], lang);
+                view! {
+                  <dl class="mx-3 my-2 grid grid-cols-2 text-sm">
+                    <dt class="text-lg font-semibold text-right px-3 py-1 text-gray-500">{ Out::Ch4Flare.label(lang) }</dt>
+                    <dd class="text-lg py-1 px-3">
+                      { crate::current_lang().get().format_number_with_fixed_precision(f64::from(required!(Out::Ch4Flare, out).unwrap()), 2) }
+                      <span class="ml-2 text-gray-400">{ "t CO₂-Äq./a" }</span>
+                    </dd>
+                    <dt class="text-lg font-semibold text-right px-3 py-1 text-gray-500">{ Out::EnclosedFlareSavings.label(lang) }</dt>
+                    <dd class="text-lg py-1 px-3">
+                      { crate::current_lang().get().format_number_with_fixed_precision(f64::from(required!(Out::EnclosedFlareSavings, out).unwrap()), 2) }
+                      <span class="ml-2 text-gray-400">{ "t CO₂-Äq./a" }</span>
+                    </dd>
+                    { embodied_emissions }
+                    <dt class="text-lg font-semibold text-right px-3 py-1 text-gray-500">{ Out::TotalEmissions.label(lang) }</dt>
+                    <dd class="text-lg py-1 px-3">
+                      { crate::current_lang().get().format_number_with_fixed_precision(f64::from(required!(Out::TotalEmissions, out).unwrap()), 2) }
+                      <span class="ml-2 text-gray-400">{ "t CO₂-Äq./a" }</span>
+                    </dd>
+                  </dl>
+                }
+              }))
+            }
+          }
+        </div>
+       </Card>
+      </div>
+    }
+}
+
+fn field_set(form_data: WriteSignal<FormData>, input_data: Signal<FormData>) -> FieldSet {
+    let id = Id::RecommendationFlareIsOpen;
+    let enclosed_flare_field = Field {
+        label: label_signal(id),
+        description: None,
+        required: false,
+        field_type: FieldType::Bool {
+            initial_value: None,
+            on_change: Callback::new(move |v: bool| {
+                form_data.update(|d| {
+                    d.insert(id, Value::bool(!v));
+                });
+            }),
+            input: Signal::derive(move || {
+                input_data
+                    .with(|d| {
+                        d.get(&id)
+                            .cloned()
+                            .map(Value::as_bool_unchecked)
+                            .map(|v| !v)
+                    })
+                    .unwrap_or(false)
+            }),
+        },
+    };
+    let embodied_emissions_field = create_field(
+        form_data,
+        input_data,
+        Id::RecommendationFlareEmbodiedEmissions,
+    );
+    let fields = vec![enclosed_flare_field, embodied_emissions_field];
+    FieldSet {
+        title: None,
+        fields,
+        draw_border: false,
+    }
+}
@@ -7,7 +7,7 @@ use klick_domain::{
     required_output_value_id as required, InputValueId as Id, OutputValueId as Out, Value,
 };
 
-use crate::pages::tool::{CalculationOutcome, Card};
+use crate::pages::tool::{fields::create_field, CalculationOutcome, Card};
 use klick_presenter::{Lng, ValueLabel};
 
 #[allow(clippy::too_many_lines)] // TODO
@@ -77,6 +77,13 @@ pub fn options(
         crate::current_lang(),
     );
 
+    let field_set = field_set3(form_data.write_only(), input_data);
+    let (form3, _, _) = render_field_sets(
+        vec![field_set],
+        accessibility_always_show_option,
+        crate::current_lang(),
+    );
+
     // -----   ----- //
     //     View      //
     // -----   ----- //
@@ -92,11 +99,14 @@ pub fn options(
           <div class = move || { if show_sludge_storage_containers_controls.get() { None } else { Some("hidden") } }>
           {form2}
           </div>
+          {form3}
         <div class="border-t pt-3 mt-4 border-gray-900/10">
           { move || {
               let show_sludge_bags_controls_class = if show_sludge_bags_controls.get() { String::new() } else { "hidden".to_string() };
               let show_sludge_storage_containers_controls_class = if show_sludge_storage_containers_controls.get() { String::new() } else { "hidden".to_string() };
-              outcome.with(|out|out.output.as_ref().map(|out|{
+              outcome.with(|outcome|outcome.output.as_ref().map(|out|{
+                let embodied_emissions =
+                    super::embodied_emissions_rows(outcome, &[Out::DigesterCoverSavings], lang);
                 view! {
                   <dl class="mx-3 my-2 grid grid-cols-2 text-sm">
                     <dt class={ format!("text-lg font-semibold text-right px-3 py-1 text-gray-500 {show_sludge_bags_controls_class}") }>{ Out::Ch4SludgeBags.label(lang) }</dt>
@@ -109,6 +119,12 @@ pub fn options(
                       { crate::current_lang().get().format_number_with_fixed_precision(f64::from(required!(Out::Ch4SludgeStorageContainers, out).unwrap()), 2) }
                       <span class="ml-2 text-gray-400">{ "t CO₂-Äq./a" }</span>
                     </dd>
+                    <dt class="text-lg font-semibold text-right px-3 py-1 text-gray-500">{ Out::DigesterCoverSavings.label(lang) }</dt>
+                    <dd class="text-lg py-1 px-3">
+                      { crate::current_lang().get().format_number_with_fixed_precision(f64::from(required!(Out::DigesterCoverSavings, out).unwrap()), 2) }
+                      <span class="ml-2 text-gray-400">{ "t CO₂-Äq./a" }</span>
+                    </dd>
+                    { embodied_emissions }
                     <dt class="text-lg font-semibold text-right px-3 py-1 text-gray-500">{ Out::TotalEmissions.label(lang) }</dt>
                     <dd class="text-lg py-1 px-3">
                       { crate::current_lang().get().format_number_with_fixed_precision(f64::from(required!(Out::TotalEmissions, out).unwrap()), 2) }
@@ -189,3 +205,20 @@ fn field_set2(form_data: WriteSignal<FormData>, input_data: Signal<FormData>) ->
         draw_border: false,
     }
 }
+
+fn field_set3(form_data: WriteSignal<FormData>, input_data: Signal<FormData>) -> FieldSet {
+    let fields = vec![
+        create_field(form_data, input_data, Id::RecommendationDigesterCoverArea),
+        create_field(form_data, input_data, Id::RecommendationDigesterCoverConcrete),
+        create_field(
+            form_data,
+            input_data,
+            Id::RecommendationDigesterCoverEmbodiedEmissions,
+        ),
+    ];
+    FieldSet {
+        title: None,
+        fields,
+        draw_border: false,
+    }
+}
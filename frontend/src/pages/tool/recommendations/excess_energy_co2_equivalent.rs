@@ -101,7 +101,7 @@ pub fn options(
             </p>
           </Show>
         <div class="border-t pt-3 mt-4 border-gray-900/10">
-        { move || outcome.with(|out|out.output.clone().map(|out|{
+        { move || outcome.with(|outcome|outcome.output.clone().map(|out|{
             let list = [
               (Out::ProcessEnergySavings),
               (Out::FossilEnergySavings),
@@ -109,6 +109,7 @@ pub fn options(
               (Out::WindExpansionSavings),
               (Out::WaterExpansionSavings),
               (Out::DistrictHeatingSavings),
+              (Out::HeatPumpSavings),
             ]
             .into_iter()
             .filter_map(|id| {
@@ -127,9 +128,19 @@ pub fn options(
                 </dd>
             })
             .collect::<Vec<_>>();
+            let embodied_emissions = super::embodied_emissions_rows(
+                outcome,
+                &[
+                    Out::PhotovoltaicExpansionSavings,
+                    Out::WindExpansionSavings,
+                    Out::WaterExpansionSavings,
+                    Out::HeatPumpSavings,
+                ],
+                lang,
+            );
 
             view! {
-              <dl class="mx-3 my-2 grid grid-cols-2 text-sm"> { list } </dl>
+              <dl class="mx-3 my-2 grid grid-cols-2 text-sm"> { list } { embodied_emissions } </dl>
             }
           }))
         }
@@ -144,6 +155,7 @@ fn field_sets(
     lang: Lng,
 ) -> Vec<FieldSet> {
     let draw_border = false;
+    let field = |id| create_field(form_data, input_data, id);
 
     vec![
         FieldSet {
@@ -152,8 +164,8 @@ fn field_sets(
                 Lng::En => Some("Processes and fossil fuels"),
             },
             fields: vec![
-                create_field(form_data, input_data, Id::RecommendationProcessEnergySaving),
-                create_field(form_data, input_data, Id::RecommendationFossilEnergySaving),
+                field(Id::RecommendationProcessEnergySaving),
+                field(Id::RecommendationFossilEnergySaving),
             ],
             draw_border,
         },
@@ -163,16 +175,10 @@ fn field_sets(
                 Lng::En => Some("Photovoltaics"),
             },
             fields: vec![
-                create_field(
-                    form_data,
-                    input_data,
-                    Id::RecommendationPhotovoltaicEnergyExpansion,
-                ),
-                create_field(
-                    form_data,
-                    input_data,
-                    Id::RecommendationEstimatedSelfPhotovolaticUsage,
-                ),
+                field(Id::RecommendationPhotovoltaicEnergyExpansion),
+                field(Id::RecommendationEstimatedSelfPhotovolaticUsage),
+                field(Id::RecommendationPhotovoltaicPeakPower),
+                field(Id::RecommendationPhotovoltaicEmbodiedEmissions),
             ],
             draw_border,
         },
@@ -182,12 +188,9 @@ fn field_sets(
                 Lng::En => Some("Windpower"),
             },
             fields: vec![
-                create_field(form_data, input_data, Id::RecommendationWindEnergyExpansion),
-                create_field(
-                    form_data,
-                    input_data,
-                    Id::RecommendationEstimatedSelfWindEnergyUsage,
-                ),
+                field(Id::RecommendationWindEnergyExpansion),
+                field(Id::RecommendationEstimatedSelfWindEnergyUsage),
+                field(Id::RecommendationWindEmbodiedEmissions),
             ],
             draw_border,
         },
@@ -197,16 +200,9 @@ fn field_sets(
                 Lng::En => Some("Hydropower"),
             },
             fields: vec![
-                create_field(
-                    form_data,
-                    input_data,
-                    Id::RecommendationWaterEnergyExpansion,
-                ),
-                create_field(
-                    form_data,
-                    input_data,
-                    Id::RecommendationEstimatedSelfWaterEnergyUsage,
-                ),
+                field(Id::RecommendationWaterEnergyExpansion),
+                field(Id::RecommendationEstimatedSelfWaterEnergyUsage),
+                field(Id::RecommendationWaterEmbodiedEmissions),
             ],
             draw_border,
         },
@@ -215,11 +211,19 @@ fn field_sets(
                 Lng::De => Some("Abwärmenutzung"),
                 Lng::En => Some("Waste heat utilization"),
             },
-            fields: vec![create_field(
-                form_data,
-                input_data,
-                Id::RecommendationDistrictHeating,
-            )],
+            fields: vec![field(Id::RecommendationDistrictHeating)],
+            draw_border,
+        },
+        FieldSet {
+            title: match lang {
+                Lng::De => Some("Wärmepumpe"),
+                Lng::En => Some("Heat pump"),
+            },
+            fields: vec![
+                field(Id::RecommendationHeatPumpThermalOutput),
+                field(Id::RecommendationHeatPumpCoefficientOfPerformance),
+                field(Id::RecommendationHeatPumpEmbodiedEmissions),
+            ],
             draw_border,
         },
     ]
@@ -1,8 +1,14 @@
+use std::collections::HashMap;
+
 use leptos::*;
 use leptos_fluent::*;
 
-use klick_app_charts::{BarChart, BarChartArguments};
+use klick_app_charts::{
+    BarChart, BarChartArguments, Color, DonutChart, DonutChartArguments, DonutChartSegment,
+    StackedBarChart, StackedBarChartArguments, StackedBarChartSegment,
+};
 use klick_boundary::FormData;
+use klick_domain::{OutputValueId as Out, ValueId as Id};
 
 use crate::{
     pages::tool::{
@@ -12,8 +18,9 @@ use crate::{
     sankey::Sankey,
 };
 
-use klick_presenter::Lng;
+use klick_presenter::{Lng, ValueLabel};
 
+mod ch4_emissions_flare;
 mod ch4_emissions_open_digesters;
 mod ch4_emissions_pre_treatment;
 mod excess_energy_co2_equivalent;
@@ -28,8 +35,10 @@ pub fn Recommendations(
     current_section: RwSignal<PageSection>,
     recommendation_outcome: Signal<CalculationOutcome>,
     sensitivity_outcome: Signal<CalculationOutcome>,
+    profile_outcome: Signal<CalculationOutcome>,
     show_side_stream_controls: Signal<bool>,
     accessibility_always_show_option: Option<RwSignal<bool>>,
+    custom_labels: Signal<HashMap<Id, String>>,
     lang: Signal<Lng>,
 ) -> impl IntoView {
     let old_output = Memo::new(move |_| sensitivity_outcome.with(|out| out.output.clone()));
@@ -51,6 +60,46 @@ pub fn Recommendations(
             })
     });
 
+    let donut_chart_arguments = Memo::new(move |_| {
+        new_output.get().map(|output| {
+            let segment = |(label, value, color)| DonutChartSegment {
+                label,
+                value,
+                color: Color::new(color),
+            };
+            klick_presenter::emission_shares_donut_chart(&output, lang.get())
+                .into_iter()
+                .map(|(group, segments)| DonutChartArguments {
+                    group: segment(group),
+                    segments: segments.into_iter().map(segment).collect(),
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    let stacked_bar_chart_arguments = Memo::new(move |_| {
+        let profile = profile_outcome.with(|out| out.output.clone());
+        klick_presenter::emission_groups_stacked_bar_chart(
+            profile.as_ref(),
+            old_output.get().as_ref(),
+            new_output.get().as_ref(),
+            lang.get(),
+        )
+        .into_iter()
+        .map(|(label, segments)| StackedBarChartArguments {
+            label,
+            segments: segments
+                .into_iter()
+                .map(|(label, value, color)| StackedBarChartSegment {
+                    label,
+                    value,
+                    color: Color::new(color),
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>()
+    });
+
     view! {
         <h4 class="my-8 text-lg font-bold">
           { move_tr!("form_data_table_overview")}
@@ -84,6 +133,14 @@ pub fn Recommendations(
             lang.get()
           )
         }
+        { ch4_emissions_flare::options(
+            form_data,
+            form_data.into(),
+            recommendation_outcome,
+            accessibility_always_show_option,
+            lang.get()
+          )
+        }
         { leak_test::options(accessibility_always_show_option) }
         { excess_energy_co2_equivalent::options(
             form_data,
@@ -104,9 +161,46 @@ pub fn Recommendations(
           }
         </h4>
         { move || recommendation_outcome.with(|out| out.output.clone().zip(out.graph.clone()).map(|(data, graph)|{
-            view!{ <Sankey data graph lang = lang.get() /> }
+            let labels = custom_labels.get();
+            view!{ <Sankey data graph lang = lang.get() labels /> }
           }))
         }
+        <div class="mx-auto p-8" >
+          <h3 class="text-xl font-semibold leading-6 text-gray-900">
+            { move_tr!("recommendation-emission-shares-title") }
+          </h3>
+          <p class="mt-2 max-w-4xl text-lg text-gray-500">
+            { move_tr!("recommendation-emission-shares-description") }
+          </p>
+          { move || donut_chart_arguments.get().map(|data| view! {
+              <DonutChart
+                width = 1100.0
+                height = 500.0
+                data
+                aria_label = Some(move_tr!("aria_label_donut_chart").get())
+                lang = lang.get()
+              />
+            })
+          }
+        </div>
+        <div class="mx-auto p-8" >
+          <h3 class="text-xl font-semibold leading-6 text-gray-900">
+            { move_tr!("recommendation-emission-groups-title") }
+          </h3>
+          <p class="mt-2 max-w-4xl text-lg text-gray-500">
+            { move_tr!("recommendation-emission-groups-description") }
+          </p>
+          { move || view! {
+              <StackedBarChart
+                width = 1100.0
+                height = 350.0
+                data = stacked_bar_chart_arguments.get()
+                aria_label = Some(move_tr!("aria_label_stacked_bar_chart").get())
+                lang = lang.get()
+              />
+            }
+          }
+        </div>
         <div
           class = move || {
             if barchart_arguments.with(|args|args.as_ref().map(|args|args.iter().any(|x| f64::abs(x.value) > 0.1))).unwrap_or(false) {
@@ -151,3 +245,39 @@ pub fn Recommendations(
       </Show>
     }
 }
+
+/// Embodied emissions and amortisation time of the given measures.
+fn embodied_emissions_rows(
+    outcome: &CalculationOutcome,
+    savings: &[Out],
+    lang: Lng,
+) -> Vec<View> {
+    let Some(output) = &outcome.output else {
+        return Vec::new();
+    };
+    let (embodied_emissions_label, amortisation_time_label, not_amortised) = match lang {
+        Lng::De => ("Graue Emissionen", "Amortisationszeit", "keine Einsparung"),
+        Lng::En => ("Embodied emissions", "Amortisation time", "no savings"),
+    };
+    klick_usecases::calculate_amortisations(&outcome.input, output)
+        .into_iter()
+        .filter(|amortisation| savings.contains(&amortisation.measure.savings))
+        .map(|amortisation| {
+            let measure = amortisation.measure.savings.label(lang);
+            let amortisation_time = amortisation.amortisation_time.map_or_else(
+                || not_amortised.to_string(),
+                |years| format!("{} a", lang.format_number_with_fixed_precision(years, 1)),
+            );
+            view! {
+              <dt class="text-lg font-semibold text-right px-3 py-1 text-gray-500">{ format!("{embodied_emissions_label} {measure}") }</dt>
+              <dd class="text-lg py-1 px-3">
+                { lang.format_number_with_fixed_precision(amortisation.embodied_emissions, 2) }
+                <span class="ml-2 text-gray-400">{ "t CO₂-Äq." }</span>
+              </dd>
+              <dt class="text-lg font-semibold text-right px-3 py-1 text-gray-500">{ format!("{amortisation_time_label} {measure}") }</dt>
+              <dd class="text-lg py-1 px-3">{ amortisation_time }</dd>
+            }
+            .into_view()
+        })
+        .collect()
+}
@@ -4,18 +4,22 @@ use leptos_fluent::*;
 use crate::pages::tool::{CalculationOutcome, Card, Cite, InfoBox, DWA_MERKBLATT_URL};
 
 use klick_boundary::FormData;
-use klick_codemirror::CodeMirror;
+use klick_codemirror::{CodeMirror, ErrorMarker};
+use klick_custom_values_parser::{
+    custom_node_names, parse_custom_emissions, parse_custom_values, Diagnostic,
+};
 use klick_domain::{
-    required_output_value_id as required, InputValueId as In, OutputValueId as Out, Value,
+    required_output_value_id as required, InputValueId as In, OutputValueId as Out,
 };
 use klick_presenter::{Lng, ValueLabel};
 
+#[allow(clippy::too_many_lines)] // TODO
 #[component]
 pub fn AdditionalCustomEmissions(
     form_data: RwSignal<FormData>,
+    input: RwSignal<Option<String>>,
     sensitivity_outcome: Signal<CalculationOutcome>,
     accessibility_always_show_option: Option<RwSignal<bool>>,
-    custom_emissions_message: RwSignal<String>,
     lang: Lng,
 ) -> impl IntoView {
     // FIXME
@@ -23,15 +27,24 @@ pub fn AdditionalCustomEmissions(
     // https://github.com/leptos-rs/leptos/discussions/2399
     let helper_node_names = get_all_internal_nodes_names();
 
-    let input_signal: Signal<Option<String>> = Signal::derive(move || {
-        form_data.with(|d| {
-            d.get(&In::SensitivityAdditionalCustomEmissions)
-                .cloned()
-                .map(Value::as_text_unchecked)
+    let custom_emissions_diagnostics = Signal::derive(move || {
+        input.with(|input| {
+            input
+                .as_deref()
+                .and_then(|input| parse_custom_values(input, lang).err())
+                .unwrap_or_default()
         })
     });
 
-    let errors = RwSignal::new(vec![]);
+    let errors = Signal::derive(move || custom_emissions_diagnostics.with(|d| error_markers(d)));
+
+    let completions = Signal::derive(move || {
+        let mut names = internal_nodes_names();
+        if let Some(input) = input.get() {
+            names.extend(custom_node_names(&input));
+        }
+        names
+    });
 
     let namelist = match lang {
         Lng::De => "Namensliste von Sankey-Knoten",
@@ -43,6 +56,29 @@ pub fn AdditionalCustomEmissions(
         Lng::En => "Example",
     };
 
+    let units_description = match lang {
+        Lng::De => {
+            "Ohne Einheit wird der Wert in t CO₂-Äq. angegeben. \
+            Einheiten: t, kg, g, l, m³, kWh; Emissionsfaktoren: kg/l, kg/m³, g/kWh, kg/kWh. \
+            Gase: CO₂e, CH₄, N₂O (CH₄ und N₂O werden mit ihrem Treibhauspotenzial umgerechnet). \
+            Mit \"as\" wird ein Anzeigename für das Sankey-Diagramm und den Bericht vergeben, \
+            mit \"cite\" eine Quellenangabe, die im Anhang des Berichts erscheint. \
+            Mit \"recommend\" wird der Wert für die Handlungsempfehlungen festgelegt, \
+            entweder absolut (z.B. recommend 800 l * 2,65 kg/l) oder als Reduktion (z.B. recommend -30 %). \
+            Alles hinter # ist ein Kommentar."
+        }
+        Lng::En => {
+            "Without a unit the value is given in t CO₂-eq. \
+            Units: t, kg, g, l, m³, kWh; emission factors: kg/l, kg/m³, g/kWh, kg/kWh. \
+            Gases: CO₂e, CH₄, N₂O (CH₄ and N₂O are converted with their global warming potential). \
+            Use \"as\" to set a display name for the Sankey chart and the report \
+            and \"cite\" to add a source that is listed in the appendix of the report. \
+            Use \"recommend\" to set the value for the recommendations, \
+            either absolute (e.g. recommend 800 l * 2.65 kg/l) or as a reduction (e.g. recommend -30 %). \
+            Everything after # is a comment."
+        }
+    };
+
     view! {
       <Card
         id = "sensitivity-misc-emissions"
@@ -54,12 +90,20 @@ pub fn AdditionalCustomEmissions(
         { move_tr!("sensitivity-custom-emissions-description") }
         </p>
         <CodeMirror
-          input = input_signal
+          input = input.into()
           on_change = Callback::new(move |value: Option<String>| {
+              input.set(value.clone());
+              let emissions = match value.as_deref().map(|v| parse_custom_emissions(v, lang)) {
+                  Some(Ok(emissions)) if !emissions.is_empty() => Some(emissions),
+                  Some(Ok(_)) | None => None,
+                  // Keep the text and the last valid custom emissions,
+                  // saving is blocked until the errors are fixed.
+                  Some(Err(_)) => return,
+              };
               form_data.update(|d|{
-                match value {
-                    Some(v) => {
-                      d.insert(In::SensitivityAdditionalCustomEmissions, Value::text(v));
+                match emissions {
+                    Some(emissions) => {
+                      d.insert(In::SensitivityAdditionalCustomEmissions, emissions.to_value());
                     }
                     None => {
                       d.remove(&In::SensitivityAdditionalCustomEmissions);
@@ -67,19 +111,35 @@ pub fn AdditionalCustomEmissions(
                 }
               });
           })
-          errors = errors.into()
+          errors
+          completions
         />
         <Show when = move || sensitivity_outcome.with(|out|out.output.is_some())>
-          <p class="mt-2 text-sm" style="color: red">
-            { custom_emissions_message }
-          </p>
+          <ul class="mt-2 text-sm" style="color: red">
+            <For
+              each = move || custom_emissions_diagnostics.get()
+              key = |d| (d.line, d.message.clone())
+              let:diagnostic
+            >
+              <li>{ diagnostic.message }</li>
+            </For>
+          </ul>
         </Show>
         <InfoBox text = "Syntax".to_string() accessibility_always_show_option>
           <Cite source = "".to_string() url = DWA_MERKBLATT_URL>
             <pre>
               "\"ID\" \"ID\"\n"
-              "\"ID\" NUM \"ID\""
+              "\"ID\" NUM \"ID\"\n"
+              "\"ID\" NUM EINHEIT [GAS] \"ID\"\n"
+              "\"ID\" NUM EINHEIT * NUM EINHEIT [GAS] \"ID\"\n"
+              "\"ID\" as \"NAME\" NUM \"ID\" cite \"QUELLE\"\n"
+              "\"ID\" NUM \"ID\" recommend NUM [EINHEIT] [* NUM EINHEIT] [GAS]\n"
+              "\"ID\" NUM \"ID\" recommend -NUM %\n"
+              "# Kommentar"
             </pre>
+            <p class="mt-2">
+              { units_description }
+            </p>
           </Cite>
         </InfoBox>
         <InfoBox text = namelist.to_string() accessibility_always_show_option>
@@ -94,7 +154,10 @@ pub fn AdditionalCustomEmissions(
               "\"Kettensäge\" 400 \"Fällmittel\"\n"
               "\"Eisen(II)-sulfat\" 200 \"Fällmittel\"\n"
               "\"Fällmittel\" \"OperatingMaterials\"\n"
-              "\"Abfalldeponie\" 23 \"Ch4Emissions\""
+              "\"Abfalldeponie\" 23 \"Ch4Emissions\"\n"
+              "# Betrieb\n"
+              "\"diesel\" as \"Notstromdiesel\" 1200 l * 2,65 kg/l \"IndirectEmissions\" recommend -30 % cite \"UBA 2023\"\n"
+              "\"Lachgasmessung\" 12,5 kg N₂O \"N2oEmissions\""
             </pre>
           </Cite>
         </InfoBox>
@@ -125,12 +188,30 @@ pub fn AdditionalCustomEmissions(
 }
 
 fn get_all_internal_nodes_names() -> String {
-    klick_usecases::get_all_internal_nodes()
+    internal_nodes_names()
         .iter()
         .fold(String::new(), |mut acc, entry| {
             let sep = if acc.is_empty() { "" } else { ", " };
-            let n = format!("{sep}\"{entry:?}\"");
+            let n = format!("{sep}\"{entry}\"");
             acc.push_str(&n);
             acc
         })
 }
+
+fn error_markers(diagnostics: &[Diagnostic]) -> Vec<ErrorMarker> {
+    diagnostics
+        .iter()
+        .map(|d| ErrorMarker {
+            line: d.line.saturating_sub(1),
+            columns: d.columns.clone(),
+            message: d.message.clone(),
+        })
+        .collect()
+}
+
+fn internal_nodes_names() -> Vec<String> {
+    klick_usecases::get_all_internal_nodes()
+        .iter()
+        .map(|id| format!("{id:?}"))
+        .collect()
+}
@@ -1,19 +1,21 @@
 use leptos::*;
 use leptos_fluent::*;
 use num_traits::{FromPrimitive, ToPrimitive};
+use strum::IntoEnumIterator;
 
 use klick_app_charts::BarChartRadioInput;
 use klick_app_components::forms::*;
 use klick_boundary::FormData;
 use klick_domain::{
+    chp_units::{self, ChpUnit},
     required_output_value_id as required,
-    units::{Ch4ChpEmissionFactorCalcMethod, Tons},
+    units::{Ch4ChpEmissionFactorCalcMethod, Percent, Qubicmeters, Tons},
     InputValueId as Id, OutputValueId as Out, Value,
 };
-use klick_presenter::{Lng, ValueLabel};
+use klick_presenter::{FieldMetaData, Lng, ValueLabel};
 
 use crate::{
-    current_lang,
+    current_lang, label_signal,
     pages::tool::{
         fields::create_field, CalculationOutcome, Card, Cite, InfoBox, DWA_MERKBLATT_URL,
     },
@@ -38,11 +40,23 @@ pub fn CH4EmissionsCHP(
                 .map(Value::as_ch4_chp_emission_factor_calc_method_unchecked)
         })
     });
+    let scenario_methods = Signal::derive(move || {
+        sensitivity_outcome.with(|out| {
+            out.sensitivity_ch4_chp_calculations
+                .iter()
+                .flatten()
+                .map(|(method, _, _)| *method)
+                .collect::<Vec<_>>()
+        })
+    });
     let selected_scenario_index = Signal::derive(move || {
-        selected_scenario
-            .get()
-            .as_ref()
-            .and_then(ToPrimitive::to_u64)
+        let selected = selected_scenario.get()?;
+        scenario_methods.with(|methods| {
+            methods
+                .iter()
+                .position(|method| *method == selected)
+                .and_then(|idx| idx.to_u64())
+        })
     });
     let show_ch4_chp = Signal::derive(move || {
         sensitivity_outcome.with(|out| {
@@ -63,12 +77,50 @@ pub fn CH4EmissionsCHP(
         current_lang(),
     ); // FIXME not sure if this works
 
+    let unit_count = Memo::new(move |_| form_data.with(|d| read_chp_units(d).len()));
+    let chp_units_view = move || {
+        let field_sets = (0..unit_count.get())
+            .map(|index| chp_unit_field_set(form_data, index))
+            .collect();
+        let (views, _, _) =
+            render_field_sets(field_sets, accessibility_always_show_option, current_lang());
+        views
+            .into_iter()
+            .enumerate()
+            .map(|(index, view)| {
+                view! {
+                  { view }
+                  <button
+                    type = "button"
+                    class="rounded bg-gray-100 px-2 py-1 text-sm font-semibold text-black shadow-sm"
+                    on:click = move |_| update_chp_units(form_data, |units| { units.remove(index); })
+                  >
+                    { move_tr!("chp-unit-remove") }
+                  </button>
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    let add_chp_unit = move |_| {
+        update_chp_units(form_data, |units| {
+            units.push(ChpUnit {
+                calculation_method: Ch4ChpEmissionFactorCalcMethod::default(),
+                custom_factor: None,
+                gas_share: None,
+                gas_volume: None,
+            });
+        });
+    };
+
     // -----   ----- //
     //   Callbacks   //
     // -----   ----- //
 
-    let on_bar_chart_input_changed = move |idx| {
-        let Some(method) = Ch4ChpEmissionFactorCalcMethod::from_u64(idx) else {
+    let on_bar_chart_input_changed = move |idx: u64| {
+        let method = usize::try_from(idx)
+            .ok()
+            .and_then(|idx| scenario_methods.with(|methods| methods.get(idx).copied()));
+        let Some(method) = method else {
             log::warn!("Invalid index {idx} for selection of calc method");
             return;
         };
@@ -165,6 +217,21 @@ pub fn CH4EmissionsCHP(
           { chp_view }
           { chp_custom_factor_warning_view }
 
+          <h3 class="mt-6 text-lg font-semibold leading-7 text-gray-900">
+            { label_signal(Id::SensitivityCH4ChpUnits) }
+          </h3>
+          <p class="mt-2 text-sm">
+            { move || FieldMetaData::lookup(current_lang().get(), "description-sensitivity-ch4-chp-units") }
+          </p>
+          { chp_units_view }
+          <button
+            type = "button"
+            class="mt-4 rounded bg-primary px-2 py-1 text-sm font-semibold text-black shadow-sm"
+            on:click = add_chp_unit
+          >
+            { move_tr!("chp-unit-add") }
+          </button>
+
             <div class="border-t pt-3 mt-4 border-gray-900/10">
               { move ||
                 sensitivity_outcome.with(|outcome|
@@ -196,10 +263,162 @@ pub fn CH4EmissionsCHP(
 fn field_set(form_data: WriteSignal<FormData>, input_data: Signal<FormData>) -> FieldSet {
     let custom_factor_field =
         create_field(form_data, input_data, Id::SensitivityCH4ChpCustomFactor);
-    let fields = vec![custom_factor_field];
     FieldSet {
         title: None,
-        fields,
+        fields: vec![custom_factor_field],
+        draw_border: false,
+    }
+}
+
+fn read_chp_units(form_data: &FormData) -> Vec<ChpUnit> {
+    form_data
+        .get(&Id::SensitivityCH4ChpUnits)
+        .and_then(|value| chp_units::from_value(value).ok())
+        .unwrap_or_default()
+}
+
+fn update_chp_units(form_data: RwSignal<FormData>, f: impl FnOnce(&mut Vec<ChpUnit>)) {
+    form_data.update(|d| {
+        let mut units = read_chp_units(d);
+        f(&mut units);
+        if units.is_empty() {
+            d.remove(&Id::SensitivityCH4ChpUnits);
+        } else {
+            d.insert(Id::SensitivityCH4ChpUnits, chp_units::to_value(&units));
+        }
+    });
+}
+
+fn chp_unit_label(index: usize, key: &'static str) -> Signal<String> {
+    let lang = current_lang();
+    Signal::derive(move || {
+        let lang = lang.get();
+        format!(
+            "{} {} {}",
+            FieldMetaData::lookup(lang, "chp-unit"),
+            index + 1,
+            FieldMetaData::lookup(lang, key)
+        )
+    })
+}
+
+fn update_chp_unit(form_data: RwSignal<FormData>, index: usize, f: impl FnOnce(&mut ChpUnit)) {
+    update_chp_units(form_data, |units| {
+        if let Some(unit) = units.get_mut(index) {
+            f(unit);
+        }
+    });
+}
+
+fn chp_unit_float_field(
+    form_data: RwSignal<FormData>,
+    index: usize,
+    (label_key, description_key): (&'static str, &'static str),
+    limits: MinMax<f64>,
+    unit: &'static str,
+    get: fn(&ChpUnit) -> Option<f64>,
+    set: fn(&mut ChpUnit, Option<f64>),
+) -> Field {
+    Field {
+        label: chp_unit_label(index, label_key),
+        description: Some(FieldMetaData::lookup(
+            current_lang().get_untracked(),
+            description_key,
+        )),
+        required: false,
+        field_type: FieldType::Float {
+            initial_value: None,
+            placeholder: None,
+            limits,
+            unit,
+            on_change: Callback::new(move |v: Option<f64>| {
+                update_chp_unit(form_data, index, |unit| set(unit, v));
+            }),
+            input: Signal::derive(move || {
+                form_data.with(|d| read_chp_units(d).get(index).and_then(get))
+            }),
+        },
+    }
+}
+
+fn chp_unit_field_set(form_data: RwSignal<FormData>, index: usize) -> FieldSet {
+    let lang = current_lang().get_untracked();
+    let percent_limits = MinMax {
+        min: Some(0.0),
+        max: Some(100.0),
+    };
+    let calculation_method = Field {
+        label: chp_unit_label(index, "chp-unit-calculation-method"),
+        description: Some(FieldMetaData::lookup(
+            lang,
+            "description-sensitivity-ch4-chp-unit-calculation-method",
+        )),
+        required: true,
+        field_type: FieldType::DropDown {
+            initial_value: None,
+            options: Ch4ChpEmissionFactorCalcMethod::iter()
+                .filter(|method| *method != Ch4ChpEmissionFactorCalcMethod::Mix)
+                .map(|method| method.label(lang))
+                .collect(),
+            on_change: Callback::new(move |idx: Option<usize>| {
+                let Some(method) = idx.and_then(Ch4ChpEmissionFactorCalcMethod::from_usize) else {
+                    return;
+                };
+                update_chp_unit(form_data, index, |unit| {
+                    unit.calculation_method = method;
+                });
+            }),
+            input: Signal::derive(move || {
+                form_data.with(|d| {
+                    read_chp_units(d)
+                        .get(index)
+                        .and_then(|unit| unit.calculation_method.to_usize())
+                })
+            }),
+        },
+    };
+    let custom_factor = chp_unit_float_field(
+        form_data,
+        index,
+        (
+            "chp-unit-custom-factor",
+            "description-sensitivity-CH4-chp-custom-factor",
+        ),
+        percent_limits,
+        "%",
+        |unit| unit.custom_factor.map(f64::from),
+        |unit, v| unit.custom_factor = v.map(Percent::new),
+    );
+    let gas_share = chp_unit_float_field(
+        form_data,
+        index,
+        (
+            "chp-unit-gas-share",
+            "description-sensitivity-ch4-chp-unit-gas-share",
+        ),
+        percent_limits,
+        "%",
+        |unit| unit.gas_share.map(f64::from),
+        |unit, v| unit.gas_share = v.map(Percent::new),
+    );
+    let gas_volume = chp_unit_float_field(
+        form_data,
+        index,
+        (
+            "chp-unit-gas-volume",
+            "description-sensitivity-ch4-chp-unit-gas-volume",
+        ),
+        MinMax {
+            min: Id::ProfileSewageGasProduced.min(),
+            max: Id::ProfileSewageGasProduced.max(),
+        },
+        "m³",
+        |unit| unit.gas_volume.map(f64::from),
+        |unit, v| unit.gas_volume = v.map(Qubicmeters::new),
+    );
+    FieldSet {
+        title: None,
+        fields: vec![calculation_method, custom_factor, gas_share, gas_volume],
         draw_border: false,
     }
 }
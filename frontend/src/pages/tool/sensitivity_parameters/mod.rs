@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
 use klick_presenter::Lng;
 use leptos::*;
 use leptos_fluent::*;
 
 use klick_app_charts::{BarChart, BarChartArguments};
 use klick_boundary::FormData;
+use klick_domain::ValueId as Id;
 
 use crate::{
     current_lang,
@@ -27,12 +30,13 @@ use self::{
 #[component]
 pub fn SensitivityParameters(
     form_data: RwSignal<FormData>,
+    custom_emissions_input: RwSignal<Option<String>>,
     current_section: RwSignal<PageSection>,
     sensitivity_outcome: Signal<CalculationOutcome>,
     profile_outcome: Signal<CalculationOutcome>,
     show_side_stream_controls: Signal<bool>,
     accessibility_always_show_option: Option<RwSignal<bool>>,
-    custom_emissions_message: RwSignal<String>,
+    custom_labels: Signal<HashMap<Id, String>>,
     lang: Lng,
 ) -> impl IntoView {
     let old_output = Memo::new(move |_| profile_outcome.with(|out| out.output.clone()));
@@ -96,9 +100,9 @@ pub fn SensitivityParameters(
         />
         <AdditionalCustomEmissions
           form_data
+          input = custom_emissions_input
           sensitivity_outcome
           accessibility_always_show_option
-          custom_emissions_message
           lang
         />
 
@@ -116,7 +120,8 @@ pub fn SensitivityParameters(
 
         { move || sensitivity_outcome.with(|out| out.output.clone().zip(out.graph.clone()).map(|(data, graph)|{
             let lang = current_lang().get();
-            view!{ <Sankey data graph lang/> }
+            let labels = custom_labels.get();
+            view!{ <Sankey data graph lang labels /> }
           }))
         }
 
@@ -1,3 +1,4 @@
+use gloo_file::File;
 use leptos::*;
 use leptos_fluent::*;
 use num_traits::{FromPrimitive, ToPrimitive};
@@ -9,9 +10,9 @@ use klick_domain::{
     required_output_value_id as required, units::N2oEmissionFactorCalcMethod, CalculationOutcome,
     InputValueId as In, OutputValueId as Out, Value,
 };
-use klick_presenter::{Lng, ValueLabel};
+use klick_presenter::{n2o_measurement_campaign_as_table, Formatting, Lng, ValueLabel};
 
-use crate::pages::tool::{fields::create_field, Card};
+use crate::pages::tool::{fields::create_field, plant_profile::table_view, Card};
 
 #[allow(clippy::too_many_lines)] // TODO
 #[component]
@@ -62,6 +63,44 @@ pub fn N2OEmissionsSensitivity(
     //   Callbacks   //
     // -----   ----- //
 
+    let measurement_upload_input: NodeRef<leptos::html::Input> = create_node_ref();
+    let measurement_import_error = RwSignal::new(None::<String>);
+
+    let measurement_upload_action = create_action(move |file: &File| {
+        let file = file.clone();
+        async move {
+            let csv = match gloo_file::futures::read_as_text(&file).await {
+                Ok(csv) => csv,
+                Err(err) => {
+                    log::warn!("Unable to upload measurement data: {err}");
+                    measurement_import_error.set(Some(err.to_string()));
+                    return;
+                }
+            };
+            let campaign = form_data.with_untracked(|d| {
+                klick_usecases::import_n2o_measurement_campaign(&csv, d)
+            });
+            match campaign {
+                Ok(campaign) => {
+                    measurement_import_error.set(None);
+                    form_data.update(|d| {
+                        d.extend(campaign.input_values());
+                        d.insert(
+                            In::SensitivityN2OCalculationMethod,
+                            Value::n2o_emission_factor_calc_method(
+                                N2oEmissionFactorCalcMethod::Measured,
+                            ),
+                        );
+                    });
+                }
+                Err(err) => {
+                    log::warn!("Unable to import measurement data: {err}");
+                    measurement_import_error.set(Some(err.to_string()));
+                }
+            }
+        }
+    });
+
     let on_bar_chart_input_changed = move |idx| {
         let Some(method) = N2oEmissionFactorCalcMethod::from_u64(idx) else {
             log::warn!("Invalid index {idx} for selection of calc method");
@@ -124,6 +163,29 @@ pub fn N2OEmissionsSensitivity(
         }
     };
 
+    let n2o_measurement_view = move || {
+        let values = form_data.with(|d| {
+            d.contains_key(&In::SensitivityN2OMeasuredFactor).then(|| {
+                d.iter()
+                    .map(|(id, value)| ((*id).into(), value.clone()))
+                    .collect()
+            })
+        })?;
+        let table = n2o_measurement_campaign_as_table(&values, Formatting::Text, lang);
+        Some(table_view(table))
+    };
+
+    let n2o_measurement_import_error_view = move || {
+        measurement_import_error.get().map(|err| {
+            view! {
+              <div class="bg-red-100 border-l-4 border-red-500 text-red-700 p-4" role="alert">
+                <p class="font-bold">{ move_tr!("n2o_measurement-import-error") }</p>
+                { err }
+              </div>
+            }
+        })
+    };
+
     view! {
       <Card id = "sensitivity-n2o" title = move_tr!("sensitivity-n2o").get() bg_color="bg-blue" accessibility_always_show_option>
         <div class="my-4 ml-4">
@@ -157,6 +219,33 @@ pub fn N2OEmissionsSensitivity(
           { n2o_custom_factor_view }
           { n2o_custom_factor_warning_view }
 
+          <h4 class="mt-6 font-semibold leading-7 text-gray-900">{ move_tr!("n2o_measurement-h4") }</h4>
+
+          <p class="my-2">
+            { move_tr!("n2o_measurement-p-1") }
+          </p>
+
+          <input
+            class = "block text-sm bg-gray-50 rounded-md shadow-sm file:bg-primary file:rounded-md file:border-0 file:mr-4 file:py-1 file:px-2 file:font-semibold"
+            type="file"
+            accept=".csv,text/csv"
+            node_ref=measurement_upload_input
+            on:change = move |ev| {
+              ev.prevent_default();
+              let Some(file_list) = measurement_upload_input.get().and_then(|input| input.files()) else {
+                  log::debug!("No file list");
+                  return;
+              };
+              let Some(file) = file_list.item(0) else {
+                  log::debug!("No file selected");
+                  return;
+              };
+              measurement_upload_action.dispatch(File::from(file));
+            }
+          />
+          { n2o_measurement_import_error_view }
+          { n2o_measurement_view }
+
           <div class = move || { if show_side_stream_controls.get() { None } else { Some("hidden") } } >
 
             <h3 class="mt-6 text-lg font-semibold leading-7 text-gray-900">{move_tr!("n2o_emissions-h3-2").get()}</h3>